@@ -1,44 +1,24 @@
-use log::info;
-use openai::{chat::{ChatCompletion, ChatCompletionMessage, ChatCompletionMessageRole, ChatCompletionResponseFormat}, Credentials};
+use log::{info, warn};
 use schemars::schema_for;
 use serde::Deserialize;
+use serde_derive::Serialize;
 
-/// Model used for chronicle naming + lore. `gpt-5-mini` is plenty for this
-/// light creative/structured work and ~5x cheaper than the old `gpt-4o`
-/// (pennies per settlement at our volume). Override with `OPENAI_MODEL`.
-const DEFAULT_MODEL: &str = "gpt-5-mini";
+use super::{provider::{configured_provider, ChatRequest, LlmProvider, TokenUsage, Turn}, schema::validate};
 
-fn model() -> String {
-    std::env::var("OPENAI_MODEL").unwrap_or_else(|_| DEFAULT_MODEL.to_string())
-}
+/// Replies a JSON call asks for before giving up: the first, and a repair turn
+/// for each that didn't fit the schema.
+pub const JSON_ATTEMPTS: usize = 3;
 
-/// Core chat call. With `json_mode`, asks the API for a single valid JSON object
-/// (no prose, no fenced block) — the OPENAI requires the word "json" in the
-/// prompt, which the JSON callers include.
-async fn chat(system: &str, user: &str, json_mode: bool) -> anyhow::Result<String> {
-    // Relies on OPENAI_KEY and optionally OPENAI_BASE_URL.
-    let credentials = Credentials::from_env();
-    let messages = vec![
-        ChatCompletionMessage {role:ChatCompletionMessageRole::System,content:Some(system.to_string()),name:None,function_call:None, tool_call_id: None, tool_calls: None },
-        ChatCompletionMessage {role:ChatCompletionMessageRole::User,content:Some(user.to_string()),name:None,function_call:None, tool_call_id: None, tool_calls: None },
-    ];
-    let mut builder = ChatCompletion::builder(&model(), messages.clone())
-        .credentials(credentials.clone());
-    if json_mode {
-        builder = builder.response_format(ChatCompletionResponseFormat::json_object());
-    }
-    let chat_completion = builder.create().await?;
-    let returned_message = chat_completion.choices.first()
-        .ok_or_else(|| anyhow::anyhow!("No choices returned from AI"))?.message.clone();
-
-    let content = returned_message.content
-        .ok_or_else(|| anyhow::anyhow!("AI returned empty content"))?;
-    let string_content = content.trim();
-    Ok(string_content.to_string())
+/// The configured model (see [`configured_provider`]), or an error saying none is.
+fn provider() -> anyhow::Result<Box<dyn LlmProvider>> {
+    configured_provider().ok_or_else(|| anyhow::anyhow!("No LLM configured; set OPENAI_KEY or LLM_PROVIDER"))
 }
 
 pub async fn get_ai_message(system :&str, user:&str) -> anyhow::Result<String> {
-    chat(system, user, false).await
+    let request = ChatRequest::new(system, user, false);
+    let reply = provider()?.chat(&request).await?;
+    log_usage(&reply.usage);
+    Ok(reply.text)
 }
 
 pub fn extract_json(response : &str) -> Option<String> {
@@ -55,24 +35,117 @@ pub fn extract_json(response : &str) -> Option<String> {
     None
 }
 
+fn log_usage(usage: &Option<TokenUsage>) {
+    match usage {
+        Some(u) => info!("AI call took {} prompt + {} completion tokens", u.prompt_tokens, u.completion_tokens),
+        None => info!("AI call took no counted tokens"),
+    }
+}
+
+/// One model call of a JSON request, for the run report.
+#[derive(Debug, Clone, PartialEq, Serialize, serde_derive::Deserialize)]
+pub struct AiCall {
+    pub model: String,
+    /// The request's 1-based attempt: the first reply, then each repair.
+    pub attempt: usize,
+    pub usage: Option<TokenUsage>,
+    /// What was wrong with the reply; empty when it was accepted.
+    pub errors: Vec<String>,
+}
+
+/// Every call a run made, in order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, serde_derive::Deserialize)]
+pub struct AiLog {
+    pub calls: Vec<AiCall>,
+}
+
+impl AiLog {
+    /// Tokens over every call.
+    pub fn usage(&self) -> TokenUsage {
+        let mut total = TokenUsage::default();
+        for usage in self.calls.iter().filter_map(|call| call.usage) {
+            total += usage;
+        }
+        total
+    }
+}
+
+/// [`try_ai_json_with`] the configured model; `None` when there is none.
 pub async fn try_ai_json<T>(query : &str) -> Option<T>
 where T: for<'de> Deserialize<'de> + schemars::JsonSchema {
-    let schema = serde_json::to_string_pretty(&schema_for!(T)).unwrap();
-    // JSON mode makes the API return a single valid JSON object directly (no
-    // prose, no fenced block), so we can parse the response as-is.
-    let response = match chat(&format!("You are a helpful assistant. Format your response as a JSON object matching this schema: {}. Do NOT include the schema in the response.", schema), query, true).await {
-        Ok(r) => r,
+    match provider() {
+        Ok(provider) => try_ai_json_with(provider.as_ref(), query).await,
         Err(e) => {
             log::error!("AI request failed: {e}");
-            return None;
+            None
         }
-    };
+    }
+}
 
-    info!("AI Response: {}", response);
+/// [`ai_json`] with nowhere to record the calls but the log; `None` on failure.
+pub async fn try_ai_json_with<T>(provider : &dyn LlmProvider, query : &str) -> Option<T>
+where T: for<'de> Deserialize<'de> + schemars::JsonSchema {
+    match ai_json(provider, query, JSON_ATTEMPTS, &mut AiLog::default()).await {
+        Ok(value) => Some(value),
+        Err(e) => {
+            log::error!("AI request failed: {e:#}");
+            None
+        }
+    }
+}
 
-    // The response should already be raw JSON; fall back to stripping a fenced
-    // block just in case a model wraps it anyway.
-    let json_response = extract_json(&response).unwrap_or_else(|| response.to_string());
+/// Asks `provider` for a `T` as JSON, checks the reply against `T`'s schema and,
+/// while attempts remain, sends the model back its own reply with the fields to
+/// fix. Every call goes into `log`. A transport error ends it at once; running
+/// out of attempts drops the whole exchange from any cache, so a retry starts
+/// over rather than replaying it.
+pub async fn ai_json<T>(provider : &dyn LlmProvider, query : &str, attempts : usize, log : &mut AiLog) -> anyhow::Result<T>
+where T: for<'de> Deserialize<'de> + schemars::JsonSchema {
+    let schema = serde_json::to_value(schema_for!(T))?;
+    // JSON mode makes the API return a single valid JSON object directly (no
+    // prose, no fenced block), so we can parse the response as-is.
+    let mut request = ChatRequest::new(
+        format!("You are a helpful assistant. Format your response as a JSON object matching this schema: {}. Do NOT include the schema in the response.", serde_json::to_string_pretty(&schema)?),
+        query,
+        true,
+    );
+    let mut sent = Vec::new();
+    let mut errors = Vec::new();
+    for attempt in 1..=attempts.max(1) {
+        let reply = provider.chat(&request).await?;
+        info!("AI Response: {}", reply.text);
+        log_usage(&reply.usage);
+
+        // The response should already be raw JSON; fall back to stripping a
+        // fenced block just in case a model wraps it anyway.
+        let json_response = extract_json(&reply.text).unwrap_or_else(|| reply.text.clone());
+        errors = match serde_json::from_str::<serde_json::Value>(&json_response) {
+            Err(e) => vec![format!("not valid JSON: {e}")],
+            Ok(value) => match validate(&schema, &value) {
+                errors if !errors.is_empty() => errors,
+                _ => match serde_json::from_value::<T>(value) {
+                    Ok(parsed) => {
+                        log.calls.push(AiCall { model: provider.model(), attempt, usage: reply.usage, errors: Vec::new() });
+                        return Ok(parsed);
+                    }
+                    Err(e) => vec![format!("$: {e}")],
+                },
+            },
+        };
+        for error in &errors {
+            warn!("{} reply {attempt}/{attempts}: {error}", provider.model());
+        }
+        log.calls.push(AiCall { model: provider.model(), attempt, usage: reply.usage, errors: errors.clone() });
 
-    serde_json::from_str(&json_response).unwrap_or(None)
-}
\ No newline at end of file
+        let answer = format!(
+            "That reply does not match the schema:\n- {}\nReply again with the whole corrected JSON object.",
+            errors.join("\n- "),
+        );
+        sent.push(request.clone());
+        request.turns.push(Turn { reply: reply.text, answer });
+    }
+    for request in &sent {
+        provider.reject(request);
+    }
+    anyhow::bail!("{} gave no valid reply in {} attempts; last: {}", provider.model(), attempts.max(1), errors.join("; "))
+}
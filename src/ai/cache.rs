@@ -0,0 +1,228 @@
+//! An on-disk cache of model responses, content-addressed by the model and the
+//! prompt. The same seed builds the same dossier and so the same prompt, so
+//! with the cache a rerun gets the chronicle it got before — without paying
+//! for it — and with the cache pinned, a run can be reproduced byte for byte
+//! from a cache directory checked in beside it.
+//!
+//! `LLM_CACHE` picks the [`CacheMode`] and `LLM_CACHE_DIR` the directory
+//! (default `ai_cache`). Each response is a JSON file named for its key, with
+//! the prompt it answered beside it so a cache can be read and pruned by hand.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use log::{info, warn};
+use serde_derive::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use super::provider::{ChatReply, ChatRequest, LlmProvider};
+
+const DEFAULT_CACHE_DIR: &str = "ai_cache";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Every request goes to the model.
+    Off,
+    /// Answer from the cache when it can, and cache what the model answers.
+    On,
+    /// Answer only from the cache: a miss is an error, never a call out.
+    Pinned,
+}
+
+impl CacheMode {
+    /// `LLM_CACHE=off|on|pinned`, on when unset.
+    pub fn from_env() -> Self {
+        match std::env::var("LLM_CACHE").ok().as_deref().map(str::trim) {
+            None | Some("" | "on") => CacheMode::On,
+            Some("off") => CacheMode::Off,
+            Some("pinned") => CacheMode::Pinned,
+            Some(other) => {
+                warn!("Unknown LLM_CACHE `{other}` (off, on, pinned); caching");
+                CacheMode::On
+            }
+        }
+    }
+}
+
+pub fn cache_dir_from_env() -> PathBuf {
+    std::env::var("LLM_CACHE_DIR").map_or_else(|_| PathBuf::from(DEFAULT_CACHE_DIR), PathBuf::from)
+}
+
+/// The cache key of `request` to `model`: the SHA-1 of everything that shapes
+/// the answer, each part length-prefixed so no two prompts run together alike.
+pub fn cache_key(model: &str, request: &ChatRequest) -> String {
+    let mut hasher = Sha1::new();
+    let mode = if request.json { "json" } else { "text" };
+    let turns = request.turns.iter().flat_map(|turn| [turn.reply.as_str(), turn.answer.as_str()]);
+    for part in [model, &request.system, &request.user, mode].into_iter().chain(turns) {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    hasher.finalize().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// One cached response, with the request it answered.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    model: String,
+    system: String,
+    user: String,
+    /// Replies and answers since `user`, alternating.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    turns: Vec<String>,
+    json: bool,
+    response: String,
+}
+
+/// A provider answered from a [`CacheMode`] cache in front of it.
+pub struct CachedProvider {
+    inner: Box<dyn LlmProvider>,
+    dir: PathBuf,
+    mode: CacheMode,
+}
+
+impl CachedProvider {
+    pub fn new(inner: Box<dyn LlmProvider>, dir: impl Into<PathBuf>, mode: CacheMode) -> Self {
+        Self { inner, dir: dir.into(), mode }
+    }
+
+    fn path(&self, request: &ChatRequest) -> PathBuf {
+        self.dir.join(format!("{}.json", cache_key(&self.inner.model(), request)))
+    }
+
+    fn read(path: &Path) -> anyhow::Result<Option<String>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let entry: CacheEntry = serde_json::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(entry.response))
+    }
+
+    fn write(&self, path: &Path, request: &ChatRequest, response: &str) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.dir).with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let entry = CacheEntry {
+            model: self.inner.model(),
+            system: request.system.clone(),
+            user: request.user.clone(),
+            turns: request.turns.iter().flat_map(|turn| [turn.reply.clone(), turn.answer.clone()]).collect(),
+            json: request.json,
+            response: response.to_string(),
+        };
+        std::fs::write(path, serde_json::to_string_pretty(&entry)?).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[async_trait]
+impl LlmProvider for CachedProvider {
+    fn model(&self) -> String {
+        self.inner.model()
+    }
+
+    async fn chat(&self, request: &ChatRequest) -> anyhow::Result<ChatReply> {
+        if self.mode == CacheMode::Off {
+            return self.inner.chat(request).await;
+        }
+        let path = self.path(request);
+        if let Some(response) = Self::read(&path)? {
+            info!("Answered from {}", path.display());
+            return Ok(ChatReply { text: response, usage: None });
+        }
+        if self.mode == CacheMode::Pinned {
+            bail!("No cached {} response at {} and LLM_CACHE=pinned", self.inner.model(), path.display());
+        }
+        let response = self.inner.chat(request).await?;
+        // A response that can't be cached is still a response.
+        if let Err(e) = self.write(&path, request, &response.text) {
+            warn!("{e:#}");
+        }
+        Ok(response)
+    }
+
+    /// Drops the cached answer, so the retry asks the model again rather than
+    /// getting the same unusable response back. A pinned cache is left alone.
+    fn reject(&self, request: &ChatRequest) {
+        self.inner.reject(request);
+        if self.mode != CacheMode::On {
+            return;
+        }
+        let path = self.path(request);
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("Failed to remove {}: {e}", path.display());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::ai::provider::{TokenUsage, Turn};
+
+    /// Answers `answer {n}` to its nth call, to tell calls from cache hits.
+    struct Counter(AtomicUsize);
+
+    #[async_trait]
+    impl LlmProvider for Counter {
+        fn model(&self) -> String {
+            "counter".to_string()
+        }
+
+        async fn chat(&self, _request: &ChatRequest) -> anyhow::Result<ChatReply> {
+            let text = format!("answer {}", self.0.fetch_add(1, Ordering::SeqCst));
+            Ok(ChatReply { text, usage: Some(TokenUsage { prompt_tokens: 10, completion_tokens: 2 }) })
+        }
+    }
+
+    fn request(user: &str) -> ChatRequest {
+        ChatRequest::new("sys", user, true)
+    }
+
+    async fn ask(provider: &CachedProvider, user: &str) -> anyhow::Result<String> {
+        provider.chat(&request(user)).await.map(|reply| reply.text)
+    }
+
+    #[test]
+    fn keys_cover_model_and_every_part_of_the_prompt() {
+        let key = cache_key("m", &request("a"));
+        assert_eq!(key.len(), 40);
+        assert_eq!(key, cache_key("m", &request("a")));
+        assert_ne!(key, cache_key("n", &request("a")));
+        assert_ne!(key, cache_key("m", &request("b")));
+        assert_ne!(key, cache_key("m", &ChatRequest { json: false, ..request("a") }));
+        let mut repaired = request("a");
+        repaired.turns.push(Turn { reply: "{".into(), answer: "fix it".into() });
+        assert_ne!(key, cache_key("m", &repaired));
+        // Parts are delimited, not concatenated.
+        assert_ne!(cache_key("m", &ChatRequest::new("sys", "a", true)), cache_key("m", &ChatRequest::new("sy", "sa", true)));
+    }
+
+    #[tokio::test]
+    async fn caches_answers_and_pins_them() {
+        let dir = std::env::temp_dir().join(format!("tome_ai_cache_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cached = CachedProvider::new(Box::new(Counter(AtomicUsize::new(0))), &dir, CacheMode::On);
+
+        let first = cached.chat(&request("a")).await.unwrap();
+        assert_eq!(first.text, "answer 0");
+        assert!(first.usage.is_some());
+        // A hit is free.
+        assert_eq!(cached.chat(&request("a")).await.unwrap(), ChatReply { text: "answer 0".into(), usage: None });
+        assert_eq!(ask(&cached, "b").await.unwrap(), "answer 1");
+        cached.reject(&request("a"));
+        assert_eq!(ask(&cached, "a").await.unwrap(), "answer 2");
+
+        let pinned = CachedProvider::new(Box::new(Counter(AtomicUsize::new(100))), &dir, CacheMode::Pinned);
+        assert_eq!(ask(&pinned, "b").await.unwrap(), "answer 1");
+        let miss = ask(&pinned, "c").await.unwrap_err().to_string();
+        assert!(miss.contains("pinned"), "{miss}");
+        pinned.reject(&request("b"));
+        assert_eq!(ask(&pinned, "b").await.unwrap(), "answer 1");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,10 @@
 mod ai;
+mod cache;
+mod provider;
+mod schema;
 mod test;
 
-pub use ai::{get_ai_message, extract_json, try_ai_json};
\ No newline at end of file
+pub use ai::{ai_json, get_ai_message, extract_json, try_ai_json, try_ai_json_with, AiCall, AiLog, JSON_ATTEMPTS};
+pub use cache::{cache_key, CacheMode, CachedProvider};
+pub use provider::{configured_provider, ChatReply, ChatRequest, LlmProvider, LocalProvider, OpenAiProvider, TemplateProvider, TokenUsage, Turn};
+pub use schema::validate;
@@ -0,0 +1,292 @@
+use async_trait::async_trait;
+use openai::{chat::{ChatCompletion, ChatCompletionMessage, ChatCompletionMessageRole, ChatCompletionResponseFormat}, Credentials};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::cache::{cache_dir_from_env, CacheMode, CachedProvider};
+
+/// Model used for chronicle naming + lore. `gpt-5-mini` is plenty for this
+/// light creative/structured work and ~5x cheaper than the old `gpt-4o`
+/// (pennies per settlement at our volume). Override with `LLM_MODEL` (or the
+/// older `OPENAI_MODEL`).
+const DEFAULT_MODEL: &str = "gpt-5-mini";
+
+/// Where the local backend looks when `LLM_ENDPOINT` isn't set: Ollama's
+/// OpenAI-compatible API.
+const DEFAULT_LOCAL_ENDPOINT: &str = "http://localhost:11434/v1";
+
+/// A system prompt and user prompt sent to a model, and any turns since.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatRequest {
+    pub system: String,
+    pub user: String,
+    /// Earlier turns of the same conversation, after `user`, oldest first.
+    pub turns: Vec<Turn>,
+    /// Ask for a single JSON object (no prose, no fenced block). OpenAI requires
+    /// the word "json" in the prompt for this, which the JSON callers include.
+    pub json: bool,
+}
+
+impl ChatRequest {
+    pub fn new(system: impl Into<String>, user: impl Into<String>, json: bool) -> Self {
+        Self { system: system.into(), user: user.into(), turns: Vec::new(), json }
+    }
+
+    /// The conversation as `(role, content)` pairs, OpenAI's role names.
+    pub fn messages(&self) -> Vec<(&'static str, &str)> {
+        let mut messages = vec![("system", self.system.as_str()), ("user", self.user.as_str())];
+        for turn in &self.turns {
+            messages.push(("assistant", turn.reply.as_str()));
+            messages.push(("user", turn.answer.as_str()));
+        }
+        messages
+    }
+}
+
+/// The model's reply to the conversation so far, and what was said back to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turn {
+    pub reply: String,
+    pub answer: String,
+}
+
+/// Tokens one call took, as the model's API counts them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatReply {
+    pub text: String,
+    /// `None` when nothing was spent: a cached or template answer, or a server
+    /// that doesn't count.
+    pub usage: Option<TokenUsage>,
+}
+
+/// Something that answers a [`ChatRequest`]: a hosted model, a local one, or a
+/// fixed response. Everything that talks to a model goes through this, so a run
+/// with no key or no network can still finish.
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// The model behind it, for logs.
+    fn model(&self) -> String;
+
+    async fn chat(&self, request: &ChatRequest) -> anyhow::Result<ChatReply>;
+
+    /// The answer to `request` couldn't be used; a retry should not get it again.
+    fn reject(&self, _request: &ChatRequest) {}
+}
+
+fn model_from_env() -> String {
+    std::env::var("LLM_MODEL")
+        .or_else(|_| std::env::var("OPENAI_MODEL"))
+        .unwrap_or_else(|_| DEFAULT_MODEL.to_string())
+}
+
+/// The OpenAI API, or any server speaking it: the key comes from `OPENAI_KEY`
+/// and the server from `OPENAI_BASE_URL` when set.
+pub struct OpenAiProvider {
+    model: String,
+}
+
+impl OpenAiProvider {
+    pub fn from_env() -> Self {
+        Self { model: model_from_env() }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    fn model(&self) -> String {
+        self.model.clone()
+    }
+
+    async fn chat(&self, request: &ChatRequest) -> anyhow::Result<ChatReply> {
+        let credentials = Credentials::from_env();
+        let messages: Vec<ChatCompletionMessage> = request.messages().into_iter().map(|(role, content)| {
+            let role = match role {
+                "system" => ChatCompletionMessageRole::System,
+                "assistant" => ChatCompletionMessageRole::Assistant,
+                _ => ChatCompletionMessageRole::User,
+            };
+            ChatCompletionMessage {role,content:Some(content.to_string()),name:None,function_call:None, tool_call_id: None, tool_calls: None }
+        }).collect();
+        let mut builder = ChatCompletion::builder(&self.model, messages)
+            .credentials(credentials);
+        if request.json {
+            builder = builder.response_format(ChatCompletionResponseFormat::json_object());
+        }
+        let chat_completion = builder.create().await?;
+        let returned_message = chat_completion.choices.first()
+            .ok_or_else(|| anyhow::anyhow!("No choices returned from AI"))?.message.clone();
+
+        let content = returned_message.content
+            .ok_or_else(|| anyhow::anyhow!("AI returned empty content"))?;
+        let usage = chat_completion.usage.map(|u| TokenUsage {
+            prompt_tokens: u.prompt_tokens as usize,
+            completion_tokens: u.completion_tokens as usize,
+        });
+        Ok(ChatReply { text: content.trim().to_string(), usage })
+    }
+}
+
+/// A model served on this machine (Ollama, llama.cpp, LM Studio) through the
+/// OpenAI-compatible `chat/completions` route, with no key.
+pub struct LocalProvider {
+    endpoint: String,
+    model: String,
+    client: reqwest::Client,
+}
+
+impl LocalProvider {
+    pub fn new(endpoint: impl Into<String>, model: impl Into<String>) -> Self {
+        Self { endpoint: endpoint.into(), model: model.into(), client: reqwest::Client::new() }
+    }
+
+    /// `LLM_ENDPOINT` (default Ollama's) and `LLM_MODEL`.
+    pub fn from_env() -> Self {
+        let endpoint = std::env::var("LLM_ENDPOINT").unwrap_or_else(|_| DEFAULT_LOCAL_ENDPOINT.to_string());
+        Self::new(endpoint, model_from_env())
+    }
+
+    fn body(&self, request: &ChatRequest) -> serde_json::Value {
+        let messages: Vec<serde_json::Value> = request
+            .messages()
+            .into_iter()
+            .map(|(role, content)| json!({ "role": role, "content": content }))
+            .collect();
+        let mut body = json!({ "model": self.model, "messages": messages });
+        if request.json {
+            body["response_format"] = json!({ "type": "json_object" });
+        }
+        body
+    }
+}
+
+#[async_trait]
+impl LlmProvider for LocalProvider {
+    fn model(&self) -> String {
+        self.model.clone()
+    }
+
+    async fn chat(&self, request: &ChatRequest) -> anyhow::Result<ChatReply> {
+        let url = format!("{}/chat/completions", self.endpoint.trim_end_matches('/'));
+        let response = self.client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .body(self.body(request).to_string())
+            .send()
+            .await?
+            .error_for_status()?;
+        let reply: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+        let content = reply["choices"][0]["message"]["content"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("{url} returned no message content"))?;
+        let tokens = |field: &str| reply["usage"][field].as_u64().map(|n| n as usize);
+        let usage = tokens("prompt_tokens")
+            .zip(tokens("completion_tokens"))
+            .map(|(prompt_tokens, completion_tokens)| TokenUsage { prompt_tokens, completion_tokens });
+        Ok(ChatReply { text: content.trim().to_string(), usage })
+    }
+}
+
+/// Answers every request with the same text, written beforehand — a response
+/// rendered from the run's own facts, so a run with no model still gets one,
+/// and gets the same one every time.
+pub struct TemplateProvider {
+    response: String,
+}
+
+impl TemplateProvider {
+    pub fn new(response: impl Into<String>) -> Self {
+        Self { response: response.into() }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for TemplateProvider {
+    fn model(&self) -> String {
+        "template".to_string()
+    }
+
+    async fn chat(&self, _request: &ChatRequest) -> anyhow::Result<ChatReply> {
+        Ok(ChatReply { text: self.response.clone(), usage: None })
+    }
+}
+
+/// The model the run is configured to talk to: `LLM_PROVIDER=openai` or
+/// `local`, or with `LLM_PROVIDER` unset, OpenAI when `OPENAI_KEY` is set.
+/// `None` (also for `LLM_PROVIDER=template`) means there is no model and the
+/// callers fall back to their templates.
+///
+/// The model sits behind the `LLM_CACHE` cache. A pinned cache answers with no
+/// key at all, so it stands in front of the configured model or, failing one,
+/// OpenAI's.
+pub fn configured_provider() -> Option<Box<dyn LlmProvider>> {
+    let mode = CacheMode::from_env();
+    let provider = match configured_model() {
+        Some(provider) => provider,
+        None if mode == CacheMode::Pinned && std::env::var("LLM_PROVIDER").is_err() => Box::new(OpenAiProvider::from_env()),
+        None => return None,
+    };
+    if mode == CacheMode::Off {
+        return Some(provider);
+    }
+    Some(Box::new(CachedProvider::new(provider, cache_dir_from_env(), mode)))
+}
+
+fn configured_model() -> Option<Box<dyn LlmProvider>> {
+    match std::env::var("LLM_PROVIDER").ok().as_deref().map(str::trim) {
+        Some("openai") => Some(Box::new(OpenAiProvider::from_env())),
+        Some("local") => Some(Box::new(LocalProvider::from_env())),
+        Some("template") => None,
+        Some(other) => {
+            log::warn!("Unknown LLM_PROVIDER `{other}` (openai, local, template); using templates");
+            None
+        }
+        None if std::env::var("OPENAI_KEY").is_ok_and(|key| !key.trim().is_empty()) => {
+            Some(Box::new(OpenAiProvider::from_env()))
+        }
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_requests_json_only_when_asked() {
+        let local = LocalProvider::new("http://localhost:11434/v1/", "llama3");
+        let mut request = ChatRequest::new("sys", "hi", false);
+        let body = local.body(&request);
+        assert_eq!(body["model"], "llama3");
+        assert_eq!(body["messages"][1]["content"], "hi");
+        assert!(body.get("response_format").is_none());
+
+        request.turns.push(Turn { reply: "{".into(), answer: "fix it".into() });
+        let body = local.body(&request);
+        assert_eq!(body["messages"][2]["role"], "assistant");
+        assert_eq!(body["messages"][3]["content"], "fix it");
+
+        request.json = true;
+        assert_eq!(local.body(&request)["response_format"]["type"], "json_object");
+    }
+
+    #[tokio::test]
+    async fn template_answers_with_its_response() {
+        let template = TemplateProvider::new("{}");
+        let request = ChatRequest::new("", "anything", true);
+        assert_eq!(template.chat(&request).await.unwrap(), ChatReply { text: "{}".into(), usage: None });
+        assert_eq!(template.model(), "template");
+    }
+}
@@ -0,0 +1,192 @@
+//! Checks a model's JSON against the schema `schema_for!` gave it, field by
+//! field, so a reply that is almost right can be sent back with what to fix
+//! rather than thrown away. Covers the draft-07 subset `schemars` writes:
+//! `$ref` into `definitions`, `type`, `enum`, `const`, `properties`,
+//! `required`, `additionalProperties`, `items`, `anyOf`/`oneOf`/`allOf` and
+//! `minimum`/`maximum`.
+
+use serde_json::Value;
+
+/// Everything wrong with `value` under `schema`, one line per field, each
+/// starting with the field's path (`$.pages[2][0].color`). Empty when it fits.
+pub fn validate(schema: &Value, value: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    check(schema, schema, value, "$", &mut errors);
+    errors
+}
+
+fn check(root: &Value, schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else { return };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match resolve(root, reference) {
+            Some(target) => check(root, target, value, path, errors),
+            None => errors.push(format!("{path}: schema has no {reference}")),
+        }
+    }
+    if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+        for branch in all {
+            check(root, branch, value, path, errors);
+        }
+    }
+    for key in ["anyOf", "oneOf"] {
+        let Some(branches) = schema.get(key).and_then(Value::as_array) else { continue };
+        let attempts: Vec<Vec<String>> = branches
+            .iter()
+            .map(|branch| {
+                let mut branch_errors = Vec::new();
+                check(root, branch, value, path, &mut branch_errors);
+                branch_errors
+            })
+            .collect();
+        if attempts.iter().all(|e| !e.is_empty()) {
+            // The branch that came closest says the most about the fix.
+            errors.extend(attempts.into_iter().min_by_key(Vec::len).unwrap_or_default());
+        }
+    }
+
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|t| is_type(value, t)) {
+            errors.push(format!("{path}: expected {}, got {}", allowed.join(" or "), describe(value)));
+            return;
+        }
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            let options: Vec<String> = options.iter().map(Value::to_string).collect();
+            errors.push(format!("{path}: {value} is not one of {}", options.join(", ")));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(format!("{path}: must be {expected}, got {value}"));
+        }
+    }
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64).filter(|&min| n < min) {
+            errors.push(format!("{path}: {value} is below the minimum {min}"));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64).filter(|&max| n > max) {
+            errors.push(format!("{path}: {value} is above the maximum {max}"));
+        }
+    }
+
+    if let Value::Object(fields) = value {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for name in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+            if !fields.contains_key(name) {
+                errors.push(format!("{path}.{name}: missing"));
+            }
+        }
+        for (name, field) in fields {
+            let field_path = format!("{path}.{name}");
+            match (properties.and_then(|p| p.get(name)), schema.get("additionalProperties")) {
+                (Some(property), _) => check(root, property, field, &field_path, errors),
+                (None, Some(Value::Bool(false))) => errors.push(format!("{field_path}: not a field here")),
+                (None, Some(extra)) => check(root, extra, field, &field_path, errors),
+                (None, None) => {}
+            }
+        }
+    }
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            check(root, item_schema, item, &format!("{path}[{i}]"), errors);
+        }
+    }
+}
+
+/// The schema at a local `#/...` pointer.
+fn resolve<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    root.pointer(reference.strip_prefix('#')?)
+}
+
+fn is_type(value: &Value, t: &str) -> bool {
+    match t {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+/// A value's JSON type, with the value itself when it's short.
+fn describe(value: &Value) -> String {
+    let kind = match value {
+        Value::Null => return "null".to_string(),
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => return "an array".to_string(),
+        Value::Object(_) => return "an object".to_string(),
+    };
+    let text = value.to_string();
+    if text.chars().count() <= 40 { format!("{kind} {text}") } else { kind.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    enum Colour {
+        #[serde(rename = "dark_red")]
+        DarkRed,
+        #[serde(rename = "gold")]
+        Gold,
+    }
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    struct Span {
+        text: String,
+        color: Option<Colour>,
+        bold: Option<bool>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    struct Book {
+        title: String,
+        copies: usize,
+        pages: Vec<Vec<Span>>,
+    }
+
+    #[test]
+    fn reports_each_bad_field_by_path() {
+        let schema = serde_json::to_value(schema_for!(Book)).unwrap();
+        let good = json!({
+            "title": "Blackbarrow",
+            "copies": 2,
+            "pages": [[{ "text": "Hi", "color": "gold", "bold": null }]],
+        });
+        assert_eq!(validate(&schema, &good), Vec::<String>::new());
+
+        let bad = json!({
+            "copies": -1,
+            "pages": [[{ "text": "Hi" }, { "text": 3, "color": "yellow", "bold": "yes" }]],
+        });
+        let errors = validate(&schema, &bad);
+        assert!(errors.contains(&"$.title: missing".to_string()), "{errors:#?}");
+        assert!(errors.iter().any(|e| e.starts_with("$.copies: -1 is below the minimum")), "{errors:#?}");
+        assert!(errors.contains(&"$.pages[0][1].text: expected string, got number 3".to_string()), "{errors:#?}");
+        assert!(
+            errors.contains(&r#"$.pages[0][1].color: "yellow" is not one of "dark_red", "gold""#.to_string()),
+            "{errors:#?}"
+        );
+        assert!(errors.iter().any(|e| e.starts_with("$.pages[0][1].bold: expected boolean or null")), "{errors:#?}");
+        assert_eq!(errors.len(), 5, "{errors:#?}");
+    }
+}
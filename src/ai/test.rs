@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use crate::{ai::ai::{extract_json, get_ai_message, try_ai_json}, http_mod::GDMCHTTPProvider, util::init_logger};
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::{ai::{ai::{ai_json, extract_json, get_ai_message, try_ai_json, AiLog}, provider::{ChatReply, ChatRequest, LlmProvider, TokenUsage}}, http_mod::GDMCHTTPProvider, util::init_logger};
     use dotenv::dotenv;
     use schemars::JsonSchema;
     use serde::Serialize;
@@ -101,4 +105,70 @@ mod tests {
 
         println!("Book given: {:?}", book);
     }
+
+    /// Gives its replies in order, keeping every request it was sent.
+    struct Scripted {
+        replies: Mutex<Vec<&'static str>>,
+        requests: Mutex<Vec<ChatRequest>>,
+        rejected: Mutex<usize>,
+    }
+
+    impl Scripted {
+        fn new(replies: &[&'static str]) -> Self {
+            Self { replies: Mutex::new(replies.to_vec()), requests: Mutex::default(), rejected: Mutex::default() }
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for Scripted {
+        fn model(&self) -> String {
+            "scripted".to_string()
+        }
+
+        async fn chat(&self, request: &ChatRequest) -> anyhow::Result<ChatReply> {
+            self.requests.lock().unwrap().push(request.clone());
+            let text = self.replies.lock().unwrap().remove(0).to_string();
+            Ok(ChatReply { text, usage: Some(TokenUsage { prompt_tokens: 100, completion_tokens: 20 }) })
+        }
+
+        fn reject(&self, _request: &ChatRequest) {
+            *self.rejected.lock().unwrap() += 1;
+        }
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Deserialize, JsonSchema)]
+    struct Town {
+        name: String,
+        population: u32,
+    }
+
+    #[tokio::test]
+    async fn bad_fields_go_back_to_the_model_to_repair() {
+        let provider = Scripted::new(&[r#"{"name": "Kiyomizu", "population": "many"}"#, r#"{"name": "Kiyomizu", "population": 300}"#]);
+        let mut log = AiLog::default();
+        let town: Town = ai_json(&provider, "A town, as json.", 3, &mut log).await.unwrap();
+        assert_eq!(town, Town { name: "Kiyomizu".into(), population: 300 });
+
+        let requests = provider.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let repair = &requests[1].turns[0];
+        assert!(repair.reply.contains("\"many\""));
+        assert!(repair.answer.contains("$.population: expected integer, got string \"many\""), "{}", repair.answer);
+
+        assert_eq!(log.calls.len(), 2);
+        assert_eq!(log.calls[0].errors.len(), 1);
+        assert!(log.calls[1].errors.is_empty());
+        assert_eq!(log.usage(), TokenUsage { prompt_tokens: 200, completion_tokens: 40 });
+        assert_eq!(*provider.rejected.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn running_out_of_repairs_fails_and_forgets_the_exchange() {
+        let provider = Scripted::new(&["not json", r#"{"name": "Kiyomizu"}"#]);
+        let mut log = AiLog::default();
+        let error = ai_json::<Town>(&provider, "A town, as json.", 2, &mut log).await.unwrap_err().to_string();
+        assert!(error.contains("$.population: missing"), "{error}");
+        assert!(log.calls[0].errors[0].starts_with("not valid JSON"), "{:?}", log.calls);
+        assert_eq!(*provider.rejected.lock().unwrap(), 2);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,608 @@
+//! The `Tome` command line: `tome <command> [flags]`, each command a thin
+//! wrapper over a library entry point. A run with no command, or one that
+//! starts with a flag, is `generate`, so the flags from before there were
+//! commands keep working. `tome help` lists the commands and their flags.
+
+use std::{path::{Path, PathBuf}, sync::Arc};
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::{
+    data::Loadable,
+    editor::{rollback, Editor, FlushMode, PipelineConfig, World},
+    generator::{
+        buildings::{roofs::{RoofComponent, RoofSet}, walls::{WallComponent, WallSet}, BuildingSet},
+        buildings_v2::{
+            blueprint::{build_blueprint, render_ascii, render_svg},
+            footprint::{generate_footprint_biased, Plot, SizeClass},
+            BuildCtx, BuildingContext, Culture, HouseOutput, build_house,
+        },
+        data::LoadedData,
+        materials::{Material, Palette, PaletteId},
+        nbts::Structure,
+        phases::{PhaseSelection, TownPhase},
+        profile::SettlementProfile,
+        ships::{build_ship, SailState, ShipCtx, ShipSpec},
+        sweep::{SweepEntry, SweepReport},
+        terrain::Forest,
+    },
+    geometry::{Cardinal, Point2D, Point3D, Rect2D, Rect3D},
+    http_mod::{AnvilBackend, GDMCConfig, GDMCHTTPProvider, WorldBackend},
+    noise::{Seed, RNG},
+};
+
+const USAGE: &str = "\
+usage: tome [command] [flags]
+
+commands:
+  generate        build a town (the default)
+                  --seed N  --culture medieval|desert|japanese  --profile <yaml>  --towns N
+                  --checkpoint <path>  --resume <checkpoint>
+                  --only a,b  --skip a,b  --from a  --until a
+                  --export <town.schem|.nbt>  --provenance <blocks.jsonl>
+                  --pipeline  --paste-structures  --journal <path>  --visualize
+  house           build one house
+                  --at x,z  --culture c  --size cottage|house|hall|manor  --seed N  --journal <path>
+  ship            build one ship
+                  --at x,z  --length L  --heading north|east|south|west  --palette <id>
+                  --furled  --seed N  --journal <path>
+  blueprint       print a house's floor plans without a world
+                  --culture c  --size s  --seed N  --svg <path>
+  chronicle       write the chronicle of a checkpointed town again
+                  --resume <checkpoint>
+  sweep           build the town offline under many seeds and rank them
+                  --seeds a..b | --seeds a,b,c  --size N (flat world, default 256)
+                  --culture c  --profile <yaml>  --towns N  --only/--skip/--from/--until
+                  --report <path> (default output/sweep.json)
+  clear [journal] restore the build area from a run's journal (the newest by default)
+  validate-data   check that every data file loads
+                  --strict (fail on draft files the loaders skip)
+  help            show this
+
+Every command that touches a world reads it from --world <save> --build-area
+x1,y1,z1,x2,y2,z2, from --replay <cassette>, or from the GDMC server (GDMC_*
+env vars or --gdmc-* flags, --record <cassette> to record it).
+
+The chronicle is written by LLM_PROVIDER=openai|local|template (OpenAI when
+OPENAI_KEY is set, else the template) through a response cache in LLM_CACHE_DIR
+(default ai_cache): LLM_CACHE=on reuses answers, off always asks the model, and
+pinned only answers from the cache, failing on a miss.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Generate,
+    House,
+    Ship,
+    Blueprint,
+    Chronicle,
+    Sweep,
+    Clear,
+    ValidateData,
+    Help,
+}
+
+impl Command {
+    /// The command named by the first argument.
+    pub fn from_args(args: &Args) -> anyhow::Result<Self> {
+        Ok(match args.0.get(1).map(String::as_str) {
+            None => Command::Generate,
+            Some("help" | "-h" | "--help") => Command::Help,
+            Some(first) if first.starts_with("--") => Command::Generate,
+            Some("generate") => Command::Generate,
+            Some("house") => Command::House,
+            Some("ship") => Command::Ship,
+            Some("blueprint") => Command::Blueprint,
+            Some("chronicle") => Command::Chronicle,
+            Some("sweep") => Command::Sweep,
+            // `rollback` was the name of `clear` before the other commands.
+            Some("clear" | "rollback") => Command::Clear,
+            Some("validate-data") => Command::ValidateData,
+            Some(other) => bail!("Unknown command `{other}`\n\n{USAGE}"),
+        })
+    }
+
+    pub async fn run(self, args: &Args) -> anyhow::Result<()> {
+        match self {
+            Command::Generate => generate(args).await,
+            Command::House => house(args).await,
+            Command::Ship => ship(args).await,
+            Command::Blueprint => blueprint(args).await,
+            Command::Chronicle => chronicle(args).await,
+            Command::Sweep => sweep(args).await,
+            Command::Clear => clear(args).await,
+            Command::ValidateData => validate_data(args),
+            Command::Help => {
+                println!("{USAGE}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The process arguments, program name first.
+pub struct Args(pub Vec<String>);
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self(std::env::args().collect())
+    }
+
+    /// The value after `--name`.
+    pub fn flag(&self, name: &str) -> Option<&str> {
+        self.0.iter().position(|arg| arg == name).and_then(|i| self.0.get(i + 1)).map(String::as_str)
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.0.iter().any(|arg| arg == name)
+    }
+
+    pub fn parse<T: std::str::FromStr>(&self, name: &str) -> anyhow::Result<Option<T>>
+    where
+        T::Err: std::fmt::Display,
+    {
+        self.flag(name)
+            .map(|value| value.parse().map_err(|e| anyhow!("{name} {value}: {e}")))
+            .transpose()
+    }
+
+    /// A build-area-local `x,z` column.
+    pub fn point(&self, name: &str) -> anyhow::Result<Option<Point2D>> {
+        let Some(value) = self.flag(name) else { return Ok(None) };
+        let coords: Vec<i32> = value
+            .split(',')
+            .map(|n| n.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow!("{name} {value}: {e}"))?;
+        let [x, z] = coords[..] else { bail!("{name} takes x,z") };
+        Ok(Some(Point2D::new(x, z)))
+    }
+
+    /// The argument after the command, unless it's a flag.
+    fn operand(&self) -> Option<&str> {
+        self.0.get(2).filter(|arg| !arg.starts_with("--")).map(String::as_str)
+    }
+
+    fn seed(&self) -> anyhow::Result<Seed> {
+        Ok(Seed(self.parse("--seed")?.unwrap_or_else(random_seed)))
+    }
+
+    fn culture(&self) -> anyhow::Result<Option<Culture>> {
+        self.flag("--culture")
+            .map(|name| match name.to_lowercase().as_str() {
+                "medieval" => Ok(Culture::Medieval),
+                "desert" => Ok(Culture::Desert),
+                "japanese" => Ok(Culture::Japanese),
+                _ => Err(anyhow!("Unknown culture `{name}`; cultures are medieval, desert, japanese")),
+            })
+            .transpose()
+    }
+
+    fn size_class(&self) -> anyhow::Result<SizeClass> {
+        Ok(match self.flag("--size").map(str::to_lowercase).as_deref() {
+            None | Some("house") => SizeClass::House,
+            Some("cottage") => SizeClass::Cottage,
+            Some("hall") => SizeClass::Hall,
+            Some("manor") => SizeClass::Manor,
+            Some(other) => bail!("Unknown size `{other}`; sizes are cottage, house, hall, manor"),
+        })
+    }
+}
+
+/// The world this run reads and writes. `--world <save folder>` together with
+/// `--build-area x1,y1,z1,x2,y2,z2` generates straight into region files with no
+/// server running; `--replay <cassette>` serves a recorded session back with no
+/// server either. Otherwise this is the GDMC provider configured from `GDMC_*`
+/// env vars (including `.env`), overridden by any `--gdmc-*` flags, recording
+/// its reads to `--record <cassette>` when given.
+pub fn connect(args: &Args) -> anyhow::Result<Arc<dyn WorldBackend>> {
+    if let Some(world_dir) = args.flag("--world") {
+        let area = args.flag("--build-area").context("--world needs --build-area x1,y1,z1,x2,y2,z2")?;
+        let corners = area
+            .split(',')
+            .map(|n| n.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("--build-area {area}: corners must be integers"))?;
+        let [x1, y1, z1, x2, y2, z2] = corners[..] else { bail!("--build-area {area}: expected six comma-separated integers") };
+        let build_area = Rect3D::from_points(Point3D::new(x1, y1, z1), Point3D::new(x2, y2, z2));
+        let backend = AnvilBackend::open(world_dir, build_area).with_context(|| format!("Failed to open world folder {world_dir}"))?;
+        return Ok(Arc::new(backend));
+    }
+
+    if let Some(cassette) = args.flag("--replay") {
+        let provider = GDMCHTTPProvider::replay(cassette).with_context(|| format!("Failed to load cassette {cassette}"))?;
+        return Ok(Arc::new(provider));
+    }
+
+    let provider = GDMCHTTPProvider::from_config(GDMCConfig::from_env().with_args(args.0.iter().cloned()));
+    Ok(match args.flag("--record") {
+        Some(cassette) => Arc::new(provider.with_recording(cassette).with_context(|| format!("Failed to create cassette {cassette}"))?),
+        None => Arc::new(provider),
+    })
+}
+
+/// A fresh seed for an interactive run, taken from the wall clock. Generation is
+/// fully deterministic in the seed, so the value printed by [`generate`]
+/// reproduces the exact town (pass it back with `--seed`, together with a
+/// `--replay` cassette of the same world, to rebuild it anywhere).
+fn random_seed() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as i64)
+        .unwrap_or(12345)
+}
+
+/// An editor on the connected world that keeps an undo journal,
+/// `--journal <path>` or a timestamped file under output/journals, so `clear`
+/// can undo what the command builds.
+async fn journaled_editor(args: &Args) -> anyhow::Result<Editor> {
+    let provider = connect(args)?;
+    let world = World::new(provider.as_ref()).await?;
+    let mut editor = world.get_editor_with_backend(provider);
+    let journal = args
+        .flag("--journal")
+        .map(String::from)
+        .unwrap_or_else(|| format!("output/journals/run_{}.jsonl", chrono::Local::now().format("%Y%m%d_%H%M%S")));
+    if let Err(e) = editor.start_journal(&journal) {
+        log::error!("Failed to start journal {journal}: {e}; this run can't be rolled back");
+    }
+    Ok(editor)
+}
+
+async fn generate(args: &Args) -> anyhow::Result<()> {
+    let mut editor = journaled_editor(args).await?;
+    if args.has("--pipeline") {
+        editor.set_flush_mode(FlushMode::Pipelined(PipelineConfig::default())).await;
+    }
+    if args.has("--paste-structures") {
+        editor.set_structure_pastes(true);
+    }
+    // `--only a,b`, `--skip a,b`, `--from a` and `--until a` pick the phases to
+    // run (see `generator::phases`).
+    let selection = PhaseSelection::from_args(&args.0)?;
+    // `--profile profiles/<name>.yaml` (under data/) overrides the settlement's
+    // tunables; see data/profiles/default.yaml.
+    let mut profile = match args.flag("--profile") {
+        Some(path) => SettlementProfile::load(path)?,
+        None => SettlementProfile::default(),
+    };
+    // `--towns N` seeds several towns linked by highways; `--culture` then
+    // picks the first town's culture and the rest choose from their own ground.
+    if let Some(towns) = args.parse("--towns")? {
+        profile.towns = towns;
+    }
+    // `--resume <checkpoint>` continues a run from its checkpoint, with the seed,
+    // culture and profile it was written with; `--checkpoint <path>` saves a
+    // fresh run after every phase.
+    match args.flag("--resume") {
+        Some(path) => crate::generator::settlement::resume_town(&mut editor, Path::new(path), &selection).await?,
+        None => {
+            // Random per-run seed so each interactive run is a different town; printed so
+            // a good one can be reproduced. `generate_town` takes the seed as a parameter,
+            // so tests can still pin it to a fixed value for determinism.
+            let seed = args.seed()?;
+            println!("Generating town with seed {}", seed.0);
+            // No --culture → auto-select the culture from the build area's climate.
+            crate::generator::settlement::generate_town_phases(
+                &mut editor, seed, args.culture()?, &profile, &selection, args.flag("--checkpoint").map(Path::new),
+            ).await?;
+        }
+    }
+
+    // `--export town.schem` (or `.nbt`) hands the finished build area to builders.
+    if let Some(path) = args.flag("--export") {
+        editor.export(path).with_context(|| format!("Failed to export to {path}"))?;
+    }
+    // `--provenance blocks.jsonl` lists which generator placed every block.
+    if let Some(path) = args.flag("--provenance") {
+        editor.dump_provenance(path).with_context(|| format!("Failed to write provenance to {path}"))?;
+    }
+    Ok(())
+}
+
+/// Half the side of the plot a single house is fitted into, around its point.
+const HOUSE_PLOT_RADIUS: i32 = 16;
+
+/// Fits a `size` footprint into the plot around `at` and builds a house of
+/// `culture` on it with the culture's palette and one of its roofs.
+async fn build_one_house(
+    editor: &mut Editor,
+    data: &LoadedData,
+    culture: Culture,
+    size: SizeClass,
+    at: Point2D,
+    rng: &mut RNG,
+) -> anyhow::Result<HouseOutput> {
+    let palette = data
+        .palettes
+        .get(&culture.palette_id())
+        .ok_or_else(|| anyhow!("Palette {:?} not found", culture.palette_id()))?;
+    let bounds = Rect2D::from_points(
+        at - Point2D::new(HOUSE_PLOT_RADIUS, HOUSE_PLOT_RADIUS),
+        at + Point2D::new(HOUSE_PLOT_RADIUS, HOUSE_PLOT_RADIUS),
+    );
+    let footprint = generate_footprint_biased(rng, &Plot::fully_usable(bounds), &size, culture.square_bias())
+        .ok_or_else(|| anyhow!("No {size:?} footprint fits around {at:?}"))?;
+    let roof_style = *rng.choose(&culture.roof_styles_for(size));
+    let bctx = BuildingContext::new(culture, size, roof_style);
+    let mut ctx = BuildCtx::new(editor, data, palette, rng);
+    build_house(&mut ctx, footprint, &bctx, bounds).await.map_err(|e| anyhow!("build_house failed: {e}"))
+}
+
+async fn house(args: &Args) -> anyhow::Result<()> {
+    let mut editor = journaled_editor(args).await?;
+    let data = LoadedData::load()?;
+    let at = args.point("--at")?.unwrap_or_else(|| editor.world().world_rect_2d().midpoint());
+    let culture = args.culture()?.unwrap_or(Culture::Medieval);
+    let size = args.size_class()?;
+    let seed = args.seed()?;
+    let house = build_one_house(&mut editor, &data, culture, size, at, &mut RNG::new(seed)).await?;
+    editor.flush_buffer().await;
+    editor.save().await;
+    println!(
+        "Built a {culture:?} {size:?} at {at:?} with seed {}: {:?} roof, {} rooms",
+        seed.0, house.roof_style, house.room_plan.rooms.len(),
+    );
+    Ok(())
+}
+
+async fn ship(args: &Args) -> anyhow::Result<()> {
+    let mut editor = journaled_editor(args).await?;
+    let data = LoadedData::load()?;
+    let at = args.point("--at")?.unwrap_or_else(|| editor.world().world_rect_2d().midpoint());
+    let heading = match args.flag("--heading") {
+        Some(name) => Cardinal::from_string(name).ok_or_else(|| anyhow!("Unknown heading `{name}`"))?,
+        None => Cardinal::North,
+    };
+    let length = args.parse("--length")?.unwrap_or(32);
+    let palette_id = PaletteId::from(args.flag("--palette").unwrap_or("ship_oak"));
+    let palette: &Palette = data
+        .palettes
+        .get(&palette_id)
+        .ok_or_else(|| anyhow!("Palette {palette_id:?} not found"))?;
+    let sail_state = if args.has("--furled") { SailState::Furled } else { SailState::Full };
+    let spec = ShipSpec::new(heading, length).with_sail_state(sail_state);
+    // `at` is the middle of the hull; the ship is built from its stern.
+    let dir: Point2D = heading.into();
+    let anchor = at - dir * (length / 2);
+    let seed = args.seed()?;
+    let mut rng = RNG::new(seed);
+    let out = build_ship(&mut ShipCtx::new(&mut editor, &data, palette, &mut rng), &spec, anchor).await;
+    editor.flush_buffer().await;
+    editor.save().await;
+    println!(
+        "Built a {:?} ship, {length} long, heading {} at {at:?} {} with seed {}",
+        out.tier, heading.to_string(), if out.on_water { "afloat" } else { "on land" }, seed.0,
+    );
+    Ok(())
+}
+
+/// Builds a house on a flat synthetic world, so it needs no server, and
+/// prints its floor plans; `--svg <path>` also draws them.
+async fn blueprint(args: &Args) -> anyhow::Result<()> {
+    let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(127, 127, 127));
+    let mut editor = World::synthetic(build_area, 64).get_offline_editor();
+    let data = LoadedData::load()?;
+    let culture = args.culture()?.unwrap_or(Culture::Medieval);
+    let size = args.size_class()?;
+    let seed = args.seed()?;
+    let at = editor.world().world_rect_2d().midpoint();
+    let house = build_one_house(&mut editor, &data, culture, size, at, &mut RNG::new(seed)).await?;
+    let blueprint = build_blueprint(&house.frame, &house.wall_segs, &house.floor_plan, &house.room_plan, house.has_attic);
+    println!("{culture:?} {size:?}, seed {}", seed.0);
+    println!("{}", render_ascii(&blueprint));
+    if let Some(path) = args.flag("--svg") {
+        std::fs::write(path, render_svg(&blueprint)).with_context(|| format!("Failed to write {path}"))?;
+    }
+    Ok(())
+}
+
+/// Runs the chronicle phase again over a checkpointed town, writing a fresh
+/// book with the seed, culture and facts it was generated with.
+async fn chronicle(args: &Args) -> anyhow::Result<()> {
+    let checkpoint = args
+        .flag("--resume")
+        .ok_or_else(|| anyhow!("chronicle needs --resume <checkpoint> of a generated town"))?;
+    let mut editor = journaled_editor(args).await?;
+    let selection = PhaseSelection { only: vec![TownPhase::Chronicle], ..Default::default() };
+    crate::generator::settlement::resume_town(&mut editor, Path::new(checkpoint), &selection).await
+}
+
+/// The seeds of a sweep: `--seeds a..b` (end exclusive), `--seeds a,b,c`, or
+/// 0..20.
+fn sweep_seeds(args: &Args) -> anyhow::Result<Vec<i64>> {
+    let Some(value) = args.flag("--seeds") else { return Ok((0..20).collect()) };
+    let number = |n: &str| n.trim().parse::<i64>().map_err(|e| anyhow!("--seeds {value}: {e}"));
+    let seeds: Vec<i64> = match value.split_once("..") {
+        Some((start, end)) => (number(start)?..number(end)?).collect(),
+        None => value.split(',').map(number).collect::<anyhow::Result<_>>()?,
+    };
+    if seeds.is_empty() {
+        bail!("--seeds {value} has no seeds");
+    }
+    Ok(seeds)
+}
+
+/// Builds the town under every seed of `--seeds` on an offline editor and
+/// prints them ranked by [`TownMetrics::score`](crate::generator::sweep::TownMetrics::score),
+/// writing the full report to `--report`. The terrain is a flat `--size`
+/// square unless `--world` or `--replay` gives a real one, read afresh for each
+/// seed; nothing is written back to it. The chronicle is skipped unless asked
+/// for with `--only`, since it calls out to the model.
+async fn sweep(args: &Args) -> anyhow::Result<()> {
+    let seeds = sweep_seeds(args)?;
+    let mut selection = PhaseSelection::from_args(&args.0)?;
+    if selection.only.is_empty() {
+        selection.skip.push(TownPhase::Chronicle);
+    }
+    let mut profile = match args.flag("--profile") {
+        Some(path) => SettlementProfile::load(path)?,
+        None => SettlementProfile::default(),
+    };
+    if let Some(towns) = args.parse("--towns")? {
+        profile.towns = towns;
+    }
+    let culture = args.culture()?;
+    let size: i32 = args.parse("--size")?.unwrap_or(256);
+    let recorded = args.has("--world") || args.has("--replay");
+
+    let mut entries = Vec::new();
+    for (i, &seed) in seeds.iter().enumerate() {
+        println!("Sweep seed {seed} ({} of {})", i + 1, seeds.len());
+        let world = if recorded {
+            World::new(connect(args)?.as_ref()).await?
+        } else {
+            World::synthetic(Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(size - 1, 255, size - 1)), 64)
+        };
+        let mut editor = world.get_offline_editor();
+        let towns = crate::generator::settlement::generate_town_phases(
+            &mut editor, Seed(seed), culture, &profile, &selection, None,
+        ).await;
+        entries.push(match towns {
+            Ok(towns) => SweepEntry::new(seed, towns),
+            Err(e) => SweepEntry::failed(seed, format!("{e:#}")),
+        });
+    }
+
+    let report = SweepReport::ranked(entries);
+    println!("{}", report.table());
+    let path = args.flag("--report").unwrap_or("output/sweep.json");
+    if let Some(dir) = Path::new(path).parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&report)?).with_context(|| format!("Failed to write {path}"))?;
+    if let Some(best) = report.best() {
+        println!("Best seed {} ({:.1}); report in {path}", best.seed, best.score);
+    }
+    Ok(())
+}
+
+/// Undoes a journaled run: the journal given, or the newest under
+/// output/journals.
+async fn clear(args: &Args) -> anyhow::Result<()> {
+    let journal = match args.operand().or(args.flag("--journal")) {
+        Some(path) => PathBuf::from(path),
+        None => newest_journal(Path::new("output/journals"))?,
+    };
+    let report = rollback(connect(args)?.as_ref(), &journal)
+        .await
+        .with_context(|| format!("Rollback of {} failed", journal.display()))?;
+    log::info!(
+        "Rolled back run {}: {} blocks restored ({} failed), entities removed: {}",
+        report.run, report.blocks - report.failed_blocks, report.failed_blocks, report.entities_removed,
+    );
+    Ok(())
+}
+
+fn newest_journal(dir: &Path) -> anyhow::Result<PathBuf> {
+    let mut journals: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("No journals in {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+        .collect();
+    // Journal names are timestamps, so the newest sorts last.
+    journals.sort();
+    journals.pop().ok_or_else(|| anyhow!("No journals in {}", dir.display()))
+}
+
+/// Loads every data file the generator reads and reports what doesn't:
+/// subsystems that come back empty, cross-references that don't resolve, and
+/// name, banner and profile configs that don't parse. Files a loader skips
+/// (drafts on an older schema) are listed as warnings, or fail the check with
+/// `--strict`.
+fn validate_data(args: &Args) -> anyhow::Result<()> {
+    let strict = args.has("--strict");
+    let mut failures = 0;
+    let mut report = |what: &str, result: anyhow::Result<()>| match result {
+        Ok(()) => println!("ok      {what}"),
+        Err(e) => {
+            failures += 1;
+            println!("FAILED  {what}: {e:#}");
+        }
+    };
+
+    let loads = |rejected: anyhow::Result<Vec<(PathBuf, String)>>| -> anyhow::Result<()> {
+        let rejected = rejected?;
+        if rejected.is_empty() {
+            return Ok(());
+        }
+        let files: Vec<String> = rejected.iter().map(|(path, err)| format!("\n    {}: {err}", path.display())).collect();
+        if strict {
+            bail!("{} files don't load:{}", rejected.len(), files.concat());
+        }
+        println!("warn    skipping {} files that don't load:{}", rejected.len(), files.concat());
+        Ok(())
+    };
+    report("palettes", loads(Palette::rejected()));
+    report("materials", loads(Material::rejected()));
+    report("structures", loads(Structure::rejected()));
+    report("wall components", loads(WallComponent::rejected()));
+    report("wall sets", loads(WallSet::rejected()));
+    report("roof components", loads(RoofComponent::rejected()));
+    report("roof sets", loads(RoofSet::rejected()));
+    report("building sets", loads(BuildingSet::rejected()));
+    report("forests", loads(Forest::rejected()));
+    report("generator data", LoadedData::load().and_then(|data| data.check()));
+    report("settlement names", crate::generator::naming::check_settlement_names());
+    report("district names", crate::generator::districts::check_district_names());
+    report("road names", crate::generator::paths::check_road_names());
+    report("open space names", crate::generator::open_space::OpenSpaceNames::check());
+    report("banners", crate::generator::heraldry::check_banners());
+    match std::fs::read_dir("data/profiles") {
+        Ok(entries) => {
+            for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+                let name = format!("profiles/{}", path.file_name().unwrap_or_default().to_string_lossy());
+                report(&name, SettlementProfile::load(&name).map(drop));
+            }
+        }
+        Err(e) => report("profiles", Err(e.into())),
+    }
+
+    if failures > 0 {
+        bail!("{failures} data checks failed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Args {
+        Args(line.split_whitespace().map(String::from).collect())
+    }
+
+    #[test]
+    fn commands_and_flags_parse() {
+        assert_eq!(Command::from_args(&args("tome")).unwrap(), Command::Generate);
+        assert_eq!(Command::from_args(&args("tome --seed 4")).unwrap(), Command::Generate);
+        assert_eq!(Command::from_args(&args("tome rollback run.jsonl")).unwrap(), Command::Clear);
+        assert_eq!(Command::from_args(&args("tome validate-data")).unwrap(), Command::ValidateData);
+        assert_eq!(Command::from_args(&args("tome --help")).unwrap(), Command::Help);
+        assert!(Command::from_args(&args("tome houses")).is_err());
+
+        let house = args("tome house --at 10,-4 --culture Japanese --size manor --seed 9");
+        assert_eq!(house.point("--at").unwrap(), Some(Point2D::new(10, -4)));
+        assert_eq!(house.culture().unwrap(), Some(Culture::Japanese));
+        assert_eq!(house.size_class().unwrap(), SizeClass::Manor);
+        assert_eq!(house.seed().unwrap().0, 9);
+        assert!(args("tome house --at 10").point("--at").is_err());
+        assert!(args("tome house --culture roman").culture().is_err());
+        assert!(args("tome ship --length long").parse::<i32>("--length").is_err());
+
+        assert_eq!(sweep_seeds(&args("tome sweep --seeds 3..6")).unwrap(), vec![3, 4, 5]);
+        assert_eq!(sweep_seeds(&args("tome sweep --seeds 9,-2")).unwrap(), vec![9, -2]);
+        assert_eq!(sweep_seeds(&args("tome sweep")).unwrap().len(), 20);
+        assert!(sweep_seeds(&args("tome sweep --seeds 5..5")).is_err());
+
+        assert_eq!(args("tome clear output/journals/a.jsonl").operand(), Some("output/journals/a.jsonl"));
+        assert_eq!(args("tome clear --replay x").operand(), None);
+    }
+
+    #[test]
+    fn connect_reads_the_parsed_flags() {
+        let err = connect(&args("tome --world saves/town")).err().expect("--world without --build-area");
+        assert!(err.to_string().contains("--build-area"));
+        assert!(connect(&args("tome --world saves/town --build-area 0,0,0,9")).is_err());
+        assert!(connect(&args("tome --replay missing.bin")).is_err());
+    }
+}
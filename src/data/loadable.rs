@@ -1,4 +1,4 @@
-use std::{collections::HashMap, env, fs::{read_dir, File}, path::Path};
+use std::{collections::HashMap, env, fs::{read_dir, File}, path::{Path, PathBuf}};
 
 use log::{debug, info};
 use serde::de::DeserializeOwned;
@@ -25,34 +25,18 @@ pub trait Loadable<'de, TItem, TKey>
             if entry_path.is_dir() {
                 Self::load_all_in(&entry_path, items)?;
             } else {
-                let ext = entry_path.extension().and_then(|e| e.to_str());
                 // Deserialization failures are logged at debug level. Known
                 // drafts (Japanese wall/roof assets on an older schema) skip
-                // silently by default; use RUST_LOG=debug to surface them.
-                // `test_data_loads_cleanly` asserts that LoadedData as a whole
-                // still succeeds.
-                let item: TItem = match ext {
-                    Some("json") => {
-                        let file = File::open(&entry_path)?;
-                        match serde_json::from_reader(file) {
-                            Ok(val) => val,
-                            Err(err) => {
-                                debug!("Failed to deserialize {:?}: {}", entry_path, err);
-                                continue;
-                            }
-                        }
+                // silently by default; use RUST_LOG=debug, or `validate-data`
+                // (see `rejected`), to surface them. `test_data_loads_cleanly`
+                // asserts that LoadedData as a whole still succeeds.
+                let item: TItem = match read_item(&entry_path)? {
+                    Some(Ok(val)) => val,
+                    Some(Err(err)) => {
+                        debug!("Failed to deserialize {:?}: {}", entry_path, err);
+                        continue;
                     }
-                    Some("yaml" | "yml") => {
-                        let file = File::open(&entry_path)?;
-                        match serde_yaml::from_reader(file) {
-                            Ok(val) => val,
-                            Err(err) => {
-                                debug!("Failed to deserialize {:?}: {}", entry_path, err);
-                                continue;
-                            }
-                        }
-                    }
-                    _ => continue,
+                    None => continue,
                 };
                 let key = Self::get_key(&item);
                 items.insert(key, item);
@@ -61,6 +45,26 @@ pub trait Loadable<'de, TItem, TKey>
         Ok(())
     }
 
+    /// The files under [`path`](Self::path) that [`load`](Self::load) skips
+    /// because they don't deserialize, with the reason for each.
+    fn rejected() -> anyhow::Result<Vec<(PathBuf, String)>> {
+        fn walk<T: DeserializeOwned>(path: &Path, rejected: &mut Vec<(PathBuf, String)>) -> anyhow::Result<()> {
+            for entry in read_dir(path)? {
+                let entry_path = entry?.path();
+                if entry_path.is_dir() {
+                    walk::<T>(&entry_path, rejected)?;
+                } else if let Some(Err(err)) = read_item::<T>(&entry_path)? {
+                    rejected.push((entry_path, err));
+                }
+            }
+            Ok(())
+        }
+        let mut rejected = Vec::new();
+        walk::<TItem>(&env::current_dir()?.join("data").join(Self::path()), &mut rejected)?;
+        rejected.sort();
+        Ok(rejected)
+    }
+
     fn get_key(item: &TItem) -> TKey;
 
     // In case we need to do something after loading all items
@@ -69,6 +73,17 @@ pub trait Loadable<'de, TItem, TKey>
     fn path() -> &'static str;
 }
 
+/// One data file as a `T`: `None` if it isn't JSON or YAML, else the item or
+/// why it didn't deserialize.
+fn read_item<T: DeserializeOwned>(path: &Path) -> anyhow::Result<Option<Result<T, String>>> {
+    let item = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_reader(File::open(path)?).map_err(|err| err.to_string()),
+        Some("yaml" | "yml") => serde_yaml::from_reader(File::open(path)?).map_err(|err| err.to_string()),
+        _ => return Ok(None),
+    };
+    Ok(Some(item))
+}
+
 /// Load a single YAML file from the data directory, deserializing into `T`.
 pub fn load_yaml<T: DeserializeOwned>(relative_path: &str) -> anyhow::Result<T> {
     let path = env::current_dir()?.join("data").join(relative_path);
@@ -1,10 +1,12 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::Ok;
 use log::{error, info, warn};
 
-use crate::{data::Loadable, editor::World, generator::materials::{Material, MaterialId}, geometry::{Point3D, Rect3D}, http_mod::{Coordinate, CommandResponse, GDMCHTTPProvider, PositionedBlock, PositionedEntity}, minecraft::{Block, BlockForm, BlockID}, noise::RNG};
+use crate::{data::Loadable, generator::nbts::NBTStructure, editor::{flush::{BatchFailure, FlushMode, FlushReport, InFlightBatch, SentBatch}, journal::{tag_entity_data, Journal}, provenance::Provenance, transaction::Transaction, World}, generator::materials::{Material, MaterialId}, geometry::{LocalPoint3D, Point3D, Rect3D, WorldPoint3D}, http_mod::{BlockPlacementResponse, Coordinate, CommandResponse, GDMCHTTPProvider, PositionedBlock, PositionedEntity, WorldBackend}, minecraft::{Block, BlockForm, BlockID}, noise::RNG};
 
 /// Editor provides the interface for modifying the Minecraft world.
 ///
@@ -15,9 +17,11 @@ use crate::{data::Loadable, editor::World, generator::materials::{Material, Mate
 /// Do not hold Ref/RefMut across .await points.
 #[derive(Debug)]
 pub struct Editor {
-    build_area: Rect3D,
-    provider: GDMCHTTPProvider,
-    block_buffer: RefCell<Vec<PositionedBlock>>,
+    pub(super) build_area: Rect3D,
+    /// Where blocks, entities and commands are sent. The live GDMC HTTP
+    /// interface unless constructed with [`Editor::with_backend`].
+    provider: Arc<dyn WorldBackend>,
+    pub(super) block_buffer: RefCell<Vec<PositionedBlock>>,
     buffer_size: usize,
     /// When false, `flush_buffer` sends buffered blocks with `doBlockUpdates=false`,
     /// so the server places exactly the blocks given without running placement side
@@ -26,10 +30,26 @@ pub struct Editor {
     /// duplicating the second half the NBT already contains — often into a wall.
     /// Defaults to true; toggle via `set_block_updates` around a structure paste.
     block_updates: RefCell<bool>,
-    block_cache: RefCell<HashMap<Point3D, Block>>,
-    world: World,
+    /// When true, `place_nbt` pastes structures in one request (`paste_blocks`).
+    paste_structures: bool,
+    flush_mode: FlushMode,
+    /// Current pipelined batch size; adapts to server latency.
+    batch_size: Cell<usize>,
+    in_flight: RefCell<VecDeque<InFlightBatch>>,
+    /// Outcome of batches settled since the last `flush_buffer` returned.
+    flush_report: RefCell<FlushReport>,
+    pub(super) block_cache: RefCell<HashMap<Point3D, Block>>,
+    pub(super) world: World,
     materials: HashMap<MaterialId, Material>,
     block_form_cache: RefCell<HashMap<BlockID, BlockForm>>,
+    /// Open provenance scopes, innermost last (see `push_provenance`).
+    pub(super) provenance_scopes: RefCell<Vec<Arc<Provenance>>>,
+    /// Who placed each cached block, by local point.
+    pub(super) block_provenance: RefCell<HashMap<Point3D, Arc<Provenance>>>,
+    /// Undo log of this run, when one was started with `start_journal`.
+    journal: RefCell<Option<Journal>>,
+    /// Open transactional scopes, innermost last (see `begin_scope`).
+    pub(super) scopes: RefCell<Vec<Transaction>>,
     /// When true, skip all outbound HTTP traffic. Block placements still land
     /// in `block_cache` so reads stay consistent, but nothing reaches the
     /// Minecraft server. Use for offline pipeline tests that only exercise
@@ -39,16 +59,32 @@ pub struct Editor {
 
 impl Editor {
     pub fn new(build_area: Rect3D, world: World) -> Self {
+        Self::with_backend(build_area, world, Arc::new(GDMCHTTPProvider::new()))
+    }
+
+    /// Construct an editor that sends its traffic to `backend` instead of the
+    /// default GDMC HTTP interface. Pair with `World::new(backend.as_ref())` so
+    /// the world is read from the same place it is written to.
+    pub fn with_backend(build_area: Rect3D, world: World, backend: Arc<dyn WorldBackend>) -> Self {
         let mut editor = Self {
             build_area,
-            provider: GDMCHTTPProvider::new(),
+            provider: backend,
             block_buffer: RefCell::new(Vec::new()),
             buffer_size: 32,
             block_updates: RefCell::new(true),
+            paste_structures: false,
+            flush_mode: FlushMode::Sequential,
+            batch_size: Cell::new(32),
+            in_flight: RefCell::new(VecDeque::new()),
+            flush_report: RefCell::new(FlushReport::default()),
             block_cache: RefCell::new(HashMap::new()),
             world,
             materials: HashMap::new(),
             block_form_cache: RefCell::new(HashMap::new()),
+            provenance_scopes: RefCell::new(Vec::new()),
+            block_provenance: RefCell::new(HashMap::new()),
+            journal: RefCell::new(None),
+            scopes: RefCell::new(Vec::new()),
             offline: false,
         };
         editor.load_data().expect("Failed to load materials");
@@ -67,6 +103,11 @@ impl Editor {
         self.offline
     }
 
+    /// The backend this editor writes to.
+    pub fn backend(&self) -> &Arc<dyn WorldBackend> {
+        &self.provider
+    }
+
     pub fn set_buffer_size(&mut self, size: usize) {
         self.buffer_size = size;
     }
@@ -75,75 +116,228 @@ impl Editor {
         self.buffer_size
     }
 
+    /// Switches between sequential and pipelined flushing (see [`FlushMode`]).
+    /// Either way, `flush_buffer` returns only once every block sent so far
+    /// has landed, and a toggle of `set_block_updates` waits for them too.
+    pub async fn set_flush_mode(&mut self, mode: FlushMode) {
+        self.settle_all().await;
+        if let FlushMode::Pipelined(config) = mode {
+            self.batch_size.set(config.min_batch.max(self.buffer_size));
+        }
+        self.flush_mode = mode;
+    }
+
+    pub fn flush_mode(&self) -> FlushMode {
+        self.flush_mode
+    }
+
     /// Controls whether buffered blocks are flushed with block updates on
     /// (`true`, the default) or off. Flushes any pending buffer first so the
     /// switch only affects blocks placed afterwards. Disable around a structure
     /// paste so the server doesn't auto-spawn the second half of beds/doors that
     /// the structure already contains; re-enable when done.
     pub async fn set_block_updates(&self, on: bool) {
-        self.flush_buffer().await;
+        self.settle_all().await;
         *self.block_updates.borrow_mut() = on;
     }
 
+    /// Starts recording an undo journal at `path` (see [`Journal`]): from now
+    /// on the original block of every cell this editor overwrites is logged,
+    /// and every entity it spawns is tagged with the run, so `rollback` can
+    /// undo the run. Blocks placed by commands (`place_feature`) are not
+    /// covered.
+    pub fn start_journal(&mut self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+        let journal = Journal::create(path)?;
+        info!("Journaling run {} to {}", journal.run_tag(), journal.path().display());
+        *self.journal.get_mut() = Some(journal);
+        Ok(())
+    }
+
+    /// The entity tag of the journaled run, if any.
+    pub fn journal_tag(&self) -> Option<String> {
+        self.journal.borrow().as_ref().map(|journal| journal.run_tag().to_string())
+    }
+
+    /// Logs the block at `local` as it was before this run, the first time the
+    /// run writes there.
+    fn journal_original(&self, local: Point3D) {
+        let mut journal = self.journal.borrow_mut();
+        let Some(journal) = journal.as_mut() else { return };
+        let point = local + self.build_area.origin;
+        if journal.contains(point) {
+            return;
+        }
+        let Some(mut original) = self.world.get_block(LocalPoint3D(local)) else {
+            warn!("No world data at {:?}; it won't be rolled back", point);
+            return;
+        };
+        original.data = self.world.get_block_entity(LocalPoint3D(local));
+        if let Err(e) = journal.record(point, original) {
+            error!("Failed to journal {:?}: {}", point, e);
+        }
+    }
+
+    /// Gets the journal onto disk before the blocks it covers are sent.
+    fn sync_journal(&self) {
+        if let Some(journal) = self.journal.borrow_mut().as_mut() {
+            if let Err(e) = journal.sync() {
+                error!("Failed to write journal {}: {}", journal.path().display(), e);
+            }
+        }
+    }
+
+    /// Entity SNBT with the journaled run's tag added.
+    fn tag_entity(&self, data: Option<&str>) -> Option<String> {
+        match self.journal.borrow().as_ref() {
+            Some(journal) => Some(tag_entity_data(data, journal.run_tag())),
+            None => data.map(str::to_string),
+        }
+    }
+
     fn load_data(&mut self) -> anyhow::Result<()> {
         info!("Loading editor data");
         self.materials = Material::load()?;
         Ok(())
     }
 
-    pub async fn place_block(&self, block: &Block, point: Point3D) {
+    /// The absolute position of build-area-local `local`.
+    pub fn to_world(&self, local: LocalPoint3D) -> WorldPoint3D {
+        self.world.to_world(local)
+    }
+
+    /// The build-area-local position of absolute `point`.
+    pub fn to_local(&self, point: WorldPoint3D) -> LocalPoint3D {
+        self.world.to_local(point)
+    }
+
+    pub async fn place_block(&self, block: &Block, point: LocalPoint3D) {
         self.place_block_options(block, point, false).await;
     }
 
-    pub async fn place_block_forced(&self, block: &Block, point: Point3D) {
+    pub async fn place_block_forced(&self, block: &Block, point: LocalPoint3D) {
         self.place_block_options(block, point, true).await;
     }
 
-    pub async fn place_block_options(&self, block: &Block, point: Point3D, force: bool) {
+    pub async fn place_block_options(&self, block: &Block, point: LocalPoint3D, force: bool) {
+        let point = point.0;
+        let Some(block) = self.stage_block(block, point, force) else { return };
+        self.block_buffer.borrow_mut().push(
+            PositionedBlock::from_block(block, (point + self.build_area.origin).into())
+        );
+
+        // Check buffer size and flush if needed
+        // Note: We get the length first, then flush, to avoid holding borrow across await
+        let threshold = match self.flush_mode {
+            FlushMode::Sequential => self.buffer_size,
+            FlushMode::Pipelined(_) => self.batch_size.get().max(self.buffer_size),
+        };
+        // An open scope holds its blocks back so an abort can drop them.
+        let should_flush = self.block_buffer.borrow().len() >= threshold && !self.in_scope();
+        if should_flush {
+            self.dispatch_buffer().await;
+        }
+    }
+
+    /// Validates a placement and records it in the cache, returning the block
+    /// to send — or `None` when it lies outside the build area or (unless
+    /// `force`) would replace a denser block.
+    fn stage_block(&self, block: &Block, point: Point3D, force: bool) -> Option<Block> {
+        let block = self.vet_block(block, point, force, None)?;
+        self.journal_original(point);
+        self.record_scoped_write(point, false);
+        self.record_provenance(point);
+        self.block_cache.borrow_mut().insert(point, block.clone());
+        Some(block)
+    }
+
+    /// The block to send for a placement at `point`, or `None` when it lies
+    /// outside the build area or (unless `force`) would replace a denser
+    /// block: `pending` if one is due there but not yet cached, else the
+    /// cached one. Records nothing.
+    fn vet_block(&self, block: &Block, point: Point3D, force: bool, pending: Option<&Block>) -> Option<Block> {
         if !self.world.build_area.contains(point + self.build_area.origin) {
             warn!("Point {:?} is outside the build area {:?} and will be ignored", point + self.build_area.origin, self.world.build_area);
-            return;
+            return None;
         }
 
         // Never place an `axis` blockstate on a block that doesn't support one
         // (e.g. a log palette-swapped into sandstone). Minecraft rejects the
         // whole placement for an invalid state and the block silently vanishes,
         // so strip the stray axis here — the single chokepoint all placers hit.
-        let stripped;
-        let block = if block.state.as_ref().map_or(false, |s| s.contains_key("axis")) && !block.id.is_axis_block() {
-            let mut b = block.clone();
-            if let Some(s) = b.state.as_mut() { s.remove("axis"); }
-            stripped = b;
-            &stripped
-        } else {
-            block
-        };
+        let mut block = block.clone();
+        if block.state.as_ref().map_or(false, |s| s.contains_key("axis")) && !block.id.is_axis_block() {
+            if let Some(s) = block.state.as_mut() { s.remove("axis"); }
+        }
 
         if !force {
-            let cache = self.block_cache.borrow();
-            if cache.contains_key(&point) {
-                let density = self.get_block_form(&block.id).density();
-                let current_block = cache.get(&point).expect("Block should be in cache").id.clone();
-                drop(cache); // Release borrow before calling get_block_form again
-
-                if density <= self.get_block_form(&current_block).density() {
+            let current = match pending {
+                Some(pending) => Some(pending.id.clone()),
+                None => self.block_cache.borrow().get(&point).map(|current| current.id.clone()),
+            };
+            if let Some(current) = current {
+                if self.get_block_form(&block.id).density() <= self.get_block_form(&current).density() {
                     info!("Block at {:?} is already placed with a denser block, skipping", point);
-                    return;
+                    return None;
                 }
             }
         }
+        Some(block)
+    }
 
-        self.block_cache.borrow_mut().insert(point, block.clone());
-        self.block_buffer.borrow_mut().push(
-            PositionedBlock::from_block(block.clone(), (point + self.build_area.origin).into())
-        );
+    /// Whether `place_nbt` pastes whole structures in one request (see
+    /// [`paste_blocks`](Self::paste_blocks)) rather than block by block.
+    pub fn pastes_structures(&self) -> bool {
+        self.paste_structures
+    }
 
-        // Check buffer size and flush if needed
-        // Note: We get the length first, then flush, to avoid holding borrow across await
-        let should_flush = self.block_buffer.borrow().len() >= self.buffer_size;
-        if should_flush {
-            self.flush_buffer().await;
+    pub fn set_structure_pastes(&mut self, on: bool) {
+        self.paste_structures = on;
+    }
+
+    /// Places `blocks` (local points) as one structure paste through the
+    /// backend's structure endpoint instead of buffered block PUTs.
+    ///
+    /// Each block is vetted like [`place_block`](Self::place_block) — build-
+    /// area bounds, the denser-block rule — and cached once the paste lands,
+    /// so later reads see it and a failed paste leaves the cache as it was.
+    /// Buffered and in-flight blocks are sent first, so the paste lands after
+    /// everything placed before it. Blocks the structure leaves out keep
+    /// whatever the world has there. Beds follow the paste block by block
+    /// without updates, as `place_nbt` places them.
+    pub async fn paste_blocks(&self, blocks: Vec<(Point3D, Block)>) -> anyhow::Result<()> {
+        let (beds, blocks): (Vec<_>, Vec<_>) = blocks.into_iter().partition(|(_, block)| block.id.is_bed());
+        // Later blocks at the same cell win, as they would placed one by one.
+        let mut staged: HashMap<Point3D, Block> = HashMap::new();
+        for (point, block) in blocks {
+            if let Some(block) = self.vet_block(&block, point, false, staged.get(&point)) {
+                staged.insert(point, block);
+            }
+        }
+
+        if !staged.is_empty() && !self.offline {
+            for point in staged.keys() {
+                self.journal_original(*point);
+            }
+            self.settle_all().await;
+            self.sync_journal();
+            let first = *staged.keys().next().expect("staged is not empty");
+            let min = staged.keys().fold(first, |min, p| Point3D::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)));
+            let structure = NBTStructure::from_blocks(staged.iter().map(|(point, block)| (block.clone(), *point - min)).collect());
+            let origin = min + self.build_area.origin;
+            let do_updates = *self.block_updates.borrow();
+            self.provider.put_structure(origin.x, origin.y, origin.z, &structure, do_updates).await?;
         }
+        for (point, block) in staged {
+            self.journal_original(point);
+            self.record_scoped_write(point, !self.offline);
+            self.record_provenance(point);
+            self.block_cache.borrow_mut().insert(point, block);
+        }
+
+        for (point, bed) in beds {
+            self.place_block_no_update(&bed, LocalPoint3D(point)).await;
+        }
+        Ok(())
     }
 
     fn get_block_form(&self, id: &BlockID) -> BlockForm {
@@ -158,7 +352,7 @@ impl Editor {
         form
     }
 
-    pub async fn place_block_chance(&self, block: &Block, point: Point3D, rng: &mut RNG, chance: i32) {
+    pub async fn place_block_chance(&self, block: &Block, point: LocalPoint3D, rng: &mut RNG, chance: i32) {
         if rng.rand_i32_range(1, 100) <= chance {
             self.place_block(block, point).await;
         }
@@ -167,20 +361,27 @@ impl Editor {
     /// Place a block immediately without triggering block updates.
     /// This is useful for placing support blocks (like floors) that might otherwise
     /// cause attached blocks (like doors) to break.
-    pub async fn place_block_no_update(&self, block: &Block, point: Point3D) {
+    pub async fn place_block_no_update(&self, block: &Block, point: LocalPoint3D) {
+        let point = point.0;
         if !self.world.build_area.contains(point + self.build_area.origin) {
             warn!("Point {:?} is outside the build area {:?} and will be ignored", point + self.build_area.origin, self.world.build_area);
             return;
         }
 
+        self.journal_original(point);
+        self.record_scoped_write(point, true);
+        self.record_provenance(point);
         self.block_cache.borrow_mut().insert(point, block.clone());
 
         if self.offline {
             return;
         }
+        self.sync_journal();
 
-        let positioned = PositionedBlock::from_block(block.clone(), (point + self.build_area.origin).into());
-        let _ = self.provider.put_blocks_no_updates(&vec![positioned]).await;
+        let world_point = point + self.build_area.origin;
+        self.await_overlapping(&HashSet::from([world_point])).await;
+        let positioned = PositionedBlock::from_block(block.clone(), world_point.into());
+        let _ = self.provider.put_blocks_no_updates(&[positioned]).await;
     }
 
     /// Spawns entities at the given local points. Each tuple is
@@ -192,6 +393,7 @@ impl Editor {
         if self.offline || entities.is_empty() {
             return;
         }
+        self.settle_all().await;
 
         let positioned: Vec<PositionedEntity> = entities
             .iter()
@@ -202,7 +404,7 @@ impl Editor {
                     y: abs.y.into(),
                     z: abs.z.into(),
                     id: id.clone(),
-                    data: data.clone(),
+                    data: self.tag_entity(data.as_deref()),
                 }
             })
             .collect();
@@ -213,67 +415,200 @@ impl Editor {
         }
     }
 
-    pub fn get_block(&self, point: Point3D) -> Block {
-        if let Some(block) = self.block_cache.borrow().get(&(point - self.build_area.origin)) {
-            return block.clone();
-        }
-
-        self.world.get_block(point).expect(&format!("Block at {:?} not found in world", point))
+    /// The block at `point` as this run left it: what the run placed there,
+    /// else the world's original block. Panics if the world has no data there.
+    pub fn get_block(&self, point: LocalPoint3D) -> Block {
+        self.try_get_block(point).unwrap_or_else(|| panic!("Block at {:?} not found in world", point))
     }
 
     /// Like `get_block` but returns `None` instead of panicking when the block
     /// is not in the cache or the world (e.g. synthetic/offline worlds).
-    pub fn try_get_block(&self, point: Point3D) -> Option<Block> {
-        if let Some(block) = self.block_cache.borrow().get(&(point - self.build_area.origin)) {
-            return Some(block.clone());
-        }
-        self.world.get_block(point)
+    pub fn try_get_block(&self, point: LocalPoint3D) -> Option<Block> {
+        self.get_cached_block(point).or_else(|| self.world.get_block(point))
     }
 
-    /// Reads a block straight from the placement cache by its **local** coordinate
-    /// — the same space `place_block` writes with. Returns `None` when nothing has
-    /// been placed at `local` this run.
-    ///
-    /// Use this to read back blocks *this run placed*. `get_block`/`try_get_block`
-    /// subtract the build-area origin (so they expect absolute coords and, given
-    /// the pipeline's local coords, fall through to world terrain instead of the
-    /// freshly-placed block on a live server). The cache survives `flush_buffer`,
+    /// Reads a block straight from the placement cache — `None` when nothing
+    /// has been placed at `local` this run. The cache survives `flush_buffer`,
     /// so a placed block stays readable here for the rest of the run.
-    pub fn get_cached_block(&self, local: Point3D) -> Option<Block> {
-        self.block_cache.borrow().get(&local).cloned()
+    pub fn get_cached_block(&self, local: LocalPoint3D) -> Option<Block> {
+        self.block_cache.borrow().get(&local.0).cloned()
+    }
+
+    /// Sends every buffered block and waits for all outstanding batches, then
+    /// reports what happened to the batches settled since the last call.
+    /// Failures are also logged as they settle, so callers that only need the
+    /// blocks out can ignore the report.
+    pub async fn flush_buffer(&self) -> FlushReport {
+        self.settle_all().await;
+        self.flush_report.take()
+    }
+
+    /// Sends the buffer and waits for every in-flight batch, leaving the
+    /// report for the next `flush_buffer`. The ordering barrier behind
+    /// `set_block_updates`, `set_flush_mode` and every command or entity
+    /// request, which may depend on blocks still in flight.
+    async fn settle_all(&self) {
+        self.dispatch_buffer().await;
+        while self.await_oldest().await {}
     }
 
-    pub async fn flush_buffer(&self) {
+    /// Sends the buffer: inline in sequential mode, as background batches in
+    /// pipelined mode.
+    async fn dispatch_buffer(&self) {
         // Drain the buffer first, releasing the borrow before the await
         let buffer: Vec<_> = self.block_buffer.borrow_mut().drain(..).collect();
 
         if buffer.is_empty() {
             return;
         }
+        if self.in_scope() {
+            self.mark_scopes_dispatched();
+        }
 
         if self.offline {
             // Offline mode: blocks already live in block_cache; skip HTTP.
             return;
         }
+        self.sync_journal();
 
         let do_updates = *self.block_updates.borrow();
-        let result = self.provider.put_blocks_options(&buffer, do_updates).await.expect("Failed to send blocks");
+        let FlushMode::Pipelined(config) = self.flush_mode else {
+            let started = Instant::now();
+            let result = self.provider.put_blocks_options(&buffer, do_updates).await;
+            self.settle(SentBatch { blocks: buffer, result, elapsed: started.elapsed() });
+            return;
+        };
 
-        for (index, response) in result.iter().enumerate() {
-            let point: Point3D = buffer[index].get_coordinate().into();
-            let block = buffer[index].get_block();
-            if response.status == 0 && self.world.get_block(point).is_none_or(|b| b != block) {
-                if block.id == "air".into() && self.world.get_block(point).is_none() {
+        // Batches may land in any order, so only the last write to each cell is sent.
+        let mut seen = HashSet::new();
+        let mut blocks: Vec<PositionedBlock> = buffer.into_iter().rev()
+            .filter(|block| {
+                let point: Point3D = block.get_coordinate().into();
+                seen.insert(point)
+            })
+            .collect();
+        blocks.reverse();
+
+        for batch in blocks.chunks(self.batch_size.get().max(1)) {
+            let positions: HashSet<Point3D> = batch.iter().map(|block| block.get_coordinate().into()).collect();
+            self.await_overlapping(&positions).await;
+            while self.in_flight.borrow().len() >= config.max_in_flight.max(1) {
+                self.await_oldest().await;
+            }
+
+            let provider = self.provider.clone();
+            let blocks = batch.to_vec();
+            let handle = tokio::spawn(async move {
+                let started = Instant::now();
+                let result = provider.put_blocks_options(&blocks, do_updates).await;
+                SentBatch { blocks, result, elapsed: started.elapsed() }
+            });
+            self.in_flight.borrow_mut().push_back(InFlightBatch { positions, handle });
+        }
+    }
+
+    /// Waits for the oldest in-flight batch. Returns false if none was pending.
+    async fn await_oldest(&self) -> bool {
+        let Some(batch) = self.in_flight.borrow_mut().pop_front() else {
+            return false;
+        };
+        match batch.handle.await {
+            Result::Ok(sent) => self.settle(sent),
+            Err(e) => self.record_failure(BatchFailure {
+                blocks: Vec::new(),
+                error: Some(format!("flush task died: {}", e)),
+                rejected: Vec::new(),
+            }),
+        }
+        true
+    }
+
+    /// Waits until no in-flight batch writes any of `positions`, so a later
+    /// write to a cell can't be overtaken by an earlier one.
+    async fn await_overlapping(&self, positions: &HashSet<Point3D>) {
+        let last_overlap = self.in_flight.borrow().iter().rposition(|batch| !batch.positions.is_disjoint(positions));
+        if let Some(index) = last_overlap {
+            for _ in 0..=index {
+                self.await_oldest().await;
+            }
+        }
+    }
+
+    /// Checks a sent batch's responses, recording blocks that didn't land and
+    /// adapting the pipelined batch size to how the request went.
+    fn settle(&self, sent: SentBatch) {
+        let SentBatch { blocks, result, elapsed } = sent;
+        {
+            let mut report = self.flush_report.borrow_mut();
+            report.batches += 1;
+            report.blocks += blocks.len();
+        }
+
+        let failure = match result {
+            Err(e) => Some(BatchFailure { blocks, error: Some(e.to_string()), rejected: Vec::new() }),
+            Result::Ok(responses) => {
+                let rejected = self.rejected_blocks(&blocks, &responses);
+                (!rejected.is_empty()).then_some(BatchFailure { blocks, error: None, rejected })
+            }
+        };
+
+        if let FlushMode::Pipelined(config) = self.flush_mode {
+            self.batch_size.set(config.adapt(self.batch_size.get(), elapsed, failure.is_some()));
+        }
+        if let Some(failure) = failure {
+            self.record_failure(failure);
+        }
+    }
+
+    fn rejected_blocks(&self, blocks: &[PositionedBlock], responses: &[BlockPlacementResponse]) -> Vec<(Point3D, Block, Option<Block>)> {
+        let mut rejected = Vec::new();
+        for (index, response) in responses.iter().enumerate() {
+            let point = WorldPoint3D(blocks[index].get_coordinate().into());
+            let local = self.to_local(point);
+            let block = blocks[index].get_block();
+            if response.status == 0 && self.world.get_block(local).is_none_or(|b| b != block) {
+                if block.id == "air".into() && self.world.get_block(local).is_none() {
                     continue;
                 }
 
-                if self.block_cache.borrow().contains_key(&(point - self.build_area.origin)) && self.get_block(point) == block {
+                if self.get_cached_block(local).is_some_and(|cached| cached == block) {
                     continue;
                 }
 
-                error!("Failed to place block {:?} at {:?}, world block is {:?}", block, point, self.world.get_block(point));
+                rejected.push((point.0, block, self.world.get_block(local)));
             }
         }
+        rejected
+    }
+
+    fn record_failure(&self, failure: BatchFailure) {
+        match &failure.error {
+            Some(e) => error!("Failed to send batch of {} blocks: {}", failure.blocks.len(), e),
+            None => {
+                let (point, block, found) = &failure.rejected[0];
+                error!("Server rejected {} of {} blocks in batch, first {:?} at {:?} (world block is {:?})", failure.rejected.len(), failure.blocks.len(), block, point, found);
+            }
+        }
+        self.flush_report.borrow_mut().failures.push(failure);
+    }
+
+    /// Flushes buffered blocks, then asks the backend to persist them (see
+    /// [`WorldBackend::save`]). Call once at the end of a run.
+    pub async fn save(&self) {
+        let report = self.flush_buffer().await;
+        if !report.is_ok() {
+            warn!("{} blocks in {} batches failed to place in the final flush", report.failed_blocks(), report.failures.len());
+        }
+        if self.offline {
+            return;
+        }
+        if let Err(e) = self.provider.save().await {
+            error!("Failed to save world: {}", e);
+        }
+        self.sync_journal();
+        if let Some(journal) = self.journal.borrow().as_ref() {
+            info!("Journaled {} blocks; undo this run with `rollback {}`", journal.len(), journal.path().display());
+        }
     }
 
     pub fn world(&self) -> &World {
@@ -307,6 +642,7 @@ impl Editor {
     /// buffer/cache**, so the placed blocks won't be visible to later
     /// `get_block` reads.
     pub async fn place_feature(&self, feature: &str, point: Point3D) -> anyhow::Result<()> {
+        self.settle_all().await;
         let world = point + self.build_area.origin;
         let command = format!("place feature {} {} {} {}", feature, world.x, world.y, world.z);
         self.provider.command(vec![command]).await?;
@@ -319,6 +655,7 @@ impl Editor {
         if self.offline {
             return Ok(());
         }
+        self.settle_all().await;
         self.provider.command(vec![format!("gamerule {} {}", rule, value)]).await?;
         Ok(())
     }
@@ -329,6 +666,7 @@ impl Editor {
         if self.offline {
             return Ok(());
         }
+        self.settle_all().await;
         self.provider.command(vec![format!("difficulty {}", difficulty)]).await?;
         Ok(())
     }
@@ -345,15 +683,16 @@ impl Editor {
         if self.offline {
             return Ok(());
         }
+        self.settle_all().await;
         let world = point + self.build_area.origin;
         let entity = PositionedEntity {
             x: Coordinate::Absolute(world.x),
             y: Coordinate::Absolute(world.y),
             z: Coordinate::Absolute(world.z),
             id: id.to_string(),
-            data: data.map(|s| s.to_string()),
+            data: self.tag_entity(data),
         };
-        self.provider.put_entities(0, 0, 0, &vec![entity]).await
+        self.provider.put_entities(0, 0, 0, &[entity]).await
     }
 
     /// Like [`spawn_entity`] but raises the entity by a fractional `y_offset`
@@ -369,15 +708,16 @@ impl Editor {
         if self.offline {
             return Ok(());
         }
+        self.settle_all().await;
         let world = point + self.build_area.origin;
         let entity = PositionedEntity {
             x: Coordinate::Absolute(world.x),
             y: Coordinate::AbsoluteF(world.y as f64 + y_offset as f64),
             z: Coordinate::Absolute(world.z),
             id: id.to_string(),
-            data: data.map(|s| s.to_string()),
+            data: self.tag_entity(data),
         };
-        self.provider.put_entities(0, 0, 0, &vec![entity]).await
+        self.provider.put_entities(0, 0, 0, &[entity]).await
     }
 }
 
@@ -386,5 +726,8 @@ impl Drop for Editor {
         if !self.block_buffer.borrow().is_empty() {
             error!("Editor was dropped with non-empty block buffer!");
         }
+        if !self.in_flight.borrow().is_empty() {
+            error!("Editor was dropped with {} block batches still in flight; their failures go unreported", self.in_flight.borrow().len());
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use anyhow::bail;
+use log::{info, warn};
+
+use crate::{generator::nbts::NBTStructure, geometry::{LocalPoint3D, Point3D, Rect3D}, minecraft::{Block, Schematic}};
+
+use super::Editor;
+
+/// Vanilla structure blocks refuse to save or load anything larger than this
+/// along any axis; `/place template` has no such limit.
+const STRUCTURE_BLOCK_MAX: i32 = 48;
+
+impl Editor {
+    /// The block at build-area-local `local` as it stands after this run: what
+    /// the run placed there, else the world's original block, else air.
+    pub fn final_block(&self, local: Point3D) -> Block {
+        self.get_cached_block(LocalPoint3D(local))
+            .or_else(|| self.world().get_block(LocalPoint3D(local)))
+            .unwrap_or_else(|| Block::from("minecraft:air"))
+    }
+
+    /// Writes the whole build area, placed blocks over original terrain, to
+    /// `path`. The format follows the extension: `.nbt` writes a vanilla
+    /// structure file, anything else a Sponge v3 `.schem`.
+    pub fn export(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let area = Rect3D { origin: Point3D::new(0, 0, 0), size: self.world().build_area.size };
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("nbt") => self.export_structure(area, path),
+            _ => self.export_schematic(area, path),
+        }
+    }
+
+    /// Writes the build-area-local `region` to `path` as a Sponge Schematic v3.
+    /// Its offset records the region's world position, so WorldEdit pastes it
+    /// back in place with `//paste -o`.
+    pub fn export_schematic(&self, region: Rect3D, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let schematic = Schematic::from_fn(region.size, region.origin + self.world().build_area.origin, |p| self.final_block(region.origin + p))?;
+        schematic.write(path)?;
+        info!("Exported {:?} schematic ({} palette entries, {} block entities) to {}", region.size, schematic.blocks.palette.len(), schematic.blocks.block_entities.len(), path.display());
+        Ok(())
+    }
+
+    /// Writes the build-area-local `region` to `path` as a vanilla structure
+    /// `.nbt`. Meant for single buildings and other small regions: structure
+    /// blocks only handle 48 blocks per side, and the format lists every block
+    /// individually, so whole towns are better exported as schematics.
+    pub fn export_structure(&self, region: Rect3D, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if region.size.x <= 0 || region.size.y <= 0 || region.size.z <= 0 {
+            bail!("cannot export empty region {:?}", region);
+        }
+        if region.size.x > STRUCTURE_BLOCK_MAX || region.size.y > STRUCTURE_BLOCK_MAX || region.size.z > STRUCTURE_BLOCK_MAX {
+            warn!("Region {:?} exceeds the structure block limit of {}; load it with /place template", region.size, STRUCTURE_BLOCK_MAX);
+        }
+
+        let mut structure = NBTStructure::from_blocks(Vec::new());
+        structure.size = [region.size.x, region.size.y, region.size.z];
+        for y in 0..region.size.y {
+            for z in 0..region.size.z {
+                for x in 0..region.size.x {
+                    let offset = Point3D::new(x, y, z);
+                    structure.add_block(self.final_block(region.origin + offset), offset);
+                }
+            }
+        }
+        structure.write(path)?;
+        info!("Exported {:?} structure ({} palette entries) to {}", region.size, structure.palette.len(), path.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use std::{collections::HashSet, time::Duration};
+
+use tokio::task::JoinHandle;
+
+use crate::{geometry::Point3D, http_mod::{BlockPlacementResponse, PositionedBlock}, minecraft::Block};
+
+/// How [`Editor::flush_buffer`](super::Editor::flush_buffer) sends buffered blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FlushMode {
+    /// One PUT per full buffer (`Editor::set_buffer_size`), awaited before
+    /// generation continues.
+    #[default]
+    Sequential,
+    /// Full buffers are sent in the background while generation continues, with
+    /// up to `max_in_flight` PUTs outstanding and the batch size adapting to
+    /// server latency.
+    Pipelined(PipelineConfig),
+}
+
+/// Tuning for [`FlushMode::Pipelined`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipelineConfig {
+    pub max_in_flight: usize,
+    /// Batch size bounds. Batches start at `min_batch` (or the editor's buffer
+    /// size, if larger), grow while the server answers within
+    /// `target_latency`, and halve when it is slower or a batch fails.
+    pub min_batch: usize,
+    pub max_batch: usize,
+    pub target_latency: Duration,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 4,
+            min_batch: 256,
+            max_batch: 8192,
+            target_latency: Duration::from_secs(2),
+        }
+    }
+}
+
+impl PipelineConfig {
+    /// The next batch size after a batch of `size` settled in `elapsed`.
+    pub fn adapt(&self, size: usize, elapsed: Duration, failed: bool) -> usize {
+        let next = if failed || elapsed > self.target_latency {
+            size / 2
+        } else if elapsed < self.target_latency / 2 {
+            size * 2
+        } else {
+            size
+        };
+        next.clamp(self.min_batch, self.max_batch.max(self.min_batch))
+    }
+}
+
+/// A flushed batch that didn't fully land.
+#[derive(Debug, Clone)]
+pub struct BatchFailure {
+    /// Every block the batch carried, in absolute coordinates.
+    pub blocks: Vec<PositionedBlock>,
+    /// The transport or server error, when the whole request failed.
+    pub error: Option<String>,
+    /// Blocks the server answered but didn't place, with the world block
+    /// found in their place (`None` if unknown). Empty when `error` is set.
+    pub rejected: Vec<(Point3D, Block, Option<Block>)>,
+}
+
+impl BatchFailure {
+    /// Blocks of this batch that are not in the world.
+    pub fn failed_count(&self) -> usize {
+        match self.error {
+            Some(_) => self.blocks.len(),
+            None => self.rejected.len(),
+        }
+    }
+}
+
+/// What the flushes since the last [`flush_buffer`](super::Editor::flush_buffer)
+/// call achieved.
+#[derive(Debug, Clone, Default)]
+pub struct FlushReport {
+    pub batches: usize,
+    pub blocks: usize,
+    pub failures: Vec<BatchFailure>,
+}
+
+impl FlushReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn failed_blocks(&self) -> usize {
+        self.failures.iter().map(BatchFailure::failed_count).sum()
+    }
+}
+
+/// A pipelined PUT that hasn't been awaited yet.
+#[derive(Debug)]
+pub(super) struct InFlightBatch {
+    /// Absolute positions the batch writes, so later writes to the same cells
+    /// wait for it instead of racing it.
+    pub positions: HashSet<Point3D>,
+    pub handle: JoinHandle<SentBatch>,
+}
+
+#[derive(Debug)]
+pub(super) struct SentBatch {
+    pub blocks: Vec<PositionedBlock>,
+    pub result: anyhow::Result<Vec<BlockPlacementResponse>>,
+    pub elapsed: Duration,
+}
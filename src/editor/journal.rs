@@ -0,0 +1,164 @@
+use std::{collections::HashSet, fs::{File, OpenOptions}, io::{BufRead, BufReader, BufWriter, Write}, path::{Path, PathBuf}};
+
+use anyhow::{anyhow, Context};
+use log::{info, warn};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{geometry::Point3D, http_mod::{PositionedBlock, WorldBackend}, minecraft::Block};
+
+/// Blocks per PUT when a journal is rolled back.
+const ROLLBACK_BATCH: usize = 4096;
+
+/// The journal's first line: the entity tag every entity of the run carries.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct JournalHeader {
+    run: String,
+}
+
+/// The undo log of one generation run: the original block of every cell the
+/// editor overwrote, and the tag put on every entity it spawned, so
+/// [`rollback`] can put the world back the way it was.
+///
+/// The file is JSON lines — a header, then one absolute [`PositionedBlock`] per
+/// cell, block entity data included — appended as the run goes and synced
+/// before each batch is sent, so a crashed run can still be undone.
+#[derive(Debug)]
+pub struct Journal {
+    run: String,
+    path: PathBuf,
+    recorded: HashSet<Point3D>,
+    file: BufWriter<File>,
+}
+
+impl Journal {
+    /// Starts a fresh journal at `path`, replacing any existing file. The run
+    /// tag is taken from the wall clock.
+    pub fn create(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let started = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis());
+        let run = format!("tome_run_{}", started);
+
+        let file = OpenOptions::new().create(true).write(true).truncate(true).open(path)
+            .with_context(|| format!("Failed to create journal {}", path.display()))?;
+        let mut file = BufWriter::new(file);
+        serde_json::to_writer(&mut file, &JournalHeader { run: run.clone() })?;
+        file.write_all(b"\n")?;
+        file.flush()?;
+        Ok(Self { run, path: path.to_path_buf(), recorded: HashSet::new(), file })
+    }
+
+    /// The entity tag of this run.
+    pub fn run_tag(&self) -> &str {
+        &self.run
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Cells recorded so far.
+    pub fn len(&self) -> usize {
+        self.recorded.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recorded.is_empty()
+    }
+
+    pub fn contains(&self, point: Point3D) -> bool {
+        self.recorded.contains(&point)
+    }
+
+    /// Records `original` as the block at absolute `point` before the run
+    /// touched it. Only the first record of a cell counts: later ones would be
+    /// the run's own blocks.
+    pub fn record(&mut self, point: Point3D, original: Block) -> anyhow::Result<()> {
+        if !self.recorded.insert(point) {
+            return Ok(());
+        }
+        serde_json::to_writer(&mut self.file, &PositionedBlock::from_block(original, point.into()))?;
+        self.file.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Writes buffered records to disk.
+    pub fn sync(&mut self) -> anyhow::Result<()> {
+        Ok(self.file.flush()?)
+    }
+}
+
+/// Adds `tag` to an entity's SNBT `data`, keeping any `Tags` it already has.
+pub fn tag_entity_data(data: Option<&str>, tag: &str) -> String {
+    let quoted = format!("\"{}\"", tag);
+    let data = data.map(str::trim).filter(|d| d.starts_with('{') && d.ends_with('}')).unwrap_or("{}");
+    if let Some(start) = data.find("Tags:[") {
+        let (head, rest) = data.split_at(start + "Tags:[".len());
+        let separator = if rest.trim_start().starts_with(']') { "" } else { "," };
+        return format!("{}{}{}{}", head, quoted, separator, rest);
+    }
+    let body = data[1..data.len() - 1].trim();
+    match body.is_empty() {
+        true => format!("{{Tags:[{}]}}", quoted),
+        false => format!("{{{},Tags:[{}]}}", body, quoted),
+    }
+}
+
+/// What [`rollback`] restored.
+#[derive(Debug, Clone, Default)]
+pub struct RollbackReport {
+    /// The run's entity tag.
+    pub run: String,
+    pub blocks: usize,
+    /// Blocks in batches the backend failed to take.
+    pub failed_blocks: usize,
+    /// Whether the run's tagged entities were killed.
+    pub entities_removed: bool,
+}
+
+/// Undoes the run recorded in the journal at `path`: puts every original block
+/// back (without block updates, so nothing pops off or flows), kills the
+/// entities tagged with the run, and saves the backend.
+pub async fn rollback(backend: &dyn WorldBackend, path: impl AsRef<Path>) -> anyhow::Result<RollbackReport> {
+    let path = path.as_ref();
+    let file = File::open(path).with_context(|| format!("Failed to open journal {}", path.display()))?;
+    let mut lines = BufReader::new(file).lines();
+    let header: JournalHeader = serde_json::from_str(&lines.next().ok_or_else(|| anyhow!("journal {} is empty", path.display()))??)
+        .with_context(|| format!("{} is not a journal", path.display()))?;
+
+    let mut blocks = Vec::new();
+    for (index, line) in lines.enumerate() {
+        let line = line?;
+        match serde_json::from_str::<PositionedBlock>(&line) {
+            Ok(block) => blocks.push(block),
+            // A run killed mid-write leaves a partial last line.
+            Err(e) => warn!("Skipping unreadable journal line {}: {}", index + 2, e),
+        }
+    }
+    info!("Rolling back run {}: {} blocks", header.run, blocks.len());
+
+    let mut report = RollbackReport { run: header.run.clone(), blocks: blocks.len(), ..Default::default() };
+    for batch in blocks.chunks(ROLLBACK_BATCH) {
+        if let Err(e) = backend.put_blocks_options(batch, false).await {
+            warn!("Failed to restore {} blocks: {}", batch.len(), e);
+            report.failed_blocks += batch.len();
+        }
+    }
+
+    match backend.command(vec![format!("kill @e[tag={}]", header.run)]).await {
+        Ok(responses) => {
+            report.entities_removed = true;
+            for response in responses {
+                info!("{}", response.message);
+            }
+        }
+        Err(e) => warn!("Failed to remove the run's entities: {}", e),
+    }
+
+    backend.save().await?;
+    Ok(report)
+}
@@ -1,6 +1,14 @@
 mod test;
 mod editor;
 mod world;
+mod export;
+mod flush;
+mod journal;
+mod provenance;
+mod transaction;
 
 pub use editor::Editor;
-pub use world::World;
\ No newline at end of file
+pub use world::World;
+pub use flush::{BatchFailure, FlushMode, FlushReport, PipelineConfig};
+pub use journal::{rollback, Journal, RollbackReport};
+pub use provenance::{Owner, Provenance};
\ No newline at end of file
@@ -0,0 +1,147 @@
+use std::{fmt, fs::File, io::{BufWriter, Write}, path::Path, sync::Arc};
+
+use log::info;
+
+use crate::{generator::{buildings::BuildingID, nbts::StructureID}, geometry::{LocalPoint3D, Point3D}, minecraft::block_state_string};
+
+use super::Editor;
+
+/// The thing a block was placed for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Owner {
+    Building(BuildingID),
+    Structure(StructureID),
+    /// Index of the road in the path list it was built from.
+    Road(usize),
+}
+
+impl fmt::Display for Owner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Owner::Building(id) => write!(f, "Building({})", id.0),
+            Owner::Structure(id) => write!(f, "Structure({} {})", id.structure_type.0, id.id),
+            Owner::Road(id) => write!(f, "Road({})", id),
+        }
+    }
+}
+
+/// Which generator placed a block: the subsystem that was running and, when it
+/// was working on one, the building, structure or road it was for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// `module_path!()` of the placing code, or a phase name such as
+    /// `settlement::roads`.
+    pub subsystem: &'static str,
+    pub owner: Option<Owner>,
+}
+
+impl Provenance {
+    /// The subsystem without the crate and `generator::` prefix, e.g.
+    /// `buildings_v2::roof::gable_roof`.
+    pub fn subsystem(&self) -> &'static str {
+        let path = self.subsystem.strip_prefix(concat!(env!("CARGO_CRATE_NAME"), "::")).unwrap_or(self.subsystem);
+        path.strip_prefix("generator::").unwrap_or(path)
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.owner {
+            Some(owner) => write!(f, "{} for {}", self.subsystem(), owner),
+            None => write!(f, "{}", self.subsystem()),
+        }
+    }
+}
+
+impl Editor {
+    /// Opens a provenance scope: blocks placed until the matching
+    /// [`pop_provenance`](Self::pop_provenance) are recorded as placed by
+    /// `subsystem`. Scopes nest; with no `owner` of its own, a scope keeps the
+    /// enclosing scope's owner, so a roof placed inside a house's scope is
+    /// still that house's.
+    pub fn push_provenance(&self, subsystem: &'static str, owner: Option<Owner>) {
+        let mut scopes = self.provenance_scopes.borrow_mut();
+        let owner = owner.or_else(|| scopes.last().and_then(|scope| scope.owner.clone()));
+        scopes.push(Arc::new(Provenance { subsystem, owner }));
+    }
+
+    pub fn pop_provenance(&self) {
+        if self.provenance_scopes.borrow_mut().pop().is_none() {
+            log::warn!("pop_provenance called with no open scope");
+        }
+    }
+
+    /// Starts a top-level generation phase: closes every open scope and opens
+    /// one for `subsystem`, so a phase that returned early without popping
+    /// can't leak its scope into the next.
+    pub fn begin_phase(&self, subsystem: &'static str) {
+        self.provenance_scopes.borrow_mut().clear();
+        self.push_provenance(subsystem, None);
+    }
+
+    /// Records the current scope against a block just placed at `local`.
+    /// Outside any scope the cell's earlier record is dropped rather than
+    /// left to name the wrong placer.
+    pub(super) fn record_provenance(&self, local: Point3D) {
+        match self.provenance_scopes.borrow().last() {
+            Some(scope) => {
+                self.block_provenance.borrow_mut().insert(local, scope.clone());
+            }
+            None => {
+                self.block_provenance.borrow_mut().remove(&local);
+            }
+        }
+    }
+
+    /// Who placed the block at `local` this run, if it was placed in a scope.
+    pub fn provenance(&self, local: LocalPoint3D) -> Option<Arc<Provenance>> {
+        self.block_provenance.borrow().get(&local.0).cloned()
+    }
+
+    /// A one-line answer to "where did this block come from?", e.g.
+    /// `minecraft:oak_stairs[facing=east] at (120, 71, -38) placed by
+    /// buildings_v2::roof::gable_roof for Building(42)`.
+    pub fn describe_block(&self, local: LocalPoint3D) -> String {
+        let world = self.to_world(local).0;
+        let at = format!("({}, {}, {})", world.x, world.y, world.z);
+        match (self.get_cached_block(local), self.provenance(local)) {
+            (Some(block), Some(provenance)) => format!("{} at {} placed by {}", block_state_string(&block), at, provenance),
+            (Some(block), None) => format!("{} at {} placed outside any provenance scope", block_state_string(&block), at),
+            (None, _) => match self.world().get_block(local) {
+                Some(block) => format!("{} at {} was not placed this run", block_state_string(&block), at),
+                None => format!("nothing known at {}", at),
+            },
+        }
+    }
+
+    /// Writes every recorded block to `path` as JSON lines: world position,
+    /// block state, subsystem and owner.
+    pub fn dump_provenance(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = BufWriter::new(File::create(path)?);
+        let provenance = self.block_provenance.borrow();
+        let mut points: Vec<_> = provenance.keys().copied().collect();
+        points.sort_by_key(|p| (p.y, p.z, p.x));
+        for local in &points {
+            let world = self.to_world(LocalPoint3D(*local)).0;
+            let block = self.get_cached_block(LocalPoint3D(*local)).map(|b| block_state_string(&b));
+            let record = &provenance[local];
+            let line = serde_json::json!({
+                "x": world.x,
+                "y": world.y,
+                "z": world.z,
+                "block": block,
+                "subsystem": record.subsystem(),
+                "owner": record.owner.as_ref().map(|owner| owner.to_string()),
+            });
+            serde_json::to_writer(&mut file, &line)?;
+            file.write_all(b"\n")?;
+        }
+        file.flush()?;
+        info!("Wrote provenance of {} blocks to {}", points.len(), path.display());
+        Ok(())
+    }
+}
@@ -1,24 +1,32 @@
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
     use log::info;
-    use crate::editor::World;
+    use crate::editor::{FlushMode, Owner, PipelineConfig, World};
+    use crate::generator::buildings::BuildingID;
+    use crate::generator::{build_claim::FULL_COLUMN, BuildClaim};
     use crate::geometry::Point2D;
 
-    use crate::geometry::Point3D;
-    use crate::http_mod::GDMCHTTPProvider;
+    use crate::geometry::{LocalPoint3D, Point3D, Rect3D, WorldPoint3D};
+    use crate::http_mod::{BlockPlacementResponse, CommandResponse, EntityResponse, HeightMapType, PositionedBiome, PositionedBlock, PositionedEntity, WorldBackend};
     
-    use crate::minecraft::Block;
+    use crate::minecraft::{Block, Chunk, Schematic, DATA_VERSION};
+    use crate::generator::nbts::load_nbt_structure;
+    use crate::http_mod::mock_server::{MockServer, MockWorld};
     use crate::util::init_logger;
 
     #[tokio::test]
     async fn place_blocks() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
 
         let build_area = provider.get_build_area().await.expect("Failed to get build area");
         
-        let world = World::new(&provider).await.expect("Failed to create world");
-        let mut editor = world.get_editor();
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let mut editor = world.get_editor_with_backend(provider.clone());
 
         let block = Block {
             id: "stone".into(),
@@ -30,18 +38,22 @@ mod tests {
             for z in 0..build_area.width() {
                 let point = editor.world_mut().add_height(Point2D { x, y: z }).expect("test cell in bounds");
                 info!("Placing block at: {:?}", point);
-                editor.place_block( &block, point).await;
+                editor.place_block( &block, LocalPoint3D(point)).await;
             }
-        }         
+        }
+        editor.flush_buffer().await;
+
+        assert_eq!(server.world().get_block(Point3D::new(3, 64, 5)).id, "minecraft:stone".into());
     }
 
     #[tokio::test]
     async fn get_surface_biome_at() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
 
         let build_area = provider.get_build_area().await.expect("Failed to get build area");
-        let world = World::new(&provider).await.expect("Failed to create world");
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
 
         for x in 0..build_area.length() {
             for z in 0..build_area.width() {
@@ -54,28 +66,32 @@ mod tests {
     #[tokio::test]
     async fn world_get_block() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
-        let world = World::new(&provider).await.expect("Failed to create world");
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
 
-        let block = world.get_block(Point3D::new(0, 0, 0));
+        let block = world.get_block(LocalPoint3D::new(0, 0, 0));
 
         println!("Block at (0, 0, 0): {:?}", block);
+        assert_eq!(block.map(|b| b.id), Some("minecraft:stone".into()));
+        assert_eq!(world.get_ground_block(Point2D::new(0, 0)).map(|b| b.id.clone()), Some("minecraft:grass_block".into()));
     }
 
     #[tokio::test]
     async fn get_surface_block_at() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
 
         let build_area = provider.get_build_area().await.expect("Failed to get build area");
-        let world = World::new(&provider).await.expect("Failed to create world");
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
 
         println!("Build area: {:?}", build_area);
 
         for x in 0..build_area.length() {
             for z in 0..build_area.width() {
                 let height = world.get_height_at(Point2D::new(x, z)).expect("test cell in bounds") - 1;
-                let block = world.get_block(Point3D::new(x, height, z));
+                let block = world.get_block(LocalPoint3D::new(x, height, z));
                 let point = Point3D::new(x, height, z) + world.build_area.origin;
                 println!("Block at ({:?}) height:{} {:?}", point, height, block);
                 //assert_ne!(block.unwrap().id, BlockID::Unknown, "Block should not be unknown");
@@ -86,24 +102,474 @@ mod tests {
     #[tokio::test]
     async fn copy_surface_blocks() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
 
         let build_area = provider.get_build_area().await.expect("Failed to get build area");
-        let world = World::new(&provider).await.expect("Failed to create world");
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
 
-        let editor = world.get_editor();
+        let editor = world.get_editor_with_backend(provider.clone());
 
         println!("Build area: {:?}", build_area);
         for x in 0..build_area.length() {
             for z in 0..build_area.width() {
                 let height = editor.world().get_height_at(Point2D::new(x, z)).expect("test cell in bounds") - 1;
-                let block = editor.world().get_block(Point3D::new(x, height, z));
+                let block = editor.world().get_block(LocalPoint3D::new(x, height, z));
                 let point = Point3D::new(x, height, z) + editor.world().build_area.origin;
                 let new_point = Point3D::new(x, 200, z);
                 println!("Block at ({:?}) height:{} {:?}", point, height, block);
-                editor.place_block( &block.unwrap(), new_point).await;
+                editor.place_block( &block.unwrap(), LocalPoint3D(new_point)).await;
                 //assert_ne!(block.unwrap().id, BlockID::Unknown, "Block should not be unknown");
             }
         }
     }
-}
\ No newline at end of file
+
+    /// A backend with flat terrain that records every placed block — enough to
+    /// drive `World::new` and an `Editor` end to end without a server.
+    #[derive(Debug, Default)]
+    struct RecordingBackend {
+        placed: std::sync::Mutex<Vec<PositionedBlock>>,
+        /// Fail every PUT, as a dead server would.
+        fail_puts: AtomicBool,
+        in_flight: AtomicUsize,
+        peak_in_flight: AtomicUsize,
+        /// How many blocks had landed when each command arrived.
+        landed_at_command: std::sync::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl WorldBackend for RecordingBackend {
+        async fn get_build_area(&self) -> anyhow::Result<Rect3D> {
+            Ok(Rect3D::from_points(Point3D::new(100, -64, 200), Point3D::new(115, 100, 215)))
+        }
+
+        async fn get_blocks(&self, _x: i32, _y: i32, _z: i32, _dx: i32, _dy: i32, _dz: i32) -> anyhow::Result<Vec<PositionedBlock>> {
+            Ok(self.placed.lock().unwrap().clone())
+        }
+
+        async fn put_blocks_options(&self, blocks: &[PositionedBlock], _do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+            if self.fail_puts.load(Ordering::SeqCst) {
+                anyhow::bail!("connection refused");
+            }
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak_in_flight.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            self.placed.lock().unwrap().extend_from_slice(blocks);
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(blocks.iter().map(|_| BlockPlacementResponse { status: 1 }).collect())
+        }
+
+        async fn get_heightmap(&self, _x: i32, _z: i32, dx: i32, dz: i32, _height_map_type: HeightMapType) -> anyhow::Result<Vec<Vec<i32>>> {
+            Ok(vec![vec![64; dz as usize]; dx as usize])
+        }
+
+        async fn get_biomes(&self, _x: i32, _y: i32, _z: i32, _dx: i32, _dy: i32, _dz: i32) -> anyhow::Result<Vec<PositionedBiome>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_chunks(&self, _x: i32, _y: i32, _z: i32, _dx: i32, _dy: i32, _dz: i32) -> anyhow::Result<Vec<Chunk>> {
+            Ok(Vec::new())
+        }
+
+        async fn command(&self, commands: Vec<String>) -> anyhow::Result<Vec<CommandResponse>> {
+            self.landed_at_command.lock().unwrap().push(self.placed.lock().unwrap().len());
+            Ok(commands.iter().map(|_| CommandResponse::default()).collect())
+        }
+
+        async fn get_entities(&self, _x: i32, _y: i32, _z: i32, _dx: i32, _dy: i32, _dz: i32) -> anyhow::Result<Vec<EntityResponse>> {
+            Ok(Vec::new())
+        }
+
+        async fn put_entities(&self, _x: i32, _y: i32, _z: i32, _entities: &[PositionedEntity]) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn editor_writes_to_custom_backend() {
+        init_logger();
+        let backend = Arc::new(RecordingBackend::default());
+        let world = World::new(backend.as_ref()).await.expect("Failed to create world");
+        assert_eq!(world.get_height_at(Point2D::new(3, 3)), Some(64 + 64));
+
+        let editor = world.get_editor_with_backend(backend.clone());
+        let stone = Block::from_id("stone".into());
+        editor.place_block(&stone, LocalPoint3D::new(1, 2, 3)).await;
+        editor.flush_buffer().await;
+
+        let placed = backend.placed.lock().unwrap().clone();
+        assert_eq!(placed.len(), 1);
+        let point: Point3D = placed[0].get_coordinate().into();
+        assert_eq!(point, Point3D::new(101, -62, 203));
+        assert_eq!(editor.get_cached_block(LocalPoint3D::new(1, 2, 3)), Some(stone));
+    }
+
+    #[tokio::test]
+    async fn only_synthetic_worlds_make_up_missing_chunks() {
+        init_logger();
+        let backend = RecordingBackend::default();
+        let world = World::new(&backend).await.expect("Failed to create world");
+        assert_eq!(world.get_block(LocalPoint3D::new(1, 2, 3)), None, "the backend served no chunks");
+
+        let synthetic = World::synthetic(world.build_area, 64);
+        assert_eq!(synthetic.get_block(LocalPoint3D::new(1, 200, 3)).map(|b| b.id), Some("minecraft:air".into()));
+    }
+
+    #[tokio::test]
+    async fn failed_paste_leaves_cache_alone() {
+        init_logger();
+        let backend = Arc::new(RecordingBackend::default());
+        let world = World::new(backend.as_ref()).await.expect("Failed to create world");
+        let editor = world.get_editor_with_backend(backend.clone());
+        let stone = Block::from_id("stone".into());
+        let bed = Block::from_id("red_bed".into());
+        let blocks = vec![(Point3D::new(1, 2, 3), stone.clone()), (Point3D::new(2, 2, 3), bed.clone())];
+
+        backend.fail_puts.store(true, Ordering::SeqCst);
+        assert!(editor.paste_blocks(blocks.clone()).await.is_err());
+        assert_eq!(editor.get_cached_block(LocalPoint3D::new(1, 2, 3)), None);
+
+        backend.fail_puts.store(false, Ordering::SeqCst);
+        editor.paste_blocks(blocks).await.expect("Paste should succeed");
+        assert_eq!(editor.get_cached_block(LocalPoint3D::new(1, 2, 3)), Some(stone));
+        assert_eq!(editor.get_cached_block(LocalPoint3D::new(2, 2, 3)), Some(bed));
+        // The bed went on its own, after the paste.
+        let placed = backend.placed.lock().unwrap().clone();
+        assert_eq!(placed.last().map(|block| block.get_block().id), Some("red_bed".into()));
+    }
+
+    #[tokio::test]
+    async fn export_schematic_and_structure() {
+        init_logger();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let editor = world.get_editor_with_backend(provider.clone());
+
+        let chest = Block::new("chest".into(), Some([("facing".to_string(), "west".to_string())].into()), Some("{Items:[{Slot:0b,id:\"minecraft:bread\",count:3}]}".to_string()));
+        editor.place_block(&chest, LocalPoint3D::new(1, 64, 1)).await;
+        let region = Rect3D { origin: Point3D::new(0, 62, 0), size: Point3D::new(3, 4, 2) };
+        let dir = std::env::temp_dir().join(format!("tome-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Failed to create export folder");
+
+        let schem_path = dir.join("town.schem");
+        editor.export_schematic(region, &schem_path).expect("Failed to export schematic");
+        let schematic = Schematic::from_bytes(&std::fs::read(&schem_path).unwrap()).expect("Failed to read schematic");
+        assert_eq!(schematic.size(), region.size);
+        let states = schematic.block_states().expect("Palette should cover every cell");
+        let at = |x: i32, y: i32, z: i32| &states[((y * 2 + z) * 3 + x) as usize];
+        assert_eq!(at(0, 0, 0), "minecraft:dirt");
+        assert_eq!(at(0, 1, 0), "minecraft:grass_block");
+        assert_eq!(at(1, 2, 1), "minecraft:chest[facing=west]");
+        assert_eq!(at(2, 3, 1), "minecraft:air");
+        assert_eq!(schematic.blocks.block_entities.len(), 1);
+        assert_eq!(schematic.blocks.block_entities[0].id, "minecraft:chest");
+        assert_eq!(schematic.blocks.block_entities[0].pos.to_vec(), vec![1, 2, 1]);
+
+        let nbt_path = dir.join("house.nbt");
+        editor.export_structure(region, &nbt_path).expect("Failed to export structure");
+        let structure = load_nbt_structure(nbt_path.to_str().unwrap()).expect("Failed to read structure");
+        assert_eq!(structure.size, [3, 4, 2]);
+        assert_eq!(structure.data_version, Some(DATA_VERSION));
+        assert_eq!(structure.blocks.len(), 24);
+        let placed = structure.blocks.iter().find(|b| b.pos == [1, 2, 1]).expect("Chest cell should be exported");
+        assert_eq!(structure.palette[placed.state].name, "chest".into());
+        assert!(placed.nbt.is_some());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn pipelined_flush_keeps_last_write() {
+        init_logger();
+        let backend = Arc::new(RecordingBackend::default());
+        let world = World::new(backend.as_ref()).await.expect("Failed to create world");
+        let mut editor = world.get_editor_with_backend(backend.clone());
+        editor.set_flush_mode(FlushMode::Pipelined(PipelineConfig { max_in_flight: 3, min_batch: 8, max_batch: 8, ..Default::default() })).await;
+        editor.set_buffer_size(1);
+
+        let stone = Block::from_id("stone".into());
+        let gold = Block::from_id("gold_block".into());
+        for x in 0..16 {
+            for z in 0..8 {
+                editor.place_block_forced(&stone, LocalPoint3D::new(x, 70, z)).await;
+            }
+        }
+        // Overwrites cells still in flight; must land after the stone.
+        editor.place_block_forced(&gold, LocalPoint3D::new(0, 70, 0)).await;
+        let report = editor.flush_buffer().await;
+
+        assert!(report.is_ok());
+        assert_eq!(report.blocks, 16 * 8 + 1);
+        assert!(backend.peak_in_flight.load(Ordering::SeqCst) > 1, "batches should overlap");
+        let placed = backend.placed.lock().unwrap().clone();
+        let corner = Point3D::new(100, -64 + 70, 200);
+        let last = placed.iter().rev().find(|b| Into::<Point3D>::into(b.get_coordinate()) == corner);
+        assert_eq!(last.expect("Corner should be placed").get_block().id, gold.id);
+    }
+
+    #[tokio::test]
+    async fn commands_wait_for_pipelined_blocks() {
+        init_logger();
+        let backend = Arc::new(RecordingBackend::default());
+        let world = World::new(backend.as_ref()).await.expect("Failed to create world");
+        let mut editor = world.get_editor_with_backend(backend.clone());
+        editor.set_flush_mode(FlushMode::Pipelined(PipelineConfig { max_in_flight: 3, min_batch: 8, max_batch: 8, ..Default::default() })).await;
+        editor.set_buffer_size(1);
+
+        let stone = Block::from_id("stone".into());
+        for x in 0..16 {
+            editor.place_block_forced(&stone, LocalPoint3D::new(x, 70, 0)).await;
+        }
+        // The feature stands on the stone, so the stone has to land first.
+        editor.place_feature("minecraft:oak", Point3D::new(0, 71, 0)).await.expect("Failed to place feature");
+
+        assert_eq!(*backend.landed_at_command.lock().unwrap(), vec![16]);
+    }
+
+    #[tokio::test]
+    async fn flush_reports_failed_batches() {
+        init_logger();
+        let backend = Arc::new(RecordingBackend::default());
+        let world = World::new(backend.as_ref()).await.expect("Failed to create world");
+        let editor = world.get_editor_with_backend(backend.clone());
+        backend.fail_puts.store(true, Ordering::SeqCst);
+
+        let stone = Block::from_id("stone".into());
+        for x in 0..40 {
+            editor.place_block(&stone, LocalPoint3D::new(x % 16, 70, x / 16)).await;
+        }
+        let report = editor.flush_buffer().await;
+
+        assert_eq!(report.batches, 2);
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failed_blocks(), 40);
+        assert!(report.failures[0].error.as_deref().is_some_and(|e| e.contains("connection refused")));
+        assert!(editor.flush_buffer().await.is_ok(), "reports cover only batches since the last flush");
+    }
+
+    #[tokio::test]
+    async fn rollback_restores_journaled_run() {
+        init_logger();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let mut editor = world.get_editor_with_backend(provider.clone());
+        let path = std::env::temp_dir().join(format!("tome-journal-{}.jsonl", std::process::id()));
+        editor.start_journal(&path).expect("Failed to start journal");
+        let tag = editor.journal_tag().expect("Journal should be running");
+
+        let ground = Point3D::new(2, 63, 2);
+        let above = Point3D::new(2, 64, 2);
+        editor.place_block_forced(&Block::from_id("stone".into()), LocalPoint3D(ground)).await;
+        // A second write to a cell must not replace its journaled original.
+        editor.place_block_forced(&Block::from_id("oak_planks".into()), LocalPoint3D(ground)).await;
+        editor.place_block(&Block::from_id("lantern".into()), LocalPoint3D(above)).await;
+        editor.spawn_entity("minecraft:villager", above, Some("{NoAI:1b,Tags:[\"home_3\"]}")).await.expect("Failed to spawn villager");
+        editor.spawn_entities(&[(above, "minecraft:text_display".to_string(), None)]).await;
+        editor.save().await;
+
+        assert_eq!(server.world().get_block(ground).id, "minecraft:oak_planks".into());
+        let tagged: Vec<_> = server.world().entities().map(|e| e.data.clone().unwrap_or_default()).collect();
+        assert_eq!(tagged, vec![format!("{{NoAI:1b,Tags:[\"{tag}\",\"home_3\"]}}"), format!("{{Tags:[\"{tag}\"]}}")]);
+
+        let report = crate::editor::rollback(provider.as_ref(), &path).await.expect("Failed to roll back");
+        assert_eq!(report.run, tag);
+        assert_eq!(report.blocks, 2);
+        assert_eq!(report.failed_blocks, 0);
+        assert!(report.entities_removed);
+        assert_eq!(server.world().get_block(ground).id, "minecraft:grass_block".into());
+        assert_eq!(server.world().get_block(above).id, "minecraft:air".into());
+        assert_eq!(server.world().commands().last(), Some(&format!("kill @e[tag={tag}]")));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn reads_after_writes_in_local_space() {
+        init_logger();
+        let build_area = Rect3D::from_points(Point3D::new(100, 0, 200), Point3D::new(115, 255, 215));
+        let server = MockServer::start(MockWorld::flat(build_area, 63)).await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let editor = world.get_editor_with_backend(provider.clone());
+        let local = Point3D::new(1, 63, 3);
+        let original = editor.world().get_block(LocalPoint3D(local)).expect("Terrain should be loaded");
+        assert_eq!(original.id, "minecraft:grass_block".into());
+
+        let stone = Block::from_id("stone".into());
+        editor.place_block_forced(&stone, LocalPoint3D(local)).await;
+        editor.flush_buffer().await;
+
+        assert_eq!(editor.get_block(LocalPoint3D(local)), stone);
+        assert_eq!(editor.try_get_block(LocalPoint3D(local)), Some(stone.clone()));
+        assert_eq!(editor.world().get_block(LocalPoint3D(local)), Some(original), "World keeps the loaded terrain");
+        let world_point = editor.to_world(LocalPoint3D(local));
+        assert_eq!(world_point, WorldPoint3D(Point3D::new(101, 63, 203)));
+        assert_eq!(editor.get_block(editor.to_local(world_point)), stone);
+        assert_eq!(server.world().get_block(world_point.0).id, "minecraft:stone".into());
+    }
+
+    #[tokio::test]
+    async fn records_block_provenance() {
+        init_logger();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let editor = world.get_editor_with_backend(provider.clone());
+        let wall = Point3D::new(2, 64, 2);
+        let roof = Point3D::new(2, 68, 2);
+        let road = Point3D::new(6, 63, 2);
+
+        editor.begin_phase("settlement::houses");
+        editor.push_provenance("buildings_v2::pipeline", Some(Owner::Building(BuildingID(42))));
+        editor.place_block(&Block::from_id("oak_planks".into()), LocalPoint3D(wall)).await;
+        editor.push_provenance(concat!(module_path!(), "::gable_roof"), None);
+        editor.place_block(&Block::from_id("oak_stairs".into()), LocalPoint3D(roof)).await;
+        editor.pop_provenance();
+        editor.pop_provenance();
+        editor.push_provenance("paths::building", Some(Owner::Road(3)));
+        editor.place_block(&Block::from_id("gravel".into()), LocalPoint3D(road)).await;
+        editor.pop_provenance();
+
+        let roof_record = editor.provenance(LocalPoint3D(roof)).expect("Roof block should be recorded");
+        assert_eq!(roof_record.owner, Some(Owner::Building(BuildingID(42))), "Nested scopes inherit the owner");
+        assert_eq!(roof_record.subsystem(), "editor::test::tests::gable_roof");
+        assert_eq!(
+            editor.describe_block(LocalPoint3D(roof)),
+            "minecraft:oak_stairs at (2, 68, 2) placed by editor::test::tests::gable_roof for Building(42)",
+        );
+        assert_eq!(editor.provenance(LocalPoint3D(road)).and_then(|p| p.owner.clone()), Some(Owner::Road(3)));
+        assert_eq!(editor.provenance(LocalPoint3D(wall)).map(|p| p.subsystem()), Some("buildings_v2::pipeline"));
+
+        // The phase scope is still open; once it is gone an overwrite forgets the placer.
+        editor.begin_phase("settlement::roads");
+        editor.pop_provenance();
+        editor.place_block_forced(&Block::from_id("stone".into()), LocalPoint3D(wall)).await;
+        assert_eq!(editor.provenance(LocalPoint3D(wall)), None);
+        assert!(editor.describe_block(LocalPoint3D::new(9, 63, 9)).ends_with("was not placed this run"));
+
+        let path = std::env::temp_dir().join(format!("tome_provenance_{}.jsonl", std::process::id()));
+        editor.dump_provenance(&path).expect("Failed to write provenance");
+        let lines: Vec<serde_json::Value> = std::fs::read_to_string(&path).unwrap().lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["owner"], "Road(3)");
+        assert_eq!(lines[1]["subsystem"], "editor::test::tests::gable_roof");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn aborted_scope_leaves_no_trace() {
+        init_logger();
+        let server = MockServer::flat().await;
+        let provider = Arc::new(server.provider());
+        let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+        let mut editor = world.get_editor_with_backend(provider.clone());
+        let stone = Block::from_id("stone".into());
+        let kept = Point3D::new(1, 64, 1);
+        let column = Point2D::new(3, 3);
+        let height = editor.world().get_height_at(column);
+
+        editor.place_block(&stone, LocalPoint3D(kept)).await;
+        editor.world_mut().claim(Point2D::new(2, 2), BuildClaim::Wall);
+        editor.begin_scope();
+        editor.world_mut().set_heights(&[Point3D::new(3, 70, 3)].into_iter().collect());
+        editor.world_mut().claim(column, BuildClaim::Building(BuildingID(0)));
+        editor.world_mut().claim_range(Point2D::new(2, 2), 70..80, BuildClaim::Building(BuildingID(0)));
+        // Well past the buffer size: an open scope holds its blocks back.
+        for x in 0..10 {
+            for z in 0..10 {
+                editor.place_block_forced(&stone, LocalPoint3D::new(x, 65, z)).await;
+            }
+        }
+        editor.begin_scope();
+        editor.place_block_forced(&Block::from_id("oak_planks".into()), LocalPoint3D(kept)).await;
+        editor.commit();
+        assert_eq!(editor.abort(), 101);
+        editor.flush_buffer().await;
+
+        assert_eq!(editor.get_block(LocalPoint3D(kept)), stone, "The block placed before the scope stays");
+        assert_eq!(editor.get_cached_block(LocalPoint3D::new(4, 65, 4)), None);
+        assert_eq!(editor.world().get_height_at(column), height);
+        assert!(!editor.world().is_claimed(column), "The scope's claims go with it");
+        assert_eq!(editor.world().claims_in(Point2D::new(2, 2), FULL_COLUMN).collect::<Vec<_>>(), vec![&BuildClaim::Wall]);
+        assert_eq!(server.world().get_block(kept).id, "minecraft:stone".into());
+        assert_eq!(server.world().get_block(Point3D::new(4, 65, 4)).id, "minecraft:air".into());
+
+        // Blocks a flush already sent are put back over.
+        let sent = Point3D::new(5, 63, 5);
+        editor.begin_scope();
+        editor.place_block_forced(&stone, LocalPoint3D(sent)).await;
+        editor.flush_buffer().await;
+        assert_eq!(server.world().get_block(sent).id, "minecraft:stone".into());
+        editor.abort();
+        editor.flush_buffer().await;
+        assert_eq!(server.world().get_block(sent).id, "minecraft:grass_block".into());
+        assert_eq!(editor.get_block(LocalPoint3D(sent)).id, "minecraft:grass_block".into());
+
+        editor.begin_scope();
+        editor.place_block_forced(&stone, LocalPoint3D(sent)).await;
+        editor.commit();
+        editor.flush_buffer().await;
+        assert_eq!(server.world().get_block(sent).id, "minecraft:stone".into());
+    }
+
+    #[test]
+    fn vertical_claims_split_and_release() {
+        use crate::generator::{build_claim::ClaimSpan, paths::PathType, BuildClaim};
+
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(15, 127, 15));
+        let mut world = World::synthetic(build_area, 64);
+        let column = Point2D::new(4, 4);
+        world.claim(column, BuildClaim::Wall);
+        world.claim_range(column, 60..68, BuildClaim::Gate);
+        let spans = world.get_column_claims(column).unwrap().spans().to_vec();
+        assert_eq!(spans, vec![
+            ClaimSpan { ys: i32::MIN..60, claim: BuildClaim::Wall },
+            ClaimSpan { ys: 60..68, claim: BuildClaim::Gate },
+            ClaimSpan { ys: 68..i32::MAX, claim: BuildClaim::Wall },
+        ]);
+        assert_eq!(world.get_claim(column), Some(BuildClaim::Gate), "The ground claim is the one at the surface");
+        assert_eq!(world.get_claim_at(Point3D::new(4, 70, 4)), Some(BuildClaim::Wall));
+        assert_eq!(world.claims_in(column, 66..70).cloned().collect::<Vec<_>>(), vec![BuildClaim::Gate, BuildClaim::Wall]);
+
+        world.claim_range(column, 60..68, BuildClaim::Wall);
+        assert_eq!(world.get_column_claims(column).unwrap().spans().len(), 1, "Touching spans of one claim join");
+        world.claim_range(column, 0..100, BuildClaim::None);
+        assert!(!world.is_claimed_in(column, 60..68));
+        assert!(world.is_claimed(column));
+        world.claim(column, BuildClaim::None);
+        assert!(!world.is_claimed(column));
+
+        let road = Point2D::new(8, 8);
+        world.claim_range(road, 70..80, BuildClaim::Path(PathType::Road));
+        assert_eq!(world.get_claim(road), Some(BuildClaim::None), "A bridge overhead leaves the ground unclaimed");
+        assert_eq!(world.get_claim(Point2D::new(-1, 8)), None);
+    }
+
+    #[test]
+    fn world_restores_from_checkpoint_json() {
+        use crate::generator::{paths::PathType, BuildClaim};
+        use crate::geometry::Cardinal;
+
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(15, 127, 15));
+        let mut world = World::synthetic(build_area, 64);
+        world.claim_range(Point2D::new(4, 4), 70..80, BuildClaim::Path(PathType::Road));
+        world.gate_locations.push((Point3D::new(8, 64, 0), Cardinal::North));
+        world.structure_anchors.insert(3, vec![(Point3D::new(1, 65, 1), 90.0)]);
+        world.urban_footprint = Some([Point2D::new(2, 2), Point2D::new(2, 3)].into());
+
+        let json = serde_json::to_string(&world).expect("World serializes");
+        let saved: World = serde_json::from_str(&json).expect("World deserializes");
+        let mut resumed = World::synthetic(build_area, 64);
+        resumed.restore(saved).expect("Same build area");
+        assert_eq!(resumed.get_claim_at(Point3D::new(4, 75, 4)), Some(BuildClaim::Path(PathType::Road)));
+        assert_eq!(resumed.gate_locations, world.gate_locations);
+        assert_eq!(resumed.structure_anchors, world.structure_anchors);
+        assert_eq!(resumed.urban_footprint, world.urban_footprint);
+
+        let elsewhere = Rect3D::from_points(Point3D::new(16, 0, 0), Point3D::new(31, 127, 15));
+        let saved: World = serde_json::from_str(&json).unwrap();
+        assert!(World::synthetic(elsewhere, 64).restore(saved).is_err(), "A checkpoint only resumes on its own build area");
+    }
+}
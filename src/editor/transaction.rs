@@ -0,0 +1,138 @@
+use std::{collections::{hash_map::Entry, HashMap, HashSet}, sync::Arc};
+
+use log::warn;
+
+use crate::{geometry::{LocalPoint3D, Point3D}, http_mod::PositionedBlock, minecraft::Block};
+
+use super::{provenance::Provenance, Editor};
+
+/// What one open editor scope changed, so [`Editor::abort`] can undo it.
+#[derive(Debug, Default)]
+pub(super) struct Transaction {
+    /// Length of the block buffer when the scope began; the scope's writes
+    /// are everything after it, as long as nothing was dispatched since.
+    buffer_start: usize,
+    /// Whether the buffer was sent while the scope was open (an explicit
+    /// flush or a `set_block_updates` barrier), taking scoped writes with it.
+    dispatched: bool,
+    /// The cache entry of each cell before the scope first wrote it.
+    cache_before: HashMap<Point3D, Option<Block>>,
+    provenance_before: HashMap<Point3D, Option<Arc<Provenance>>>,
+    /// Cells written straight to the backend, bypassing the buffer.
+    sent: HashSet<Point3D>,
+}
+
+impl Editor {
+    /// Opens a transactional scope. Until the matching [`commit`](Self::commit)
+    /// or [`abort`](Self::abort), buffered blocks are held back instead of
+    /// flushed at the buffer size, so an abort can usually just drop them.
+    /// Scopes nest.
+    pub fn begin_scope(&mut self) {
+        let buffer_start = self.block_buffer.borrow().len();
+        self.scopes.get_mut().push(Transaction { buffer_start, ..Default::default() });
+        self.world_mut().begin_height_scope();
+        self.world_mut().begin_claim_scope();
+    }
+
+    /// Keeps everything placed since the innermost [`begin_scope`](Self::begin_scope).
+    /// Inside an outer scope the writes become part of it, so aborting the
+    /// outer scope still undoes them; the held buffer goes out with the next
+    /// placement once no scope is open.
+    pub fn commit(&mut self) {
+        let Some(scope) = self.scopes.get_mut().pop() else {
+            warn!("commit called with no open scope");
+            return;
+        };
+        self.world_mut().commit_height_scope();
+        self.world_mut().commit_claim_scope();
+        if let Some(outer) = self.scopes.get_mut().last_mut() {
+            outer.dispatched |= scope.dispatched;
+            outer.sent.extend(scope.sent);
+            for (point, block) in scope.cache_before {
+                outer.cache_before.entry(point).or_insert(block);
+            }
+            for (point, provenance) in scope.provenance_before {
+                outer.provenance_before.entry(point).or_insert(provenance);
+            }
+        }
+    }
+
+    /// Undoes everything placed since the innermost [`begin_scope`](Self::begin_scope):
+    /// buffered writes are discarded, cached blocks and provenance go back to
+    /// what they were, and heights changed through `World::set_heights` and
+    /// claims made through `World::claim_range` are restored. Cells whose
+    /// blocks already reached the backend are queued to be overwritten with
+    /// what was there before. Returns the cells reverted.
+    pub fn abort(&mut self) -> usize {
+        let Some(scope) = self.scopes.get_mut().pop() else {
+            warn!("abort called with no open scope");
+            return 0;
+        };
+        self.world_mut().abort_height_scope();
+        self.world_mut().abort_claim_scope();
+
+        let resend: Vec<Point3D> = if scope.dispatched {
+            scope.cache_before.keys().copied().collect()
+        } else {
+            self.block_buffer.get_mut().truncate(scope.buffer_start);
+            scope.sent.iter().copied().collect()
+        };
+        for local in resend {
+            let before = match &scope.cache_before[&local] {
+                Some(block) => Some(block.clone()),
+                None => self.world.get_block(LocalPoint3D(local)).map(|mut original| {
+                    original.data = self.world.get_block_entity(LocalPoint3D(local));
+                    original
+                }),
+            };
+            match before {
+                Some(block) => {
+                    let point = local + self.build_area.origin;
+                    self.block_buffer.get_mut().push(PositionedBlock::from_block(block, point.into()));
+                }
+                None => warn!("No world data at {:?}; the aborted block there stays", local),
+            }
+        }
+
+        let cache = self.block_cache.get_mut();
+        for (point, block) in &scope.cache_before {
+            match block {
+                Some(block) => cache.insert(*point, block.clone()),
+                None => cache.remove(point),
+            };
+        }
+        let provenance = self.block_provenance.get_mut();
+        for (point, record) in scope.provenance_before {
+            match record {
+                Some(record) => provenance.insert(point, record),
+                None => provenance.remove(&point),
+            };
+        }
+        scope.cache_before.len()
+    }
+
+    pub(super) fn in_scope(&self) -> bool {
+        !self.scopes.borrow().is_empty()
+    }
+
+    /// Remembers the cache and provenance of `local` before the innermost
+    /// scope first writes it. `sent` marks a write that bypasses the buffer.
+    pub(super) fn record_scoped_write(&self, local: Point3D, sent: bool) {
+        let mut scopes = self.scopes.borrow_mut();
+        let Some(scope) = scopes.last_mut() else { return };
+        if let Entry::Vacant(entry) = scope.cache_before.entry(local) {
+            entry.insert(self.block_cache.borrow().get(&local).cloned());
+            scope.provenance_before.insert(local, self.block_provenance.borrow().get(&local).cloned());
+        }
+        if sent {
+            scope.sent.insert(local);
+        }
+    }
+
+    /// Notes that buffered blocks left the editor while scopes were open.
+    pub(super) fn mark_scopes_dispatched(&self) {
+        for scope in self.scopes.borrow_mut().iter_mut() {
+            scope.dispatched = true;
+        }
+    }
+}
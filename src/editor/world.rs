@@ -1,10 +1,12 @@
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, ops::Range, sync::Arc};
+
+use serde_derive::{Deserialize, Serialize};
 
 use anyhow::Ok;
-use fastnbt::LongArray;
+use fastnbt::{LongArray, Value};
 use log::info;
 
-use crate::{generator::{build_claim::BuildClaim, buildings::BuildingData, districts::{Parcel, ParcelAnalysis, ParcelID, ParcelType, District, DistrictID}, nbts::StructureID}, geometry::{Cardinal, DOWN, Point2D, Point3D, Rect2D, Rect3D}, http_mod::{GDMCHTTPProvider, HeightMapType}, minecraft::{Biome, Block, Chunk, util::point_to_chunk_coordinates}};
+use crate::{data::to_snbt, generator::{build_claim::{BuildClaim, ColumnClaims, FULL_COLUMN}, buildings::BuildingData, districts::{Parcel, ParcelAnalysis, ParcelID, ParcelType, District, DistrictID, TownSite}, nbts::StructureID}, geometry::{Cardinal, DOWN, LocalPoint3D, Point2D, Point3D, Rect2D, Rect3D, WorldPoint3D}, http_mod::{HeightMapType, WorldBackend}, minecraft::{Biome, Block, Chunk, util::point_to_chunk_coordinates}};
 
 use super::Editor;
 
@@ -42,7 +44,7 @@ fn log_oob(point: Point2D) {
     log::trace!("World map access out of bounds at {:?}; returning None", point);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct World {
     pub build_area : Rect3D,
     pub parcels : HashMap<ParcelID, Parcel>,
@@ -59,9 +61,9 @@ pub struct World {
     /// tower's base, where a guard NPC can stand. Populated by `build_wall_towers`.
     pub tower_guard_posts : Vec<Vec<Point3D>>,
     /// Per wall tower: `(base_centre, support_y)` — the tower's 5×5 base centre
-    /// (build-area local) and a height within its solid base ring. Used to hang
-    /// the civic banner on the tower's outward face without reading blocks back
-    /// (see `generator/civic_banner.rs`). Populated by `build_wall_towers`.
+    /// (build-area local) and a height within its solid base ring. Tells the
+    /// civic banner where to look for the tower's outward face (see
+    /// `generator/civic_banner.rs`). Populated by `build_wall_towers`.
     pub tower_bases : Vec<(Point2D, i32)>,
     /// Per placed structure instance (keyed by `StructureID::id`): the
     /// hand-authored worker stand posts transformed into world coords —
@@ -73,18 +75,45 @@ pub struct World {
     /// Regularized "inside the wall" cell set. When `Some`, `get_urban_points`
     /// returns it instead of the raw district union (see districts/footprint.rs).
     pub urban_footprint : Option<HashSet<Point2D>>,
+    /// Every settlement in the build area, best first (see districts/town.rs).
+    /// Empty until parcels are generated, or when no town could be grown.
+    #[serde(default)]
+    pub towns : Vec<TownSite>,
+    /// Index into `towns` of the settlement the urban fields above describe.
+    #[serde(default)]
+    pub active_town : usize,
+    /// The type each district had before [`World::enter_town`] changed it;
+    /// [`World::leave_town`] puts them back.
+    #[serde(default)]
+    entered_types : HashMap<DistrictID, ParcelType>,
 
     ground_height_map : Vec<Vec<i32>>,
     ground_block_map : Vec<Vec<Block>>,
     ocean_floor_height_map : Vec<Vec<i32>>,
     ground_biome_map: Vec<Vec<Biome>>,
     motion_blocking_height_map : Vec<Vec<i32>>,
-    build_claim_map : Vec<Vec<BuildClaim>>,
+    build_claim_map : Vec<Vec<ColumnClaims>>,
+    /// Block data read from the backend. Not part of a checkpoint: a resumed
+    /// run reads the chunks back, blocks placed before the checkpoint included.
+    #[serde(skip)]
     pub chunks: HashMap<Point2D, Chunk>,
+    /// Per open editor scope, innermost last: the `(ground, ocean floor)`
+    /// heights of each column before the scope first changed it.
+    #[serde(skip)]
+    height_scopes : Vec<HashMap<Point2D, (i32, i32)>>,
+    /// Per open editor scope, innermost last: the claims of each column
+    /// before the scope first changed them.
+    #[serde(skip)]
+    claim_scopes : Vec<HashMap<Point2D, ColumnClaims>>,
+    /// Made up by [`World::synthetic`] rather than read from a backend, so a
+    /// block with no chunk behind it is read off the heightmaps. Like
+    /// `chunks`, it belongs to the world a checkpoint is restored into.
+    #[serde(skip)]
+    synthetic: bool,
 }
 
 impl World {
-    pub async fn new(provider: &GDMCHTTPProvider) -> anyhow::Result<Self> {
+    pub async fn new(provider: &dyn WorldBackend) -> anyhow::Result<Self> {
         let build_area = provider.get_build_area().await.expect("Failed to get build area");
         let (origin_x, origin_z, size_x, size_z) = (
             build_area.origin.x,
@@ -137,7 +166,7 @@ impl World {
         let ocean_floor_height_map = vec![vec![0; size_z_usize]; size_x_usize];
         let motion_blocking_height_map = vec![vec![0; size_z_usize]; size_x_usize];
         let ground_block_map = vec![vec![Block::new(Default::default(), None, None); size_z_usize]; size_x_usize];
-        let build_claim_map = vec![vec![BuildClaim::None; size_z_usize]; size_x_usize];
+        let build_claim_map = vec![vec![ColumnClaims::default(); size_z_usize]; size_x_usize];
         let ground_biome_map = vec![vec![Biome::unknown(); size_z_usize]; size_x_usize];
 
         let mut world = World {
@@ -153,11 +182,17 @@ impl World {
             tower_bases: Vec::new(),
             structure_anchors: HashMap::new(),
             urban_footprint: None,
+            towns: Vec::new(),
+            active_town: 0,
+            entered_types: HashMap::new(),
             ground_height_map,
             ocean_floor_height_map,
             motion_blocking_height_map,
             build_claim_map,
             chunks,
+            height_scopes: Vec::new(),
+            claim_scopes: Vec::new(),
+            synthetic: false,
             ground_biome_map,
             ground_block_map,
             parcel_analysis_data: HashMap::new(),
@@ -179,7 +214,7 @@ impl World {
                 // Fall back to a sane default if the chunk/section isn't loaded
                 // for this cell rather than aborting the whole world load.
                 world.ground_block_map[x][z] = world
-                    .get_block(Point3D::new(x as i32, world.ground_height_map[x][z] - 1, z as i32))
+                    .get_block(LocalPoint3D::new(x as i32, world.ground_height_map[x][z] - 1, z as i32))
                     .unwrap_or_else(|| Block::new(Default::default(), None, None));
                 world.ground_biome_map[x][z] = world
                     .get_biome(Point3D::new(x as i32, world.ocean_floor_height_map[x][z], z as i32))
@@ -194,11 +229,17 @@ impl World {
         Editor::new(self.build_area, self)
     }
 
+    /// Like [`get_editor`](Self::get_editor) but writes to `backend` — normally
+    /// the same backend this world was loaded from.
+    pub fn get_editor_with_backend(self, backend: Arc<dyn WorldBackend>) -> Editor {
+        Editor::with_backend(self.build_area, self, backend)
+    }
+
     /// Build a synthetic World for offline / dry-run use. No HTTP calls.
     /// Ground is flat at `ground_y` (absolute world Y). Biome = Plains, surface
-    /// block = grass. Chunks are empty — `get_block` will return None for any
-    /// point, so callers must either guard against missing blocks or use the
-    /// editor's block cache.
+    /// block = grass. Chunks are empty, so `get_block` reads blocks off the
+    /// heightmaps: grass just under `ground_y`, dirt below, air from `ground_y`
+    /// up.
     pub fn synthetic(build_area: Rect3D, ground_y: i32) -> Self {
         let size_x_usize = build_area.size.x as usize;
         let size_z_usize = build_area.size.z as usize;
@@ -211,7 +252,7 @@ impl World {
         let motion_blocking_height_map = vec![vec![y_local; size_z_usize]; size_x_usize];
         let ground_block_map = vec![vec![Block::new("minecraft:grass_block".into(), None, None); size_z_usize]; size_x_usize];
         let ground_biome_map = vec![vec![Biome::unknown(); size_z_usize]; size_x_usize];
-        let build_claim_map = vec![vec![BuildClaim::None; size_z_usize]; size_x_usize];
+        let build_claim_map = vec![vec![ColumnClaims::default(); size_z_usize]; size_x_usize];
         let parcel_map = vec![vec![None; size_z_usize]; size_x_usize];
         let district_map = vec![vec![None; size_z_usize]; size_x_usize];
 
@@ -230,6 +271,9 @@ impl World {
             tower_bases: Vec::new(),
             structure_anchors: HashMap::new(),
             urban_footprint: None,
+            towns: Vec::new(),
+            active_town: 0,
+            entered_types: HashMap::new(),
             ground_height_map,
             ground_block_map,
             ocean_floor_height_map,
@@ -237,6 +281,9 @@ impl World {
             motion_blocking_height_map,
             build_claim_map,
             chunks: HashMap::new(),
+            height_scopes: Vec::new(),
+            claim_scopes: Vec::new(),
+            synthetic: true,
         }
     }
 
@@ -283,6 +330,21 @@ impl World {
         Editor::new_offline(self.build_area, self)
     }
 
+    /// Takes over the generator state of `saved` (a world from a town
+    /// checkpoint) while keeping the chunks this world read from its backend.
+    /// Fails if `saved` covers a different build area.
+    pub fn restore(&mut self, saved: World) -> anyhow::Result<()> {
+        if saved.build_area.origin != self.build_area.origin || saved.build_area.size != self.build_area.size {
+            anyhow::bail!("Checkpoint is for build area {:?}, not {:?}", saved.build_area, self.build_area);
+        }
+        let chunks = std::mem::take(&mut self.chunks);
+        let synthetic = self.synthetic;
+        *self = saved;
+        self.chunks = chunks;
+        self.synthetic = synthetic;
+        Ok(())
+    }
+
     pub fn origin(&self) -> Point3D {
         self.build_area.origin
     }
@@ -314,7 +376,7 @@ impl World {
         // Walk down through tree blocks to the real surface. If a block read
         // fails (unloaded chunk / out of section range), stop where we are
         // rather than panicking — the height so far is the best estimate.
-        while let Some(block) = self.get_block(Point3D::new(point.x, height - 1, point.y)) {
+        while let Some(block) = self.get_block(LocalPoint3D::new(point.x, height - 1, point.y)) {
             if !block.id.is_tree() {
                 break;
             }
@@ -339,7 +401,7 @@ impl World {
         &self.ocean_floor_height_map
     }
 
-    pub fn get_build_claim_map(&self) -> &Vec<Vec<BuildClaim>> {
+    pub fn get_build_claim_map(&self) -> &Vec<Vec<ColumnClaims>> {
         &self.build_claim_map
     }
 
@@ -354,8 +416,62 @@ impl World {
             if point.x < 0 || point.z < 0 || point.x >= xlen || point.z >= zlen {
                 continue;
             }
-            self.ground_height_map[point.x as usize][point.z as usize] = point.y;
-            self.ocean_floor_height_map[point.x as usize][point.z as usize] = point.y;
+            let (x, z) = (point.x as usize, point.z as usize);
+            if let Some(scope) = self.height_scopes.last_mut() {
+                scope.entry(Point2D::new(point.x, point.z))
+                    .or_insert((self.ground_height_map[x][z], self.ocean_floor_height_map[x][z]));
+            }
+            self.ground_height_map[x][z] = point.y;
+            self.ocean_floor_height_map[x][z] = point.y;
+        }
+    }
+
+    /// Starts recording `set_heights` changes for an editor scope.
+    pub(super) fn begin_height_scope(&mut self) {
+        self.height_scopes.push(HashMap::new());
+    }
+
+    /// Keeps the innermost scope's height changes, handing them to the
+    /// enclosing scope so an abort there still undoes them.
+    pub(super) fn commit_height_scope(&mut self) {
+        let Some(scope) = self.height_scopes.pop() else { return };
+        if let Some(outer) = self.height_scopes.last_mut() {
+            for (column, heights) in scope {
+                outer.entry(column).or_insert(heights);
+            }
+        }
+    }
+
+    /// Puts back the heights the innermost scope changed.
+    pub(super) fn abort_height_scope(&mut self) {
+        let Some(scope) = self.height_scopes.pop() else { return };
+        for (column, (ground, ocean_floor)) in scope {
+            self.ground_height_map[column.x as usize][column.y as usize] = ground;
+            self.ocean_floor_height_map[column.x as usize][column.y as usize] = ocean_floor;
+        }
+    }
+
+    /// Starts recording claim changes for an editor scope.
+    pub(super) fn begin_claim_scope(&mut self) {
+        self.claim_scopes.push(HashMap::new());
+    }
+
+    /// Keeps the innermost scope's claims, handing the columns' old claims to
+    /// the enclosing scope so an abort there still undoes them.
+    pub(super) fn commit_claim_scope(&mut self) {
+        let Some(scope) = self.claim_scopes.pop() else { return };
+        if let Some(outer) = self.claim_scopes.last_mut() {
+            for (column, claims) in scope {
+                outer.entry(column).or_insert(claims);
+            }
+        }
+    }
+
+    /// Puts back the claims of every column the innermost scope changed.
+    pub(super) fn abort_claim_scope(&mut self) {
+        let Some(scope) = self.claim_scopes.pop() else { return };
+        for (column, claims) in scope {
+            self.build_claim_map[column.x as usize][column.y as usize] = claims;
         }
     }
 
@@ -393,10 +509,12 @@ impl World {
         cell_2d(&self.motion_blocking_height_map, point).copied()
     }
 
+    /// The biome at the ocean floor, or the ground biome map's where no chunk
+    /// is loaded (a [`World::synthetic`] has none).
     pub fn get_surface_biome_at(&self, point : Point2D) -> Option<Biome> {
         let height = self.get_ocean_floor_height_at(point)?;
         let point_3d = Point3D::new(point.x, height, point.y);
-        self.get_biome(point_3d)
+        self.get_biome(point_3d).or_else(|| cell_2d(&self.ground_biome_map, point).cloned())
     }
 
     pub fn get_parcel_at(&self, point : Point2D) -> Option<ParcelID> {
@@ -414,7 +532,7 @@ impl World {
     pub fn add_non_tree_height(&self, point : Point2D) -> Option<Point3D> {
         let mut new_point = Point3D::new(point.x, self.get_height_at(point)?, point.y);
         // Walk down through tree blocks; stop on a failed read rather than panic.
-        while let Some(block) = self.get_block(new_point + DOWN) {
+        while let Some(block) = self.get_block(LocalPoint3D(new_point + DOWN)) {
             if !block.id.is_tree() {
                 break;
             }
@@ -427,14 +545,32 @@ impl World {
         self.build_area.drop_y().contains(point + self.build_area.origin.drop_y())
     }
 
-    pub fn get_block(&self, mut point: Point3D) -> Option<Block> {
-        point = point + self.build_area.origin;
+    /// The absolute position of build-area-local `local`.
+    pub fn to_world(&self, local: LocalPoint3D) -> WorldPoint3D {
+        local.to_world(self.build_area.origin)
+    }
+
+    /// The build-area-local position of absolute `point`.
+    pub fn to_local(&self, point: WorldPoint3D) -> LocalPoint3D {
+        point.to_local(self.build_area.origin)
+    }
+
+    /// The block at `point` as the world was loaded — before anything this run
+    /// placed. Use [`Editor::get_block`] to see the run's own blocks. `None`
+    /// where no chunk is loaded, except in a [`World::synthetic`], which has no
+    /// chunks and reads the block off its heightmaps instead (see
+    /// [`World::block_from_heightmaps`]).
+    pub fn get_block(&self, point: LocalPoint3D) -> Option<Block> {
+        let local = point;
+        let point = self.to_world(local).0;
         //info!("Getting block at point: {:?}", point); uncomment if needed, but generates way to many lines of logs
 
         let chunk_coordinates = point_to_chunk_coordinates(point);
         //info!("Chunk coordinates: {:?}", chunk_coordinates);
 
-        let chunk = self.chunks.get(&chunk_coordinates.drop_y())?;
+        let Some(chunk) = self.chunks.get(&chunk_coordinates.drop_y()) else {
+            return if self.synthetic { self.block_from_heightmaps(local.0) } else { None };
+        };
         //info!("Found chunk: {:?}", chunk);
 
         let section = chunk.sections.iter().find(|s| s.y == chunk_coordinates.y)?;
@@ -464,6 +600,44 @@ impl World {
         })
     }
 
+    /// The block the heightmaps put at local `point`: the ground block on top of
+    /// the column, dirt under it, water up to the motion-blocking height and air
+    /// above. `None` outside the build area.
+    fn block_from_heightmaps(&self, point: Point3D) -> Option<Block> {
+        let column = point.drop_y();
+        if !self.is_in_bounds_2d(column) {
+            return None;
+        }
+        let floor = self.get_ocean_floor_height_at(column)?;
+        let surface = self.get_motion_blocking_height_at(column)?;
+        let id = match point.y {
+            y if y < floor - 1 => "minecraft:dirt",
+            y if y == floor - 1 => return cell_2d(&self.ground_block_map, column).cloned(),
+            y if y < surface => "minecraft:water",
+            _ => "minecraft:air",
+        };
+        Some(Block::new(id.into(), None, None))
+    }
+
+    /// The block entity at local `point` as SNBT — a chest's items, a sign's
+    /// text — without its `id` and position tags, in the form `Block::data`
+    /// takes. `None` if the chunk holds no block entity there.
+    pub fn get_block_entity(&self, point: LocalPoint3D) -> Option<String> {
+        let point = self.to_world(point).0;
+        let chunk = self.chunks.get(&point_to_chunk_coordinates(point).drop_y())?;
+        let entity = chunk.block_entities.as_ref()?.iter().find(|entity| {
+            let Value::Compound(tags) = entity else { return false };
+            [("x", point.x), ("y", point.y), ("z", point.z)]
+                .iter()
+                .all(|(key, coord)| matches!(tags.get(*key), Some(Value::Int(v)) if v == coord))
+        })?;
+        let Value::Compound(mut tags) = entity.clone() else { return None };
+        for key in ["id", "x", "y", "z", "keepPacked"] {
+            tags.remove(key);
+        }
+        Some(to_snbt(&Value::Compound(tags)))
+    }
+
     pub fn get_biome(&self, mut point: Point3D) -> Option<Biome> {
         point = point + self.build_area.origin;
         let chunk_coordinates = point_to_chunk_coordinates(point);
@@ -511,25 +685,71 @@ impl World {
 
     pub fn is_water_3d(&self, point : Point3D) -> bool {
         // Missing block (unloaded chunk / out of range) is treated as not water.
-        self.get_block(point).map_or(false, |b| b.id.is_water())
+        self.get_block(LocalPoint3D(point)).map_or(false, |b| b.id.is_water())
     }
 
+    /// Whether anything claims any height of the column.
     pub fn is_claimed(&self, point : Point2D) -> bool {
         // Out of bounds cells are unclaimable, so treat them as unclaimed.
-        cell_2d(&self.build_claim_map, point).map_or(false, |c| *c != BuildClaim::None)
+        cell_2d(&self.build_claim_map, point).map_or(false, |c| !c.is_empty())
+    }
+
+    /// Whether anything claims a cell of the column within `ys`.
+    pub fn is_claimed_in(&self, point : Point2D, ys : Range<i32>) -> bool {
+        cell_2d(&self.build_claim_map, point).is_some_and(|c| c.overlapping(ys).next().is_some())
     }
 
+    /// Claims the whole column. `BuildClaim::None` releases it.
     pub fn claim(&mut self, point: Point2D, claim: BuildClaim) {
+        self.claim_range(point, FULL_COLUMN, claim);
+    }
+
+    /// Claims the cells `ys` of the column, leaving the rest of it to other
+    /// claims: a road on the ground under a jetty, a wall-walk over a gate road.
+    pub fn claim_range(&mut self, point: Point2D, ys: Range<i32>, claim: BuildClaim) {
         if self.is_in_bounds_2d(point) {
-            self.build_claim_map[point.x as usize][point.y as usize] = claim;
+            let column = &mut self.build_claim_map[point.x as usize][point.y as usize];
+            if let Some(scope) = self.claim_scopes.last_mut() {
+                scope.entry(point).or_insert_with(|| column.clone());
+            }
+            column.set(ys, claim);
         } else {
             log::warn!("Tried to claim point {:?} out of bounds", point);
         }
     }
 
+    /// The claim on the column's ground: the lowest claim on its surface
+    /// block or the air cell above it, so a jetty or bridge overhead doesn't
+    /// hide the road beneath. `None` out of bounds.
     pub fn get_claim(&self, point : Point2D) -> Option<BuildClaim> {
+        let column = self.get_column_claims(point)?;
+        let height = self.ground_height_map[point.x as usize][point.y as usize];
+        Some(column.overlapping(height - 1..height + 1).next().cloned().unwrap_or(BuildClaim::None))
+    }
+
+    /// The claim on the cell at `point`. `None` out of bounds.
+    pub fn get_claim_at(&self, point : Point3D) -> Option<BuildClaim> {
+        let column = self.get_column_claims(point.drop_y())?;
+        Some(column.at(point.y).cloned().unwrap_or(BuildClaim::None))
+    }
+
+    /// The lowest claim on any cell of the column within `ys`. `None` out of
+    /// bounds.
+    pub fn get_claim_in(&self, point : Point2D, ys : Range<i32>) -> Option<BuildClaim> {
+        let column = self.get_column_claims(point)?;
+        Some(column.overlapping(ys).next().cloned().unwrap_or(BuildClaim::None))
+    }
+
+    /// The claims on any cell of the column within `ys`, bottom-up. Empty out
+    /// of bounds.
+    pub fn claims_in(&self, point : Point2D, ys : Range<i32>) -> impl Iterator<Item = &BuildClaim> {
+        self.get_column_claims(point).into_iter().flat_map(move |column| column.overlapping(ys.clone()))
+    }
+
+    /// Every claim on the column. `None` out of bounds.
+    pub fn get_column_claims(&self, point : Point2D) -> Option<&ColumnClaims> {
         if self.is_in_bounds_2d(point) {
-            Some(self.build_claim_map[point.x as usize][point.y as usize].clone())
+            Some(&self.build_claim_map[point.x as usize][point.y as usize])
         } else {
             None
         }
@@ -556,6 +776,48 @@ impl World {
         }
     }
 
+    /// Points the urban fields — `urban_footprint`, the gates and towers, the
+    /// Urban/Rural district types — at `towns[town]`, stashing the active town's
+    /// gates and towers back on its site. Meanwhile every other town's districts
+    /// and hinterland read OffLimits, so one town's phases never build on land
+    /// another town owns, until [`World::leave_town`].
+    pub fn enter_town(&mut self, town: usize) {
+        if let Some(site) = self.towns.get_mut(self.active_town) {
+            site.gate_locations = std::mem::take(&mut self.gate_locations);
+            site.tower_guard_posts = std::mem::take(&mut self.tower_guard_posts);
+            site.tower_bases = std::mem::take(&mut self.tower_bases);
+        }
+        let Some(site) = self.towns.get_mut(town) else { return };
+        self.gate_locations = std::mem::take(&mut site.gate_locations);
+        self.tower_guard_posts = std::mem::take(&mut site.tower_guard_posts);
+        self.tower_bases = std::mem::take(&mut site.tower_bases);
+        self.urban_footprint = Some(site.footprint.clone());
+        self.active_town = town;
+        self.leave_town();
+        for (i, site) in self.towns.iter().enumerate() {
+            let owned = |parcel_type| if i == town { parcel_type } else { ParcelType::OffLimits };
+            for (ids, parcel_type) in [(&site.districts, ParcelType::Urban), (&site.hinterland, ParcelType::Rural)] {
+                for id in ids {
+                    if let Some(district) = self.districts.get_mut(id) {
+                        self.entered_types.insert(*id, district.data.parcel_type);
+                        district.data.parcel_type = owned(parcel_type);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Undoes [`World::enter_town`]'s district types, so every town's land
+    /// reads as it did before, other towns' no longer OffLimits. The active
+    /// town's gates and footprint stay put.
+    pub fn leave_town(&mut self) {
+        for (id, parcel_type) in self.entered_types.drain() {
+            if let Some(district) = self.districts.get_mut(&id) {
+                district.data.parcel_type = parcel_type;
+            }
+        }
+    }
+
     pub fn get_parcel_type(&self, point: Point2D) -> Option<ParcelType> {
         self.get_district_at(point).and_then(|parcel_id| {
             self.districts.get(&parcel_id).map(|parcel| parcel.data.parcel_type)
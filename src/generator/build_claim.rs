@@ -1,6 +1,10 @@
+use std::ops::Range;
+
+use serde_derive::{Deserialize, Serialize};
+
 use crate::generator::{buildings::BuildingID, nbts::StructureID, paths::PathType};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BuildClaim {
     Nature,
     Wall,
@@ -18,5 +22,80 @@ pub enum BuildClaim {
     /// Footprint of a placed ship (scattered on a water district). Reserves the
     /// water cells so later passes (harbour, roads, bridges) and other ships skip them.
     Ship,
+    /// A town map board standing by a gate (see `town_map_copies`).
+    MapBoard,
     None,
 }
+
+/// Every height of a column: what [`World::claim`](crate::editor::World::claim)
+/// takes, and what a claim on the ground alone has always meant.
+pub const FULL_COLUMN: Range<i32> = i32::MIN..i32::MAX;
+
+/// A claim on the cells `ys.start <= y < ys.end` of one column.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimSpan {
+    pub ys: Range<i32>,
+    pub claim: BuildClaim,
+}
+
+/// The claims on one XZ column, as non-overlapping spans sorted bottom-up, so
+/// a road can hold the ground while a jetty or bridge holds the air above it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnClaims {
+    spans: Vec<ClaimSpan>,
+}
+
+impl ColumnClaims {
+    pub fn spans(&self) -> &[ClaimSpan] {
+        &self.spans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Gives `ys` to `claim`, cutting back whatever held any of it before.
+    /// `BuildClaim::None` releases the range.
+    pub fn set(&mut self, ys: Range<i32>, claim: BuildClaim) {
+        if ys.is_empty() {
+            return;
+        }
+        let mut spans = Vec::with_capacity(self.spans.len() + 2);
+        for span in self.spans.drain(..) {
+            if span.ys.end <= ys.start || span.ys.start >= ys.end {
+                spans.push(span);
+                continue;
+            }
+            if span.ys.start < ys.start {
+                spans.push(ClaimSpan { ys: span.ys.start..ys.start, claim: span.claim.clone() });
+            }
+            if span.ys.end > ys.end {
+                spans.push(ClaimSpan { ys: ys.end..span.ys.end, claim: span.claim });
+            }
+        }
+        if claim != BuildClaim::None {
+            spans.push(ClaimSpan { ys, claim });
+        }
+        spans.sort_by_key(|span| span.ys.start);
+
+        // Join touching spans of the same claim, so re-claiming doesn't fragment.
+        for span in spans {
+            match self.spans.last_mut() {
+                Some(last) if last.ys.end == span.ys.start && last.claim == span.claim => last.ys.end = span.ys.end,
+                _ => self.spans.push(span),
+            }
+        }
+    }
+
+    /// The claim on the cell at `y`, if any.
+    pub fn at(&self, y: i32) -> Option<&BuildClaim> {
+        self.spans.iter().find(|span| span.ys.contains(&y)).map(|span| &span.claim)
+    }
+
+    /// Claims on any cell of `ys`, bottom-up.
+    pub fn overlapping(&self, ys: Range<i32>) -> impl Iterator<Item = &BuildClaim> {
+        self.spans.iter()
+            .filter(move |span| span.ys.start < ys.end && ys.start < span.ys.end)
+            .map(|span| &span.claim)
+    }
+}
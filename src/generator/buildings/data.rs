@@ -1,6 +1,7 @@
+use serde_derive::{Deserialize, Serialize};
 use crate::generator::{buildings::{shape::BuildingShape, BuildingID, Grid}, materials::Palette, style::Style};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuildingData {
     pub id : BuildingID,
     pub grid : Grid,
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use strum::IntoEnumIterator;
 
-use crate::{editor::Editor, generator::{buildings::BuildingData, data::LoadedData, materials::{MaterialPlacer, MaterialRole, Placer}}, geometry::{get_outer_edge, Cardinal}, minecraft::BlockForm, noise::RNG};
+use crate::{editor::Editor, generator::{buildings::BuildingData, data::LoadedData, materials::{MaterialPlacer, MaterialRole, Placer}}, geometry::{get_outer_edge, Cardinal, LocalPoint3D}, minecraft::BlockForm, noise::RNG};
 
 pub async fn build_foundation(
     editor: &Editor,
@@ -33,8 +33,8 @@ pub async fn build_foundation(
         };
         let grid_height = grid.origin.y;
         if height >= grid_height - 1 {
-            let block = editor.world().get_block(point.add_y(height - 1)).expect("Block not found at point");
-            editor.place_block(&block, point.add_y(grid_height - 1)).await;
+            let block = editor.world().get_block(LocalPoint3D(point.add_y(height - 1))).expect("Block not found at point");
+            editor.place_block(&block, LocalPoint3D(point.add_y(grid_height - 1))).await;
         } else if height < grid_height {
             let relative = *point - grid.origin.drop_y();
             let placer = if (relative.x) % (grid.cell_size.x) == 0 || (relative.y) % (grid.cell_size.z) == 0 {
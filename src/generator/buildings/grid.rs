@@ -1,7 +1,8 @@
 
+use serde_derive::{Deserialize, Serialize};
 use crate::{editor::Editor, generator::{data::LoadedData, materials::{Palette, Placer}, nbts::{place_nbt, NBTMeta, Rotation, Structure, Transform}}, geometry::{Cardinal, Point2D, Point3D, Rect2D, Rect3D}};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Grid {
     pub origin : Point3D,
     pub cell_size : Point3D,
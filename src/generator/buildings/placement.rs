@@ -2,7 +2,7 @@ use std::{cell::RefCell, collections::{HashMap, HashSet}, i32};
 
 use strum::IntoEnumIterator;
 
-use crate::{editor::Editor, generator::{BuildClaim, buildings::{BuildingData, Grid, build_floor, build_stairs, foundation::build_foundation, grid::DEFAULT_GRID_CELL_SIZE, roofs::build_roof, set::BuildingSetID, shape::BuildingShape, walls::build_walls}, data::LoadedData, districts::{DistrictID, replace_ground_smooth}, materials::{MaterialId, MaterialRole, Palette, PaletteId}, nbts::Rotation, style::{DistrictStyle, Style}, terrain::force_height}, geometry::{ Cardinal, Point2D, UP, average_to_neighbours_5_away, get_edge, get_ordered_edge, get_outer_and_inner_points, voronoi_fill_with_recenter}, minecraft::{Biome, BiomeStonetype, BiomeWoodtype, Block}, noise::RNG};
+use crate::{editor::Editor, generator::{BuildClaim, buildings::{BuildingData, Grid, build_floor, build_stairs, foundation::build_foundation, grid::DEFAULT_GRID_CELL_SIZE, roofs::build_roof, set::BuildingSetID, shape::BuildingShape, walls::build_walls}, data::LoadedData, districts::{DistrictID, replace_ground_smooth}, materials::{MaterialId, MaterialRole, Palette, PaletteId}, nbts::Rotation, style::{DistrictStyle, Style}, terrain::force_height}, geometry::{ Cardinal, Point2D, UP, average_to_neighbours_5_away, get_edge, get_ordered_edge, get_outer_and_inner_points, voronoi_fill_with_recenter, LocalPoint3D}, minecraft::{Biome, BiomeStonetype, BiomeWoodtype, Block}, noise::RNG};
 
 use super::BuildingID;
 
@@ -324,7 +324,7 @@ pub async fn place_building(editor : &mut Editor, shape : &BuildingShape, grid :
 
     for cell in building.shape.cells().iter() {
         for point in grid.get_cell_rect(*cell).iter() {
-            editor.place_block_forced(&"air".into(), point).await;
+            editor.place_block_forced(&"air".into(), LocalPoint3D(point)).await;
         }
     }
 
@@ -351,8 +351,8 @@ pub async fn place_building(editor : &mut Editor, shape : &BuildingShape, grid :
             let mut clear_point = point;
 
             for _ in 0..5 {
-                editor.place_block_forced(&"air".into(), point).await;
-                editor.place_block_forced(&"air".into(), point + UP).await;
+                editor.place_block_forced(&"air".into(), LocalPoint3D(point)).await;
+                editor.place_block_forced(&"air".into(), LocalPoint3D(point + UP)).await;
                 clear_point += door.direction.into();
 
                 match editor.world().get_claim(clear_point.drop_y()) {
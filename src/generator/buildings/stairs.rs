@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use serde_derive::{Deserialize, Serialize};
-use crate::{editor::{Editor}, generator::{buildings::BuildingData, data::LoadedData, materials::{MaterialPlacer, MaterialRole, Placer}}, geometry::{Cardinal, Point3D, UP}, minecraft::BlockForm, noise::RNG};
+use crate::{editor::{Editor}, generator::{buildings::BuildingData, data::LoadedData, materials::{MaterialPlacer, MaterialRole, Placer}}, geometry::{Cardinal, LocalPoint3D, Point3D, UP}, minecraft::BlockForm, noise::RNG};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Eq)]
 pub struct StairPlacement {
@@ -50,11 +50,11 @@ pub async fn build_stair(editor: &Editor, building: &BuildingData, data: &Loaded
     };
 
     // First air block
-    editor.place_block_forced(&"air".into(), ref_point + inner_vec + left_vec * 2 + UP * (building.grid.cell_size.y - 2)).await;
+    editor.place_block_forced(&"air".into(), LocalPoint3D(ref_point + inner_vec + left_vec * 2 + UP * (building.grid.cell_size.y - 2))).await;
 
     for i in 0..building.grid.cell_size.y - 1 {
         // Clear air
-        editor.place_block_forced(&"air".into(), ref_point + inner_vec + left_vec * (1 - i) + UP * (building.grid.cell_size.y - 2)).await;
+        editor.place_block_forced(&"air".into(), LocalPoint3D(ref_point + inner_vec + left_vec * (1 - i) + UP * (building.grid.cell_size.y - 2))).await;
         // Stairs
         placer.place_block_forced(
             editor,
@@ -4,7 +4,7 @@ mod tests {
 
     use log::info;
 
-    use crate::{data::Loadable, editor::World, generator::{buildings::{Grid, placement::{place_building, place_buildings}, shape::{BuildingShape, WallPlacement}, stairs::StairPlacement, walls::WallComponent}, chronicle::generate_chronicle, data::LoadedData, districts::{WallType, build_wall, generate_parcels}, materials::{Material, MaterialId, Placer}, nbts::Structure, style::Style, terrain::log_trees}, geometry::{Cardinal, NORTH, Point3D, UP}, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
+    use crate::{data::Loadable, editor::World, generator::{buildings::{Grid, placement::{place_building, place_buildings}, shape::{BuildingShape, WallPlacement}, stairs::StairPlacement, walls::WallComponent}, chronicle::generate_chronicle, data::LoadedData, districts::{WallType, build_wall, generate_parcels}, materials::{Material, MaterialId, Placer}, nbts::Structure, style::Style, terrain::log_trees}, geometry::{Cardinal, NORTH, LocalPoint3D, Point3D, UP}, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
 
 
     #[tokio::test]
@@ -85,7 +85,7 @@ mod tests {
 
         info!("NBT structure placed successfully");
 
-        editor.place_block(&"red_wool".into(), point + NORTH * 10 + UP * 5).await;
+        editor.place_block(&"red_wool".into(), LocalPoint3D(point + NORTH * 10 + UP * 5)).await;
         editor.flush_buffer().await;
     }
 
@@ -169,7 +169,7 @@ mod tests {
         let dossier = crate::generator::settlement::minimal_dossier(
             &editor, crate::generator::buildings_v2::Culture::Medieval, &mut rng.derive(),
         );
-        let _ = generate_chronicle(&mut editor, &dossier).await;
+        let _ = generate_chronicle(&mut editor, &dossier, &[], &mut crate::ai::AiLog::default()).await;
         editor.flush_buffer().await;
     }
     
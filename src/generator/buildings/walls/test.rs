@@ -5,7 +5,7 @@ mod tests {
 
     
 
-    use crate::{editor::World, generator::{buildings::{BuildingData, Grid, shape::BuildingShape, walls::wall::build_walls}, data::LoadedData, materials::PaletteId, style::Style}, geometry::Point3D, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
+    use crate::{editor::World, generator::{buildings::{BuildingData, Grid, shape::BuildingShape, walls::wall::build_walls}, data::LoadedData, materials::PaletteId, style::Style}, geometry::{LocalPoint3D, Point3D}, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
 
 
     #[tokio::test]
@@ -41,7 +41,7 @@ mod tests {
 
         for cell in building.shape.cells().iter() {
             let midpoint = building.grid.grid_to_world(*cell) + building.grid.cell_size / 2;
-            editor.place_block(&"red_mushroom_block".into(), midpoint).await;
+            editor.place_block(&"red_mushroom_block".into(), LocalPoint3D(midpoint)).await;
         }
 
         let mut rng = RNG::new(100);
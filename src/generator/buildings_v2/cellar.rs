@@ -17,7 +17,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Cardinal, Point2D, Point3D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::BlockForm;
 use crate::noise::RNG;
 
@@ -49,7 +49,7 @@ fn volume_is_wet(editor: &Editor, interior: &Rect2D, floor_y: i32, ceiling_y: i3
     let lava = "minecraft:lava".into();
     for p in interior.iter() {
         for y in (floor_y - 1)..ceiling_y {
-            if let Some(block) = editor.try_get_block(Point3D::new(p.x, y, p.y)) {
+            if let Some(block) = editor.try_get_block(LocalPoint3D::new(p.x, y, p.y)) {
                 if block.id == water || block.id == lava {
                     return true;
                 }
@@ -339,7 +339,7 @@ async fn excavate(
                     .place_block_forced(editor, pos, BlockForm::Block, None, None)
                     .await;
             } else {
-                editor.place_block_forced(&"air".into(), pos).await;
+                editor.place_block_forced(&"air".into(), LocalPoint3D(pos)).await;
             }
         }
     }
@@ -411,7 +411,7 @@ async fn place_descending_stair(
         };
         for clear_y in (y + 1)..=top {
             editor
-                .place_block_forced(&"air".into(), Point3D::new(pos.x, clear_y, pos.y))
+                .place_block_forced(&"air".into(), LocalPoint3D::new(pos.x, clear_y, pos.y))
                 .await;
         }
     }
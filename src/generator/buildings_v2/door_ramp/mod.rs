@@ -29,7 +29,7 @@ use std::collections::HashMap;
 
 use crate::editor::{Editor, World};
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Cardinal, Point2D, Point3D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D};
 use crate::minecraft::{Block, BlockForm, BlockID};
 
 use super::footprint::Footprint;
@@ -417,7 +417,7 @@ async fn place_sill_tile(
             for carve_y in y..=top {
                 editor.place_block_forced(
                     air,
-                    Point3D::new(cell.x, carve_y, cell.y),
+                    LocalPoint3D::new(cell.x, carve_y, cell.y),
                 ).await;
             }
             stone.place_block_forced(
@@ -474,7 +474,7 @@ async fn place_steps(
                     for carve_y in (step.y + 1)..=top {
                         editor.place_block_forced(
                             air,
-                            Point3D::new(cell.x, carve_y, cell.y),
+                            LocalPoint3D::new(cell.x, carve_y, cell.y),
                         ).await;
                     }
                     if terrain_y < step.y {
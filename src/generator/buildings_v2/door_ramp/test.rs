@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 
-use crate::geometry::{Cardinal, Point2D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, Rect2D, LocalPoint3D};
 use crate::generator::buildings_v2::footprint::Footprint;
 use crate::generator::buildings_v2::footprint::merge::outline_from_rects;
 use crate::generator::buildings_v2::frame::{Frame, generate_frame};
@@ -215,7 +215,7 @@ async fn place_writes_stair_blocks_on_sloped_world() {
 
     // Each step position should now read back as a stairs block (cached in the editor).
     for step in &ramps[0].steps {
-        let block = ctx.editor.get_block(Point3D::new(step.cell.x, step.y, step.cell.y));
+        let block = ctx.editor.get_block(LocalPoint3D::new(step.cell.x, step.y, step.cell.y));
         assert!(block.id.as_str().contains("stairs"),
             "expected stair block at {:?} y={}, got {:?}", step.cell, step.y, block.id.as_str());
     }
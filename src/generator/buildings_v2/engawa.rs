@@ -19,7 +19,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Cardinal, Point2D, Point3D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{Block, BlockForm};
 
 use super::footprint::Footprint;
@@ -240,7 +240,7 @@ pub async fn place_engawa(ctx: &mut BuildCtx<'_>, frame: &Frame, plan: &EngawaPl
         // solid block.
         for y in ((deck_y - MAX_UNDERFILL)..deck_y).rev() {
             let p = Point3D::new(cell.x, y, cell.y);
-            if editor.try_get_block(p).as_ref().map_or(false, is_air) {
+            if editor.try_get_block(LocalPoint3D(p)).as_ref().map_or(false, is_air) {
                 deck_placer.place_block_forced(editor, p, BlockForm::Fence, None, None).await;
             } else {
                 break; // hit the ground (or unknown) — stop the column
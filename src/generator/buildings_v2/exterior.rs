@@ -8,11 +8,13 @@
 
 use std::collections::{HashMap, HashSet};
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::editor::Editor;
 use crate::generator::BuildClaim;
 use crate::generator::buildings::BuildingID;
 use crate::generator::materials::{Material, MaterialId, MaterialRole, Palette};
-use crate::geometry::{Cardinal, Point2D, Point3D, CARDINALS_2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, CARDINALS_2D};
 use crate::minecraft::{string_to_block, Block, BlockForm, Color};
 use crate::noise::RNG;
 
@@ -57,7 +59,7 @@ pub async fn place_family_banner(
         let mut block = string_to_block(&banner).expect("family banner block");
         block.data = banner_data.clone();
         ctx.editor
-            .place_block_forced(&block, Point3D::new(pos.x, y, pos.y))
+            .place_block_forced(&block, LocalPoint3D::new(pos.x, y, pos.y))
             .await;
     }
 }
@@ -67,6 +69,7 @@ pub async fn place_family_banner(
 /// until the household's surname is rolled in the population pass — so the site
 /// is held and lettered later by [`place_manor_sign`]. `anchor_idx` is the
 /// manor's index in the town house list, used to look up its household.
+#[derive(Serialize, Deserialize)]
 pub struct ManorSignSite {
     pub anchor_idx: usize,
     sign_pos: Point3D,
@@ -140,7 +143,7 @@ pub async fn place_manor_sign(editor: &Editor, site: &ManorSignSite, name: &str)
         Some(state),
         Some(data),
     );
-    editor.place_block_forced(&sign, site.sign_pos).await;
+    editor.place_block_forced(&sign, LocalPoint3D(site.sign_pos)).await;
 }
 
 /// Sign rotation (0-15) for the cardinal the front text should face. Mirrors
@@ -269,7 +272,7 @@ pub async fn decorate_exterior_walls(
         let Some(y) = ctx.editor.world().get_height_at(cell) else {
             continue;
         };
-        ctx.editor.place_block(&prop, Point3D::new(cell.x, y, cell.y)).await;
+        ctx.editor.place_block(&prop, LocalPoint3D::new(cell.x, y, cell.y)).await;
         ctx.editor
             .world_mut()
             .claim(cell, BuildClaim::Building(BuildingID(building_idx)));
@@ -292,7 +295,7 @@ fn is_open_ground(editor: &Editor, cell: Point2D) -> bool {
     let Some(y) = world.get_height_at(cell) else {
         return false;
     };
-    match world.get_block(Point3D::new(cell.x, y - 1, cell.y)) {
+    match world.get_block(LocalPoint3D::new(cell.x, y - 1, cell.y)) {
         Some(b) => {
             let id = b.id.as_str();
             !b.id.is_water() && id != "minecraft:air" && id != "air"
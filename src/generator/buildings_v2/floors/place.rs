@@ -6,7 +6,7 @@ use std::collections::HashSet;
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::BlockForm;
 
 use super::super::footprint::merge::{concave_corner_cells, walk_edge_cells};
@@ -187,7 +187,7 @@ pub async fn clear_attic_stair_headroom(
                 for clear_y in (base_y + 1)..=(attic_y + 2) {
                     editor.place_block_forced(
                         &"air".into(),
-                        Point3D::new(pos.x, clear_y, pos.y),
+                        LocalPoint3D::new(pos.x, clear_y, pos.y),
                     ).await;
                 }
             }
@@ -204,7 +204,7 @@ pub async fn clear_attic_stair_headroom(
                         let ceil_y = frame.roof_y(0) - 2;
                         editor.place_block_forced(
                             &"air".into(),
-                            Point3D::new(pos.x, ceil_y, pos.y),
+                            LocalPoint3D::new(pos.x, ceil_y, pos.y),
                         ).await;
                     }
                     (i - 1) as i32
@@ -215,7 +215,7 @@ pub async fn clear_attic_stair_headroom(
             for clear_y in (y + 1)..=(y + 2) {
                 editor.place_block_forced(
                     &"air".into(),
-                    Point3D::new(pos.x, clear_y, pos.y),
+                    LocalPoint3D::new(pos.x, clear_y, pos.y),
                 ).await;
             }
         }
@@ -281,14 +281,14 @@ pub async fn place_room_floors(
                                 Some(state),
                                 None,
                             );
-                            editor.place_block_forced(&block, Point3D::new(point.x, y, point.y)).await;
+                            editor.place_block_forced(&block, LocalPoint3D::new(point.x, y, point.y)).await;
                         }
                     }
                     _ => {
                         // Stone bricks for temperate kitchens
                         let block = Block::from_id("minecraft:stone_bricks".into());
                         for point in interior.iter() {
-                            editor.place_block_forced(&block, Point3D::new(point.x, y, point.y)).await;
+                            editor.place_block_forced(&block, LocalPoint3D::new(point.x, y, point.y)).await;
                         }
                     }
                 }
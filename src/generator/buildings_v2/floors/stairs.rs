@@ -9,7 +9,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Cardinal, Point2D, Point3D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{Block, BlockForm};
 use crate::noise::RNG;
 
@@ -805,7 +805,7 @@ async fn place_ladder(editor: &Editor, sw: &Stairwell, frame: &Frame) {
         let mut ladder = Block::from_id("minecraft:ladder".into());
         ladder.state = Some(HashMap::from([("facing".to_string(), facing.clone())]));
         editor
-            .place_block_forced(&ladder, Point3D::new(cell.x, y, cell.y))
+            .place_block_forced(&ladder, LocalPoint3D::new(cell.x, y, cell.y))
             .await;
     }
 }
@@ -995,7 +995,7 @@ async fn clear_headroom(editor: &Editor, x: i32, y: i32, z: i32) {
     for clear_y in (y + 1)..=(y + 2) {
         editor.place_block_forced(
             &"air".into(),
-            Point3D::new(x, clear_y, z),
+            LocalPoint3D::new(x, clear_y, z),
         ).await;
     }
 }
@@ -297,6 +297,7 @@ fn select_layout_gallery() {
 
 #[cfg(test)]
 mod minecraft_tests {
+    use crate::geometry::LocalPoint3D;
     use super::*;
     use crate::{editor::World, http_mod::GDMCHTTPProvider, util::init_logger, noise::RNG};
 
@@ -420,7 +421,7 @@ mod minecraft_tests {
                         "white_wool".into()
                     };
 
-                    editor.place_block(&block, world_point.add_y(y)).await;
+                    editor.place_block(&block, LocalPoint3D(world_point.add_y(y))).await;
                 }
             }
 
@@ -429,7 +430,7 @@ mod minecraft_tests {
             let sign_z = if oz < 0 { area_max.y } else { area_min.y };
             let sign_point = Point2D::new(sign_x, sign_z);
             let sign_y = editor.world().get_height_at(sign_point).expect("test cell in bounds");
-            editor.place_block(&sign_block(name), sign_point.add_y(sign_y + 1)).await;
+            editor.place_block(&sign_block(name), LocalPoint3D(sign_point.add_y(sign_y + 1))).await;
         }
 
         editor.flush_buffer().await;
@@ -486,7 +487,7 @@ mod minecraft_tests {
                     "red_wool"
                 };
 
-                editor.place_block(&block.into(), world_point.add_y(y)).await;
+                editor.place_block(&block.into(), LocalPoint3D(world_point.add_y(y))).await;
             }
         }
 
@@ -8,7 +8,7 @@ use crate::generator::buildings_v2::footprint::Footprint;
 use crate::generator::buildings_v2::pipeline::BuildCtx;
 use crate::generator::data::LoadedData;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Palette, Placer};
-use crate::geometry::{Point2D, Point3D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D};
 use crate::minecraft::{Block, BlockForm, BlockID};
 use crate::noise::RNG;
 
@@ -145,22 +145,22 @@ async fn execute_columns(
                 // Copy the surface block down to base_y - 1
                 let surface = editor
                     .world()
-                    .get_block(point.add_y(terrain_y - 1))
+                    .get_block(LocalPoint3D(point.add_y(terrain_y - 1)))
                     .unwrap_or_else(|| Block::new("dirt".into(), None, None));
                 editor
-                    .place_block(&surface, point.add_y(profile.base_y - 1))
+                    .place_block(&surface, LocalPoint3D(point.add_y(profile.base_y - 1)))
                     .await;
 
                 // Clear everything from base_y up to terrain_y
                 for y in profile.base_y..=terrain_y {
-                    editor.place_block_forced(&air, point.add_y(y)).await;
+                    editor.place_block_forced(&air, LocalPoint3D(point.add_y(y))).await;
                 }
             }
             ColumnAction::Fill { terrain_y } => {
                 let native = native_stone_at(editor, point, terrain_y);
                 for y in terrain_y..profile.base_y {
                     if let Some(ref block) = native {
-                        editor.place_block_forced(block, point.add_y(y)).await;
+                        editor.place_block_forced(block, LocalPoint3D(point.add_y(y))).await;
                     } else {
                         stone_placer
                             .place_block(editor, point.add_y(y), BlockForm::Block, None, None)
@@ -175,7 +175,7 @@ async fn execute_columns(
 /// If the surface block at `(point, terrain_y - 1)` is vanilla stone, return
 /// it. Otherwise None — callers should fall back to palette stone.
 fn native_stone_at(editor: &Editor, point: Point2D, terrain_y: i32) -> Option<Block> {
-    let surface = editor.world().get_block(point.add_y(terrain_y - 1))?;
+    let surface = editor.world().get_block(LocalPoint3D(point.add_y(terrain_y - 1)))?;
     if is_vanilla_stone(surface.id.as_str()) {
         Some(surface)
     } else {
@@ -192,9 +192,9 @@ async fn clear_snow(editor: &Editor, footprint: &Footprint, base_y: i32) {
         // Check a few blocks above base_y for snow layers.
         for y in base_y..(base_y + 4) {
             let pos = point.add_y(y);
-            if let Some(block) = editor.try_get_block(pos) {
+            if let Some(block) = editor.try_get_block(LocalPoint3D(pos)) {
                 if block.id.as_str().contains("snow") {
-                    editor.place_block_forced(&air, pos).await;
+                    editor.place_block_forced(&air, LocalPoint3D(pos)).await;
                 }
             }
         }
@@ -227,7 +227,7 @@ async fn place_foundation_course(
         let native = native_stone_at(editor, point, terrain_y);
         let pos = point.add_y(base_y - 1);
         if let Some(ref block) = native {
-            editor.place_block_forced(block, pos).await;
+            editor.place_block_forced(block, LocalPoint3D(pos)).await;
         } else {
             stone_placer
                 .place_block_forced(editor, pos, BlockForm::Block, None, None)
@@ -4,7 +4,7 @@ use crate::editor::Editor;
 use crate::generator::BuildClaim;
 use crate::generator::buildings_v2::footprint::Footprint;
 use crate::generator::buildings_v2::pipeline::BuildCtx;
-use crate::geometry::{Point2D, Point3D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D};
 
 /// How many blocks outward from the footprint edge to blend terrain.
 const BLEND_RADIUS: i32 = 5;
@@ -45,22 +45,24 @@ pub async fn blend_terrain(ctx: &mut BuildCtx<'_>, footprint: &Footprint, base_y
         let ring = ring_at_distance(&footprint_set, dist, &world_bounds);
 
         for point in ring {
+            let Some(terrain_y) = ctx.editor.world().get_ocean_floor_height_at(point) else {
+                continue;
+            };
+
             // Don't raise terrain onto already-paved roads (the lerped fill would
             // bury the pavement) or onto another building/structure (the fill
             // would cut earth and grass into a neighbouring house). `PathPlanned`
             // is fine to blend through: those cells are reserved but not yet
             // built, and a later pave pass repaves at the new (raised) height.
-            if matches!(
-                ctx.editor.world().get_claim(point),
-                Some(BuildClaim::Path(_) | BuildClaim::Building(_) | BuildClaim::Structure(_))
-            ) {
+            // Only claims between the ground and `base_y` count: earth can still
+            // be banked up under a neighbour's jetty overhang.
+            if ctx.editor.world().claims_in(point, terrain_y - 1..base_y.max(terrain_y) + 1).any(|claim| matches!(
+                claim,
+                BuildClaim::Path(_) | BuildClaim::Building(_) | BuildClaim::Structure(_)
+            )) {
                 continue;
             }
 
-            let Some(terrain_y) = ctx.editor.world().get_ocean_floor_height_at(point) else {
-                continue;
-            };
-
             // Only raise terrain, never lower it. If terrain is already at or
             // above the lerped target there's nothing to do.
             let t = dist as f64 / BLEND_RADIUS as f64;
@@ -88,25 +90,25 @@ pub async fn blend_terrain(ctx: &mut BuildCtx<'_>, footprint: &Footprint, base_y
             let surface = ctx
                 .editor
                 .world()
-                .get_block(point.add_y(terrain_y - 1))
+                .get_block(LocalPoint3D(point.add_y(terrain_y - 1)))
                 .unwrap_or_else(|| crate::minecraft::Block::from_id("minecraft:dirt".into()));
             let is_snow = surface.id.as_str().contains("snow");
             let (fill, top) = crate::generator::terrain::terraform_layers(&surface);
 
             // Convert the old surface to fill material since it's being buried.
-            ctx.editor.place_block(&fill, point.add_y(terrain_y - 1)).await;
+            ctx.editor.place_block(&fill, LocalPoint3D(point.add_y(terrain_y - 1))).await;
 
             // Fill column.
             for y in terrain_y..target_y {
-                ctx.editor.place_block(&fill, point.add_y(y)).await;
+                ctx.editor.place_block(&fill, LocalPoint3D(point.add_y(y))).await;
             }
 
             // Top block becomes the appropriate surface. Forced, because the
             // fill loop above just placed dirt at `target_y - 1`, and a normal
             // place_block skips an equal-density block — leaving a dirt top.
-            ctx.editor.place_block_forced(&top, point.add_y(target_y - 1)).await;
+            ctx.editor.place_block_forced(&top, LocalPoint3D(point.add_y(target_y - 1))).await;
             if is_snow {
-                ctx.editor.place_block(&surface, point.add_y(target_y)).await;
+                ctx.editor.place_block(&surface, LocalPoint3D(point.add_y(target_y))).await;
             }
 
             // Heightmap = top of solid ground (above grass), excluding snow.
@@ -7,7 +7,8 @@ use std::collections::{HashMap, HashSet};
 use crate::editor::Editor;
 use crate::generator::materials::{Material, MaterialId, Palette};
 use crate::generator::population::{AnchorScene, AnchorSlot};
-use crate::geometry::{Point3D, Rect2D};
+use crate::geometry::{LocalPoint3D, Point3D, Rect2D};
+use crate::minecraft::Block;
 use crate::noise::RNG;
 
 use super::block::swap_block_for_palette;
@@ -43,7 +44,7 @@ pub(super) async fn try_place_item(
     materials: &HashMap<MaterialId, Material>,
     loot_tables: &HashMap<String, LootTable>,
     rng: &mut RNG,
-) -> Option<(Vec<(i32, i32)>, Vec<AnchorCandidate>)> {
+) -> Option<(Vec<(i32, i32)>, Vec<AnchorCandidate>, Vec<(Point3D, Block)>)> {
     let result = if is_ceiling_item(item) {
         try_place_ceiling(item, interior, constraints, ceiling_y)
     } else if needs_wall(item) {
@@ -61,6 +62,7 @@ pub(super) async fn try_place_item(
 
     if let Some(placement) = result {
         let mut cells = Vec::new();
+        let mut containers = Vec::new();
         for &cell in &placement.new_blocked { constraints.set(cell, CellState::Blocked); }
         for &cell in &placement.new_reserved { constraints.set(cell, CellState::BlockedReachable); }
         for &cell in &placement.new_empty_reachable { constraints.set(cell, CellState::UnblockedReachable); }
@@ -72,7 +74,10 @@ pub(super) async fn try_place_item(
                         block.data = Some(roll_loot_snbt(table, rng));
                     }
                 }
-                editor.place_block(&block, rb.world_pos).await;
+                editor.place_block(&block, LocalPoint3D(rb.world_pos)).await;
+                if rb.loot.is_some() || block.id.as_str().ends_with("lectern") {
+                    containers.push((rb.world_pos, block));
+                }
             }
             if rb.layer.occupies_ceiling() {
                 constraints.set_ceiling(rb.cell);
@@ -91,7 +96,7 @@ pub(super) async fn try_place_item(
                 cells.push(cell);
             }
         }
-        Some((cells, placement.anchors))
+        Some((cells, placement.anchors, containers))
     } else {
         None
     }
@@ -219,7 +224,7 @@ pub(crate) async fn furnish_interior(
     for entry in &room_list.required {
         let candidates = resolve_candidates(entry, items, room_area, is_attic, &placed_tags, rng);
         for (name, item) in candidates {
-            if let Some((cells, anchors)) = try_place_item(
+            if let Some((cells, anchors, containers)) = try_place_item(
                 editor, item, interior, constraints,
                 &slots, &open_cells, floor_y, ceiling_y,
                 roof_clearance,
@@ -230,7 +235,7 @@ pub(crate) async fn furnish_interior(
                         placed_tags.insert(tag.to_string());
                     }
                 }
-                placed.push(PlacedFurniture { name: name.clone(), cells, anchors });
+                placed.push(PlacedFurniture { name: name.clone(), cells, anchors, containers });
                 break;
             }
         }
@@ -251,7 +256,7 @@ pub(crate) async fn furnish_interior(
             if constraints.fill_ratio() >= fill_threshold { break; }
             let candidates = resolve_candidates(entry, items, room_area, is_attic, &placed_tags, rng);
             for (name, item) in candidates {
-                if let Some((cells, anchors)) = try_place_item(
+                if let Some((cells, anchors, containers)) = try_place_item(
                     editor, item, interior, constraints,
                     &slots, &open_cells, floor_y, ceiling_y,
                     roof_clearance,
@@ -262,7 +267,7 @@ pub(crate) async fn furnish_interior(
                             placed_tags.insert(tag.to_string());
                         }
                     }
-                    placed.push(PlacedFurniture { name: name.clone(), cells, anchors });
+                    placed.push(PlacedFurniture { name: name.clone(), cells, anchors, containers });
                     placed_this_pass = true;
                     break;
                 }
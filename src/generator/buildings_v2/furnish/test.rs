@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use crate::geometry::{Cardinal, Point2D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, Rect2D, LocalPoint3D};
 use crate::noise::RNG;
 use crate::generator::buildings_v2::RoomType;
 use crate::generator::buildings_v2::rooms::{CellState, ConstraintMap, Room, RoomRole};
@@ -1190,7 +1190,7 @@ async fn place_room_sizes_in_world() {
     use crate::generator::buildings_v2::footprint::Footprint;
     use crate::generator::buildings_v2::frame::Frame;
     use crate::generator::materials::{Material, MaterialId, MaterialRole, Palette, PaletteId};
-    use crate::geometry::Point3D;
+    
     use crate::http_mod::GDMCHTTPProvider;
     use crate::minecraft::{Block, Color};
     use super::room::furnish_room;
@@ -1271,7 +1271,7 @@ async fn place_room_sizes_in_world() {
 
         // Oak plank floor under the rect (no ceiling — open top so you can see in).
         for p in rect.iter() {
-            editor.place_block(&floor_block, Point3D::new(p.x, floor_y - 1, p.y)).await;
+            editor.place_block(&floor_block, LocalPoint3D::new(p.x, floor_y - 1, p.y)).await;
         }
         // Wood walls on the rect perimeter, 3 high (floor_y..floor_y+2).
         // Carve out the door with air at the wall opening.
@@ -1280,13 +1280,13 @@ async fn place_room_sizes_in_world() {
             let is_door = (p.x, p.y) == world_wdoor;
             for dy in 0..3 {
                 let block = if is_door && dy < 2 { &air } else { &wall_block };
-                editor.place_block(block, Point3D::new(p.x, floor_y + dy, p.y)).await;
+                editor.place_block(block, LocalPoint3D::new(p.x, floor_y + dy, p.y)).await;
             }
         }
         // Clear interior air column so old blocks don't survive.
         for p in interior.iter() {
             for dy in 0..3 {
-                editor.place_block(&air, Point3D::new(p.x, floor_y + dy, p.y)).await;
+                editor.place_block(&air, LocalPoint3D::new(p.x, floor_y + dy, p.y)).await;
             }
         }
 
@@ -1353,7 +1353,7 @@ async fn place_feature_rooms_in_world() {
     use crate::generator::buildings_v2::footprint::Footprint;
     use crate::generator::buildings_v2::frame::Frame;
     use crate::generator::materials::{Material, MaterialId, MaterialRole, Palette, PaletteId};
-    use crate::geometry::Point3D;
+    
     use crate::http_mod::GDMCHTTPProvider;
     use crate::minecraft::{Block, Color};
     use super::room::furnish_room;
@@ -1483,19 +1483,19 @@ async fn place_feature_rooms_in_world() {
 
         // Floor planks beneath the rect (overwrites ground).
         for p in rect.iter() {
-            editor.place_block(&floor_block, Point3D::new(p.x, floor_y - 1, p.y)).await;
+            editor.place_block(&floor_block, LocalPoint3D::new(p.x, floor_y - 1, p.y)).await;
         }
         // Walls on the perimeter, WALL_HEIGHT high.
         for p in rect.iter() {
             if !rect.on_edge(p) { continue; }
             for dy in 0..(WALL_HEIGHT as i32) {
-                editor.place_block(&wall_block, Point3D::new(p.x, floor_y + dy, p.y)).await;
+                editor.place_block(&wall_block, LocalPoint3D::new(p.x, floor_y + dy, p.y)).await;
             }
         }
         // Clear interior air column.
         for p in interior.iter() {
             for dy in 0..(WALL_HEIGHT as i32) {
-                editor.place_block(&air, Point3D::new(p.x, floor_y + dy, p.y)).await;
+                editor.place_block(&air, LocalPoint3D::new(p.x, floor_y + dy, p.y)).await;
             }
         }
 
@@ -1510,7 +1510,7 @@ async fn place_feature_rooms_in_world() {
                     // Carve a 2-tall opening in the wall, then install an
                     // actual oak door so the entry point is obvious.
                     for dy in 0..2 {
-                        editor.place_block(&air, Point3D::new(wx, floor_y + dy, wz)).await;
+                        editor.place_block(&air, LocalPoint3D::new(wx, floor_y + dy, wz)).await;
                     }
                     // Door "facing" = direction from the wall into the room.
                     let dir_in = match (
@@ -1532,7 +1532,7 @@ async fn place_feature_rooms_in_world() {
                     ]);
                     let mut door_lower = Block::from_id("minecraft:oak_door".into());
                     door_lower.state = Some(door_state_lower);
-                    editor.place_block_forced(&door_lower, Point3D::new(wx, floor_y, wz)).await;
+                    editor.place_block_forced(&door_lower, LocalPoint3D::new(wx, floor_y, wz)).await;
                     let door_state_upper = HashMap::from([
                         ("facing".to_string(), dir_in.to_string()),
                         ("half".to_string(), "upper".to_string()),
@@ -1542,7 +1542,7 @@ async fn place_feature_rooms_in_world() {
                     ]);
                     let mut door_upper = Block::from_id("minecraft:oak_door".into());
                     door_upper.state = Some(door_state_upper);
-                    editor.place_block_forced(&door_upper, Point3D::new(wx, floor_y + 1, wz)).await;
+                    editor.place_block_forced(&door_upper, LocalPoint3D::new(wx, floor_y + 1, wz)).await;
                     let wic = to_world(*i_cell);
                     constraints.set(wic, CellState::UnblockedReachable);
                 }
@@ -1570,11 +1570,11 @@ async fn place_feature_rooms_in_world() {
                             stair.state = Some(HashMap::from([
                                 ("facing".to_string(), facing.to_string()),
                             ]));
-                            editor.place_block(&stair, Point3D::new(wp.0, step_y, wp.1)).await;
+                            editor.place_block(&stair, LocalPoint3D::new(wp.0, step_y, wp.1)).await;
                             // Ensure the air above the stair is clear so the
                             // player's head doesn't hit the ceiling.
                             for dy in (i as i32)..(WALL_HEIGHT as i32) {
-                                editor.place_block(&air, Point3D::new(wp.0, floor_y + dy, wp.1)).await;
+                                editor.place_block(&air, LocalPoint3D::new(wp.0, floor_y + dy, wp.1)).await;
                             }
                             constraints.set(wp, CellState::Blocked);
                         }
@@ -1610,19 +1610,19 @@ async fn place_feature_rooms_in_world() {
                         // is a visible opening from above. Forced so air
                         // overrides the denser floor block already placed.
                         for dy in step_y..=(floor_y + 1) {
-                            editor.place_block_forced(&air, Point3D::new(wp.0, dy, wp.1)).await;
+                            editor.place_block_forced(&air, LocalPoint3D::new(wp.0, dy, wp.1)).await;
                         }
                         // Solid floor below the deepest step so the pit is enclosed.
-                        editor.place_block(&stone, Point3D::new(wp.0, step_y - 1, wp.1)).await;
+                        editor.place_block(&stone, LocalPoint3D::new(wp.0, step_y - 1, wp.1)).await;
                         let mut stair = Block::from_id(stair_id_str.as_str().into());
                         stair.state = Some(HashMap::from([
                             ("facing".to_string(), facing.to_string()),
                         ]));
-                        editor.place_block(&stair, Point3D::new(wp.0, step_y, wp.1)).await;
+                        editor.place_block(&stair, LocalPoint3D::new(wp.0, step_y, wp.1)).await;
                         // Glass marker a few blocks above the stair so the
                         // descent is visible from across the room without
                         // blocking sightlines into the pit.
-                        editor.place_block_forced(&glass, Point3D::new(wp.0, floor_y + 2, wp.1)).await;
+                        editor.place_block_forced(&glass, LocalPoint3D::new(wp.0, floor_y + 2, wp.1)).await;
                     }
                     // Append one extra step at the next cell in the descent
                     // direction, one block lower than the deepest current
@@ -1633,15 +1633,15 @@ async fn place_feature_rooms_in_world() {
                     constraints.set(wp, CellState::UnblockedReachable);
                     let extra_step_y = floor_y - positions.len() as i32;
                     for dy in extra_step_y..=(floor_y + 1) {
-                        editor.place_block_forced(&air, Point3D::new(wp.0, dy, wp.1)).await;
+                        editor.place_block_forced(&air, LocalPoint3D::new(wp.0, dy, wp.1)).await;
                     }
-                    editor.place_block(&stone, Point3D::new(wp.0, extra_step_y - 1, wp.1)).await;
+                    editor.place_block(&stone, LocalPoint3D::new(wp.0, extra_step_y - 1, wp.1)).await;
                     let mut extra_stair = Block::from_id(stair_id_str.as_str().into());
                     extra_stair.state = Some(HashMap::from([
                         ("facing".to_string(), facing.to_string()),
                     ]));
-                    editor.place_block(&extra_stair, Point3D::new(wp.0, extra_step_y, wp.1)).await;
-                    editor.place_block_forced(&glass, Point3D::new(wp.0, floor_y + 2, wp.1)).await;
+                    editor.place_block(&extra_stair, LocalPoint3D::new(wp.0, extra_step_y, wp.1)).await;
+                    editor.place_block_forced(&glass, LocalPoint3D::new(wp.0, floor_y + 2, wp.1)).await;
                 }
                 LiveFeature::Ladder { wall_cell, interior: i_cell } => {
                     let (wx, wz) = to_world(*wall_cell);
@@ -1661,7 +1661,7 @@ async fn place_feature_rooms_in_world() {
                         ladder.state = Some(HashMap::from([
                             ("facing".to_string(), facing.to_string()),
                         ]));
-                        editor.place_block_forced(&ladder, Point3D::new(ix, floor_y + dy, iz)).await;
+                        editor.place_block_forced(&ladder, LocalPoint3D::new(ix, floor_y + dy, iz)).await;
                     }
                     constraints.set((ix, iz), CellState::UnblockedReachable);
                 }
@@ -1721,7 +1721,7 @@ async fn place_feature_rooms_in_world() {
                 Some(CellState::Blocked) => &red,
                 None => continue,
             };
-            editor.place_block_forced(block, Point3D::new(p.x, map_y, p.y)).await;
+            editor.place_block_forced(block, LocalPoint3D::new(p.x, map_y, p.y)).await;
         }
     }
 
@@ -2185,7 +2185,7 @@ async fn build_furniture_gallery() {
     use crate::util::init_logger;
     use crate::generator::data::LoadedData;
     use crate::generator::materials::PaletteId;
-    use crate::geometry::Point3D;
+    
     use crate::minecraft::Block;
 
     // Booth geometry. A 7×7 booth → 5×5 interior (fits the largest items, which
@@ -2228,7 +2228,7 @@ async fn build_furniture_gallery() {
     let plat_max = Point2D::new(base_x + COLS * PITCH + 1, base_z + rows * PITCH + 1);
     let floor_block: Block = "minecraft:smooth_stone".into();
     for p in Rect2D::from_points(plat_min, plat_max).iter() {
-        editor.place_block(&floor_block, Point3D::new(p.x, surface_y, p.y)).await;
+        editor.place_block(&floor_block, LocalPoint3D::new(p.x, surface_y, p.y)).await;
     }
 
     let wall_block: Block = "minecraft:stone_bricks".into();
@@ -2252,7 +2252,7 @@ async fn build_furniture_gallery() {
         for p in booth.iter() {
             if p.y == booth.min().y {
                 for h in 0..WALL_HEIGHT {
-                    editor.place_block(&wall_block, Point3D::new(p.x, floor_y + h, p.y)).await;
+                    editor.place_block(&wall_block, LocalPoint3D::new(p.x, floor_y + h, p.y)).await;
                 }
             }
         }
@@ -2261,7 +2261,7 @@ async fn build_furniture_gallery() {
         // open.
         if is_ceiling_item(item) {
             for p in interior.iter() {
-                editor.place_block(&ceiling_block, Point3D::new(p.x, ceiling_y, p.y)).await;
+                editor.place_block(&ceiling_block, LocalPoint3D::new(p.x, ceiling_y, p.y)).await;
             }
         }
 
@@ -2290,7 +2290,7 @@ async fn build_furniture_gallery() {
         // Label sign at the front-center threshold, facing the viewer.
         let sign = gallery_sign(name, "");
         let sign_x = (booth.min().x + booth.max().x) / 2;
-        editor.place_block_forced(&sign, Point3D::new(sign_x, floor_y, booth.max().y)).await;
+        editor.place_block_forced(&sign, LocalPoint3D::new(sign_x, floor_y, booth.max().y)).await;
     }
 
     editor.flush_buffer().await;
@@ -18,6 +18,7 @@ pub mod walls;
 pub use pipeline::{BuildCtx, HouseOutput, build_house};
 pub use self::walls::{TimberPattern, WindowFill};
 
+use serde_derive::{Deserialize, Serialize};
 use crate::generator::materials::PaletteId;
 use crate::minecraft::Color;
 use footprint::SizeClass;
@@ -31,7 +32,7 @@ pub enum BuildingType {
 }
 
 /// Cultural style that drives palette selection, roof/window/floor defaults.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Culture {
     Medieval,
     Desert,
@@ -8,7 +8,7 @@
 
 use std::collections::HashSet;
 
-use crate::editor::Editor;
+use crate::editor::{Editor, Owner};
 use crate::generator::data::LoadedData;
 use crate::generator::materials::Palette;
 use crate::generator::population::AnchorScene;
@@ -30,6 +30,7 @@ use super::{BuildingContext, Culture};
 use super::roof::RoofStyle;
 use super::roof::gable::GablePitch;
 use super::roof::{place_roof, place_roof_ladder};
+use super::roof::heightmap::RoofHeightmap;
 use super::floors::place_room_floors;
 use super::rooms::{
     RoomPlan, assign_attic_types, assign_room_floors, build_rooms,
@@ -91,11 +92,38 @@ pub struct HouseOutput {
 /// Runs the full per-building pipeline. Caller owns footprint generation and
 /// plot markup (so a single plot can host multiple buildings) and the final
 /// `editor.flush_buffer()`. `plot_bounds` is used for door-distance scoring.
+///
+/// Every block the house places is recorded as owned by the building it will
+/// become (`Owner::Building`), under the stage that placed it. The build runs in
+/// an editor scope: on `Err` its blocks, height changes and claims are rolled
+/// back, so a failed house leaves no half-built shell or stale `Building` claim
+/// and the caller can try another.
 pub async fn build_house(
     ctx: &mut BuildCtx<'_>,
     footprint: Footprint,
     bctx: &BuildingContext,
     plot_bounds: Rect2D,
+) -> Result<HouseOutput, String> {
+    let building_id = BuildingID(ctx.editor.world().buildings.len());
+    ctx.editor.begin_scope();
+    ctx.editor.push_provenance(module_path!(), Some(Owner::Building(building_id)));
+    let house = build_house_stages(ctx, footprint, bctx, plot_bounds).await;
+    ctx.editor.pop_provenance();
+    match &house {
+        Ok(_) => ctx.editor.commit(),
+        Err(_) => {
+            let reverted = ctx.editor.abort();
+            log::info!("Rolled back {} blocks of a failed house", reverted);
+        }
+    }
+    house
+}
+
+async fn build_house_stages(
+    ctx: &mut BuildCtx<'_>,
+    footprint: Footprint,
+    bctx: &BuildingContext,
+    plot_bounds: Rect2D,
 ) -> Result<HouseOutput, String> {
     let size_class = bctx.size_class;
     let roof_style = bctx.roof_style;
@@ -105,7 +133,9 @@ pub async fn build_house(
     // Foundation: terrain analysis + level + stone course. Needs &mut Editor
     // to update the world heightmap. `base_y_override` pins the floor (e.g. to a
     // road's height) instead of deriving it from the terrain percentile.
+    ctx.editor.push_provenance("buildings_v2::foundation", None);
     let base_y = place_foundation(ctx, &footprint, bctx.base_y_override).await;
+    ctx.editor.pop_provenance();
 
     // Engawa: inset the walled footprint by one on every open-air side and raise
     // it one block onto a decked platform. Japanese only; `plan_engawa` gates on
@@ -151,14 +181,18 @@ pub async fn build_house(
         Vec::new()
     };
 
+    ctx.editor.push_provenance("buildings_v2::floors", None);
     let floor_plan = place_floors(ctx, &frame, &wall_segs, has_attic, skip_ceilings).await;
+    ctx.editor.pop_provenance();
     // Japanese walls are white shoji panels over a timber baseboard, divided by
     // vertical wood beams; other cultures keep the stone-base / solid fill.
     let (ground_infill, upper_infill) = match bctx.culture {
         Culture::Japanese => (WallInfill::TimberPanels, WallInfill::TimberPanels),
         _ => (WallInfill::StoneBase, WallInfill::Solid),
     };
+    ctx.editor.push_provenance("buildings_v2::walls", None);
     place_wall_infill(ctx, &wall_segs, &ground_infill, &upper_infill).await;
+    ctx.editor.pop_provenance();
 
     // Resolve the timber pattern now that the frame is known — auto-pick
     // filters out patterns whose studs wouldn't fit the longest wall segment.
@@ -177,7 +211,9 @@ pub async fn build_house(
         let mut timber_rng = ctx.rng.derive();
         TimberPattern::pick(size_class, max_seg_len, &mut timber_rng)
     });
+    ctx.editor.push_provenance("buildings_v2::walls::frame", None);
     place_frame(ctx, &frame, &timber_pattern).await;
+    ctx.editor.pop_provenance();
     let (gable_doorways, roof_heightmaps) = place_roof(ctx, &frame, roof_style).await;
     if has_attic {
         clear_attic_stair_headroom(ctx, &frame, &floor_plan).await;
@@ -188,12 +224,16 @@ pub async fn build_house(
     // the skirt overlays cleanly; the deck planks overwrite the foundation
     // course in the perimeter ring.
     if let Some(plan) = &engawa_plan {
+        ctx.editor.push_provenance("buildings_v2::engawa", None);
         engawa::place_engawa(ctx, &frame, plan).await;
+        ctx.editor.pop_provenance();
     }
 
     // Rooms are built before windows so window placement can avoid interior
     // wall cells and attic-ladder walls.
+    ctx.editor.push_provenance("buildings_v2::rooms", None);
     let mut room_plan = build_rooms(ctx, &frame, &wall_segs, &floor_plan, has_attic, size_class).await;
+    ctx.editor.pop_provenance();
     mark_gable_doorways(&mut room_plan, &gable_doorways);
     let ladder_walls = place_attic_ladders(ctx, &mut room_plan, &frame, &floor_plan, &wall_segs, &gable_doorways).await;
     assign_attic_types(&mut room_plan, size_class, ctx.rng);
@@ -218,7 +258,9 @@ pub async fn build_house(
     }
     place_windows(&mut wall_segs, &interior_wall_cells, ctx.rng);
     mark_windows(&mut room_plan, &wall_segs);
+    ctx.editor.push_provenance("buildings_v2::walls::openings", None);
     place_openings(ctx, &wall_segs, window_fill, bctx.culture).await;
+    ctx.editor.pop_provenance();
 
     // Reconcile doors with terrain: run parallel stair ramps along the wall
     // for doors where `base_y` doesn't match outside-terrain. Skipped for an
@@ -232,7 +274,9 @@ pub async fn build_house(
     // Save the real exterior entrance per door (bottom of the ramp, if any) for
     // the settlement's door→road connectors.
     let door_entrances = super::door_ramp::door_entrances(&wall_segs, &door_ramps);
+    ctx.editor.push_provenance("buildings_v2::door_ramp", None);
     place_door_ramps(ctx, &door_ramps).await;
+    ctx.editor.pop_provenance();
 
     assign_room_floors(&mut room_plan);
     place_room_floors(ctx, &frame, &room_plan, bctx).await;
@@ -241,7 +285,9 @@ pub async fn build_house(
     // Furnish, then harvest the NPC anchor scenes the placed furniture offers
     // (validated against the final per-room CellState inside furnish_rooms).
     // Rooftop terraces and the cellar add their own anchors below.
+    ctx.editor.push_provenance("buildings_v2::furnish", None);
     let mut npc_anchors = furnish_rooms(ctx, &mut room_plan, &frame, &roof_heightmaps).await;
+    ctx.editor.pop_provenance();
 
     // Flat roofs are open terraces — decorate the deck (shade, seating, plants)
     // once the interior is furnished. Keeps the ladder exit clear.
@@ -251,7 +297,9 @@ pub async fn build_house(
 
     // A few sparse props against the outside walls (barrels, pots, …) so the
     // house reads as lived-in. Skips doors, roads, and claimed cells.
+    ctx.editor.push_provenance("buildings_v2::exterior", None);
     decorate_exterior_walls(ctx, &building_footprint, &wall_segs).await;
+    ctx.editor.pop_provenance();
 
     check_building_invariants(&frame, &room_plan, &floor_plan, &roof_heightmaps)?;
 
@@ -260,7 +308,9 @@ pub async fn build_house(
     // that blueprint/invariant code iterates.
     // Cellar uses `building_footprint` (the inset walls for an engawa) so its
     // retaining walls sit under the actual walls, not out under the veranda deck.
+    ctx.editor.push_provenance("buildings_v2::cellar", None);
     let cellar = cellar::maybe_build_cellar(ctx, &frame, &building_footprint, &wall_segs, &floor_plan, &room_plan, size_class).await;
+    ctx.editor.pop_provenance();
     let has_cellar = cellar.is_some();
     let cellar_stair = cellar.map(|(stair, anchors)| {
         npc_anchors.extend(anchors);
@@ -279,16 +329,26 @@ pub async fn build_house(
 
     // Claim the structural footprint (the actual house cells, no buffer) so a
     // later building's foundation blend won't raise earth/grass into this house
-    // — `blend_terrain` skips Building-claimed cells.
+    // — `blend_terrain` skips Building-claimed cells. The footprint is the
+    // house's from the ground up to the roof; a cell only an upper floor covers
+    // (a jetty's overhang) is claimed from that floor up, leaving the street
+    // beneath free for a road.
     let building_idx = ctx.editor.world().buildings.len();
+    let claim = BuildClaim::Building(BuildingID(building_idx));
+    let top = house_top(&frame, &roof_heightmaps);
+    for floor in (1..frame.max_floors()).rev() {
+        for p in frame.filled_points_at_floor(floor) {
+            ctx.editor.world_mut().claim_range(p, frame.floor_y(floor)..top, claim.clone());
+        }
+    }
     for p in footprint.filled_points() {
-        ctx.editor.world_mut().claim(p, BuildClaim::Building(BuildingID(building_idx)));
+        ctx.editor.world_mut().claim_range(p, i32::MIN..top, claim.clone());
     }
     // The engawa deck can bump just outside the nominal footprint at junctions;
     // claim those cells too so a neighbour's terrain blend doesn't bury the deck.
     if let Some(plan) = &engawa_plan {
         for &p in &plan.deck_cells {
-            ctx.editor.world_mut().claim(p, BuildClaim::Building(BuildingID(building_idx)));
+            ctx.editor.world_mut().claim_range(p, i32::MIN..top, claim.clone());
         }
     }
 
@@ -330,3 +390,15 @@ fn door_keepout_cells(wall_segs: &WallSegments) -> HashSet<(i32, Point2D)> {
     }
     out
 }
+
+/// One above the highest block the house can reach: its tallest wing's roof
+/// plus the steepest pitch any roof rises to (heightmaps are relative to the
+/// roof line), with a block to spare for a ridge cap.
+fn house_top(frame: &Frame, roof_heightmaps: &[RoofHeightmap]) -> i32 {
+    let roof_y = (0..frame.rect_count()).map(|i| frame.roof_y(i)).max().unwrap_or(frame.base_y());
+    let pitch = roof_heightmaps.iter()
+        .flat_map(|map| (map.min_x()..=map.max_x())
+            .flat_map(move |x| (map.min_z()..=map.max_z()).map(move |z| map.get(x, z))))
+        .fold(0.0, f32::max);
+    roof_y + pitch.ceil() as i32 + 2
+}
@@ -10,7 +10,7 @@
 use std::collections::HashMap;
 
 use crate::editor::Editor;
-use crate::geometry::{Point3D, Rect2D};
+use crate::geometry::{LocalPoint3D, Rect2D};
 use crate::minecraft::Block;
 
 /// Minimum square side that gets a dome. Below this the hemisphere is a 1–2
@@ -59,7 +59,7 @@ pub(super) async fn place_dome(editor: &Editor, rect: &Rect2D, deck_y: i32) {
     // Flat sealing layer across the whole square at wall-top: caps the top room
     // (flat roofs skip ceilings).
     for p in rect.iter() {
-        editor.place_block(&dark_prismarine(), p.add_y(deck_y)).await;
+        editor.place_block(&dark_prismarine(), LocalPoint3D(p.add_y(deck_y))).await;
     }
 
     // Square base course one block up — the full square (corners included) that
@@ -67,7 +67,7 @@ pub(super) async fn place_dome(editor: &Editor, rect: &Rect2D, deck_y: i32) {
     // circular. The hemisphere curve rises on top of it from base + 1.
     let base = deck_y + 1;
     for p in rect.iter() {
-        editor.place_block(&dark_prismarine(), p.add_y(base)).await;
+        editor.place_block(&dark_prismarine(), LocalPoint3D(p.add_y(base))).await;
     }
 
     // Solid columns curving up from the square base to each cell's rounded
@@ -82,11 +82,11 @@ pub(super) async fn place_dome(editor: &Editor, rect: &Rect2D, deck_y: i32) {
             let has_slab = half_steps % 2 == 1;
 
             for k in 1..full_count {
-                editor.place_block(&dark_prismarine(), Point3D::new(x, base + k, z)).await;
+                editor.place_block(&dark_prismarine(), LocalPoint3D::new(x, base + k, z)).await;
             }
             if has_slab && full_count >= 1 {
                 editor
-                    .place_block(&dark_prismarine_bottom_slab(), Point3D::new(x, base + full_count, z))
+                    .place_block(&dark_prismarine_bottom_slab(), LocalPoint3D::new(x, base + full_count, z))
                     .await;
             }
         }
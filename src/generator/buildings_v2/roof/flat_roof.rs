@@ -3,7 +3,7 @@
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Point2D, Point3D};
+use crate::geometry::{Point2D, LocalPoint3D};
 use crate::minecraft::{Block, BlockForm};
 
 use super::super::frame::Frame;
@@ -337,7 +337,7 @@ pub async fn place_roof_ladder(
         ladder.state = Some(std::collections::HashMap::from([
             ("facing".to_string(), facing.to_string()),
         ]));
-        editor.place_block_forced(&ladder, Point3D::new(ladder_pos.x, y, ladder_pos.y)).await;
+        editor.place_block_forced(&ladder, LocalPoint3D::new(ladder_pos.x, y, ladder_pos.y)).await;
     }
 
     // Mark ladder cell as UnblockedReachable on the top floor
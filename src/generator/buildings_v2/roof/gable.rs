@@ -1,7 +1,7 @@
 use crate::editor::Editor;
 use crate::generator::data::LoadedData;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Palette, Placer};
-use crate::geometry::{Point2D, Point3D, Rect2D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{Block, BlockForm};
 use crate::noise::RNG;
 use super::heightmap::RoofHeightmap;
@@ -237,7 +237,7 @@ pub async fn place_gable_walls(
                 } else if is_window_col && y >= win_y_start && y < win_y_end {
                     editor.place_block_forced(
                         &Block::from_id("minecraft:glass_pane".into()),
-                        Point3D::new(x, y, z),
+                        LocalPoint3D::new(x, y, z),
                     ).await;
                 } else {
                     wall_placer.place_block(
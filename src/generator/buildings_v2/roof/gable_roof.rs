@@ -7,7 +7,7 @@ use std::collections::BTreeMap;
 use crate::editor::Editor;
 use crate::generator::data::LoadedData;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Palette, Placer};
-use crate::geometry::{Point2D, Point3D, Rect2D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{Block, BlockForm};
 use crate::noise::RNG;
 
@@ -272,11 +272,11 @@ async fn place_attic_lantern(
 
     // Chains from just below the roof surface down to above the lantern
     for y in (lantern_y + 1)..(roof_surface_y - 1) {
-        editor.place_block_forced(&chain, Point3D::new(center.x, y, center.y)).await;
+        editor.place_block_forced(&chain, LocalPoint3D::new(center.x, y, center.y)).await;
     }
 
     // Lantern at the bottom
-    editor.place_block_forced(&lantern, Point3D::new(center.x, lantern_y, center.y)).await;
+    editor.place_block_forced(&lantern, LocalPoint3D::new(center.x, lantern_y, center.y)).await;
 }
 
 /// Place a chimney on the roof of a rect.
@@ -358,7 +358,7 @@ async fn place_chimney(
     // Place campfire on top
     editor.place_block_forced(
         &Block::from_id("minecraft:campfire".into()),
-        Point3D::new(cx, top_y, cz),
+        LocalPoint3D::new(cx, top_y, cz),
     ).await;
 
     // Surround the campfire with dark oak shelves facing outward
@@ -370,6 +370,6 @@ async fn place_chimney(
         shelf.state = Some(HashMap::from([
             ("facing".to_string(), facing.to_string()),
         ]));
-        editor.place_block_forced(&shelf, Point3D::new(cx + dx, top_y, cz + dz)).await;
+        editor.place_block_forced(&shelf, LocalPoint3D::new(cx + dx, top_y, cz + dz)).await;
     }
 }
@@ -12,7 +12,7 @@ use std::collections::{BTreeMap, HashMap};
 use crate::editor::Editor;
 use crate::generator::data::LoadedData;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Palette, Placer};
-use crate::geometry::{Point2D, Point3D, Rect2D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{Block, BlockForm};
 use crate::noise::RNG;
 
@@ -169,7 +169,7 @@ async fn raise_corners(
             .place_block_forced(editor, Point3D::new(cx, roof_y, cz), BlockForm::Block, None, None)
             .await;
         editor
-            .place_block_forced(&Block::from_id("minecraft:air".into()), Point3D::new(cx, roof_y - 1, cz))
+            .place_block_forced(&Block::from_id("minecraft:air".into()), LocalPoint3D::new(cx, roof_y - 1, cz))
             .await;
 
         // Step into the corner along each eave (the cardinal overhang row/column).
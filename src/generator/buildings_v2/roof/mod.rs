@@ -62,10 +62,19 @@ pub async fn place_roof(
     frame: &Frame,
     style: RoofStyle,
 ) -> (Vec<Point2D>, Vec<RoofHeightmap>) {
-    match style {
+    let subsystem = match style {
+        RoofStyle::Gable(_) => concat!(module_path!(), "::gable_roof"),
+        RoofStyle::Hipped(_) => concat!(module_path!(), "::hipped_roof"),
+        RoofStyle::Irimoya => concat!(module_path!(), "::irimoya_roof"),
+        RoofStyle::Flat => concat!(module_path!(), "::flat_roof"),
+    };
+    ctx.editor.push_provenance(subsystem, None);
+    let roof = match style {
         RoofStyle::Gable(pitch) => gable_roof::place_gable_roof(ctx, frame, pitch).await,
         RoofStyle::Hipped(pitch) => hipped_roof::place_hipped_roof(ctx, frame, pitch).await,
         RoofStyle::Irimoya => irimoya_roof::place_irimoya_roof(ctx, frame).await,
         RoofStyle::Flat => flat_roof::place_flat_roof(ctx, frame).await,
-    }
+    };
+    ctx.editor.pop_provenance();
+    roof
 }
@@ -5,7 +5,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
-use crate::geometry::{Point2D, Point3D, Rect2D};
+use crate::geometry::{Point2D, LocalPoint3D, Rect2D};
 use crate::minecraft::Block;
 
 use super::super::footprint::find_boundaries;
@@ -166,7 +166,7 @@ pub async fn place_attic_ladders(
             ladder.state = Some(std::collections::HashMap::from([
                 ("facing".to_string(), facing.to_string()),
             ]));
-            editor.place_block_forced(&ladder, Point3D::new(ladder_cell.0, y, ladder_cell.1)).await;
+            editor.place_block_forced(&ladder, LocalPoint3D::new(ladder_cell.0, y, ladder_cell.1)).await;
         }
 
         // Ladder cell is walkable (player climbs through it) but no
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 
 use crate::generator::population::{Occupant, SceneKind, SlotRole};
-use crate::geometry::Rect2D;
+use crate::geometry::{Point3D, Rect2D};
+use crate::minecraft::Block;
 
 /// State of a cell in a room's interior.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -136,6 +137,9 @@ pub struct PlacedFurniture {
     /// + facing at placement time but not yet validated against the final room
     /// layout (see [`AnchorCandidate`]).
     pub anchors: Vec<AnchorCandidate>,
+    /// The item's containers, as placed with their loot, and its lecterns — the
+    /// blocks a later pass can leave a book in. Editor-local positions.
+    pub containers: Vec<(Point3D, Block)>,
 }
 
 /// A candidate NPC scene contributed by one placed furniture item: where people
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use crate::geometry::{Point2D, Point3D, Rect2D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::noise::RNG;
 use crate::minecraft::Block;
 use crate::generator::buildings_v2::RoomType;
@@ -585,7 +585,7 @@ async fn build_single_class_with_signs(label: &str, size_class: SizeClass, max:
             let line1 = format!("F{} R{}", room.floor, room.rect_index);
             let line2 = format!("{:?}", room.room_type);
             let sign = sign_block(&line1, &line2);
-            ctx.editor.place_block_forced(&sign, Point3D::new(cx, y, cz)).await;
+            ctx.editor.place_block_forced(&sign, LocalPoint3D::new(cx, y, cz)).await;
         }
 
         println!(
@@ -693,7 +693,7 @@ async fn build_style_catalog(culture: crate::generator::buildings_v2::Culture, s
                         let cz = (rect.min().y + rect.max().y) / 2;
                         let y = house.frame.floor_y(0) + 1;
                         let sign = sign_block(bstyle.name, &format!("{:?}", culture));
-                        editor.place_block_forced(&sign, Point3D::new(cx, y, cz)).await;
+                        editor.place_block_forced(&sign, LocalPoint3D::new(cx, y, cz)).await;
                     }
                 }
                 Err(e) => println!("  style '{}' sample {j}: FAILED: {e}", bstyle.name),
@@ -917,7 +917,7 @@ async fn build_single_manor() {
         let cz = (room.rect.min().y + room.rect.max().y) / 2;
         let y = house.frame.floor_y(room.floor) + 1;
         let sign = sign_block(&format!("F{} R{}", room.floor, room.rect_index), &format!("{:?}", room.room_type));
-        ctx.editor.place_block_forced(&sign, Point3D::new(cx, y, cz)).await;
+        ctx.editor.place_block_forced(&sign, LocalPoint3D::new(cx, y, cz)).await;
     }
 
     editor.flush_buffer().await;
@@ -1011,7 +1011,7 @@ async fn build_mixed_sizes_with_random_roofs() {
             let line1 = format!("F{} R{}", room.floor, room.rect_index);
             let line2 = format!("{:?}", room.room_type);
             let sign = sign_block(&line1, &line2);
-            editor.place_block_forced(&sign, Point3D::new(cx, y, cz)).await;
+            editor.place_block_forced(&sign, LocalPoint3D::new(cx, y, cz)).await;
         }
 
         println!(
@@ -1448,7 +1448,7 @@ async fn build_irimoya_house_offline() {
 
     let wall_top = house.frame.roof_y(0);
     let center = rect.midpoint();
-    let at = |p: Point2D, y: i32| editor.try_get_block(Point3D::new(p.x, y, p.y));
+    let at = |p: Point2D, y: i32| editor.try_get_block(LocalPoint3D::new(p.x, y, p.y));
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     // Topmost solid block in a column, scanning the roof band.
     let top_solid = |p: Point2D| -> Option<i32> {
@@ -1555,7 +1555,7 @@ async fn build_engawa_house_offline() {
         );
     }
 
-    let at = |p: Point2D, y: i32| editor.try_get_block(Point3D::new(p.x, y, p.y));
+    let at = |p: Point2D, y: i32| editor.try_get_block(LocalPoint3D::new(p.x, y, p.y));
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     let is_stair = |b: &Block| b.id.as_str().contains("stairs");
     let is_slab = |b: &Block| b.id.as_str().contains("slab");
@@ -1659,7 +1659,7 @@ async fn engawa_cellar_shrinks_to_inset_offline() {
 
     let inset = house.frame.rect_at(0, 0).expect("ground extent");
     let cellar_floor_y = house.frame.floor_y(CELLAR_FLOOR);
-    let at = |p: Point2D, y: i32| editor.try_get_block(Point3D::new(p.x, y, p.y));
+    let at = |p: Point2D, y: i32| editor.try_get_block(LocalPoint3D::new(p.x, y, p.y));
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     let air_at = |p: Point2D| at(p, cellar_floor_y).as_ref().map_or(false, is_air);
 
@@ -1735,7 +1735,7 @@ async fn desert_dome_built_on_square_rect_offline() {
     let roof_y = house.frame.roof_y(0);
     let deck_y = roof_y - 2;
 
-    let at = |p: Point2D, y: i32| editor.try_get_block(Point3D::new(p.x, y, p.y));
+    let at = |p: Point2D, y: i32| editor.try_get_block(LocalPoint3D::new(p.x, y, p.y));
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     let is_prismarine = |b: &Block| b.id.as_str().contains("dark_prismarine");
 
@@ -1831,7 +1831,7 @@ async fn cellar_built_under_manor_offline() {
     // stone slab below.
     let probe = house.cellar_stair.as_ref().expect("cellar must have a stair")[0];
 
-    let at = |y: i32| editor.try_get_block(Point3D::new(probe.x, y, probe.y));
+    let at = |y: i32| editor.try_get_block(LocalPoint3D::new(probe.x, y, probe.y));
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     let floor_block = at(floor_y);
     let slab_block = at(slab_y);
@@ -2396,7 +2396,7 @@ async fn build_jetty_manors_halls_live() {
         let sz = (house.footprint.bounds().min().y + house.footprint.bounds().max().y) / 2;
         let sy = house.frame.floor_y(0) + 1;
         let sign = sign_block(&format!("{:?} s{}", size_class, seed), tag);
-        ctx.editor.place_block_forced(&sign, Point3D::new(sx, sy, sz)).await;
+        ctx.editor.place_block_forced(&sign, LocalPoint3D::new(sx, sy, sz)).await;
 
         println!(
             "seed {:>2} {:?}: rects={}, floors={}, {}",
@@ -2506,7 +2506,7 @@ async fn build_engawa_houses_live() {
         let sz = (house.footprint.bounds().min().y + house.footprint.bounds().max().y) / 2;
         let sy = house.frame.floor_y(0) + 1;
         let sign = sign_block(&format!("{:?} s{}", size_class, seed), tag);
-        ctx.editor.place_block_forced(&sign, Point3D::new(sx, sy, sz)).await;
+        ctx.editor.place_block_forced(&sign, LocalPoint3D::new(sx, sy, sz)).await;
 
         println!(
             "seed {:>2} {:?}: rects={}, floors={}, {}",
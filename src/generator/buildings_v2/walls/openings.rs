@@ -7,7 +7,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialPlacer, MaterialRole, Placer};
-use crate::geometry::{Cardinal, Point2D, Point3D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::minecraft::{string_to_block, Block, BlockForm};
 use crate::noise::RNG;
 
@@ -391,7 +391,7 @@ pub async fn place_openings(
                         // placement would skip an existing solid.
                         let out = cell + Point2D::from(seg.facing);
                         for h in 0..opening.height as i32 {
-                            editor.place_block_forced(&"air".into(), Point3D::new(out.x, y + h, out.y)).await;
+                            editor.place_block_forced(&"air".into(), LocalPoint3D::new(out.x, y + h, out.y)).await;
                         }
 
                         // Curtain banner: hung one block out on the *exterior*
@@ -404,7 +404,7 @@ pub async fn place_openings(
                             let banner = format!("minecraft:{color}_wall_banner[facing={banner_facing}]");
                             editor.place_block_forced(
                                 &string_to_block(&banner).expect("curtain banner block"),
-                                Point3D::new(outside.x, y + 1, outside.y),
+                                LocalPoint3D::new(outside.x, y + 1, outside.y),
                             ).await;
                         }
                     }
@@ -449,7 +449,7 @@ pub async fn place_openings(
                                 WindowFill::Glass => {
                                     editor.place_block_forced(
                                         &Block::from_id("minecraft:glass_pane".into()),
-                                        pos,
+                                        LocalPoint3D(pos),
                                     ).await;
                                 }
                                 WindowFill::Trapdoor => {
@@ -475,7 +475,7 @@ pub async fn place_openings(
                                 WindowFill::Open => {
                                     editor.place_block_forced(
                                         &"air".into(),
-                                        pos,
+                                        LocalPoint3D(pos),
                                     ).await;
                                 }
                             }
@@ -1,4 +1,4 @@
-use crate::geometry::{Cardinal, Point2D, Point3D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D};
 use crate::generator::buildings_v2::footprint::{Footprint, Plot, SizeClass, generate_footprint};
 use crate::generator::buildings_v2::footprint::merge::outline_from_rects;
 use crate::generator::buildings_v2::frame::{Frame, generate_frame};
@@ -579,7 +579,7 @@ async fn visualize_timber_patterns_offline() {
             let y = seg.base_y + ry as i32;
             let mut row = String::new();
             for cell in &cells {
-                let b = editor.try_get_block(Point3D::new(cell.x, y, cell.y));
+                let b = editor.try_get_block(LocalPoint3D::new(cell.x, y, cell.y));
                 row.push(classify(b.as_ref()));
             }
             println!("  y{} |{}|", ry, row);
@@ -587,7 +587,7 @@ async fn visualize_timber_patterns_offline() {
         // Floor beam (y = base_y - 1) and ceiling beam (y = base_y + height).
         let mut beam = String::new();
         for cell in &cells {
-            let b = editor.try_get_block(Point3D::new(cell.x, seg.base_y - 1, cell.y));
+            let b = editor.try_get_block(LocalPoint3D::new(cell.x, seg.base_y - 1, cell.y));
             beam.push(classify(b.as_ref()));
         }
         println!("  y-1|{}|  (floor beam)", beam);
@@ -661,7 +661,7 @@ async fn debug_single_manor_segments() {
 
         for cell in &cells {
             for y in (seg.base_y - 1)..=(seg.base_y + seg.height as i32) {
-                editor.place_block(&block, Point3D::new(cell.x, y, cell.y)).await;
+                editor.place_block(&block, LocalPoint3D::new(cell.x, y, cell.y)).await;
             }
         }
     }
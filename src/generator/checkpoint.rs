@@ -0,0 +1,75 @@
+//! Checkpoints of a [`generate_town`](super::settlement::generate_town) run.
+//! A full town takes a long time, so a run started with
+//! [`generate_town_phases`](super::settlement::generate_town_phases) and a
+//! checkpoint path saves its state after every phase, and a run that dies
+//! later is finished with [`resume_town`](super::settlement::resume_town).
+
+use std::{fs::File, io::{BufReader, BufWriter, Write}, path::Path};
+
+use anyhow::Context;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{editor::World, generator::{buildings_v2::Culture, profile::SettlementProfile, settlement::TownState}, noise::{Seed, RNG}};
+
+/// A town run saved at a phase boundary: the seed, culture and profile it
+/// started with, the generator state of its world (parcels, districts, claims,
+/// heightmaps, buildings, gates, towers, structure anchors), the main RNG, and
+/// the phases that ran with what they hand the later ones. Blocks aren't
+/// saved; they're in the world by the time a checkpoint is written.
+#[derive(Deserialize)]
+pub struct TownCheckpoint {
+    pub seed: Seed,
+    pub culture: Culture,
+    /// Checkpoints from before profiles resume with the default one.
+    #[serde(default)]
+    pub profile: SettlementProfile,
+    pub world: World,
+    pub rng: RNG,
+    pub state: TownState,
+}
+
+/// A [`TownCheckpoint`] borrowed from a run in progress.
+#[derive(Serialize)]
+struct CheckpointRef<'a> {
+    seed: Seed,
+    culture: Culture,
+    profile: &'a SettlementProfile,
+    world: &'a World,
+    rng: &'a RNG,
+    state: &'a TownState,
+}
+
+impl TownCheckpoint {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).with_context(|| format!("Failed to open checkpoint {}", path.display()))?;
+        serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("{} is not a town checkpoint", path.display()))
+    }
+
+    /// Writes a run's state to `path`, by way of a temporary file so a crash
+    /// mid-write leaves the previous checkpoint intact.
+    pub fn save(
+        path: impl AsRef<Path>,
+        seed: Seed,
+        culture: Culture,
+        profile: &SettlementProfile,
+        world: &World,
+        rng: &RNG,
+        state: &TownState,
+    ) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let partial = path.with_extension("partial");
+        let mut file = BufWriter::new(
+            File::create(&partial).with_context(|| format!("Failed to create checkpoint {}", partial.display()))?,
+        );
+        serde_json::to_writer(&mut file, &CheckpointRef { seed, culture, profile, world, rng, state })?;
+        file.flush()?;
+        drop(file);
+        std::fs::rename(&partial, path)?;
+        Ok(())
+    }
+}
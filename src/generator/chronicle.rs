@@ -1,9 +1,9 @@
 use anyhow::Ok;
-use log::{error, info};
+use log::{error, info, warn};
 use schemars::JsonSchema;
 use serde_derive::Serialize;
 
-use crate::{ai::try_ai_json, editor::Editor};
+use crate::{ai::{ai_json, configured_provider, AiLog, CacheMode, LlmProvider, TemplateProvider, JSON_ATTEMPTS}, editor::Editor, generator::chronicle_copies::{place_copies, BookHolder, WrittenBook}};
 
 /// A single narratable place in the town — a road, manor, trade, park, gate, etc.
 /// This is a view-model: a flat, stringly-typed digest assembled from the typed
@@ -45,6 +45,40 @@ pub struct DossierDistrict {
     pub quarter: String,
 }
 
+/// Another settlement in the same build area, as seen from the town the guide is
+/// about. Only set when a run builds several towns (see `SettlementProfile::towns`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Neighbour {
+    pub name: String,
+    /// English gloss of its name. May be empty.
+    pub subtitle: String,
+    /// Its culture word ("medieval"/"desert"/"japanese").
+    pub culture: String,
+    /// Compass word from this town to it ("east").
+    pub direction: String,
+    /// Blocks between the two town centres.
+    pub distance: i32,
+    /// What it is to this town — see [`neighbour_relation`].
+    pub relation: String,
+    /// Whether a highway runs between the two towns' gates.
+    pub highway: bool,
+}
+
+/// What a neighbour is to a town. Towns of one culture are kin: a neighbour with
+/// twice the town's population is its mother town, one with half its daughter,
+/// otherwise a sister town. Towns of different cultures trade along a highway
+/// when there is one and are strangers without.
+pub fn neighbour_relation(same_culture: bool, population: usize, their_population: usize, highway: bool) -> String {
+    match (same_culture, highway) {
+        (true, _) if their_population >= population * 2 && population > 0 => "mother town",
+        (true, _) if population >= their_population * 2 && their_population > 0 => "daughter town",
+        (true, _) => "sister town",
+        (false, true) => "trading partner",
+        (false, false) => "foreign neighbour",
+    }
+    .to_string()
+}
+
 /// Everything the chronicle needs to write a guide to the town. Scalars describe
 /// what the place *is*; `landmarks` is what's *in* it. Assembled once at the end
 /// of `generate_town`, where every source is still in scope, and handed to
@@ -81,6 +115,8 @@ pub struct CityDossier {
     /// chronicle walks these one at a time; empty for the minimal pipeline.
     pub districts: Vec<DossierDistrict>,
     pub landmarks: Vec<Landmark>,
+    /// The other towns of the build area; empty for a lone town.
+    pub neighbours: Vec<Neighbour>,
 }
 
 /// House-count → size word for prose.
@@ -229,6 +265,20 @@ pub fn build_instruction(d: &CityDossier) -> String {
         .collect();
     push_block(&mut s, "AROUND THE EDGE", &edge);
 
+    // ── The other towns of the region, and how this one stands to them ──
+    if !d.neighbours.is_empty() {
+        s.push_str("NEIGHBOURS\n");
+        for n in &d.neighbours {
+            let name = if n.subtitle.is_empty() { n.name.clone() } else { format!("{} — \"{}\"", n.name, n.subtitle) };
+            let road = if n.highway { ", reached by the highway from the gates" } else { "" };
+            s.push_str(&format!(
+                "  {name}, a {} town about {} blocks to the {} — {}'s {}{road}\n",
+                n.culture, n.distance, n.direction, d.name, n.relation,
+            ));
+        }
+        s.push('\n');
+    }
+
     // ── Shape + grounding ──
     // The opener is fixed in spirit: every guide must begin "Welcome to {name}, …"
     // and, in the same breath, say what makes the place distinctive.
@@ -240,6 +290,16 @@ pub fn build_instruction(d: &CityDossier) -> String {
          it to a sentence or two; let it set the tone before the guide proper.\n\n",
         name = d.name,
     ));
+    if !d.neighbours.is_empty() {
+        s.push_str(
+            "Before the closing word, give the NEIGHBOURS a short section of their own: where \
+             each town lies and how this one regards it, in keeping with the relationship listed \
+             — the pull of a mother town on her daughters, the rivalry of sisters, the traffic of \
+             a trading partner, the wariness toward a foreign neighbour. Tell a traveller which \
+             highway leads where. Those towns are real places and may be named; say nothing of \
+             what lies inside them.\n\n",
+        );
+    }
     s.push_str(
         "Then organise the rest as a handful of SHORT titled sections, each opening with a brief \
          bold heading and then a few flowing lines. Follow the welcome with an at-a-glance sense \
@@ -332,17 +392,171 @@ enum TextColors {
     Black,
 }
 
-#[derive(Debug, serde_derive::Deserialize, JsonSchema)]
+impl Text {
+    fn plain(text: impl Into<String>) -> Self {
+        Self { text: text.into(), color: None, bold: None, italic: None, underlined: None, strikethrough: None, obfuscated: None }
+    }
+
+    /// A bold page heading on its own line.
+    fn heading(text: &str) -> Self {
+        Self { bold: Some(true), ..Self::plain(format!("{text}\n")) }
+    }
+}
+
+#[derive(Debug, serde_derive::Deserialize, Serialize, JsonSchema)]
 struct Book {
     title: String,
     author : String,
     pages : Vec<Vec<Text>>,
 }
 
-/// Run the instruction through the LLM and return a formatted [`Book`] — the
+/// Characters of text a template page holds, heading included — the budget
+/// [`write_book`] holds the model to.
+const PAGE_CHARS: usize = 120;
+
+/// Pages being filled a sentence at a time under a section heading. A section
+/// that outgrows its page carries on onto the next under "<heading> (cont.)".
+#[derive(Default)]
+struct Pages {
+    pages: Vec<Vec<Text>>,
+    heading: String,
+    used: usize,
+}
+
+impl Pages {
+    fn section(&mut self, heading: &str) {
+        self.heading = heading.to_string();
+        self.page(heading.to_string());
+    }
+
+    fn page(&mut self, heading: String) {
+        self.used = heading.chars().count() + 1;
+        self.pages.push(vec![Text::heading(&heading)]);
+    }
+
+    fn say(&mut self, sentence: String) {
+        let text = format!("{sentence} ");
+        let len = text.chars().count();
+        let has_body = self.pages.last().is_some_and(|page| page.len() > 1);
+        if self.pages.is_empty() || (has_body && self.used + len > PAGE_CHARS) {
+            let heading = format!("{} (cont.)", self.heading);
+            self.page(heading);
+        }
+        self.used += len;
+        self.pages.last_mut().expect("a page was just started").push(Text::plain(text));
+    }
+}
+
+/// Upper-cases the first letter, for a sentence that opens on a name.
+fn sentence_case(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+}
+
+/// One landmark as a guide sentence, its name verbatim so it becomes a link.
+fn landmark_sentence(m: &Landmark) -> String {
+    let by = m.near.first().map_or_else(
+        || if m.quarter.starts_with("on ") { m.quarter.clone() } else { format!("in the {} quarter", m.quarter) },
+        |road| format!("by {road}"),
+    );
+    let sentence = match m.kind.as_str() {
+        "road" => format!("{} runs through here.", m.name),
+        "industry" => format!("{} works {by}.", m.name),
+        "manor" if !m.notes.is_empty() => format!("{} keeps house {by}, under {}.", m.name, m.notes.join(", ")),
+        "manor" => format!("{} keeps house {by}.", m.name),
+        "park" => format!("{} is a fine place to rest, {by}.", m.name),
+        "gate" => format!("{} opens {by}.", m.name),
+        _ => format!("{} stands {by}.", m.name),
+    };
+    sentence_case(&sentence)
+}
+
+/// A guidebook written straight from the dossier, for a run with no model or
+/// whose model failed: the town, its colours and trade, a section per district
+/// with its landmarks, the gates and the neighbouring towns. Plain and short, but
+/// every landmark is named the way [`link_components`] links it.
+fn template_book(d: &CityDossier) -> Book {
+    let mut pages = Pages::default();
+
+    pages.section(&d.name);
+    if !d.subtitle.is_empty() {
+        pages.say(format!("\"{}\".", d.subtitle));
+    }
+    let culture = if d.culture.is_empty() { String::new() } else { format!("{} ", d.culture) };
+    let among = if d.biomes.is_empty() { String::new() } else { format!(" among the {}", join_natural(&d.biomes)) };
+    pages.say(format!("{} is a {culture}{}{among}.", d.name, d.size));
+    if d.walled {
+        pages.say("A wall rings it; you enter by its gates.".to_string());
+    }
+    if d.population > 0 {
+        pages.say(format!("Some {} souls live here.", d.population));
+    }
+
+    if !d.town_colours.is_empty() || !d.civic_blazon.is_empty() {
+        pages.section("Colours");
+        if !d.town_colours.is_empty() {
+            pages.say(format!("The town's colours are {}.", join_natural(&d.town_colours)));
+        }
+        if !d.civic_blazon.is_empty() {
+            pages.say(format!("Its banner shows {}.", d.civic_blazon));
+        }
+    }
+
+    if !d.harvests.is_empty() || !d.produces.is_empty() {
+        pages.section("Trade");
+        if !d.harvests.is_empty() {
+            pages.say(format!("The land around gives {}.", join_natural(&d.harvests)));
+        }
+        if !d.produces.is_empty() {
+            pages.say(format!("Its workshops make {}.", join_natural(&d.produces)));
+        }
+    }
+
+    for district in &d.districts {
+        pages.section(&district.name);
+        let quarter = &district.quarter;
+        pages.say(if quarter.starts_with("on ") {
+            format!("This district lies {quarter}.")
+        } else {
+            format!("This is the {quarter} quarter.")
+        });
+        for m in d.landmarks.iter().filter(|m| m.district == district.name) {
+            pages.say(landmark_sentence(m));
+        }
+    }
+
+    let edge: Vec<&Landmark> = d.landmarks.iter().filter(|m| m.district.is_empty()).collect();
+    if !edge.is_empty() {
+        pages.section("Around the Edge");
+        for m in edge {
+            pages.say(landmark_sentence(m));
+        }
+    }
+
+    if !d.neighbours.is_empty() {
+        pages.section("Neighbours");
+        for n in &d.neighbours {
+            pages.say(format!(
+                "{}, a {} town {} blocks {}, is {}'s {}.",
+                n.name, n.culture, n.distance, n.direction, d.name, n.relation,
+            ));
+            if n.highway {
+                pages.say("A highway runs there from the gates.".to_string());
+            }
+        }
+    }
+
+    Book {
+        title: d.name.chars().take(32).collect(),
+        author: "The Town Clerk".to_string(),
+        pages: pages.pages,
+    }
+}
+
+/// Run the instruction through `provider` and return a formatted [`Book`] — the
 /// network half, with no Minecraft I/O. Split out so the live model can be
 /// exercised in a test without a running server (see `tests::live_llm_*`).
-async fn write_book(instruction : &str) -> anyhow::Result<Book> {
+async fn write_book(provider: &dyn LlmProvider, instruction : &str, log: &mut AiLog) -> anyhow::Result<Book> {
     let user = &format!(r#"{}.
 
             Formatting rules — follow exactly:
@@ -358,8 +572,7 @@ async fn write_book(instruction : &str) -> anyhow::Result<Book> {
 
             Keep the title and author under 32 characters each.
             Do NOT use § section codes or unicode escape codes — format only using the JSON elements."#, instruction);
-    try_ai_json::<Book>(user).await
-        .ok_or_else(|| anyhow::anyhow!("Failed to get or parse AI response for book"))
+    ai_json::<Book>(provider, user, JSON_ATTEMPTS, log).await
 }
 
 /// A landmark name paired with the world coordinate a `/tp` link should send the
@@ -513,13 +726,19 @@ fn linkify(comp: &serde_json::Value, text: &str, l: &LandmarkLink) -> serde_json
     v
 }
 
+/// Writes the book, leaves a copy in each of `holders` and gives it to the
+/// player, returning how many copies went into the world.
+#[allow(clippy::too_many_arguments)]
 pub async fn give_player_book(
     editor: &Editor,
+    provider: &dyn LlmProvider,
     instruction: &str,
     links: &[LandmarkLink],
     town_name: &str,
-) -> anyhow::Result<()> {
-    let book: Book = write_book(instruction).await?;
+    holders: &[BookHolder],
+    log: &mut AiLog,
+) -> anyhow::Result<usize> {
+    let book: Book = write_book(provider, instruction, log).await?;
 
     // First mention of each landmark across the whole book becomes the link.
     let mut linked: std::collections::HashSet<String> = std::collections::HashSet::new();
@@ -538,16 +757,32 @@ pub async fn give_player_book(
             serde_json::json!({ "text": "", "extra": extra }).to_string()
         }).collect();
     let page_refs = pages.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    // The copies go down first, so a player who can't be given the book (no
+    // one online) still finds it in town.
+    let written = WrittenBook::new(&book.title, &book.author, pages.clone());
+    let copies = place_copies(editor, &written, holders).await;
     if let Err(e) = editor.give_player_book(&page_refs, &book.title, &book.author).await {
+        // With copies in town the book has somewhere to be read; rewriting it
+        // just to retry the give would only put different copies down.
+        if copies > 0 {
+            warn!("Couldn't give the player the book ({e}); {copies} copies left in town");
+            return Ok(copies);
+        }
         error!("Error while giving player book: {:?}", e);
         return Err(e);
     }
 
-    Ok(())
+    Ok(copies)
 }
 
-pub async fn generate_chronicle(editor: &Editor, dossier : &CityDossier) -> anyhow::Result<()> {
-    let retries = 3;
+/// Writes the town's chronicle, gives it to the player and leaves a copy in
+/// each of `holders`, recording every model call it made in `log`. Returns the
+/// copies left in the world.
+pub async fn generate_chronicle(editor: &Editor, dossier : &CityDossier, holders: &[BookHolder], log: &mut AiLog) -> anyhow::Result<usize> {
+    // A pinned cache gives the same answer every try, and a run reproducing
+    // another must get that run's book or none at all — never the template.
+    let pinned = CacheMode::from_env() == CacheMode::Pinned;
+    let retries = if pinned { 1 } else { 3 };
     let instruction = build_instruction(dossier);
     // Landmarks with a known world position become clickable `/tp` links.
     let links: Vec<LandmarkLink> = dossier
@@ -556,13 +791,21 @@ pub async fn generate_chronicle(editor: &Editor, dossier : &CityDossier) -> anyh
         .filter_map(|m| m.tp.map(|pos| LandmarkLink { name: m.name.clone(), pos }))
         .collect();
 
+    // With no model configured, or none that answers, the town still gets the
+    // book written from its dossier.
+    let template = TemplateProvider::new(serde_json::to_string(&template_book(dossier))?);
+    let Some(provider) = configured_provider() else {
+        info!("No LLM configured; writing the chronicle from its template");
+        return give_player_book(editor, &template, &instruction, &links, &dossier.name, holders, log).await;
+    };
+
     for _ in 0..retries {
-        let result = give_player_book(editor, &instruction, &links, &dossier.name).await;
+        let result = give_player_book(editor, provider.as_ref(), &instruction, &links, &dossier.name, holders, log).await;
 
         match result {
-            Result::Ok(()) => {
-                info!("Chronicle generated successfully.");
-                return anyhow::Ok(());
+            Result::Ok(copies) => {
+                info!("Chronicle generated successfully, with {copies} copies in town.");
+                return anyhow::Ok(copies);
             },
             Err(e) => {
                 error!("Error generating chronicle: {:?}, retrying", e);
@@ -570,7 +813,11 @@ pub async fn generate_chronicle(editor: &Editor, dossier : &CityDossier) -> anyh
         }
     }
 
-    Err(anyhow::anyhow!("Failed to generate chronicle after {} retries", retries))
+    if pinned {
+        anyhow::bail!("The pinned LLM cache has no usable chronicle for {}", dossier.name);
+    }
+    error!("{} failed to write the chronicle after {} tries; writing it from its template", provider.model(), retries);
+    give_player_book(editor, &template, &instruction, &links, &dossier.name, holders, log).await
 }
 
 #[cfg(test)]
@@ -602,6 +849,7 @@ mod tests {
                 Landmark { kind: "park".into(), name: "the flower garden".into(), quarter: "on the northern edge".into(), near: vec![], notes: vec![], district: "Garden End".into(), tp: Some((120, 64, 250)) },
                 Landmark { kind: "gate".into(), name: "the north gate".into(), quarter: "on the northern edge".into(), near: vec![], notes: vec![], district: String::new(), tp: Some((120, 64, 260)) },
             ],
+            neighbours: vec![],
         }
     }
 
@@ -661,7 +909,8 @@ mod tests {
         dotenv::dotenv().ok();
         let instruction = build_instruction(&sample());
         println!("\n===== INSTRUCTION =====\n{instruction}\n");
-        let book = write_book(&instruction).await.expect("LLM returned a parseable book");
+        let provider = configured_provider().expect("an LLM is configured");
+        let book = write_book(provider.as_ref(), &instruction, &mut AiLog::default()).await.expect("LLM returned a parseable book");
         assert!(!book.title.is_empty(), "empty title");
         assert!(!book.pages.is_empty(), "no pages");
         println!("\n===== BOOK: {} — by {} =====", book.title, book.author);
@@ -750,6 +999,7 @@ mod tests {
             produces: vec![],
             districts: vec![],
             landmarks: vec![],
+            neighbours: vec![],
         };
         let s = build_instruction(&d);
         assert!(s.contains("Name: Nowhere\n"), "no bare-name line:\n{s}");
@@ -762,5 +1012,63 @@ mod tests {
         // No district intro or edge block when there's nothing to place.
         assert!(!s.contains("named DISTRICTS"), "{s}");
         assert!(!s.contains("AROUND THE EDGE"), "{s}");
+        assert!(!s.contains("NEIGHBOURS"), "{s}");
+    }
+
+    #[test]
+    fn neighbours_are_listed_with_their_relation() {
+        let mut d = sample();
+        d.neighbours = vec![Neighbour {
+            name: "Ashford".into(),
+            subtitle: "Ash Ford".into(),
+            culture: "medieval".into(),
+            direction: "east".into(),
+            distance: 140,
+            relation: neighbour_relation(true, 142, 60, true),
+            highway: true,
+        }];
+        let s = build_instruction(&d);
+        assert!(
+            s.contains("Ashford — \"Ash Ford\", a medieval town about 140 blocks to the east — Blackbarrow's daughter town, reached by the highway"),
+            "neighbour line missing:\n{s}",
+        );
+        assert!(s.contains("give the NEIGHBOURS a short section"), "{s}");
+    }
+
+    #[test]
+    fn relation_follows_culture_size_and_roads() {
+        assert_eq!(neighbour_relation(true, 40, 100, false), "mother town");
+        assert_eq!(neighbour_relation(true, 100, 40, false), "daughter town");
+        assert_eq!(neighbour_relation(true, 100, 80, true), "sister town");
+        assert_eq!(neighbour_relation(false, 100, 80, true), "trading partner");
+        assert_eq!(neighbour_relation(false, 100, 80, false), "foreign neighbour");
+    }
+
+    #[tokio::test]
+    async fn template_book_names_every_landmark_within_its_pages() {
+        let dossier = sample();
+        let book = template_book(&dossier);
+        assert_eq!(book.title, "Blackbarrow");
+
+        let text: String = book.pages.iter().flatten().map(|t| t.text.as_str()).collect();
+        for landmark in &dossier.landmarks {
+            // Linked case-insensitively, so a name may open its sentence capitalised.
+            assert!(find_ci(&text, &landmark.name).is_some(), "{} missing:\n{text}", landmark.name);
+        }
+        assert!(text.contains("Smith Row\n") && text.contains("Around the Edge\n"), "{text}");
+        assert!(text.contains("The Blackwell Manor keeps house by the Rivergate"), "{text}");
+        for page in &book.pages {
+            let len: usize = page.iter().map(|t| t.text.chars().count()).sum();
+            assert!(len <= PAGE_CHARS + 1, "page over budget ({len}): {page:?}");
+            assert_eq!(page[0].bold, Some(true), "page should open on its heading");
+        }
+
+        // The fallback path: the template goes through the same parse as a model.
+        let provider = TemplateProvider::new(serde_json::to_string(&book).unwrap());
+        let mut log = AiLog::default();
+        let parsed = write_book(&provider, &build_instruction(&dossier), &mut log).await.unwrap();
+        assert_eq!(log.calls.len(), 1);
+        assert!(log.calls[0].errors.is_empty(), "{:?}", log.calls[0].errors);
+        assert_eq!(parsed.pages.len(), book.pages.len());
     }
 }
\ No newline at end of file
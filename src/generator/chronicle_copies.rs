@@ -0,0 +1,212 @@
+//! Copies of the town chronicle left in the world, for whoever arrives after
+//! the run: one open on a lectern at the main plaza (or, with no plaza, just
+//! inside a gate), and one in every study and library chest and on every
+//! study and library lectern the houses were furnished with.
+//!
+//! A copy is the same `written_book` item the player is given, pages and all,
+//! so its landmark names keep their `/tp` links. It's written by re-placing
+//! the block that holds it with the book added to its block entity: the chest
+//! keeps the loot it was furnished with, the book going in the first free slot.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::editor::Editor;
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D};
+use crate::minecraft::{string_to_block, Block};
+
+/// Slots in a single chest.
+const CHEST_SLOTS: i32 = 27;
+/// How far from the plaza's centre to look for a free cell for its lectern.
+const PLAZA_REACH: i32 = 6;
+
+/// A block a copy can be left in: a chest or a lectern, at its editor-local
+/// position, as it was placed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookHolder {
+    pub pos: Point3D,
+    pub block: Block,
+}
+
+impl BookHolder {
+    /// Whether a book can go in `block`: a chest or a lectern. Barrels and
+    /// the like keep their loot to themselves.
+    pub fn can_hold(block: &Block) -> bool {
+        let id = block.id.as_str();
+        id.ends_with("chest") || id.ends_with("lectern")
+    }
+}
+
+/// A finished chronicle: its pages as the text-component SNBT the book shows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrittenBook {
+    pub title: String,
+    pub author: String,
+    pub pages: Vec<String>,
+}
+
+impl WrittenBook {
+    /// Title and author are cut to the 32 characters a book allows.
+    pub fn new(title: &str, author: &str, pages: Vec<String>) -> Self {
+        Self { title: title.chars().take(32).collect(), author: author.chars().take(32).collect(), pages }
+    }
+
+    /// The book as an item stack, in `slot` when it goes in a container.
+    pub fn item_snbt(&self, slot: Option<i32>) -> String {
+        let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let slot = slot.map_or(String::new(), |slot| format!("Slot:{slot}b,"));
+        format!(
+            "{{{slot}id:\"minecraft:written_book\",count:1,components:{{\"minecraft:written_book_content\":{{title:\"{}\",author:\"{}\",pages:[{}]}}}}}}",
+            quote(&self.title),
+            quote(&self.author),
+            self.pages.join(","),
+        )
+    }
+
+    /// `block` with this book in it: open on a lectern, or in a chest's first
+    /// free slot. `None` for a full chest, one whose contents can't be read, or
+    /// any other block.
+    pub fn placed_in(&self, block: &Block) -> Option<Block> {
+        let id = block.id.as_str();
+        let mut placed = block.clone();
+        if id.ends_with("lectern") {
+            placed.state.get_or_insert_with(Default::default).insert("has_book".to_string(), "true".to_string());
+            placed.data = Some(format!("{{Book:{},Page:0}}", self.item_snbt(None)));
+            return Some(placed);
+        }
+        if !BookHolder::can_hold(block) {
+            return None;
+        }
+        let items = block.data.as_deref().unwrap_or("{Items:[]}");
+        let listed = items.strip_prefix("{Items:[")?.strip_suffix("]}")?;
+        let slot = free_slot(listed)?;
+        let separator = if listed.is_empty() { "" } else { "," };
+        placed.data = Some(format!("{{Items:[{listed}{separator}{}]}}", self.item_snbt(Some(slot))));
+        Some(placed)
+    }
+}
+
+/// The lowest chest slot the `Items` entries don't fill.
+fn free_slot(items: &str) -> Option<i32> {
+    let taken: Vec<i32> = items
+        .split("Slot:")
+        .skip(1)
+        .filter_map(|rest| rest.split('b').next()?.parse().ok())
+        .collect();
+    (0..CHEST_SLOTS).find(|slot| !taken.contains(slot))
+}
+
+/// Leaves a copy of `book` in every holder, returning how many took one.
+pub async fn place_copies(editor: &Editor, book: &WrittenBook, holders: &[BookHolder]) -> usize {
+    let mut placed = 0;
+    for holder in holders {
+        if let Some(block) = book.placed_in(&holder.block) {
+            // The holder is already there; only forcing replaces it.
+            editor.place_block_forced(&block, LocalPoint3D(holder.pos)).await;
+            placed += 1;
+        }
+    }
+    placed
+}
+
+/// A new lectern for the town's own copy: on a free cell near the centre of
+/// the main plaza, facing it, or failing that beside the road just inside the
+/// first gate, facing the road.
+pub fn civic_lectern(editor: &Editor, main_plaza: Option<Point2D>, centre: Point2D) -> Option<BookHolder> {
+    let plaza = main_plaza.and_then(|plaza| {
+        let pos = free_cell_near(editor, plaza, PLAZA_REACH)?;
+        Some((pos, plaza))
+    });
+    let gate = || {
+        editor.world().gate_locations.iter().find_map(|(gate, dir)| {
+            let opening = gate.drop_y();
+            // The passage runs along `dir` turned clockwise (see `civic_banner`);
+            // step along it towards the centre, then off to the side of the road.
+            let along: Point2D = dir.rotate_right().into();
+            let inward = if (opening + along).distance_squared(&centre) < (opening - along).distance_squared(&centre) {
+                along
+            } else {
+                Point2D::new(-along.x, -along.y)
+            };
+            let road = opening + Point2D::new(inward.x * 4, inward.y * 4);
+            let side: Point2D = (*dir).into();
+            let beside = road + Point2D::new(side.x * 3, side.y * 3);
+            let pos = free_cell_near(editor, beside, 1)?;
+            Some((pos, road))
+        })
+    };
+    let (pos, focus) = plaza.or_else(gate)?;
+    let facing = toward(pos.drop_y(), focus);
+    let block = string_to_block(&format!("minecraft:lectern[facing={}]", facing.to_string()))?;
+    Some(BookHolder { pos, block })
+}
+
+/// The nearest cell within `reach` of `around` that's open ground: air, on
+/// something solid and dry.
+fn free_cell_near(editor: &Editor, around: Point2D, reach: i32) -> Option<Point3D> {
+    let mut cells: Vec<Point2D> = (-reach..=reach)
+        .flat_map(|dx| (-reach..=reach).map(move |dz| around + Point2D::new(dx, dz)))
+        .collect();
+    cells.sort_by_key(|cell| (cell.distance_squared(&around), cell.x, cell.y));
+    cells.into_iter().find_map(|cell| {
+        let y = editor.world().get_height_at(cell)?;
+        let pos = Point3D::new(cell.x, y, cell.y);
+        let here = editor.try_get_block(LocalPoint3D(pos))?;
+        let below = editor.try_get_block(LocalPoint3D::new(cell.x, y - 1, cell.y))?;
+        (here.id.is_air() && !below.id.is_air() && !below.id.is_liquid()).then_some(pos)
+    })
+}
+
+/// The cardinal pointing from `from` to `to` along its longer axis.
+fn toward(from: Point2D, to: Point2D) -> Cardinal {
+    let (dx, dz) = (to.x - from.x, to.y - from.y);
+    if dx.abs() >= dz.abs() {
+        if dx >= 0 { Cardinal::East } else { Cardinal::West }
+    } else if dz >= 0 {
+        Cardinal::South
+    } else {
+        Cardinal::North
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> WrittenBook {
+        let page = r#"{"text":"","extra":[{"text":"High Street","click_event":{"action":"run_command","command":"/tp @s 1 64 2"}}]}"#;
+        WrittenBook::new("The \"Chronicle\" of Blackbarrow, in full", "The Town Clerk", vec![page.to_string()])
+    }
+
+    #[test]
+    fn books_go_in_the_first_free_chest_slot_beside_the_loot() {
+        let book = book();
+        let item = book.item_snbt(Some(2));
+        assert!(item.starts_with(r#"{Slot:2b,id:"minecraft:written_book",count:1,"#), "{item}");
+        assert!(item.contains(r#"title:"The \"Chronicle\" of Blackbarrow, ""#), "{item}");
+        assert!(item.contains(r#""command":"/tp @s 1 64 2""#), "links survive: {item}");
+
+        let loot = r#"{Items:[{Slot:0b,id:"minecraft:paper",Count:3b},{Slot:1b,id:"minecraft:book",Count:1b}]}"#;
+        let chest = Block::new("minecraft:chest".into(), None, Some(loot.to_string()));
+        let data = book.placed_in(&chest).unwrap().data.unwrap();
+        assert!(data.starts_with(r#"{Items:[{Slot:0b,id:"minecraft:paper",Count:3b},{Slot:1b,"#), "{data}");
+        assert!(data.contains(&book.item_snbt(Some(2))), "{data}");
+
+        let empty = Block::new("minecraft:chest".into(), None, None);
+        assert_eq!(book.placed_in(&empty).unwrap().data.unwrap(), format!("{{Items:[{}]}}", book.item_snbt(Some(0))));
+
+        let full = (0..CHEST_SLOTS).map(|slot| format!("{{Slot:{slot}b,id:\"minecraft:stick\",Count:1b}}")).collect::<Vec<_>>();
+        let full = Block::new("minecraft:chest".into(), None, Some(format!("{{Items:[{}]}}", full.join(","))));
+        assert_eq!(book.placed_in(&full), None);
+        assert_eq!(book.placed_in(&Block::new("minecraft:barrel".into(), None, None)), None);
+    }
+
+    #[test]
+    fn lecterns_open_the_book() {
+        let lectern = string_to_block("minecraft:lectern[facing=south]").unwrap();
+        let placed = book().placed_in(&lectern).unwrap();
+        let state = placed.state.unwrap();
+        assert_eq!(state["has_book"], "true");
+        assert_eq!(state["facing"], "south");
+        assert!(placed.data.unwrap().starts_with(r#"{Book:{id:"minecraft:written_book""#));
+    }
+}
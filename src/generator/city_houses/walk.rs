@@ -3,7 +3,7 @@ use crate::generator::buildings_v2::footprint::{Footprint, Plot, SizeClass};
 use crate::generator::buildings_v2::walls::{DoorStyle, OpeningKind, WallSegments, segment_cells};
 use crate::generator::buildings_v2::{BuildCtx, BuildingContext, Culture, HouseOutput, build_house};
 use crate::generator::buildings_v2::roof::RoofStyle;
-use crate::geometry::{Cardinal, Point2D, Rect2D};
+use crate::geometry::{Cardinal, Point2D, Rect2D, LocalPoint3D};
 use crate::minecraft::Block;
 use crate::noise::RNG;
 
@@ -131,7 +131,7 @@ fn road_floor_level(chain_slice: &[Point2D], outward: Cardinal, editor: &Editor)
 fn road_surface_top(editor: &Editor, cell: Point2D) -> Option<i32> {
     let ref_y = editor.world().get_ocean_floor_height_at(cell)?;
     for y in (ref_y - 5..=ref_y + 5).rev() {
-        if let Some(block) = editor.try_get_block(cell.add_y(y)) {
+        if let Some(block) = editor.try_get_block(LocalPoint3D(cell.add_y(y))) {
             if !block.id.is_air() {
                 return Some(y + 1);
             }
@@ -177,8 +177,8 @@ async fn clear_door_thresholds(editor: &Editor, wall_segs: &WallSegments) {
                 let c = door_cell + Point2D::new(outward.x * step, outward.y * step);
                 for y in sill..=sill + 1 {
                     let p = c.add_y(y);
-                    if editor.try_get_block(p).is_some_and(|b| is_road_slab(&b)) {
-                        editor.place_block_forced(&air, p).await;
+                    if editor.try_get_block(LocalPoint3D(p)).is_some_and(|b| is_road_slab(&b)) {
+                        editor.place_block_forced(&air, LocalPoint3D(p)).await;
                     }
                 }
             }
@@ -6,17 +6,20 @@
 //! town's colour identity reads from the approach. Returns the English blazon so
 //! the chronicle can name the arms.
 //!
-//! Placement uses geometry recorded at build time ([`World::tower_bases`],
-//! [`World::gate_locations`]) rather than reading blocks back — the editor block
-//! cache is keyed in a different space than the pipeline's local coords, so a
-//! freshly-placed wall reads as terrain (see `Editor::get_cached_block`).
+//! The recorded tower bases and gates ([`World::tower_bases`],
+//! [`World::gate_locations`]) say where to look; the wall itself is read back
+//! through the editor, so each banner hangs on a face that is really there.
 
 use crate::editor::Editor;
 use crate::generator::heraldry::pick_family_banner;
-use crate::geometry::{Cardinal, Point2D, Point3D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D};
 use crate::minecraft::{string_to_block, Color};
 use crate::noise::RNG;
 
+/// How far out from a tower's centre its outer wall is looked for; the 5×5
+/// base puts it two cells out.
+const TOWER_REACH: i32 = 4;
+
 /// Mint the civic banner and fly it on the towers + gates. `town` is the two town
 /// colours (field, then charge); `centre` is the urban centroid (build-area
 /// local), used to face each tower banner outward. Returns the blazon (e.g. "a red
@@ -31,13 +34,19 @@ pub async fn place_civic_banners(
     let banner = pick_family_banner(town[0], town[1], rng)?;
     let base: String = town[0].into();
 
-    // Towers: one banner on the outward (away-from-centre) face of each tower.
+    // Towers: one banner on the outward (away-from-centre) face of each tower,
+    // in the open cell past the outermost wall block.
     for (tower, support_y) in editor.world().tower_bases.clone() {
         let out = cardinal_away(tower, centre);
         let off: Point2D = out.into();
-        // 5×5 base: the outer wall block sits two cells out, the banner one beyond.
-        let air = tower + scale(off, 3);
-        hang_banner(editor, Point3D::new(air.x, support_y, air.y), out, &base, &banner.data).await;
+        let at = |k: i32| {
+            let cell = tower + scale(off, k);
+            Point3D::new(cell.x, support_y, cell.y)
+        };
+        match (0..TOWER_REACH).rev().find(|&k| is_solid(editor, at(k))) {
+            Some(k) if !is_solid(editor, at(k + 1)) => hang_banner(editor, at(k + 1), out, &base, &banner.data).await,
+            _ => log::debug!("No outer face on the tower at {:?}; no banner", tower),
+        }
     }
 
     // Gates: a banner on the jamb either side of the opening, on BOTH faces of the
@@ -59,7 +68,10 @@ pub async fn place_civic_banners(
                 // of letting a block update re-orient it onto the passage side wall.
                 let jamb = opening + scale(side, k);
                 let air = jamb + off;
-                hang_banner(editor, Point3D::new(air.x, y, air.y), facing, &base, &banner.data).await;
+                let (jamb, air) = (Point3D::new(jamb.x, y, jamb.y), Point3D::new(air.x, y, air.y));
+                if is_solid(editor, jamb) && !is_solid(editor, air) {
+                    hang_banner(editor, air, facing, &base, &banner.data).await;
+                }
             }
         }
     }
@@ -67,6 +79,11 @@ pub async fn place_civic_banners(
     Some(banner.blazon)
 }
 
+/// Whether the block at `p`, as placed so far, could hold a wall banner.
+fn is_solid(editor: &Editor, p: Point3D) -> bool {
+    editor.try_get_block(LocalPoint3D(p)).is_some_and(|block| !block.id.is_air() && !block.id.is_liquid())
+}
+
 /// Place one `<base>_wall_banner` carrying the civic pattern `data` at `air`,
 /// facing `out` (the front of the banner points away from the wall behind it).
 /// Placed with block updates OFF: a deep gate passage has no solid block directly
@@ -83,7 +100,7 @@ async fn hang_banner(
     let banner = format!("minecraft:{base_color}_wall_banner[facing={}]", out.to_string());
     let Some(mut block) = string_to_block(&banner) else { return };
     block.data = Some(data.to_string());
-    editor.place_block_no_update(&block, air).await;
+    editor.place_block_no_update(&block, LocalPoint3D(air)).await;
 }
 
 /// The cardinal pointing from the town `centre` out to `p` — the dominant axis, so
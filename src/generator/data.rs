@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use anyhow::bail;
+
 use crate::{data::{Loadable, load_yaml}, generator::{districts::{PaintPalette, PaintPaletteId, PaintPalettesFile}, population::NpcData, resource_chain::ResourceRegistry, buildings::{roofs::{RoofComponent, RoofSet, RoofSetId}, walls::{WallComponent, WallSet, WallSetId}, BuildingSet, BuildingSetID}, buildings_v2::furnish::data::FurnitureData, materials::{Material, MaterialId, Palette, PaletteId}, nbts::{Structure, StructureType}}};
 
 #[derive(Debug)]
@@ -47,6 +49,37 @@ impl LoadedData {
             npc_data,
         })
     }
+
+    /// Fails if a data subsystem came back empty. Individual file
+    /// deserialization failures are swallowed as debug logs in
+    /// `Loadable::load_all_in`, so an empty subsystem is the signal that its
+    /// files stopped parsing.
+    pub fn check(&self) -> anyhow::Result<()> {
+        let subsystems = [
+            ("palettes", self.palettes.is_empty()),
+            ("materials", self.materials.is_empty()),
+            ("structures", self.structures.is_empty()),
+            ("wall components", self.wall_components.is_empty()),
+            ("wall sets", self.wall_sets.is_empty()),
+            ("roof components", self.roof_components.is_empty()),
+            ("roof sets", self.roof_sets.is_empty()),
+            ("building sets", self.building_sets.is_empty()),
+            ("furniture items", self.furniture.items.is_empty()),
+            ("room furniture lists", self.furniture.rooms.is_empty()),
+            ("paint palettes", self.paint_palettes.is_empty()),
+            ("production painters", self.resource_registry.production_painters.is_empty()),
+            ("guard looks", self.npc_data.guards.looks.is_empty()),
+            ("default staffing looks", self.npc_data.default_staffing.looks.is_empty()),
+        ];
+        if let Some((name, _)) = subsystems.iter().find(|(_, empty)| *empty) {
+            bail!("no {name} loaded");
+        }
+        // Inline staffing: resource buildings declare their own crew on the JSON.
+        if !self.structures.values().any(|s| s.staffing.is_some()) {
+            bail!("no structures declare staffing");
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -54,28 +87,11 @@ mod tests {
     use super::*;
 
     /// Sanity check that every data subsystem loads and has at least one
-    /// non-empty entry. Individual file deserialization failures are
-    /// swallowed as debug logs in `Loadable::load_all_in`, so this test is
-    /// the canonical signal that the overall data tree is healthy.
+    /// non-empty entry; this is the canonical signal that the overall data
+    /// tree is healthy.
     #[test]
     fn test_data_loads_cleanly() {
         let data = LoadedData::load().expect("LoadedData::load() failed");
-        assert!(!data.palettes.is_empty(), "no palettes loaded");
-        assert!(!data.materials.is_empty(), "no materials loaded");
-        assert!(!data.structures.is_empty(), "no structures loaded");
-        assert!(!data.wall_components.is_empty(), "no wall components loaded");
-        assert!(!data.wall_sets.is_empty(), "no wall sets loaded");
-        assert!(!data.roof_components.is_empty(), "no roof components loaded");
-        assert!(!data.roof_sets.is_empty(), "no roof sets loaded");
-        assert!(!data.building_sets.is_empty(), "no building sets loaded");
-        assert!(!data.furniture.items.is_empty(), "no furniture items loaded");
-        assert!(!data.furniture.rooms.is_empty(), "no room furniture lists loaded");
-        assert!(!data.paint_palettes.is_empty(), "no paint palettes loaded");
-        assert!(!data.resource_registry.production_painters.is_empty(), "no production painters loaded");
-        assert!(!data.npc_data.guards.looks.is_empty(), "no guard looks loaded");
-        assert!(!data.npc_data.default_staffing.looks.is_empty(), "no default staffing looks loaded");
-        // Inline staffing: resource buildings declare their own crew on the JSON.
-        let staffed = data.structures.values().filter(|s| s.staffing.is_some()).count();
-        assert!(staffed > 0, "no structures declare staffing");
+        data.check().unwrap();
     }
 }
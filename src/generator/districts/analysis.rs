@@ -1,7 +1,8 @@
 
 
+use serde_derive::{Deserialize, Serialize};
 use crate::editor::Editor;
-use crate::geometry::CARDINALS_2D;
+use crate::geometry::{LocalPoint3D, CARDINALS_2D};
 use crate::geometry::DOWN;
 use crate::minecraft::Biome;
 use crate::minecraft::BlockID;
@@ -10,7 +11,7 @@ use std::collections::HashMap;
 use super::data::HasParcelData;
 use super::ParcelData;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParcelAnalysis {
     count : usize,
     roughness: f32,
@@ -95,7 +96,7 @@ pub async fn analyze_parcel<'a, TID : 'a>(area: &ParcelData<TID>, editor: &Edito
 
     for point in area.points() {
         let Some(biome) = editor.world().get_surface_biome_at(point.drop_y()) else { continue; };
-        let block = editor.get_block(*point + DOWN);
+        let block = editor.get_block(LocalPoint3D(*point + DOWN));
         let is_water = block.id.is_water();
         let Some(leaf_height) = editor.world().get_motion_blocking_height_at(point.drop_y()) else { continue; };
 
@@ -17,6 +17,8 @@ use super::{
 use std::collections::{HashMap, HashSet};
 use log::info;
 
+use crate::geometry::Point2D;
+
 pub fn classify_parcels<'a>(parcels: & mut HashMap<ParcelID, Parcel>, parcel_analysis_data: &HashMap<ParcelID, ParcelAnalysis>){
     
     let mut options: Vec<ParcelID> = Vec::new(); // Placeholder for options to choose from
@@ -71,7 +73,16 @@ pub fn classify_parcels<'a>(parcels: & mut HashMap<ParcelID, Parcel>, parcel_ana
     
 }
 
-pub fn classify_districts<'a>(districts: &mut HashMap<DistrictID, District>, parcels: &mut HashMap<ParcelID, Parcel>, parcel_analysis_data: &HashMap<DistrictID, ParcelAnalysis>) {
+/// Classify districts and seed up to `town_count` cities, each a set of urban districts, best
+/// first. A later city's prime must sit at least `town_spacing` blocks from every earlier core and
+/// may not grow into an earlier city or a district bordering one, so two towns never share a wall.
+pub fn classify_districts<'a>(
+    districts: &mut HashMap<DistrictID, District>,
+    parcels: &mut HashMap<ParcelID, Parcel>,
+    parcel_analysis_data: &HashMap<DistrictID, ParcelAnalysis>,
+    town_count: usize,
+    town_spacing: i32,
+) -> Vec<Vec<DistrictID>> {
     // This function will classify districts based on their parcels
 
     let mut options: Vec<DistrictID> = Vec::new(); // Placeholder for options to choose from
@@ -119,34 +130,67 @@ pub fn classify_districts<'a>(districts: &mut HashMap<DistrictID, District>, par
     // URBAN_SIZE_MIN from qualifying neighbours, fall back to the next-best prime.
     let primes = rank_prime_urban_districts(options, parcel_analysis_data);
 
-    let mut city: Option<Vec<DistrictID>> = None;
+    // Districts of the cities committed so far plus their neighbours, and each city's core.
+    let mut towns: Vec<Vec<DistrictID>> = Vec::new();
+    let mut reserved: HashSet<DistrictID> = HashSet::new();
+    let mut cores: Vec<Point2D> = Vec::new();
     for prime in primes.iter() {
-        if let Some(grown) = try_grow_city(*prime, districts, parcel_analysis_data) {
-            info!("Prime {:?} grew a city of size {}", prime, grown.len());
-            city = Some(grown);
+        if towns.len() >= town_count {
             break;
         }
-        info!("Prime {:?} could not reach minimum city size, trying next-best prime", prime);
+        if reserved.contains(prime) {
+            continue;
+        }
+        let core = districts.get(prime).expect("District not found").average().drop_y();
+        if let Some(near) = cores.iter().find(|c| c.distance_squared(&core) < town_spacing * town_spacing) {
+            info!("Prime {:?} is within {} blocks of the core at {:?}, trying next-best prime", prime, town_spacing, near);
+            continue;
+        }
+        let Some(grown) = try_grow_city(*prime, districts, parcel_analysis_data, &reserved) else {
+            info!("Prime {:?} could not reach minimum city size, trying next-best prime", prime);
+            continue;
+        };
+        info!("Prime {:?} grew a city of size {}", prime, grown.len());
+        commit_city(&grown, districts);
+        for id in &grown {
+            reserved.insert(*id);
+            reserved.extend(districts.get(id).expect("District not found").data().parcel_adjacency.keys());
+        }
+        cores.push(core);
+        towns.push(grown);
     }
 
     // Final fallback: no prime could reach the minimum, so commit the best prime alone
     // (whatever it could anchor) rather than producing no city at all.
-    let city = city.or_else(|| {
-        primes.first().map(|p| {
-            info!("No prime reached URBAN_SIZE_MIN; committing best prime {:?} alone", p);
-            vec![*p]
-        })
-    });
+    if towns.is_empty() {
+        let Some(prime) = primes.first() else {
+            log::warn!("No prime urban candidate found");
+            return towns;
+        };
+        info!("No prime reached URBAN_SIZE_MIN; committing best prime {:?} alone", prime);
+        commit_city(&[*prime], districts);
+        towns.push(vec![*prime]);
+    }
+    if towns.len() < town_count {
+        log::warn!("Asked for {} towns, but only {} cores fit {} blocks apart", town_count, towns.len(), town_spacing);
+    }
 
-    let Some(city) = city else {
-        log::warn!("No prime urban candidate found");
-        return;
-    };
+    // classify remaining districts as rural if they are unknown
+    for parcel in districts.values_mut() {
+        if parcel.data.parcel_type == ParcelType::Unknown {
+            parcel.data.parcel_type = ParcelType::Rural;
+        }
+    }
+
+    towns
+}
 
+/// Mark a grown city's districts Urban.
+fn commit_city(city: &[DistrictID], districts: &mut HashMap<DistrictID, District>) {
     // Tally the committed city's total footprint for visibility into how large the urban core ended up.
     let mut city_blocks = 0usize;
     let mut city_parcels = 0usize;
-    for id in &city {
+    for id in city {
         let sd = districts.get_mut(id).expect("District not found");
         sd.data.parcel_type = ParcelType::Urban;
         city_blocks += sd.data.points_2d.len();
@@ -160,27 +204,20 @@ pub fn classify_districts<'a>(districts: &mut HashMap<DistrictID, District>, par
         "City committed: {} districts, {} parcels, {} blocks total",
         city.len(), city_parcels, city_blocks
     );
-
-    // classify remaining districts as rural if they are unknown
-    for parcel in districts.values_mut() {
-        if parcel.data.parcel_type == ParcelType::Unknown {
-            parcel.data.parcel_type = ParcelType::Rural;
-        }
-    }
-
-
 }
 
 /// Attempt to grow a city anchored at `prime` without mutating any classification.
 ///
 /// Greedily adds the best adjacent unclassified district (by `get_candidate_score`)
 /// as long as it clears the relevant cutoff: the normal cutoff while below URBAN_SIZE_MIN,
-/// a higher cutoff to keep growing up to URBAN_SIZE_MAX. Returns the chosen set only if it
-/// reaches URBAN_SIZE_MIN, otherwise `None` so the caller can fall back to another prime.
+/// a higher cutoff to keep growing up to URBAN_SIZE_MAX. Never grows into `reserved` (the
+/// cities already committed and their borders). Returns the chosen set only if it reaches
+/// URBAN_SIZE_MIN, otherwise `None` so the caller can fall back to another prime.
 fn try_grow_city(
     prime: DistrictID,
     districts: &HashMap<DistrictID, District>,
     parcel_analysis_data: &HashMap<DistrictID, ParcelAnalysis>,
+    reserved: &HashSet<DistrictID>,
 ) -> Option<Vec<DistrictID>> {
     let mut urban: Vec<DistrictID> = vec![prime];
     let mut urban_set: HashSet<DistrictID> = HashSet::from([prime]);
@@ -192,6 +229,7 @@ fn try_grow_city(
             let neighbours = districts.get(id).expect("District not found").data().parcel_adjacency.keys()
                 .filter(|&&neighbour_id| {
                     !urban_set.contains(&neighbour_id)
+                        && !reserved.contains(&neighbour_id)
                         && districts.get(&neighbour_id).expect("District not found").data().parcel_type == ParcelType::Unknown
                 })
                 .cloned()
@@ -317,4 +355,71 @@ fn district_score(district: &District, parcels: &mut HashMap<ParcelID, Parcel>)
             }
         })
         .sum::<f32>() / district.parcels().len() as f32
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::districts::data::ParcelData;
+    use crate::geometry::Point3D;
+    use crate::minecraft::Biome;
+
+    /// `n` identical 16-block-wide districts in a row along x, each one urban-leaning
+    /// parcel, each bordering the next.
+    fn row(n: usize) -> (HashMap<DistrictID, District>, HashMap<ParcelID, Parcel>, HashMap<DistrictID, ParcelAnalysis>) {
+        let mut districts = HashMap::new();
+        let mut parcels = HashMap::new();
+        let mut analysis = HashMap::new();
+        for i in 0..n {
+            let mut parcel = Parcel { id: ParcelID(i), data: ParcelData::new(Point3D::new(i as i32 * 16 + 8, 64, 8)) };
+            parcel.data.parcel_type = ParcelType::Urban;
+            let mut district = District::new(DistrictID(i));
+            district.parcels.insert(parcel.id);
+            for x in 0..16 {
+                for z in 0..16 {
+                    let p = Point3D::new(i as i32 * 16 + x, 64, z);
+                    district.data.points.insert(p);
+                    district.data.points_2d.insert(p.drop_y());
+                    district.data.sum += p;
+                }
+            }
+            for j in [i.wrapping_sub(1), i + 1].into_iter().filter(|&j| j < n) {
+                district.data.parcel_adjacency.insert(DistrictID(j), 16);
+            }
+            district.data.adjacencies_count = 64;
+            districts.insert(district.id, district);
+            parcels.insert(parcel.id, parcel);
+            analysis.insert(DistrictID(i), ParcelAnalysis::from_biome_count(HashMap::from([(Biome::from("plains"), 256)])));
+        }
+        (districts, parcels, analysis)
+    }
+
+    #[test]
+    fn towns_are_seeded_apart_and_never_touch() {
+        let (mut districts, mut parcels, analysis) = row(20);
+        let towns = classify_districts(&mut districts, &mut parcels, &analysis, 2, 100);
+
+        assert_eq!(towns.len(), 2, "{towns:?}");
+        for town in &towns {
+            assert!(town.len() as u32 >= URBAN_SIZE_MIN, "{towns:?}");
+        }
+        let core = |town: &Vec<DistrictID>| districts[&town[0]].average().drop_y();
+        assert!(core(&towns[0]).distance_squared(&core(&towns[1])) >= 100 * 100, "{towns:?}");
+        // A one-district gap at least, so the two walls never merge.
+        for a in &towns[0] {
+            for b in &towns[1] {
+                assert!(a.0.abs_diff(b.0) >= 2, "{towns:?}");
+            }
+        }
+        let urban = districts.values().filter(|d| d.data.parcel_type == ParcelType::Urban).count();
+        assert_eq!(urban, towns[0].len() + towns[1].len());
+    }
+
+    #[test]
+    fn one_town_is_the_default_city() {
+        let (mut districts, mut parcels, analysis) = row(20);
+        let towns = classify_districts(&mut districts, &mut parcels, &analysis, 1, 0);
+        assert_eq!(towns.len(), 1);
+        assert_eq!(towns[0].len() as u32, URBAN_SIZE_MAX);
+    }
+}
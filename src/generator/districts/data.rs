@@ -1,11 +1,14 @@
 use std::collections::{HashMap, HashSet};
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::geometry::{Point2D, Point3D};
 
 use super::parcel::ParcelType;
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = "TID: Eq + std::hash::Hash + serde::Deserialize<'de>"))]
 pub struct ParcelData<TID> {
     pub origin: Point3D,
     pub is_border: bool,
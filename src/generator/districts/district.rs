@@ -1,13 +1,15 @@
 use std::collections::{HashMap, HashSet};
+
+use serde_derive::{Deserialize, Serialize};
 use crate::{editor::World, geometry::Point3D};
 
 use super::{adjacency::AdjacencyAnalyzeable, data::{ParcelData, HasParcelData}, Parcel, ParcelID, ParcelType};
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DistrictID(pub usize);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct District {
     pub id : DistrictID,
     pub parcels : HashSet<ParcelID>,
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 use crate::editor::Editor;
 use crate::generator::materials::{MaterialId, Placer};
 use crate::generator::nbts::{place_structure, Structure, StructureType};
-use crate::geometry::{Point2D, Point3D, is_straight_not_diagonal_point2d, Cardinal};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, is_straight_not_diagonal_point2d, Cardinal};
 use crate::minecraft::BlockForm;
 use crate::noise::RNG;
 use crate::generator::BuildClaim;
@@ -372,9 +372,9 @@ async fn place_palisade_gate(
     };
     let height = middle_point.y;
     for neighbour in neighbours.iter() {
-        editor.world_mut().claim(*neighbour, BuildClaim::Gate);
+        claim_gate_cell(editor, *neighbour, height + gate_height);
         for h in height..height + gate_height {
-            editor.place_block_forced(&air, neighbour.add_y(h)).await;
+            editor.place_block_forced(&air, LocalPoint3D(neighbour.add_y(h))).await;
         }
     }
     // Banner backing posts. `place_civic_banners` hangs a banner facing `facing`
@@ -435,9 +435,9 @@ async fn place_thin_gate(
     };
     let height = middle_point.y;
     for neighbour in neighbours.iter() {
-        editor.world_mut().claim(*neighbour, BuildClaim::Gate);
+        claim_gate_cell(editor, *neighbour, height + gate_height);
         for h in height..height + gate_height {
-            editor.place_block_forced(&air, neighbour.add_y(h)).await;
+            editor.place_block_forced(&air, LocalPoint3D(neighbour.add_y(h))).await;
         }
     }
     let mirror_x = direction == Cardinal::North || direction == Cardinal::South;
@@ -448,6 +448,13 @@ async fn place_thin_gate(
     editor.world_mut().gate_locations.push((middle_point, direction));
 }
 
+/// Claims a gate cell: the passage below `top` as `Gate` for the road through
+/// it, and the gatehouse above as `Wall` for the wall-walk crossing over it.
+fn claim_gate_cell(editor: &mut Editor, cell: Point2D, top: i32) {
+    editor.world_mut().claim_range(cell, i32::MIN..top, BuildClaim::Gate);
+    editor.world_mut().claim_range(cell, top..i32::MAX, BuildClaim::Wall);
+}
+
 async fn place_wide_gate(
     enhanced_points: &Vec<(Point3D, Vec<Cardinal>, WallType)>,
     editor: &mut Editor,
@@ -465,9 +472,9 @@ async fn place_wide_gate(
         .collect();
     let Some(height) = editor.world().get_height_at(middle_point) else { return; };
     for neighbour in neighbours.iter() {
-        editor.world_mut().claim(*neighbour, BuildClaim::Gate);
+        claim_gate_cell(editor, *neighbour, height + gate_height);
         for h in height..height + gate_height {
-            editor.place_block_forced(&air, neighbour.add_y(h)).await;
+            editor.place_block_forced(&air, LocalPoint3D(neighbour.add_y(h))).await;
         }
     }
     let mirror_x = direction == Cardinal::North || direction == Cardinal::South;
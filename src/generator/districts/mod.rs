@@ -10,6 +10,7 @@ mod classification;
 mod naming;
 mod wall;
 mod footprint;
+mod town;
 mod parcel_painter;
 mod gate;
 mod paint_palette;
@@ -19,13 +20,14 @@ pub use parcel::Parcel;
 pub use parcel::ParcelID;
 pub use analysis::ParcelAnalysis;
 pub use parcel::ParcelType;
-pub use parcel::generate_parcels;
+pub use parcel::{generate_parcels, generate_town_parcels};
 pub use district::District;
 pub use district::DistrictID;
-pub use naming::{name_districts, DistrictName};
+pub use naming::{check_district_names, name_districts, DistrictName};
 pub use data::{ParcelData, HasParcelData};
 pub use parcel_painter::*;
 pub use wall::{build_wall, get_wall_points, TowerSkin, WallType};
 pub use footprint::{regularize_urban_footprint, reconcile_districts_to_footprint};
+pub use town::TownSite;
 pub use gate::build_wall_gate;
 pub use paint_palette::{PaintPaletteId, PaintPalette, PaintPalettesFile};
@@ -93,6 +93,12 @@ struct Theme<'a> {
     weight: i32,
 }
 
+/// Fails if `districts_names.yaml` doesn't parse; [`name_districts`] would
+/// leave the districts unnamed.
+pub fn check_district_names() -> anyhow::Result<()> {
+    load_yaml::<DistrictNamesCfg>("districts_names.yaml").map(drop)
+}
+
 /// Name every urban district in `world`. `centre` is the town centroid (build-area
 /// local coords); `manors` are `(position, surname)` of placed manor families;
 /// `parks` are open-space landmark positions. Returns a name per urban
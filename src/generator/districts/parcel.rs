@@ -1,15 +1,17 @@
 use std::{collections::{HashMap, HashSet}, hash::Hash};
 
+use serde_derive::{Deserialize, Serialize};
+
 use log::info;
 
 use crate::{editor::{Editor, World}, geometry::{Point2D, Point3D, Rect2D, CARDINALS_2D}, noise::{Seed, RNG}};
 
-use super::{adjacency::{analyze_adjacency, AdjacencyAnalyzeable}, analysis::analyze_parcel, constants::{CHUNK_SIZE, NUM_RECENTER, SPAWN_PARCELS_MIN_DISTANCE, SPAWN_PARCELS_RETRIES}, data::{ParcelData, HasParcelData}, merge::merge_down, classification::{classify_parcels, classify_districts}, footprint::{regularize_urban_footprint, reconcile_districts_to_footprint}, ParcelAnalysis, District, DistrictID};
+use super::{adjacency::{analyze_adjacency, AdjacencyAnalyzeable}, analysis::analyze_parcel, constants::{CHUNK_SIZE, NUM_RECENTER, SPAWN_PARCELS_MIN_DISTANCE, SPAWN_PARCELS_RETRIES}, data::{ParcelData, HasParcelData}, merge::merge_down, classification::{classify_parcels, classify_districts}, footprint::{regularize_urban_footprint, reconcile_districts_to_footprint}, town::{assign_town_districts, town_sites}, ParcelAnalysis, District, DistrictID};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ParcelID(pub usize);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ParcelType {
     Unknown, // placeholder for unclassified parcels
     Urban,
@@ -21,7 +23,7 @@ pub enum ParcelType {
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parcel {
     pub id: ParcelID,
     pub data : ParcelData<ParcelID>,
@@ -71,6 +73,13 @@ impl AdjacencyAnalyzeable<ParcelID> for Parcel {
 }
 
 pub async fn generate_parcels(seed : Seed, editor : &mut Editor) {
+    generate_town_parcels(seed, editor, 1, 0).await;
+}
+
+/// [`generate_parcels`] seeding up to `town_count` towns whose cores sit at least
+/// `town_spacing` blocks apart, one [`TownSite`](super::TownSite) each on
+/// `World::towns`. The first town is active afterwards.
+pub async fn generate_town_parcels(seed : Seed, editor : &mut Editor, town_count : usize, town_spacing : i32) {
     info!("Generating parcels with seed: {:?}", seed);
 
     let parcels = spawn_parcels(seed, editor.world_mut());
@@ -146,15 +155,25 @@ pub async fn generate_parcels(seed : Seed, editor : &mut Editor) {
 
     // district classification
     let world = editor.world_mut();
-    classify_districts(&mut world.districts, &mut world.parcels, &district_analysis_data);
+    let towns = classify_districts(&mut world.districts, &mut world.parcels, &district_analysis_data, town_count, town_spacing);
 
     // Regularize the urban footprint (smooth the wall outline) and re-vote each
     // district's urban/rural classification against it so every downstream consumer
-    // stays consistent with "inside the wall".
-    let raw_urban = world.get_urban_points();
-    let footprint = regularize_urban_footprint(world, &raw_urban);
-    reconcile_districts_to_footprint(&mut world.districts, &footprint);
-    world.urban_footprint = Some(footprint);
+    // stays consistent with "inside the wall". Each town is regularized on its own,
+    // then the districts are split between them and the first one made active.
+    if towns.is_empty() {
+        let raw_urban = world.get_urban_points();
+        let footprint = regularize_urban_footprint(world, &raw_urban);
+        reconcile_districts_to_footprint(&mut world.districts, &footprint);
+        world.urban_footprint = Some(footprint);
+    } else {
+        let mut sites = town_sites(world, &towns);
+        let footprint: HashSet<Point2D> = sites.iter().flat_map(|site| site.footprint.iter().copied()).collect();
+        reconcile_districts_to_footprint(&mut world.districts, &footprint);
+        assign_town_districts(&world.districts, &mut sites);
+        world.towns = sites;
+        world.enter_town(0);
+    }
 
     info!("Parcels generated successfully");
 
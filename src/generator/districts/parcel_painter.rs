@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use log::info;
 
-use crate::{editor::Editor, generator::{paths::PathType, terrain::{generate_tree, Forest}, BuildClaim}, geometry::{cardinal_to_str, Point2D, Point3D, CARDINALS_2D}, minecraft::{Block, BlockID}, noise::RNG};
+use crate::{editor::Editor, generator::{paths::PathType, terrain::{generate_tree, Forest}, BuildClaim}, geometry::{cardinal_to_str, Point2D, LocalPoint3D, Point3D, CARDINALS_2D}, minecraft::{Block, BlockID}, noise::RNG};
 
 pub async fn replace_ground(
     points: &HashSet<Point2D>,
@@ -30,7 +30,7 @@ pub async fn replace_ground(
 
             let Some(ground_height) = editor.world_mut().get_non_tree_height(*point) else { continue; };
             let mut height = ground_height - 1; // -1 to ensure we are placing on the ground
-            let block = editor.get_block(Point3D::new(point.x, height, point.y));
+            let block = editor.get_block(LocalPoint3D::new(point.x, height, point.y));
 
             if let Some(permit_blocks) = permit_blocks {
                 if permit_blocks.contains(&block.id) {
@@ -43,9 +43,9 @@ pub async fn replace_ground(
             let block_pos = rng.choose_weighted(block_dict);
             let pos = Point3D::new(point.x, height, point.y);
             if force {
-                editor.place_block_forced(&block_list[*block_pos], pos).await;
+                editor.place_block_forced(&block_list[*block_pos], LocalPoint3D(pos)).await;
             } else {
-                editor.place_block(&block_list[*block_pos], pos).await;
+                editor.place_block(&block_list[*block_pos], LocalPoint3D(pos)).await;
             }
 
         }
@@ -71,7 +71,7 @@ pub async fn replace_ground_smooth(
             }
 
             let Some(mut height) = editor.world_mut().get_non_tree_height(*point) else { continue; };
-            let block = editor.get_block(Point3D::new(point.x, height, point.y));
+            let block = editor.get_block(LocalPoint3D::new(point.x, height, point.y));
             
             if let Some(permit_blocks) = permit_blocks {
                 if permit_blocks.contains(&block.id) {
@@ -116,7 +116,7 @@ pub async fn replace_ground_smooth(
                 block = block_list[*rng.choose_weighted(block_dict.get(&0).unwrap()) as usize].clone();
             }
 
-            editor.place_block(&block, Point3D::new(point.x, height-1, point.y)).await;// height-1 to ensure we are placing on the ground
+            editor.place_block(&block, LocalPoint3D::new(point.x, height-1, point.y)).await;// height-1 to ensure we are placing on the ground
             editor.world_mut().claim(*point, BuildClaim::Path(PathType::Pavement));
         }
     }
@@ -141,7 +141,7 @@ pub async fn plant_forest(
         }
 
         let Some(height) = editor.world().get_non_tree_height(point) else { continue; };
-        let block = editor.get_block(Point3D::new(point.x, height, point.y));
+        let block = editor.get_block(LocalPoint3D::new(point.x, height, point.y));
 
         if let Some(permit_blocks) = permit_blocks {
             if permit_blocks.contains(&block.id) {
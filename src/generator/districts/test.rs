@@ -1,7 +1,7 @@
 ﻿#[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
-    use crate::{data::Loadable, editor::World, generator::districts::{WallType, build_wall, HasParcelData, parcel::{self, generate_parcels}, parcel_painter::{replace_ground, replace_ground_smooth}}, geometry::{Point2D, Point3D}, http_mod::{GDMCHTTPProvider, HeightMapType}, minecraft::Block, noise::{RNG, Seed}, util::init_logger};
+    use crate::{data::Loadable, editor::World, generator::districts::{WallType, build_wall, HasParcelData, parcel::{self, generate_parcels}, parcel_painter::{replace_ground, replace_ground_smooth}}, geometry::{Point2D, LocalPoint3D, Point3D}, http_mod::{GDMCHTTPProvider, HeightMapType}, minecraft::Block, noise::{RNG, Seed}, util::init_logger};
     use crate::generator::materials::{Placer, Material, MaterialId};
     use crate::generator::nbts::Structure;
 
@@ -78,10 +78,10 @@ mod tests {
                 if let Some(parcel) = editor.world_mut().parcels.get(&parcel_id) {
                     
                     if parcel.data.edges.contains(&point) {
-                        editor.place_block(&glass, Point3D::new(x, height , z)).await;
-                        editor.place_block(&block, Point3D::new(x, height - 1, z)).await;
+                        editor.place_block(&glass, LocalPoint3D::new(x, height , z)).await;
+                        editor.place_block(&block, LocalPoint3D::new(x, height - 1, z)).await;
                     } else {
-                        editor.place_block(&block, Point3D::new(x, height, z)).await;
+                        editor.place_block(&block, LocalPoint3D::new(x, height, z)).await;
                     }
                 }
             }
@@ -136,7 +136,7 @@ mod tests {
                     continue;
                 }
                 let h = height_map[p.x as usize][p.y as usize] - build_area.origin.y;
-                editor.place_block(&wool, Point3D::new(p.x, h, p.y)).await;
+                editor.place_block(&wool, LocalPoint3D::new(p.x, h, p.y)).await;
                 total_cells += 1;
             }
 
@@ -146,7 +146,7 @@ mod tests {
                 if e.x < 0 || e.z < 0 || e.x >= build_area.size.x || e.z >= build_area.size.z {
                     continue;
                 }
-                editor.place_block(&wool, Point3D::new(e.x, e.y + 1, e.z)).await;
+                editor.place_block(&wool, LocalPoint3D::new(e.x, e.y + 1, e.z)).await;
                 total_edge_cells += 1;
             }
         }
@@ -209,14 +209,14 @@ mod tests {
                 let district = districts.get(&district_id).expect("Failed to get super parcel");
                 let parcel = parcels.get(&parcel_id).expect("Failed to get parcel");
                 if district.data.edges.contains(&point) {
-                    editor.place_block(&bedrock, Point3D::new(x, height, z)).await;
+                    editor.place_block(&bedrock, LocalPoint3D::new(x, height, z)).await;
                 }
                 else if parcel.data.edges.contains(&point) {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(&block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height - 1, z)).await;
                 }
                 else {
-                    editor.place_block(&block, Point3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height, z)).await;
                 }
 
             }
@@ -274,14 +274,14 @@ mod tests {
                 let parcel = parcels.get(&parcel_id).expect("Failed to get parcel");
 
                 if district.data.edges.contains(&point) {
-                    editor.place_block(&bedrock, Point3D::new(x, height, z)).await;
+                    editor.place_block(&bedrock, LocalPoint3D::new(x, height, z)).await;
                 }
                 else if parcel.data.edges.contains(&point) {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(&block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height - 1, z)).await;
                 }
                 else {
-                    editor.place_block(&block, Point3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height, z)).await;
                 }
 
             }
@@ -312,9 +312,9 @@ mod tests {
             // A 3-tall pole so the marker pokes above terrain, with the numbered sign on top.
             let h = surface_y - build_area.origin.y;
             for dy in 1..=3 {
-                editor.place_block(&pole, Point3D::new(centre.x, h + dy, centre.y)).await;
+                editor.place_block(&pole, LocalPoint3D::new(centre.x, h + dy, centre.y)).await;
             }
-            editor.place_block(&sign_block(&id.to_string()), Point3D::new(centre.x, h + 4, centre.y)).await;
+            editor.place_block(&sign_block(&id.to_string()), LocalPoint3D::new(centre.x, h + 4, centre.y)).await;
         }
 
         // Verify the size band: every Urban/Rural (interior) parcel should be within Â±50% of the
@@ -415,7 +415,7 @@ mod tests {
                         continue;
                     }
                     let h = height_map[p.x as usize][p.y as usize] - build_area.origin.y;
-                    editor.place_block(&paint, Point3D::new(p.x, h, p.y)).await;
+                    editor.place_block(&paint, LocalPoint3D::new(p.x, h, p.y)).await;
                 }
             }
 
@@ -424,7 +424,7 @@ mod tests {
                     continue;
                 }
                 let h = height_map[p.x as usize][p.y as usize] - build_area.origin.y;
-                editor.place_block(&alley_block, Point3D::new(p.x, h, p.y)).await;
+                editor.place_block(&alley_block, LocalPoint3D::new(p.x, h, p.y)).await;
             }
         }
 
@@ -704,7 +704,7 @@ mod tests {
                 continue;
             }
             let Some(h) = editor.world().get_ocean_floor_height_at(*p) else { continue; };
-            editor.place_block(&alley_block, Point3D::new(p.x, h - 1, p.y)).await;
+            editor.place_block(&alley_block, LocalPoint3D::new(p.x, h - 1, p.y)).await;
             editor.world_mut().claim(*p, BuildClaim::Path(PathType::Pavement));
         }
 
@@ -754,10 +754,10 @@ mod tests {
                 if let Some(parcel) = editor.world_mut().parcels.get(&parcel_id) {
                     
                     if parcel.data.edges.contains(&point) {
-                        editor.place_block(&glass, Point3D::new(x, height , z)).await;
-                        editor.place_block(&block, Point3D::new(x, height - 1, z)).await;
+                        editor.place_block(&glass, LocalPoint3D::new(x, height , z)).await;
+                        editor.place_block(&block, LocalPoint3D::new(x, height - 1, z)).await;
                     } else {
-                        editor.place_block(&block, Point3D::new(x, height, z)).await;
+                        editor.place_block(&block, LocalPoint3D::new(x, height, z)).await;
                     }
                 }
             }
@@ -800,10 +800,10 @@ mod tests {
             let block = get_block_for_parcel_type(parcel_type);
             for point in points.iter() {
                 if edges.contains(point) {
-                    editor.place_block(&glass, *point).await;
-                    editor.place_block(&block, Point3D::new(point.x, point.y - 1, point.z)).await;
+                    editor.place_block(&glass, LocalPoint3D(*point)).await;
+                    editor.place_block(&block, LocalPoint3D::new(point.x, point.y - 1, point.z)).await;
                 } else {
-                    editor.place_block(&block, *point).await;
+                    editor.place_block(&block, LocalPoint3D(*point)).await;
                 }
             }
         }
@@ -1085,14 +1085,14 @@ mod tests {
                 let parcel = parcels.get(&parcel_id).expect("Failed to get parcel");
 
                 if district.data.edges.contains(&point) {
-                    editor.place_block(&bedrock, Point3D::new(x, height, z)).await;
+                    editor.place_block(&bedrock, LocalPoint3D::new(x, height, z)).await;
                 }
                 else if parcel.data.edges.contains(&point) {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(&block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height - 1, z)).await;
                 }
                 else {
-                    editor.place_block(&block, Point3D::new(x, height, z)).await;
+                    editor.place_block(&block, LocalPoint3D::new(x, height, z)).await;
                 }
 
             }
@@ -1100,11 +1100,11 @@ mod tests {
         let wall_points = crate::generator::districts::wall::get_wall_points(&editor.world().get_urban_points(), &mut editor);
         for point in wall_points.clone() {
             let height = height_map[point.x as usize][point.y as usize] - build_area.origin.y;
-            editor.place_block(&black_wool, Point3D::new(point.x, height, point.y)).await;
+            editor.place_block(&black_wool, LocalPoint3D::new(point.x, height, point.y)).await;
         }
         for point in editor.world().get_urban_points().difference(&wall_points) {
             let height = height_map[point.x as usize][point.y as usize] - build_area.origin.y;
-            editor.place_block(&lime_wool, Point3D::new(point.x, height, point.y)).await;
+            editor.place_block(&lime_wool, LocalPoint3D::new(point.x, height, point.y)).await;
         }
         editor.flush_buffer().await;
 
@@ -0,0 +1,165 @@
+//! Settlement sites. A build area can hold several towns, each grown from its own
+//! prime district (see [`super::classification::classify_districts`]). A
+//! [`TownSite`] is one of them: its regularized footprint, the urban districts
+//! inside it, the rural districts that farm for it, and — once its wall is up —
+//! the wall's gates and towers. [`World::enter_town`] points the world's urban
+//! fields at one site, so the town phases build one town at a time.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{editor::World, geometry::{Cardinal, Point2D, Point3D}};
+
+use super::{data::HasParcelData, footprint::regularize_urban_footprint, District, DistrictID, ParcelType};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TownSite {
+    /// Regularized "inside the wall" cells.
+    pub footprint: HashSet<Point2D>,
+    /// Urban districts inside the footprint.
+    pub districts: Vec<DistrictID>,
+    /// Rural districts nearer this town's core than any other's.
+    pub hinterland: Vec<DistrictID>,
+    /// The wall's gates and towers, stashed here while another town is active
+    /// (see [`World::enter_town`]); empty until the wall is raised.
+    pub gate_locations: Vec<(Point3D, Cardinal)>,
+    pub tower_guard_posts: Vec<Vec<Point3D>>,
+    pub tower_bases: Vec<(Point2D, i32)>,
+}
+
+impl TownSite {
+    /// Centroid of the footprint.
+    pub fn core(&self) -> Point2D {
+        if self.footprint.is_empty() {
+            return Point2D::ZERO;
+        }
+        self.footprint.iter().fold(Point2D::ZERO, |a, p| a + *p) / self.footprint.len() as i32
+    }
+}
+
+/// One site per city, best first, each footprint regularized on its own so two
+/// towns get two walls. A later town drops any cell an earlier one already holds.
+pub fn town_sites(world: &World, towns: &[Vec<DistrictID>]) -> Vec<TownSite> {
+    let mut taken: HashSet<Point2D> = HashSet::new();
+    towns
+        .iter()
+        .map(|city| {
+            let raw: HashSet<Point2D> = city
+                .iter()
+                .filter_map(|id| world.districts.get(id))
+                .flat_map(|d| d.data.points_2d.iter().copied())
+                .collect();
+            let mut footprint = regularize_urban_footprint(world, &raw);
+            footprint.retain(|p| !taken.contains(p));
+            taken.extend(&footprint);
+            TownSite { footprint, ..TownSite::default() }
+        })
+        .collect()
+}
+
+/// Hands out the districts once they've been reconciled against the union of the
+/// footprints: an Urban district goes to the town holding most of its cells, a
+/// Rural one to the hinterland of the town whose core is nearest.
+pub fn assign_town_districts(districts: &HashMap<DistrictID, District>, sites: &mut [TownSite]) {
+    if sites.is_empty() {
+        return;
+    }
+    let cores: Vec<Point2D> = sites.iter().map(TownSite::core).collect();
+    let mut ids: Vec<DistrictID> = districts.keys().copied().collect();
+    ids.sort_by_key(|id| id.0);
+    for id in ids {
+        let district = &districts[&id];
+        match district.data.parcel_type {
+            ParcelType::Urban => {
+                let (town, _) = sites
+                    .iter()
+                    .enumerate()
+                    .map(|(i, site)| (i, district.data.points_2d.iter().filter(|p| site.footprint.contains(p)).count()))
+                    .max_by_key(|&(i, inside)| (inside, std::cmp::Reverse(i)))
+                    .expect("sites is non-empty");
+                sites[town].districts.push(id);
+            }
+            ParcelType::Rural => {
+                let centre = district.average().drop_y();
+                let (town, _) = cores
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|&(i, core)| (core.distance_squared(&centre), i))
+                    .expect("sites is non-empty");
+                sites[town].hinterland.push(id);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point3D;
+
+    fn district(id: usize, x0: i32, x1: i32, parcel_type: ParcelType) -> District {
+        let mut d = District::new(DistrictID(id));
+        for x in x0..x1 {
+            for z in 0..4 {
+                d.data.points.insert(Point3D::new(x, 64, z));
+                d.data.points_2d.insert(Point2D::new(x, z));
+                d.data.sum += Point3D::new(x, 64, z);
+            }
+        }
+        d.data.parcel_type = parcel_type;
+        d
+    }
+
+    #[test]
+    fn districts_go_to_the_town_that_holds_them() {
+        let footprint = |x0: i32, x1: i32| (x0..x1).flat_map(|x| (0..4).map(move |z| Point2D::new(x, z))).collect();
+        let mut sites = vec![
+            TownSite { footprint: footprint(0, 10), ..TownSite::default() },
+            TownSite { footprint: footprint(90, 100), ..TownSite::default() },
+        ];
+        let districts: HashMap<DistrictID, District> = [
+            district(0, 0, 10, ParcelType::Urban),
+            district(1, 90, 100, ParcelType::Urban),
+            district(2, 20, 30, ParcelType::Rural),
+            district(3, 70, 80, ParcelType::Rural),
+            district(4, 40, 50, ParcelType::Water),
+        ]
+        .into_iter()
+        .map(|d| (d.id, d))
+        .collect();
+
+        assign_town_districts(&districts, &mut sites);
+
+        assert_eq!(sites[0].districts, vec![DistrictID(0)]);
+        assert_eq!(sites[1].districts, vec![DistrictID(1)]);
+        assert_eq!(sites[0].hinterland, vec![DistrictID(2)]);
+        assert_eq!(sites[1].hinterland, vec![DistrictID(3)]);
+    }
+
+    #[test]
+    fn leaving_a_town_gives_the_others_their_land_back() {
+        use crate::geometry::Rect3D;
+
+        let mut world = World::synthetic(Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(99, 255, 3)), 64);
+        world.districts = [district(0, 0, 10, ParcelType::Urban), district(1, 90, 100, ParcelType::Urban)]
+            .into_iter()
+            .map(|d| (d.id, d))
+            .collect();
+        world.towns = vec![
+            TownSite { districts: vec![DistrictID(0)], ..TownSite::default() },
+            TownSite { districts: vec![DistrictID(1)], ..TownSite::default() },
+        ];
+        let parcel_type = |world: &World, id: usize| world.districts[&DistrictID(id)].data.parcel_type;
+
+        world.enter_town(1);
+        assert_eq!(parcel_type(&world, 0), ParcelType::OffLimits);
+        assert_eq!(parcel_type(&world, 1), ParcelType::Urban);
+        world.enter_town(0);
+        assert_eq!(parcel_type(&world, 1), ParcelType::OffLimits);
+        world.leave_town();
+        assert_eq!(parcel_type(&world, 0), ParcelType::Urban);
+        assert_eq!(parcel_type(&world, 1), ParcelType::Urban);
+    }
+}
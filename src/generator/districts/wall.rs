@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use log::info;
-use crate::{generator::{data::LoadedData, districts::build_wall_gate, materials::{MaterialId, Palette, Placer}, nbts::{load_nbt_structure, place_structure, Structure, StructureType}, BuildClaim}, geometry::{get_neighbours_in_set, get_edge, is_point_surrounded_by_points, Cardinal, Point2D, Point3D, CARDINALS_2D}, minecraft::BlockForm, noise::RNG};
+use crate::{generator::{data::LoadedData, districts::build_wall_gate, materials::{MaterialId, Palette, Placer}, nbts::{load_nbt_structure, place_structure, Structure, StructureType}, BuildClaim}, geometry::{get_neighbours_in_set, get_edge, is_point_surrounded_by_points, Cardinal, Point2D, LocalPoint3D, Point3D, CARDINALS_2D}, minecraft::BlockForm, noise::RNG};
 
 use crate::editor::Editor;
 
@@ -801,7 +801,7 @@ pub async fn flatten_walkway(
                 continue;
             };
             for y in (low + 1)..high {
-                editor.place_block(&ladder, Point3D { x: point.x, y, z: point.y }).await;
+                editor.place_block(&ladder, LocalPoint3D::new(point.x, y, point.y)).await;
             }
         }
     }
@@ -41,6 +41,12 @@ pub struct FamilyBanner {
     pub blazon: String,
 }
 
+/// Fails if `banners.yaml` doesn't parse; [`pick_family_banner`] would leave
+/// the manor banners plain.
+pub fn check_banners() -> anyhow::Result<()> {
+    load_yaml::<BannerCfg>("banners.yaml").map(drop)
+}
+
 /// Pick a heraldic design for one family and render it for a banner whose base
 /// (field) colour is `primary`, with the charge in `secondary`. `secondary` must
 /// differ from `primary` for the charge to read against the field. Returns
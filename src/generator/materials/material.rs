@@ -1,7 +1,7 @@
 use std::{collections::HashMap};
 use serde_derive::{Serialize, Deserialize};
 
-use crate::{data::Loadable, editor::Editor, generator::materials::{feature::{map_features, MaterialParameters}, MaterialFeature}, geometry::Point3D, minecraft::{Block, BlockForm, BlockID}, noise::RNG};
+use crate::{data::Loadable, editor::Editor, generator::materials::{feature::{map_features, MaterialParameters}, MaterialFeature}, geometry::{LocalPoint3D, Point3D}, minecraft::{Block, BlockForm, BlockID}, noise::RNG};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MaterialId(String);
@@ -81,16 +81,26 @@ impl Material {
     }
 
     pub async fn place_block(&self, editor: &Editor, point: Point3D, form: BlockForm, materials: &HashMap<MaterialId, Material>, state: Option<&HashMap<String, String>>, data: Option<&String>, parameters: MaterialParameters, rng: &mut RNG, is_forced: bool) {
+        if let Some(block) = self.resolve_block(form, materials, state, data, parameters, rng) {
+            editor.place_block_options(&block, LocalPoint3D(point), is_forced).await;
+        }
+    }
+
+    /// The block `place_block` would place, without placing it: the feature-
+    /// mapped material's block for `form`, carrying `state` and `data`.
+    pub fn resolve_block(&self, form: BlockForm, materials: &HashMap<MaterialId, Material>, state: Option<&HashMap<String, String>>, data: Option<&String>, parameters: MaterialParameters, rng: &mut RNG) -> Option<Block> {
         let material = map_features(&parameters, self.id(), materials);
-        
-        if let Some(block_id) = materials.get(&material).unwrap().get_block(&form, rng) {
-            editor.place_block_options(&Block{
+
+        match materials.get(&material).unwrap().get_block(&form, rng) {
+            Some(block_id) => Some(Block {
                 id: block_id.clone(),
                 state: state.cloned(),
                 data: data.cloned(),
-            }, point, is_forced).await;
-        } else {
-            log::warn!("No block found for material {} with form {:?}", self.id().0, form);
+            }),
+            None => {
+                log::warn!("No block found for material {} with form {:?}", self.id().0, form);
+                None
+            }
         }
     }
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{editor::Editor, generator::materials::{feature::MaterialParameters, Material, MaterialId}, geometry::Point3D, minecraft::BlockForm, noise::RNG};
+use crate::{editor::Editor, generator::materials::{feature::MaterialParameters, Material, MaterialId}, geometry::Point3D, minecraft::{Block, BlockForm}, noise::RNG};
 
 
 pub struct Placer<'a> {
@@ -72,6 +72,18 @@ impl<'a> Placer<'a> {
         }
     }
 
+    /// The block `place_block` would put at `point`, without placing it.
+    pub fn resolve_block(&mut self, point: Point3D, material: &MaterialId, form: BlockForm, state: Option<&HashMap<String, String>>, data: Option<&String>) -> Option<Block> {
+        let parameters = MaterialParameters {
+            shade: self.shade_function.as_ref().map_or(0.5, |f| f(point)),
+            wear: self.wear_function.as_ref().map_or(0.5, |f| f(point)),
+            moisture: self.wetness_function.as_ref().map_or(0.5, |f| f(point)),
+            decoration: self.decorativeness_function.as_ref().map_or(0.5, |f| f(point)),
+        };
+
+        self.materials.get(material)?.resolve_block(form, self.materials, state, data, parameters, self.rng)
+    }
+
     pub async fn place_block_forced(&mut self, editor: &Editor, point: Point3D, material: &MaterialId, form: BlockForm, state: Option<&HashMap<String, String>>, data: Option<&String>) {
         let parameters = MaterialParameters {
             shade: self.shade_function.as_ref().map_or(0.5, |f| f(point)),
@@ -13,14 +13,21 @@ pub mod paths;
 pub mod placement;
 pub mod style;
 pub mod chronicle;
+pub mod checkpoint;
+pub mod phases;
+pub mod profile;
 pub mod settlement;
 pub mod open_space;
 pub mod naming;
 pub mod heraldry;
 pub mod civic_banner;
+pub mod chronicle_copies;
+pub mod town_map_copies;
 pub mod npc;
 pub mod population;
 pub mod welcome;
+pub mod sweep;
+pub mod report;
 
 pub use build_claim::BuildClaim;
 pub use settlement::generate_town;
\ No newline at end of file
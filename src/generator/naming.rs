@@ -24,7 +24,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::data::load_yaml;
 use crate::editor::World;
@@ -139,6 +139,7 @@ impl CultureWords {
 /// For medieval (Old-English) names the subtitle is the modern-English reading
 /// ("Mylnham" → "Mill Homestead"); for japanese/desert it is the translation
 /// ("Bir al-Raml" → "Well of the Sand").
+#[derive(Serialize, Deserialize)]
 pub struct SettlementName {
     pub name: String,
     pub subtitle: String,
@@ -162,6 +163,12 @@ pub(crate) fn culture_key(culture: Culture) -> &'static str {
     }
 }
 
+/// Fails if `settlement_names.yaml` doesn't parse, which
+/// [`generate_settlement_name`] only logs before falling back to a default.
+pub fn check_settlement_names() -> anyhow::Result<()> {
+    load_yaml::<NamesCfg>("settlement_names.yaml").map(drop)
+}
+
 /// Generate a name for the settlement occupying `urban` (build-area local
 /// coords). Reads the dominant building, civic landmarks (the open-space feature
 /// keys in `civic_features` — plaza/park `.key()` strings), land shape, and
@@ -1,9 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io::Write, path::Path};
+
+use flate2::{write::GzEncoder, Compression};
 
 use fastnbt::Value;
 use serde_derive::{Serialize, Deserialize};
 
-use crate::{geometry::Point3D, minecraft::{Block, BlockID}};
+use crate::{data::to_snbt, geometry::Point3D, http_mod::PositionedBlock, minecraft::{Block, BlockID, DATA_VERSION}};
 
 /// Parse a block's SNBT `data` string into an NBT value for storage in a
 /// structure. Returns `None` when there is no data; logs and drops the data
@@ -21,6 +23,10 @@ fn snbt_to_value(data : Option<String>) -> Option<Value> {
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct NBTStructure {
+    /// Version of the game that wrote the file. Absent in older authored
+    /// structures; exports write [`DATA_VERSION`] so the game doesn't upgrade them.
+    #[serde(rename = "DataVersion", default, skip_serializing_if = "Option::is_none")]
+    pub data_version : Option<i32>,
     pub size : [i32; 3],
     pub palette : Vec<PaletteBlock>,
     pub blocks : Vec<BlockData>,
@@ -82,6 +88,7 @@ impl NBTStructure {
         }
 
         NBTStructure {
+            data_version: None,
             size: [max.x - min.x + 1, max.y - min.y + 1, max.z - min.z + 1],
             palette,
             blocks: block_data,
@@ -90,6 +97,35 @@ impl NBTStructure {
     }
 }
 
+impl NBTStructure {
+    /// The structure's blocks as absolute placements with its corner at
+    /// `origin`, block-entity NBT carried as SNBT `data`.
+    pub fn to_positioned_blocks(&self, origin : Point3D) -> Vec<PositionedBlock> {
+        self.blocks.iter().map(|block| {
+            let palette = &self.palette[block.state];
+            let data = block.nbt.as_ref().map(to_snbt);
+            let block_at = Block { id: palette.name.clone(), state: palette.properties.clone(), data };
+            PositionedBlock::from_block(block_at, (origin + Point3D::from(block.pos)).into())
+        }).collect()
+    }
+
+    /// Writes a gzip-compressed vanilla structure file, loadable with a
+    /// structure block or `/place template`.
+    pub fn write(&self, path : &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// Gzip-compressed NBT as the game stores structures, stamped with
+    /// [`DATA_VERSION`] unless the structure already carries one.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        let structure = NBTStructure { data_version: Some(self.data_version.unwrap_or(DATA_VERSION)), ..self.clone() };
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&fastnbt::to_bytes(&structure)?)?;
+        Ok(encoder.finish()?)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PaletteBlock {
     #[serde(rename = "Name")]
@@ -4,7 +4,7 @@ use anyhow::Ok;
 use flate2::read::GzDecoder;
 use log::info;
 
-use crate::{data::to_snbt, editor::Editor, generator::{data::LoadedData, materials::{Palette, PaletteSwapResult, Placer}, nbts::{Rotation, Structure, meta::NBTMeta, nbt::NBTStructure, transform::Transform}}, geometry::{Cardinal, Point3D}, minecraft::{Block, BlockForm}};
+use crate::{data::to_snbt, editor::Editor, generator::{data::LoadedData, materials::{Palette, PaletteSwapResult, Placer}, nbts::{Rotation, Structure, meta::NBTMeta, nbt::NBTStructure, transform::Transform}}, geometry::{Cardinal, LocalPoint3D, Point3D}, minecraft::{Block, BlockForm}};
 
 /// Read and decode an NBT structure file (plain or gzip-compressed). Shared by
 /// placement and by callers that only need the structure's metadata — e.g. its
@@ -60,11 +60,33 @@ pub async fn place_nbt<'materials>(data: &NBTMeta, transform: Transform, editor:
     info!("Placing NBT structure: {}", data.path);
 
     let structure = load_nbt_structure(&data.path)?;
+    let blocks = resolve_nbt_blocks(structure, transform, placer, generator_data, input_palette, output_palette, mirror_x, mirror_z);
+
+    if editor.pastes_structures() {
+        return editor.paste_blocks(blocks).await;
+    }
 
     // Beds are pasted without block updates: with updates on, the server runs the
     // placement side effect of a bed foot auto-spawning its head — duplicating the
     // half the NBT already contains, often shoved into an adjacent wall where it
     // then breaks. Every other block is written normally.
+    for (point, block) in blocks {
+        if block.id.is_bed() {
+            editor.place_block_no_update(&block, LocalPoint3D(point)).await;
+        } else {
+            editor.place_block(&block, LocalPoint3D(point)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Every block `place_nbt` writes, in structure order: mirrored, rotated and
+/// palette-swapped into the editor's local coordinates. Air and structure voids
+/// are left out so existing terrain shows through.
+#[allow(clippy::too_many_arguments)]
+fn resolve_nbt_blocks<'materials>(structure: NBTStructure, transform: Transform, placer: Option<&mut Placer<'materials>>, generator_data: Option<&LoadedData>, input_palette: Option<&Palette>, output_palette: Option<&Palette>, mirror_x: Option<i32>, mirror_z: Option<i32>) -> Vec<(Point3D, Block)> {
+    let mut blocks = Vec::with_capacity(structure.blocks.len());
 
     if input_palette.is_none() || output_palette.is_none() {
         for blockdata in structure.blocks {
@@ -89,12 +111,7 @@ pub async fn place_nbt<'materials>(data: &NBTMeta, transform: Transform, editor:
                 state: palette_data.properties.clone(),
                 data, // Now contains the SNBT string if data exists
             });
-            let point = transform.apply(Point3D::from(blockdata.pos));
-            if block.id.is_bed() {
-                editor.place_block_no_update(&block, point).await;
-            } else {
-                editor.place_block(&block, point).await;
-            }
+            blocks.push((transform.apply(pos), block));
         }
     } else {
         let placer = placer.unwrap();
@@ -132,13 +149,7 @@ pub async fn place_nbt<'materials>(data: &NBTMeta, transform: Transform, editor:
                         state,
                         data, // Now contains the SNBT string if data exists
                     });
-
-                    let point = transform.apply(pos);
-                    if block.id.is_bed() {
-                        editor.place_block_no_update(&block, point).await;
-                    } else {
-                        editor.place_block(&block, point).await;
-                    }
+                    blocks.push((transform.apply(pos), block));
                 },
                 PaletteSwapResult::Material(material_id, form) => {
                     // Only log/wood/pillar forms keep an axis; for any other form
@@ -153,18 +164,14 @@ pub async fn place_nbt<'materials>(data: &NBTMeta, transform: Transform, editor:
                         data, // Now contains the SNBT string if data exists
                     });
 
-                    placer.place_block(
-                        editor,
-                        transform.apply(pos),
-                        material_id,
-                        form,
-                        block.state.as_ref(),
-                        block.data.as_ref()
-                    ).await
+                    let point = transform.apply(pos);
+                    if let Some(block) = placer.resolve_block(point, material_id, form, block.state.as_ref(), block.data.as_ref()) {
+                        blocks.push((point, block));
+                    }
                 }
             }
         }
     }
 
-    Ok(())
-}
\ No newline at end of file
+    blocks
+}
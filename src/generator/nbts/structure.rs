@@ -24,7 +24,7 @@ impl From<&str> for StructureType {
 /// Unique runtime identifier for a *placed instance* of a structure, paired
 /// with the type so callers can ask both "which one?" and "what kind?". Minted
 /// at placement time from a counter on `World`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct StructureID {
     pub id: u32,
     pub structure_type: StructureType,
@@ -5,7 +5,7 @@ mod tests {
 
     use log::info;
 
-    use crate::{data::Loadable, editor::World, generator::{buildings::{roofs::{HipRoofPart, RoofComponent, RoofType}, walls::{VerticalWallPosition, WallComponent, WallType}}, data::LoadedData, materials::Placer, nbts::{nbt::NBTStructure, place::place_nbt, place::place_structure, NBTMeta, Structure, StructureType}, style::Style}, geometry::{Cardinal, Point3D}, http_mod::{Coordinate, GDMCHTTPProvider}, minecraft::Block, noise::RNG, util::init_logger};
+    use crate::{data::Loadable, editor::World, generator::{buildings::{roofs::{HipRoofPart, RoofComponent, RoofType}, walls::{VerticalWallPosition, WallComponent, WallType}}, data::LoadedData, materials::Placer, nbts::{nbt::NBTStructure, place::place_nbt, place::place_structure, NBTMeta, Structure, StructureType}, style::Style}, geometry::{Cardinal, LocalPoint3D, Point3D}, http_mod::{Coordinate, GDMCHTTPProvider}, minecraft::Block, noise::RNG, util::init_logger};
     use std::fs::File;
     use fastnbt::to_writer;
 
@@ -590,4 +590,57 @@ mod tests {
             );
         }
     }
-}
\ No newline at end of file
+
+    /// The structure-endpoint fast path must leave the world exactly as the
+    /// per-block path does — rotation, block states and block entities included.
+    #[tokio::test]
+    async fn structure_paste_matches_per_block_placement() {
+        use std::sync::Arc;
+        use crate::http_mod::mock_server::MockServer;
+        use crate::generator::nbts::{Rotation, Transform};
+
+        init_logger();
+        let stairs = Block::new("oak_stairs".into(), Some([("facing".to_string(), "north".to_string())].into()), None);
+        let chest = Block::new("chest".into(), Some([("facing".to_string(), "east".to_string())].into()), Some("{Items:[{Slot:0b,id:\"minecraft:apple\",count:2}]}".to_string()));
+        let mut structure = NBTStructure::from_blocks(vec![
+            (Block::from("cobblestone"), Point3D::new(0, 0, 0)),
+            (Block::from("cobblestone"), Point3D::new(1, 0, 0)),
+            (Block::from("air"), Point3D::new(2, 0, 0)),
+            (stairs, Point3D::new(0, 1, 0)),
+            (chest, Point3D::new(1, 1, 2)),
+        ]);
+        structure.size = [3, 2, 3];
+        let path = env::temp_dir().join(format!("tome-paste-{}.nbt", std::process::id()));
+        structure.write(&path).expect("Failed to write structure");
+        let meta = NBTMeta { path: path.to_string_lossy().to_string() };
+        let transform = Transform::new(Point3D::new(6, 64, 6), Rotation::Once);
+
+        // Block entity SNBT is re-serialized from NBT along the way, so compare
+        // its parsed compound rather than its key order.
+        let parsed = |block: Block| {
+            let data = block.data.as_deref().map(|snbt| fastsnbt::from_str::<fastnbt::Value>(snbt).expect("Unparseable block data"));
+            (block.id, block.state, data)
+        };
+        let mut worlds = Vec::new();
+        for paste in [false, true] {
+            let server = MockServer::flat().await;
+            let provider = Arc::new(server.provider());
+            let world = World::new(provider.as_ref()).await.expect("Failed to create world");
+            let mut editor = world.get_editor_with_backend(provider.clone());
+            editor.set_structure_pastes(paste);
+            place_nbt(&meta, transform.clone(), &editor, None, None, None, None, None, None).await.expect("Failed to place structure");
+            editor.flush_buffer().await;
+
+            assert_eq!(server.world().structure_pastes(), if paste { 1 } else { 0 });
+            let cells: Vec<_> = (4..9).flat_map(|x| (64..66).flat_map(move |y| (4..9).map(move |z| Point3D::new(x, y, z)))).collect();
+            let cached: Vec<_> = cells.iter().map(|p| editor.get_cached_block(LocalPoint3D(*p)).map(parsed)).collect();
+            let placed: Vec<_> = cells.iter().map(|p| parsed(server.world().get_block(*p))).collect();
+            worlds.push((cached, placed));
+        }
+
+        assert_eq!(worlds[0].0, worlds[1].0, "editor caches differ");
+        assert_eq!(worlds[0].1, worlds[1].1, "server worlds differ");
+        assert!(worlds[1].1.iter().any(|(id, _, data)| *id == "minecraft:chest".into() && data.is_some()));
+        let _ = std::fs::remove_file(&path);
+    }
+}
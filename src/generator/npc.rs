@@ -10,7 +10,7 @@
 //! 1.21.5+ form the server (1.21.11) expects for `text_display` and entity
 //! `CustomName`.
 
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::editor::Editor;
 use crate::geometry::Point3D;
@@ -56,7 +56,7 @@ const YELL_VIEW_RANGE: f32 = 0.16;
 /// How loudly an NPC's dialogue bubble reads. `Normal` is a quiet, close-range
 /// aside; `Yelled` is a big, bold, far-visible shout for market criers and stage
 /// performers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum DialogueVolume {
     #[default]
     Normal,
@@ -131,7 +131,7 @@ impl VillagerBiome {
 /// The villager's profession — sets its outfit (and, in vanilla, its trades).
 /// `None` is the unemployed green robe and `Nitwit` the lazy green-robe variant;
 /// the rest are the working professions. See <https://minecraft.wiki/w/Villager>.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Profession {
     None,
     Armorer,
@@ -207,7 +207,7 @@ impl Profession {
 /// Witches and wandering traders are the colourful background characters that
 /// make a town feel lived-in. Deserialized from data (e.g. a `guard_mob:
 /// pillager` in `npcs.yaml`) in snake_case.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Mob {
     Witch,
@@ -242,7 +242,7 @@ impl Mob {
 /// a `Profession` name (PascalCase, e.g. `Armorer`) parses as `Villager`, a `Mob`
 /// name (snake_case, e.g. `pillager`) as `Mob`. The two name sets are disjoint,
 /// so there's no ambiguity.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum NpcLook {
     Villager(Profession),
@@ -197,6 +197,12 @@ impl OpenSpaceNames {
         }
     }
 
+    /// Fails if `open_space_names.yaml` doesn't parse, where [`load`](Self::load)
+    /// leaves the open spaces unnamed.
+    pub fn check() -> anyhow::Result<()> {
+        load_yaml::<Self>("open_space_names.yaml").map(drop)
+    }
+
     /// Name a plaza by its built [`PlazaType`], in the settlement's culture.
     pub fn name_plaza(
         &self,
@@ -220,13 +226,18 @@ impl OpenSpaceNames {
     }
 }
 
+/// Air an open space needs clear above its ground: room for a park tree or a
+/// market stall under anything overhead.
+const OPEN_SPACE_HEADROOM: i32 = 8;
+
 /// A green cell is an unclaimed (or only `Nature`) urban cell that isn't water —
-/// open spaces must not expand onto rivers/lakes/ponds, only the dry gaps.
+/// open spaces must not expand onto rivers/lakes/ponds, only the dry gaps. The
+/// ground and `OPEN_SPACE_HEADROOM` of air above it must be free, so a park
+/// doesn't grow under a jetty's overhang; a claim higher up doesn't matter.
 fn is_green(world: &World, c: Point2D) -> bool {
-    matches!(
-        world.get_claim(c),
-        None | Some(BuildClaim::None) | Some(BuildClaim::Nature)
-    ) && !world.is_water(c)
+    let Some(ground) = world.get_height_at(c) else { return false };
+    world.claims_in(c, ground - 1..ground + OPEN_SPACE_HEADROOM).all(|claim| *claim == BuildClaim::Nature)
+        && !world.is_water(c)
 }
 
 /// Minimum width (cells) a strip must have to survive the thin-strip cull. A
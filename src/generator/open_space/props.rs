@@ -7,7 +7,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use crate::editor::{Editor, World};
 use crate::generator::terrain::{generate_tree_feature, Tree};
 use crate::generator::BuildClaim;
-use crate::geometry::{Point2D, Point3D, CARDINALS_2D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D, CARDINALS_2D};
 use crate::minecraft::{string_to_block, Biome};
 use crate::noise::RNG;
 
@@ -16,13 +16,13 @@ use super::theme::{Theme, CHERRY_CHANCE};
 /// Place a single block from an id string (`"id"` or `"id[state=…]"`).
 pub(super) async fn put(editor: &Editor, x: i32, y: i32, z: i32, id: &str) {
     let block = string_to_block(id).unwrap_or_else(|| panic!("bad block id: {id}"));
-    editor.place_block(&block, Point3D::new(x, y, z)).await;
+    editor.place_block(&block, LocalPoint3D::new(x, y, z)).await;
 }
 
 /// Like [`put`] but forced — overrides whatever is already there.
 pub(super) async fn put_forced(editor: &Editor, x: i32, y: i32, z: i32, id: &str) {
     let block = string_to_block(id).unwrap_or_else(|| panic!("bad block id: {id}"));
-    editor.place_block_forced(&block, Point3D::new(x, y, z)).await;
+    editor.place_block_forced(&block, LocalPoint3D::new(x, y, z)).await;
 }
 
 /// Chebyshev (chessboard) distance between two cells — used for prop spacing.
@@ -199,18 +199,18 @@ pub(super) async fn place_bench(editor: &Editor, c: Point2D, h: i32, inward: Poi
     // Extend one cell along the wall (perpendicular to inward) for a two-wide seat.
     let along = Point2D::new(-inward.y, inward.x);
     for cell in [c, c + along] {
-        editor.place_block(&block, Point3D::new(cell.x, h, cell.y)).await;
+        editor.place_block(&block, LocalPoint3D::new(cell.x, h, cell.y)).await;
     }
 }
 
 /// A planter: a `wood` base with a leafy azalea on top.
 pub(super) async fn place_planter(editor: &Editor, c: Point2D, h: i32, wood: &str) {
     put(editor, c.x, h, c.y, &format!("minecraft:{}_planks", wood)).await;
-    editor.place_block(&"minecraft:azalea".into(), Point3D::new(c.x, h + 1, c.y)).await;
+    editor.place_block(&"minecraft:azalea".into(), LocalPoint3D::new(c.x, h + 1, c.y)).await;
 }
 
 /// A lantern on a `wood` fence post.
 pub(super) async fn place_lantern_post(editor: &Editor, c: Point2D, h: i32, wood: &str) {
     put(editor, c.x, h, c.y, &format!("minecraft:{}_fence", wood)).await;
-    editor.place_block(&"minecraft:lantern".into(), Point3D::new(c.x, h + 1, c.y)).await;
+    editor.place_block(&"minecraft:lantern".into(), LocalPoint3D::new(c.x, h + 1, c.y)).await;
 }
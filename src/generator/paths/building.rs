@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{editor::Editor, generator::{BuildClaim, data::LoadedData, materials::{MaterialId, MaterialPlacer, Placer}, paths::path::{Path, PathPriority}}, geometry::{DOWN, Point2D, Point3D, UP, get_surrounding_set}, minecraft::BlockForm, noise::RNG, util::MeanExt};
+use crate::{editor::{Editor, Owner, World}, generator::{BuildClaim, data::LoadedData, materials::{MaterialId, MaterialPlacer, Placer}, paths::path::{Path, PathPriority, PathType}}, geometry::{DOWN, Point2D, LocalPoint3D, Point3D, UP, get_surrounding_set}, minecraft::BlockForm, noise::RNG, util::MeanExt};
 
 /// A road must never carve *into* a finished building, a placed structure, or
 /// the town wall. When a paved cell coincides with one of those claims we skip
@@ -27,11 +27,30 @@ enum PaveMode {
     Skip,
 }
 
-fn pave_mode_for_claim(editor: &Editor, cell: Point2D) -> PaveMode {
-    match editor.world().get_claim(cell) {
-        Some(BuildClaim::Building(_) | BuildClaim::Structure(_) | BuildClaim::Ship) => PaveMode::Skip,
-        Some(BuildClaim::Wall) => PaveMode::SurfaceOnly,
-        _ => PaveMode::Clear,
+/// Cells of air a paved road clears above its surface.
+const ROAD_HEADROOM: i32 = 4;
+
+/// How to pave `cell` with its surface at `road_y`. Only claims on the road's
+/// own cells count — its surface block and headroom — so a road runs under a
+/// jetty or bridge that holds the air above it.
+fn pave_mode_for_claim(editor: &Editor, cell: Point2D, road_y: i32) -> PaveMode {
+    let mut mode = PaveMode::Clear;
+    for claim in editor.world().claims_in(cell, road_y - 1..road_y + ROAD_HEADROOM) {
+        match claim {
+            BuildClaim::Building(_) | BuildClaim::Structure(_) | BuildClaim::Ship | BuildClaim::MapBoard => return PaveMode::Skip,
+            BuildClaim::Wall => mode = PaveMode::SurfaceOnly,
+            _ => {}
+        }
+    }
+    mode
+}
+
+/// Claims paved road cells from the ground up through the road's headroom,
+/// leaving the air above free for a jetty, bridge or wall-walk to claim.
+pub fn claim_road(world: &mut World, cells: impl IntoIterator<Item = Point2D>, path_type: PathType) {
+    for cell in cells {
+        let Some(height) = world.get_height_at(cell) else { continue };
+        world.claim_range(cell, i32::MIN..height + ROAD_HEADROOM, BuildClaim::Path(path_type));
     }
 }
 
@@ -81,11 +100,11 @@ pub async fn build_path(
         // so we force. Claim handling: skip buildings/structures entirely; on a
         // wall/gate tile lay the surface but don't clear the air above (no
         // gouging the wall); otherwise clear headroom and pave normally.
-        match pave_mode_for_claim(editor, *point) {
+        match pave_mode_for_claim(editor, *point, int_height) {
             PaveMode::Skip => continue,
             PaveMode::Clear => {
-                for i in 0..=3 {
-                    editor.place_block_forced(&"air".into(), point3d + UP * i).await;
+                for i in 0..ROAD_HEADROOM {
+                    editor.place_block_forced(&"air".into(), LocalPoint3D(point3d + UP * i)).await;
                 }
             }
             PaveMode::SurfaceOnly => {}
@@ -167,10 +186,9 @@ fn smooth_road_heights(points_2d: &HashSet<Point2D>, height_by_point: &mut HashM
 /// paths overlap, the lower height and the higher-priority material win.
 ///
 /// Returns the set of cells (as the slab block's exact `Point3D`) where a
-/// half-step slab was laid — the half-block grade lips. Callers that align
-/// buildings to the road (e.g. seating a front door's floor) use this to raise a
-/// house a block over a fronting slab and to clear stray slab lips at doorways,
-/// without having to read the placed road back out of the editor's block cache.
+/// half-step slab was laid — the half-block grade lips — for callers that want
+/// them all at once, e.g. to clear stray slab lips at doorways. The slabs read
+/// back from the editor too.
 pub async fn build_paths_merged(
     editor: &Editor,
     data: &LoadedData,
@@ -189,11 +207,12 @@ pub async fn build_paths_merged(
     };
 
     // Centreline cells: height (lower wins on overlap) + the highest-priority
-    // covering path's material.
+    // covering path's material, and that path's index as the cell's owner.
     let mut height_by_point: HashMap<Point2D, f32> = HashMap::new();
     let mut material_by_point: HashMap<Point2D, MaterialId> = HashMap::new();
     let mut rank_by_point: HashMap<Point2D, u8> = HashMap::new();
-    for path in paths {
+    let mut owner_by_point: HashMap<Point2D, usize> = HashMap::new();
+    for (index, path) in paths.iter().enumerate() {
         let r = rank(path.priority());
         for p in path.points() {
             let c = p.drop_y();
@@ -201,6 +220,7 @@ pub async fn build_paths_merged(
             if rank_by_point.get(&c).map_or(true, |&existing| r >= existing) {
                 rank_by_point.insert(c, r);
                 material_by_point.insert(c, path.material().clone());
+                owner_by_point.insert(c, index);
             }
         }
     }
@@ -208,7 +228,7 @@ pub async fn build_paths_merged(
     // Widen each path by its own (width - 1) and fold the shoulders into the
     // paved set, inheriting the higher-priority material on overlap.
     let mut points_2d: HashSet<Point2D> = height_by_point.keys().copied().collect();
-    for path in paths {
+    for (index, path) in paths.iter().enumerate() {
         let r = rank(path.priority());
         let centre: HashSet<Point2D> = path.points().iter().map(|p| p.drop_y()).collect();
         for cell in get_surrounding_set(&centre, path.width() - 1) {
@@ -219,6 +239,7 @@ pub async fn build_paths_merged(
             if rank_by_point.get(&cell).map_or(true, |&existing| r > existing) {
                 rank_by_point.insert(cell, r);
                 material_by_point.insert(cell, path.material().clone());
+                owner_by_point.insert(cell, index);
             }
         }
     }
@@ -235,7 +256,7 @@ pub async fn build_paths_merged(
         let remainder = height - int_height as f32;
         let material = material_by_point.get(point).unwrap_or(&fallback);
 
-        let mode = pave_mode_for_claim(editor, *point);
+        let mode = pave_mode_for_claim(editor, *point, int_height);
 
         if DEBUG_ROAD_MARKERS {
             let marker = if matches!(mode, PaveMode::Skip) {
@@ -247,17 +268,23 @@ pub async fn build_paths_merged(
                     _ => "white_concrete",
                 }
             };
-            editor.place_block_forced(&marker.into(), point3d + UP * DEBUG_MARKER_HEIGHT).await;
+            editor.place_block_forced(&marker.into(), LocalPoint3D(point3d + UP * DEBUG_MARKER_HEIGHT)).await;
         }
 
+        let owner = owner_by_point.get(point).map(|&index| Owner::Road(index));
+        editor.push_provenance(module_path!(), owner);
+
         // Claim handling: skip buildings/structures; on a wall/gate tile lay the
         // surface but don't clear air above (no gouging the wall); otherwise
         // clear headroom and pave normally.
         match mode {
-            PaveMode::Skip => continue,
+            PaveMode::Skip => {
+                editor.pop_provenance();
+                continue;
+            }
             PaveMode::Clear => {
-                for i in 0..=3 {
-                    editor.place_block_forced(&"air".into(), point3d + UP * i).await;
+                for i in 0..ROAD_HEADROOM {
+                    editor.place_block_forced(&"air".into(), LocalPoint3D(point3d + UP * i)).await;
                 }
             }
             PaveMode::SurfaceOnly => {}
@@ -269,6 +296,7 @@ pub async fn build_paths_merged(
         }
 
         placer.place_block_forced(editor, point3d + DOWN, material, BlockForm::Block, None, None).await;
+        editor.pop_provenance();
     }
 
     slab_cells
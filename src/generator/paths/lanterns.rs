@@ -17,7 +17,7 @@ use crate::generator::data::LoadedData;
 use crate::generator::materials::{MaterialId, MaterialPlacer, Placer};
 use crate::generator::open_space::Region;
 use crate::generator::BuildClaim;
-use crate::geometry::{Cardinal, Point2D, Point3D, CARDINALS_2D, UP};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, CARDINALS_2D, UP};
 use crate::minecraft::{Block, BlockForm};
 use crate::noise::RNG;
 
@@ -124,7 +124,7 @@ fn has_clear_footprint(editor: &Editor, c: Point2D) -> bool {
 async fn build_stone_lantern(editor: &Editor, stone: &mut MaterialPlacer<'_>, foot: Point3D) {
     // Centre column: power → lamp → cap.
     let redstone: Block = "minecraft:redstone_block".into();
-    editor.place_block_forced(&redstone, foot).await;
+    editor.place_block_forced(&redstone, LocalPoint3D(foot)).await;
 
     // Force `lit=true` for an immediate glow; the redstone block beneath keeps
     // it powered so it never reverts on the next block update.
@@ -133,7 +133,7 @@ async fn build_stone_lantern(editor: &Editor, stone: &mut MaterialPlacer<'_>, fo
         Some(HashMap::from([("lit".to_string(), "true".to_string())])),
         None,
     );
-    editor.place_block_forced(&lamp, foot + UP).await;
+    editor.place_block_forced(&lamp, LocalPoint3D(foot + UP)).await;
 
     stone
         .place_block_forced(editor, foot + UP * 2, BlockForm::Block, None, None)
@@ -163,7 +163,7 @@ async fn build_stone_lantern(editor: &Editor, stone: &mut MaterialPlacer<'_>, fo
             ("facing".to_string(), dir.to_string()),
         ]);
         let trapdoor = Block::new("minecraft:oak_trapdoor".into(), Some(cage_state), None);
-        editor.place_block_forced(&trapdoor, n + UP).await;
+        editor.place_block_forced(&trapdoor, LocalPoint3D(n + UP)).await;
 
         // Cap ring: bottom slabs flaring out under the cap block — the roof eave.
         let slab_state = HashMap::from([("type".to_string(), "bottom".to_string())]);
@@ -9,7 +9,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::BuildClaim;
-use crate::geometry::{get_surrounding_set, Point2D, Point3D, DOWN, UP};
+use crate::geometry::{get_surrounding_set, Point2D, LocalPoint3D, Point3D, DOWN, UP};
 use crate::minecraft::Block;
 
 use super::path::Path;
@@ -180,18 +180,18 @@ async fn place_lamp(editor: &Editor, cell: Point2D, toward_road: Point2D, lanter
 
     // Vertical post.
     for h in 0..POST_FENCES {
-        editor.place_block_forced(&fence, ground + UP * h).await;
+        editor.place_block_forced(&fence, LocalPoint3D(ground + UP * h)).await;
     }
 
     // Arm fence one block toward the road, level with the post's top.
     let top = ground.y + POST_FENCES - 1;
     let arm = Point3D::new(cell.x + toward_road.x, top, cell.y + toward_road.y);
-    editor.place_block_forced(&fence, arm).await;
+    editor.place_block_forced(&fence, LocalPoint3D(arm)).await;
 
     // Lantern hung beneath the arm — force `hanging=true` regardless of the
     // caller's block state.
     let mut state = HashMap::new();
     state.insert("hanging".to_string(), "true".to_string());
     let hung = Block::new(lantern.id.clone(), Some(state), None);
-    editor.place_block_forced(&hung, arm + DOWN).await;
+    editor.place_block_forced(&hung, LocalPoint3D(arm + DOWN)).await;
 }
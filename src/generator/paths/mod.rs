@@ -15,15 +15,15 @@ mod torii;
 pub mod network;
 
 pub use a_star::a_star;
-pub use building::{build_path, build_paths_merged};
+pub use building::{build_path, build_paths_merged, claim_road};
 pub use connect::connect_doors_to_roads;
 pub use lights::place_street_lights;
 pub use lanterns::scatter_garden_lanterns;
 pub use signs::place_street_signs;
-pub use road_map::{render_town_map, rasterize_to_png};
+pub use road_map::{rasterize_to_pixels, rasterize_to_png, render_town_map, render_town_plan, town_map_bounds, PIXELS_PER_CELL};
 pub use labelling::label_roads_geometric;
-pub use naming::name_roads_layered;
-pub use network::{build_road_network, find_blocks};
+pub use naming::{check_road_names, name_roads_layered};
+pub use network::{build_road_network, find_blocks, RoadNetwork};
 pub use rural::{build_rural_road_network, RuralBuilding};
 pub use torii::place_rural_torii;
 pub use path::{Path, PathPriority, PathType};
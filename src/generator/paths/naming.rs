@@ -128,6 +128,12 @@ impl Layer {
     }
 }
 
+/// Fails if `road_names.yaml` doesn't parse; [`name_roads_layered`] would
+/// leave the roads numbered.
+pub fn check_road_names() -> anyhow::Result<()> {
+    load_yaml::<RoadNamesCfg>("road_names.yaml").map(drop)
+}
+
 /// Name every road in `road_labels`. See module docs. Logs the per-layer
 /// distribution and each road's name.
 pub fn name_roads_layered(
@@ -10,6 +10,8 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::editor::Editor;
 use crate::generator::districts::ParcelType;
 use crate::generator::materials::MaterialId;
@@ -40,7 +42,7 @@ impl std::fmt::Display for NodeKind {
 }
 
 /// One edge of the abstract road graph (before A* routing curves it).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RoadEdge {
     /// Indices into [`RoadNetwork::nodes`].
     pub a: usize,
@@ -53,6 +55,7 @@ pub struct RoadEdge {
 
 /// The routed roads plus the abstract graph they came from, so callers can
 /// overlay the underlying node/edge structure on the realized network.
+#[derive(Serialize, Deserialize)]
 pub struct RoadNetwork {
     pub paths: Vec<Path>,
     /// Destination nodes (industry, district centres, gates, town centre),
@@ -65,6 +68,7 @@ pub struct RoadNetwork {
     /// crossings — then merged by actual straightness). This, not `Path::road_id`,
     /// is the source of truth for road identity in the map and street signs:
     /// one number = one continuous physical road. Centreline cells only.
+    #[serde(with = "crate::util::pairs")]
     pub road_labels: HashMap<Point2D, u32>,
 }
 
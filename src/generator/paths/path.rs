@@ -1,6 +1,7 @@
+use serde_derive::{Deserialize, Serialize};
 use crate::{generator::materials::MaterialId, geometry::Point3D};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PathType {
     Pavement,
     Road,
@@ -8,7 +9,7 @@ pub enum PathType {
 
 /// Road hierarchy tier, named after the standard urban-planning hierarchy.
 /// Each tier maps to a road width in `routing.rs`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PathPriority {
     /// Local roads / alleys — narrowest, the minor connections within a block.
     Low,
@@ -18,7 +19,7 @@ pub enum PathPriority {
     High,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Path {
     points : Vec<Point3D>,
     width : u32,
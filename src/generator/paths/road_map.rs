@@ -25,6 +25,9 @@ const ROAD_SVG: [&str; 16] = [
     "#9d9d97", "#169c9c", "#8932b8", "#3c44aa", "#835432", "#5e7c16", "#b02e26", "#1d1d21",
 ];
 
+/// Pixels per cell of the rendered map, as its `width` and `height` say.
+pub const PIXELS_PER_CELL: i32 = 4;
+
 /// Render the town map. `urban` sets the drawn bounds; `paths` supplies the road
 /// geometry (paved width), `road_labels` the geometric per-cell road number used
 /// to colour them; `road_names` labels each road (falling back to its number);
@@ -42,23 +45,49 @@ pub fn render_town_map(
     signs: &[Point2D],
     places: &[(Point2D, String)],
 ) -> String {
-    if urban.is_empty() {
-        return String::from("<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>\n");
-    }
+    render(world, urban, paths, road_labels, road_names, alleys, network, signs, places, true)
+}
 
-    // Bounds from the urban footprint, padded.
-    let (mut minx, mut minz, mut maxx, mut maxz) = (i32::MAX, i32::MAX, i32::MIN, i32::MIN);
-    for c in urban {
-        minx = minx.min(c.x);
-        maxx = maxx.max(c.x);
-        minz = minz.min(c.y);
-        maxz = maxz.max(c.y);
-    }
+/// The town map with no lettering or markers: ground, water, footprints and
+/// roads in their colours, for copies too small to read a label on.
+pub fn render_town_plan(
+    world: &World,
+    urban: &HashSet<Point2D>,
+    paths: &[Path],
+    road_labels: &HashMap<Point2D, u32>,
+    alleys: &HashSet<Point2D>,
+) -> String {
+    render(world, urban, paths, road_labels, &HashMap::new(), alleys, None, &[], &[], false)
+}
+
+/// The cells the town map covers, as its (min, max) corners: the urban
+/// footprint, padded. `None` with no urban cells.
+pub fn town_map_bounds(urban: &HashSet<Point2D>) -> Option<(Point2D, Point2D)> {
     let pad = 3;
-    minx -= pad;
-    minz -= pad;
-    maxx += pad;
-    maxz += pad;
+    let minx = urban.iter().map(|c| c.x).min()? - pad;
+    let maxx = urban.iter().map(|c| c.x).max()? + pad;
+    let minz = urban.iter().map(|c| c.y).min()? - pad;
+    let maxz = urban.iter().map(|c| c.y).max()? + pad;
+    Some((Point2D::new(minx, minz), Point2D::new(maxx, maxz)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render(
+    world: &World,
+    urban: &HashSet<Point2D>,
+    paths: &[Path],
+    road_labels: &HashMap<Point2D, u32>,
+    road_names: &HashMap<u32, String>,
+    alleys: &HashSet<Point2D>,
+    network: Option<&RoadNetwork>,
+    signs: &[Point2D],
+    places: &[(Point2D, String)],
+    labelled: bool,
+) -> String {
+    let Some((min, max)) = town_map_bounds(urban) else {
+        return String::from("<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>\n");
+    };
+    let (minx, minz, maxx, maxz) = (min.x, min.y, max.x, max.y);
     let (w, h) = (maxx - minx + 1, maxz - minz + 1);
 
     let mut svg = String::new();
@@ -66,8 +95,8 @@ pub fn render_town_map(
         svg,
         "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" \
          width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
-        w * 4,
-        h * 4
+        w * PIXELS_PER_CELL,
+        h * PIXELS_PER_CELL
     );
     let _ = write!(
         svg,
@@ -183,6 +212,10 @@ pub fn render_town_map(
     }
 
     // Road-name labels last, so they sit above the road fill (number as fallback).
+    // A plain plan has none, not even the numbers.
+    if !labelled {
+        centroid.clear();
+    }
     for (rid, (sx, sz, n)) in &centroid {
         if *n == 0 {
             continue;
@@ -230,18 +263,22 @@ fn xml_escape(s: &str) -> String {
         .replace('>', "&gt;")
 }
 
-/// Rasterise an SVG string to a PNG file using resvg (pure-Rust). Uses the SVG's
-/// own width/height for the output size and the system fonts for text.
-pub fn rasterize_to_png(svg: &str, path: &str) -> anyhow::Result<()> {
-    use resvg::{tiny_skia, usvg};
-
-    let mut opt = usvg::Options::default();
+/// Parses a town SVG, with the system fonts loaded for its labels.
+fn parse_svg(svg: &str) -> anyhow::Result<resvg::usvg::Tree> {
+    let mut opt = resvg::usvg::Options::default();
     // Road-name labels use a generic sans-serif; resolve it from system fonts.
     opt.fontdb_mut().load_system_fonts();
     opt.font_family = "Arial".to_string();
 
-    let tree = usvg::Tree::from_str(svg, &opt)
-        .map_err(|e| anyhow::anyhow!("parse town SVG: {e}"))?;
+    resvg::usvg::Tree::from_str(svg, &opt).map_err(|e| anyhow::anyhow!("parse town SVG: {e}"))
+}
+
+/// Rasterise an SVG string to a PNG file using resvg (pure-Rust). Uses the SVG's
+/// own width/height for the output size and the system fonts for text.
+pub fn rasterize_to_png(svg: &str, path: &str) -> anyhow::Result<()> {
+    use resvg::tiny_skia;
+
+    let tree = parse_svg(svg)?;
     let size = tree.size().to_int_size();
     let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
         .ok_or_else(|| anyhow::anyhow!("zero-size town map"))?;
@@ -249,3 +286,28 @@ pub fn rasterize_to_png(svg: &str, path: &str) -> anyhow::Result<()> {
     pixmap.save_png(path)?;
     Ok(())
 }
+
+/// Rasterise an SVG string into a `width`×`height` image, row by row, as
+/// straight (not premultiplied) RGBA. The SVG is centred, `zoom` image pixels
+/// to each of its own, or as large as fits when `zoom` is `None`; the margin
+/// it leaves is transparent.
+pub fn rasterize_to_pixels(svg: &str, width: u32, height: u32, zoom: Option<f32>) -> anyhow::Result<Vec<[u8; 4]>> {
+    use resvg::tiny_skia;
+
+    let tree = parse_svg(svg)?;
+    let size = tree.size();
+    let zoom = zoom.unwrap_or_else(|| (width as f32 / size.width()).min(height as f32 / size.height()));
+    let dx = (width as f32 - size.width() * zoom) / 2.0;
+    let dy = (height as f32 - size.height() * zoom) / 2.0;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| anyhow::anyhow!("zero-size town map"))?;
+    resvg::render(&tree, tiny_skia::Transform::from_row(zoom, 0.0, 0.0, zoom, dx, dy), &mut pixmap.as_mut());
+    Ok(pixmap
+        .pixels()
+        .iter()
+        .map(|p| {
+            let c = p.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect())
+}
@@ -13,6 +13,10 @@
 //! into the router's on-road discount field, so routes hug the ring rather than
 //! running beside it. The ring itself is paved later by the painter.
 //!
+//! When the build area holds several towns, the network also lays a **highway**
+//! from this town's gates to the nearest gate of each neighbouring town it is
+//! given, before any building is routed, so buildings on that side merge onto it.
+//!
 //! Run **after** all rural buildings are placed and **before** the production
 //! painters. Realise the returned paths with
 //! [`build_paths_merged`](super::build_paths_merged).
@@ -21,11 +25,11 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::editor::Editor;
 use crate::generator::BuildClaim;
-use crate::generator::districts::{District, DistrictID};
+use crate::generator::districts::{District, DistrictID, TownSite};
 use crate::generator::materials::MaterialId;
 use crate::generator::nbts::StructureID;
 use crate::generator::resource_chain::border_ring_cells;
-use crate::geometry::{get_surrounding_set, Point2D, Point3D, ALL_8, CARDINALS_2D};
+use crate::geometry::{get_surrounding_set, Point2D, LocalPoint3D, Point3D, ALL_8, CARDINALS_2D};
 
 use super::network::wall_distance_field;
 use super::path::{Path, PathPriority};
@@ -43,13 +47,16 @@ pub struct RuralBuilding {
 /// How far up a footprint column the door scan looks for a door block.
 const DOOR_SCAN_HEIGHT: i32 = 6;
 
-/// Build the rural road network. Returns one routed [`Path`] per building that
-/// reached a gate (buildings that fail to route are logged and skipped — partial
-/// connectivity is acceptable). `route_step` is the A* lattice step (1 = exact
-/// per-cell, 4 = sparse/faster over long open runs).
+/// Build the rural road network. Returns one arterial-width ([`PathPriority::High`])
+/// highway per town in `neighbours` that could be reached, then one routed [`Path`]
+/// per building that reached a gate (buildings that fail to route are logged and
+/// skipped — partial connectivity is acceptable). `neighbours` are towns whose
+/// walls are already up, their gates stashed on the [`TownSite`]. `route_step` is
+/// the A* lattice step (1 = exact per-cell, 4 = sparse/faster over long open runs).
 pub async fn build_rural_road_network(
     editor: &Editor,
     buildings: &[RuralBuilding],
+    neighbours: &[&TownSite],
     material: MaterialId,
     route_step: i32,
 ) -> Vec<Path> {
@@ -103,6 +110,12 @@ pub async fn build_rural_road_network(
     let urban = editor.world().get_urban_points();
     let mut blocked: HashSet<Point2D> = urban.clone();
     blocked.extend(&footprints);
+    // The other towns are as off-limits as this one: a highway meets them at a gate.
+    let mut walled = urban.clone();
+    for site in neighbours {
+        blocked.extend(&site.footprint);
+        walled.extend(&site.footprint);
+    }
     // Block a ~half-step margin around each footprint: the router only tests
     // `blocked` at lattice nodes `route_step` apart, so a building narrower than the
     // step could be hopped over and routed straight through. Anchors are freed below.
@@ -124,7 +137,7 @@ pub async fn build_rural_road_network(
         wall_weight: 12,
         ..RouteParams::default()
     };
-    let wall_dist = wall_distance_field(&urban, params.wall_clearance);
+    let wall_dist = wall_distance_field(&walled, params.wall_clearance);
 
     let mut paths: Vec<Path> = Vec::new();
     // Cells of roads laid so far (a route may end on these to merge), plus their
@@ -132,6 +145,47 @@ pub async fn build_rural_road_network(
     let mut network_cells: HashSet<Point2D> = HashSet::new();
     let mut road_height: HashMap<Point2D, i32> = HashMap::new();
 
+    // Highways: from whichever of our gates is nearest one of theirs, all the way
+    // to it (no early stop on our own network — it must reach the other town).
+    for site in neighbours {
+        let theirs = site_gate_nodes(editor, site);
+        let Some((from, to)) = gates
+            .iter()
+            .flat_map(|g| theirs.iter().map(move |t| (*g, *t)))
+            .min_by_key(|(g, t)| g.drop_y().distance_squared(&t.drop_y()))
+        else {
+            log::warn!("highway: the town at {:?} has no gates to reach", site.core());
+            continue;
+        };
+        blocked.remove(&to.drop_y());
+        let routed = {
+            let ctx = RouteContext {
+                region: None,
+                road_cells: Some(&network_cells),
+                road_height: Some(&road_height),
+                goal_cells: None,
+                wall_dist: Some(&wall_dist),
+                blocked: Some(&blocked),
+            };
+            get_path_with(editor, from, to, PathPriority::High, material.clone(), params, ctx, async |_| {}).await
+        };
+        match routed {
+            Some(path) => {
+                for p in path.points() {
+                    network_cells.insert(p.drop_y());
+                    road_height.insert(p.drop_y(), p.y);
+                }
+                paths.push(path);
+            }
+            None => log::warn!(
+                "highway: gate {:?} failed to route to gate {:?} — {}",
+                from.drop_y(), to.drop_y(),
+                diagnose_route_failure(editor, from.drop_y(), to.drop_y(), &blocked, route_step),
+            ),
+        }
+    }
+    let highways = paths.len();
+
     for (anchor, gate) in jobs {
         let Some(start) = editor.world().add_height(anchor) else { continue; };
 
@@ -170,7 +224,7 @@ pub async fn build_rural_road_network(
             ),
         }
     }
-    let building_segments = paths.len();
+    let building_segments = paths.len() - highways;
 
     // Ensure every gate has a road. A gate no building routed to would otherwise
     // open onto blank countryside, so give it a spur out to the nearest reachable
@@ -220,8 +274,8 @@ pub async fn build_rural_road_network(
     }
 
     log::info!(
-        "rural road network: {} buildings, {} gates, {} segments routed ({} building, {} gate-spur), {} predicted ring cells",
-        buildings.len(), gates.len(), paths.len(), building_segments, paths.len() - building_segments, ring_cells.len(),
+        "rural road network: {} buildings, {} gates, {} segments routed ({} highway, {} building, {} gate-spur), {} predicted ring cells",
+        buildings.len(), gates.len(), paths.len(), highways, building_segments, paths.len() - highways - building_segments, ring_cells.len(),
     );
     paths
 }
@@ -247,6 +301,22 @@ fn gate_nodes(editor: &Editor) -> Vec<Point3D> {
         .collect()
 }
 
+/// [`gate_nodes`] for a town that isn't the active one, stepped off its footprint.
+fn site_gate_nodes(editor: &Editor, site: &TownSite) -> Vec<Point3D> {
+    let world = editor.world();
+    site.gate_locations
+        .iter()
+        .filter_map(|(gate, dir)| {
+            let cell = gate.drop_y();
+            let d = Point2D::from(*dir);
+            let outward = if site.footprint.contains(&(cell + d)) { Point2D::new(-d.x, -d.y) } else { d };
+            let out = cell + outward;
+            let node = if world.is_in_bounds_2d(out) { out } else { cell };
+            world.add_height(node)
+        })
+        .collect()
+}
+
 /// How far short of the build-area boundary a spur stops. The router and the
 /// road-widening sample `is_water` / the heightmap on cells *beyond* the road
 /// centreline, and those lookups aren't bounds-checked — running a road right
@@ -417,10 +487,9 @@ fn door_approach(editor: &Editor, footprint: &HashSet<Point2D>, target: Point2D)
     for &cell in footprint {
         let Some(ground) = editor.world().get_non_tree_height(cell) else { continue; };
         for dy in 0..DOOR_SCAN_HEIGHT {
-            // Read from the placement cache by local coord: the door was placed
-            // this run, and `try_get_block` would subtract the build-area origin
-            // and return world terrain instead (no door) on a live server.
-            let Some(block) = editor.get_cached_block(Point3D::new(cell.x, ground + dy, cell.y)) else {
+            // Read from the placement cache: the door was placed this run, and
+            // only doors this run placed count.
+            let Some(block) = editor.get_cached_block(LocalPoint3D::new(cell.x, ground + dy, cell.y)) else {
                 continue;
             };
             let id = block.id.as_str();
@@ -12,7 +12,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 use crate::editor::Editor;
 use crate::generator::BuildClaim;
-use crate::geometry::{get_surrounding_set, Point2D, Point3D};
+use crate::geometry::{get_surrounding_set, Point2D, LocalPoint3D};
 use crate::minecraft::Block;
 
 use super::path::{Path, PathType};
@@ -244,7 +244,7 @@ async fn place_fingerpost(
         editor
             .place_block_forced(
                 &standing_sign_block(&name, rotation, arrow),
-                Point3D::new(cell.x, sign_y, cell.y),
+                LocalPoint3D::new(cell.x, sign_y, cell.y),
             )
             .await;
     }
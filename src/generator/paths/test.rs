@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use lerp::num_traits::Signed;
-    use crate::{editor::World, generator::{data::LoadedData, materials::MaterialId, paths::{a_star, building::build_path, path::PathPriority, routing::{get_path, route_path}}}, geometry::Point3D, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
+    use crate::{editor::World, generator::{data::LoadedData, materials::MaterialId, paths::{a_star, building::build_path, path::PathPriority, routing::{get_path, route_path}}}, geometry::{LocalPoint3D, Point3D}, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
     use std::time::Instant;
 
     #[tokio::test]
@@ -62,11 +62,11 @@ mod tests {
 
         
         let path = route_path(&editor, start, end, async |point : &Vec<Point3D>| {
-            editor2.place_block(&"pink_wool".into(), *point.iter().last().unwrap()).await
+            editor2.place_block(&"pink_wool".into(), LocalPoint3D(*point.iter().last().unwrap())).await
         }).await.expect("Failed to route path");
 
         for point in path {
-            editor.place_block(&"red_wool".into(), point).await;
+            editor.place_block(&"red_wool".into(), LocalPoint3D(point)).await;
         }
 
         editor.flush_buffer().await;
@@ -172,4 +172,34 @@ mod tests {
             assert!((lamp.y - 32).abs() >= 1, "lamp at {:?} on the alley centreline", lamp);
         }
     }
+
+    /// Offline: a road paves under a jetty that claims only the air above it,
+    /// skips a house that claims its whole column, and claims just its own band.
+    #[tokio::test]
+    async fn road_paves_under_a_jetty_offline() {
+        use crate::generator::{buildings::BuildingID, paths::{build_paths_merged, claim_road, Path, PathType}, BuildClaim};
+        use crate::geometry::{Point2D, Rect3D};
+
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(63, 127, 63));
+        let mut world = World::synthetic(build_area, 64);
+        let jetty = Point2D::new(15, 32);
+        let house = Point2D::new(20, 32);
+        world.claim_range(jetty, 70..80, BuildClaim::Building(BuildingID(0)));
+        world.claim(house, BuildClaim::Building(BuildingID(1)));
+        let mut editor = world.get_offline_editor();
+        let data = LoadedData::load().expect("Failed to load data");
+
+        let pts: Vec<Point3D> = (10..=30).map(|x| Point3D::new(x, 64, 32)).collect();
+        let road = Path::new(pts, 1, MaterialId::new("cobblestone".to_string()), PathPriority::High);
+        build_paths_merged(&editor, &data, std::slice::from_ref(&road), &mut RNG::new(crate::noise::Seed(7))).await;
+
+        assert!(editor.get_cached_block(LocalPoint3D::new(15, 63, 32)).is_some(), "road should pave under the jetty");
+        assert!(editor.get_cached_block(LocalPoint3D::new(20, 63, 32)).is_none(), "road must not pave into the house");
+
+        claim_road(editor.world_mut(), road.points().iter().map(|p| p.drop_y()), PathType::Road);
+        let world = editor.world();
+        assert_eq!(world.get_claim(jetty), Some(BuildClaim::Path(PathType::Road)));
+        assert_eq!(world.get_claim_at(Point3D::new(15, 72, 32)), Some(BuildClaim::Building(BuildingID(0))));
+        assert!(!world.is_claimed_in(Point2D::new(12, 32), 70..80), "the air above a plain road stays free");
+    }
 }
\ No newline at end of file
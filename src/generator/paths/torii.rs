@@ -13,7 +13,7 @@ use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
 use crate::generator::buildings_v2::Culture;
-use crate::geometry::{Cardinal, Point2D, Point3D};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D};
 use crate::minecraft::Block;
 
 use super::path::Path;
@@ -120,35 +120,35 @@ async fn build_torii(editor: &Editor, center: Point3D, road_dir: Cardinal) {
         let Some(ground) = editor.world().add_height(foot) else { continue; };
         let ground_y = ground.y;
         for y in ground_y.min(post_top)..=post_top {
-            editor.place_block_forced(&red, at(side, y)).await;
+            editor.place_block_forced(&red, LocalPoint3D(at(side, y))).await;
         }
     }
 
     // Nuki: the lower tie-beam joining the posts a block below their tops.
     let nuki_y = base_y + POST_HEIGHT - 2;
     for t in -POST_OFFSET..=POST_OFFSET {
-        editor.place_block_forced(&red, at(t, nuki_y)).await;
+        editor.place_block_forced(&red, LocalPoint3D(at(t, nuki_y))).await;
     }
 
     // Gakuzuka: the short central strut between the tie-beam and the lintel.
     let kasagi_y = base_y + POST_HEIGHT;
     for y in (nuki_y + 1)..kasagi_y {
-        editor.place_block_forced(&red, at(0, y)).await;
+        editor.place_block_forced(&red, LocalPoint3D(at(0, y))).await;
     }
 
     // Kasagi: the lintel, overhanging the posts by one cell on each end.
     let end = POST_OFFSET + 1;
     for t in -end..=end {
-        editor.place_block_forced(&black, at(t, kasagi_y)).await;
+        editor.place_block_forced(&black, LocalPoint3D(at(t, kasagi_y))).await;
     }
 
     // Upturned tips: a stair one block above each lintel end, its tall side
     // facing outward so the corner sweeps up and away — the torii's flared roof.
     editor
-        .place_block_forced(&upturn_stair(perp.opposite()), at(end, kasagi_y + 1))
+        .place_block_forced(&upturn_stair(perp.opposite()), LocalPoint3D(at(end, kasagi_y + 1)))
         .await;
     editor
-        .place_block_forced(&upturn_stair(perp), at(-end, kasagi_y + 1))
+        .place_block_forced(&upturn_stair(perp), LocalPoint3D(at(-end, kasagi_y + 1)))
         .await;
 }
 
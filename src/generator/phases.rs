@@ -0,0 +1,206 @@
+//! The phase graph of a [`generate_town`](super::settlement::generate_town)
+//! run. Each [`TownPhase`] is a named unit that declares the phases whose
+//! output it reads, so a [`PhaseSelection`] (`--only`, `--skip`, `--from`,
+//! `--until`) can run a subset of the town, and a single pass can be re-run on
+//! a world resumed from a [checkpoint](super::checkpoint).
+
+use anyhow::bail;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TownPhase {
+    Parcels,
+    Economy,
+    Terrain,
+    Wall,
+    RuralBuildings,
+    RuralRoads,
+    Torii,
+    ProductionAreas,
+    Industrial,
+    Roads,
+    Houses,
+    StreetLights,
+    StreetSigns,
+    OpenSpaces,
+    PlazaFixtures,
+    Population,
+    WorkerFixtures,
+    TownMap,
+    Welcome,
+    Chronicle,
+    Ships,
+}
+
+/// One phase's place in the graph. `needs` lists only the phases whose output
+/// it reads — from the world (parcels, claims, gates) or from the run's
+/// state — not ones it merely runs after, so `--skip terrain` still builds an
+/// (unflattened) town.
+pub struct PhaseSpec {
+    pub phase: TownPhase,
+    /// Provenance subsystem of the blocks it places; its CLI name is this
+    /// without the `settlement::` prefix.
+    pub subsystem: &'static str,
+    pub needs: &'static [TownPhase],
+    /// What it leaves for later phases.
+    pub makes: &'static str,
+}
+
+use TownPhase::*;
+
+/// Every phase, in run order.
+pub const TOWN_PHASES: [PhaseSpec; 21] = [
+    PhaseSpec { phase: Parcels, subsystem: "settlement::parcels", needs: &[], makes: "parcels, districts and the urban footprint" },
+    PhaseSpec { phase: Economy, subsystem: "settlement::economy", needs: &[Parcels], makes: "the rural production plan" },
+    PhaseSpec { phase: Terrain, subsystem: "settlement::terrain", needs: &[Parcels], makes: "logged, drained and flattened urban ground" },
+    PhaseSpec { phase: Wall, subsystem: "settlement::wall", needs: &[Parcels], makes: "the wall, its gates and tower posts" },
+    PhaseSpec { phase: RuralBuildings, subsystem: "settlement::rural_buildings", needs: &[Economy], makes: "the placed rural buildings" },
+    PhaseSpec { phase: RuralRoads, subsystem: "settlement::rural_roads", needs: &[RuralBuildings, Wall], makes: "the rural roads" },
+    PhaseSpec { phase: Torii, subsystem: "settlement::torii", needs: &[RuralRoads], makes: "torii over the rural roads" },
+    PhaseSpec { phase: ProductionAreas, subsystem: "settlement::production_areas", needs: &[RuralBuildings], makes: "painted production areas" },
+    PhaseSpec { phase: Industrial, subsystem: "settlement::industrial", needs: &[Parcels], makes: "industrial buildings and the barrier around them" },
+    PhaseSpec { phase: Roads, subsystem: "settlement::roads", needs: &[Wall, Industrial], makes: "the road network, city blocks and lots" },
+    PhaseSpec { phase: Houses, subsystem: "settlement::houses", needs: &[Roads], makes: "houses, manor sign sites and resident anchors" },
+    PhaseSpec { phase: StreetLights, subsystem: "settlement::street_lights", needs: &[Roads], makes: "street lamps" },
+    PhaseSpec { phase: StreetSigns, subsystem: "settlement::street_signs", needs: &[Roads], makes: "road names and street signs" },
+    PhaseSpec { phase: OpenSpaces, subsystem: "settlement::open_spaces", needs: &[Parcels], makes: "plazas, parks, their names and NPC scenes" },
+    PhaseSpec { phase: PlazaFixtures, subsystem: "settlement::plaza_fixtures", needs: &[OpenSpaces], makes: "plaza NPCs" },
+    PhaseSpec { phase: Population, subsystem: "settlement::population", needs: &[Houses], makes: "residents, manor families and unfilled workplace posts" },
+    PhaseSpec { phase: WorkerFixtures, subsystem: "settlement::worker_fixtures", needs: &[Population, OpenSpaces, Industrial, RuralBuildings], makes: "workers and guards" },
+    PhaseSpec { phase: TownMap, subsystem: "settlement::town_map", needs: &[Wall, Roads, Houses, StreetSigns, OpenSpaces], makes: "output/town.svg, town.png, the map item and the map boards" },
+    PhaseSpec { phase: Welcome, subsystem: "settlement::welcome", needs: &[Houses, OpenSpaces], makes: "the settlement name and its welcome title" },
+    PhaseSpec { phase: Chronicle, subsystem: "settlement::chronicle", needs: &[Economy, Houses, StreetSigns, OpenSpaces, Population, Welcome], makes: "the chronicle book" },
+    PhaseSpec { phase: Ships, subsystem: "settlement::ships", needs: &[Parcels], makes: "ships and their crews" },
+];
+
+impl TownPhase {
+    pub fn spec(self) -> &'static PhaseSpec {
+        TOWN_PHASES.iter().find(|spec| spec.phase == self).expect("every phase has a spec")
+    }
+
+    pub fn name(self) -> &'static str {
+        self.spec().subsystem.trim_start_matches("settlement::")
+    }
+
+    pub fn from_name(name: &str) -> anyhow::Result<Self> {
+        match TOWN_PHASES.iter().find(|spec| spec.phase.name() == name) {
+            Some(spec) => Ok(spec.phase),
+            None => bail!(
+                "Unknown phase `{name}`; phases are {}",
+                TOWN_PHASES.iter().map(|spec| spec.phase.name()).collect::<Vec<_>>().join(", "),
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for TownPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Which phases of a town to run. The default runs every phase a fresh run
+/// or a resumed checkpoint hasn't run yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseSelection {
+    /// Run just these (in run order), whether or not they ran before.
+    pub only: Vec<TownPhase>,
+    pub skip: Vec<TownPhase>,
+    /// Start here instead of after the last phase that ran.
+    pub from: Option<TownPhase>,
+    /// Stop after this phase.
+    pub until: Option<TownPhase>,
+}
+
+impl PhaseSelection {
+    /// Reads `--only a,b`, `--skip a,b`, `--from a` and `--until a`.
+    pub fn from_args(args: &[String]) -> anyhow::Result<Self> {
+        let flag = |name: &str| args.iter().position(|arg| arg == name).and_then(|i| args.get(i + 1));
+        let list = |name: &str| -> anyhow::Result<Vec<TownPhase>> {
+            flag(name).map_or(Ok(Vec::new()), |names| names.split(',').map(|n| TownPhase::from_name(n.trim())).collect())
+        };
+        let selection = Self {
+            only: list("--only")?,
+            skip: list("--skip")?,
+            from: flag("--from").map(|n| TownPhase::from_name(n)).transpose()?,
+            until: flag("--until").map(|n| TownPhase::from_name(n)).transpose()?,
+        };
+        if !selection.only.is_empty() && (selection.from.is_some() || selection.until.is_some()) {
+            bail!("--only can't be combined with --from or --until");
+        }
+        Ok(selection)
+    }
+
+    /// The phases to run, in order, given the ones that already ran. Fails if
+    /// a phase needs one that neither ran before nor runs ahead of it.
+    pub fn plan(&self, done: &[TownPhase]) -> anyhow::Result<Vec<TownPhase>> {
+        let mut plan: Vec<TownPhase> = TOWN_PHASES.iter().map(|spec| spec.phase).collect();
+        if !self.only.is_empty() {
+            plan.retain(|phase| self.only.contains(phase));
+        } else {
+            match self.from {
+                Some(from) => {
+                    plan = plan.into_iter().skip_while(|&phase| phase != from).collect();
+                }
+                None => plan.retain(|phase| !done.contains(phase)),
+            }
+            if let Some(until) = self.until {
+                if let Some(i) = plan.iter().position(|&phase| phase == until) {
+                    plan.truncate(i + 1);
+                }
+            }
+        }
+        plan.retain(|phase| !self.skip.contains(phase));
+
+        for (i, phase) in plan.iter().enumerate() {
+            for need in phase.spec().needs {
+                if !done.contains(need) && !plan[..i].contains(need) {
+                    bail!("Phase `{phase}` needs `{need}`, which hasn't run; run it too or resume from a checkpoint that has it");
+                }
+            }
+        }
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn needs_come_earlier_in_run_order() {
+        for (i, spec) in TOWN_PHASES.iter().enumerate() {
+            for need in spec.needs {
+                let at = TOWN_PHASES.iter().position(|s| s.phase == *need).unwrap();
+                assert!(at < i, "{} needs {need}, which runs after it", spec.phase);
+            }
+        }
+    }
+
+    #[test]
+    fn selection_plans_subsets() {
+        let all = PhaseSelection::default().plan(&[]).unwrap();
+        assert_eq!(all.len(), TOWN_PHASES.len());
+
+        let until = PhaseSelection::from_args(&args("tome --until roads --skip terrain,torii")).unwrap();
+        let plan = until.plan(&[]).unwrap();
+        assert_eq!(plan.last(), Some(&Roads));
+        assert!(!plan.contains(&Terrain) && !plan.contains(&Torii));
+
+        // Resuming runs what's left; `--only` re-runs a phase that already ran.
+        let done: Vec<TownPhase> = TOWN_PHASES.iter().map(|s| s.phase).take_while(|&p| p != Houses).collect();
+        assert_eq!(PhaseSelection::default().plan(&done).unwrap().first(), Some(&Houses));
+        let only = PhaseSelection::from_args(&args("tome --only open_spaces")).unwrap();
+        assert!(only.plan(&[]).is_err(), "A fresh world has no parcels to furnish");
+        let all_done: Vec<TownPhase> = TOWN_PHASES.iter().map(|s| s.phase).collect();
+        assert_eq!(only.plan(&all_done).unwrap(), vec![OpenSpaces]);
+
+        assert!(PhaseSelection::from_args(&args("tome --only open_space")).is_err());
+        assert!(PhaseSelection::from_args(&args("tome --skip roads")).unwrap().plan(&[]).is_err());
+    }
+}
@@ -2,19 +2,20 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 use log::{info, warn};
+use serde_derive::{Deserialize, Serialize};
 
 use crate::{
-    editor::Editor,
+    editor::{Editor, Owner},
     generator::{
         BuildClaim,
         data::LoadedData,
         districts::{District, DistrictID, ParcelAnalysis},
         materials::{Palette, Placer},
-        nbts::{Rotation, Structure, StructureID, StructureType, place_structure},
+        nbts::{Rotation, Structure, StructureID, StructureType, load_nbt_structure, place_structure},
         resource_chain::{CompetitionCaps, ParcelResourceAssignment, SettlementProductionResult},
         terrain::{force_height, log_trees},
     },
-    geometry::{Cardinal, Point2D, Point3D, Rect2D},
+    geometry::{Cardinal, Point2D, LocalPoint3D, Point3D, Rect2D},
     minecraft::Block,
     noise::RNG,
 };
@@ -223,6 +224,7 @@ pub async fn place_rural_building(
 /// border ring), so `try_place_rural` no longer paints inline — the caller paints
 /// via [`paint_production_area_for`](crate::generator::resource_chain::paint_production_area_for)
 /// once the roads are down.
+#[derive(Serialize, Deserialize)]
 pub struct PlacedRural {
     pub district: DistrictID,
     pub structure: StructureID,
@@ -617,6 +619,7 @@ fn select_best_candidate(
     const ALL_CARDINALS: [Cardinal; 4] =
         [Cardinal::North, Cardinal::East, Cardinal::South, Cardinal::West];
 
+    let height = structure_height(structure);
     let mut best: Option<(Candidate, CandidateScore, Rect2D)> = None;
     for centre in centres {
         let preferred = nearest_road_direction(*centre, ROAD_SEARCH_RADIUS, editor)
@@ -637,7 +640,7 @@ fn select_best_candidate(
             if !rect_inside_points(&rect, points_2d) {
                 continue;
             }
-            if rect_overlaps_claim(&rect, editor) {
+            if rect_overlaps_claim(&rect, editor, height) {
                 continue;
             }
             if rect_too_close_to_wall(&rect, editor, WALL_BUFFER_RADIUS) {
@@ -775,7 +778,14 @@ async fn execute_placement(
     let offset = Point3D::new(candidate.centre.x, anchor_y, candidate.centre.y);
     let mut placer_rng = rng.derive();
     let mut placer = palette.map(|_| Placer::new(&data.materials, &mut placer_rng));
-    place_structure(
+    // The instance id is minted up front so the placed blocks record it as
+    // their owner; it is only committed to the world once placement succeeds.
+    let instance_id = StructureID {
+        id: editor.world().structures.len() as u32,
+        structure_type: structure.id.clone(),
+    };
+    editor.push_provenance(module_path!(), Some(Owner::Structure(instance_id.clone())));
+    let placed = place_structure(
         editor,
         placer.as_mut(),
         structure,
@@ -786,19 +796,19 @@ async fn execute_placement(
         false,
         false,
     )
-    .await?;
+    .await;
+    editor.pop_provenance();
+    placed?;
 
-    // Step 6 — mint a unique instance id, record it on the world, and claim
-    // the footprint cells. The blend ring is intentionally not claimed.
-    let instance_id = StructureID {
-        id: editor.world().structures.len() as u32,
-        structure_type: structure.id.clone(),
-    };
+    // Step 6 — record the instance id on the world and claim the footprint
+    // cells from the ground up to the structure's top, leaving the air above
+    // free. The blend ring is intentionally not claimed.
     editor.world_mut().structures.push(instance_id.clone());
 
     let claim = BuildClaim::Structure(instance_id.clone());
+    let top = structure_height(structure).map_or(i32::MAX, |height| offset.y - structure.origin.y + height);
     for cell in &footprint_cells {
-        editor.world_mut().claim(*cell, claim.clone());
+        editor.world_mut().claim_range(*cell, i32::MIN..top, claim.clone());
     }
 
     // Step 7 — transform the structure's hand-authored worker anchors into world
@@ -844,9 +854,24 @@ fn rect_inside_points(rect: &Rect2D, points: &HashSet<Point2D>) -> bool {
     rect.iter().all(|p| points.contains(&p))
 }
 
-fn rect_overlaps_claim(rect: &Rect2D, editor: &Editor) -> bool {
+/// Height of the structure's NBT, or `None` if it can't be read.
+fn structure_height(structure: &Structure) -> Option<i32> {
+    load_nbt_structure(&structure.meta.path).ok().map(|nbt| nbt.size[1])
+}
+
+/// Whether anything claims the cells a structure `height` tall would take on
+/// `rect`, from each cell's surface block up. Unknown height checks whole
+/// columns. Claims above it — a wall-walk or bridge overhead — don't count.
+fn rect_overlaps_claim(rect: &Rect2D, editor: &Editor, height: Option<i32>) -> bool {
+    let world = editor.world();
     rect.iter().any(|p| {
-        editor.world().is_in_bounds_2d(p) && editor.world().is_claimed(p)
+        if !world.is_in_bounds_2d(p) {
+            return false;
+        }
+        match (height, world.get_height_at(p)) {
+            (Some(height), Some(ground)) => world.is_claimed_in(p, ground - 1..ground + height),
+            _ => world.is_claimed(p),
+        }
     })
 }
 
@@ -1114,16 +1139,16 @@ async fn build_foundation_skirt(editor: &mut Editor, rect: &Rect2D, target_y: i3
         if skirt_top > natural_y {
             // Downhill: raise a solid material plinth from grade up to the skirt top.
             for y in natural_y..skirt_top {
-                editor.place_block_forced(&material, Point3D::new(p.x, y, p.y)).await;
+                editor.place_block_forced(&material, LocalPoint3D::new(p.x, y, p.y)).await;
             }
         } else if skirt_top < natural_y {
             // Uphill: cut the ground down to the skirt top so the pad isn't walled in.
             for y in skirt_top..natural_y {
-                editor.place_block_forced(&"air".into(), Point3D::new(p.x, y, p.y)).await;
+                editor.place_block_forced(&"air".into(), LocalPoint3D::new(p.x, y, p.y)).await;
             }
         }
         // Cap the new surface (top solid sits at skirt_top - 1) with the material.
-        editor.place_block_forced(&material, Point3D::new(p.x, skirt_top - 1, p.y)).await;
+        editor.place_block_forced(&material, LocalPoint3D::new(p.x, skirt_top - 1, p.y)).await;
         new_heights.insert(Point3D::new(p.x, skirt_top, p.y));
     }
     editor.world_mut().set_heights(&new_heights);
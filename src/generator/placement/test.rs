@@ -16,7 +16,7 @@ mod tests {
             resource_chain::paint_production_area,
             terrain::log_trees,
         },
-        geometry::{Point2D, Point3D},
+        geometry::{Point2D, LocalPoint3D, Point3D},
         http_mod::{GDMCHTTPProvider, HeightMapType},
         minecraft::Block,
         noise::{Seed, RNG},
@@ -720,9 +720,8 @@ mod tests {
         force_height(&mut editor, &corridor_pts, false).await;
         // `build_paths_merged` returns the exact cells where it laid a half-step
         // slab (the grade lips). We drive door-floor raising and threshold clearing
-        // off this set instead of reading the placed road back out of the editor
-        // (whose block cache is keyed by local coords while get_block subtracts the
-        // build-area origin — reading there returns world terrain, not the road).
+        // off this set instead of reading the placed road back out of the editor,
+        // which would also match slabs that were already in the terrain.
         let road_slabs: HashSet<Point3D> = build_paths_merged(&editor, &data, &all_paths, &mut rng).await;
         // Per-cell slab height for the alignment probe.
         let slab_y_by_cell: HashMap<Point2D, i32> =
@@ -793,7 +792,7 @@ mod tests {
             for (c, (_, _, rid, y)) in &label {
                 named_roads.insert(*rid);
                 let wool: Block = WOOL_COLORS[*rid as usize % WOOL_COLORS.len()].into();
-                editor.place_block_forced(&wool, Point3D::new(c.x, y + LABEL_HEIGHT, c.y)).await;
+                editor.place_block_forced(&wool, LocalPoint3D::new(c.x, y + LABEL_HEIGHT, c.y)).await;
             }
             println!("Labelled {} named roads", named_roads.len());
         }
@@ -916,10 +915,8 @@ mod tests {
                         // (= door sill) to the routed height of the *nearest* road cell.
                         // Probe outward from every frontage cell and keep the closest
                         // road-height hit. (Uses road_h — the routed integer height —
-                        // NOT a live block read: the editor's block cache is keyed by
-                        // local coords while get_block subtracts the build-area origin,
-                        // so reading placed road blocks here returns world terrain, not
-                        // the road. See note below on the slab detection.)
+                        // not a live block read, so the probe only finds road the
+                        // router laid. See note below on the slab detection.)
                         let road_dir = P2::from(frontage.outward);
                         let base_lvl = {
                             let mut best: Option<(i32, i32, P2)> = None; // (dist, height, road cell)
@@ -1036,7 +1033,7 @@ mod tests {
             for dy in 0..=1 {
                 let q = Point3D::new(p.x, p.y + dy, p.z);
                 if road_slabs.contains(&q) {
-                    editor.place_block_forced(&"air".into(), q).await;
+                    editor.place_block_forced(&"air".into(), LocalPoint3D(q)).await;
                     cleared_door_slabs += 1;
                 }
             }
@@ -1055,7 +1052,7 @@ mod tests {
         for (ti, cells) in tier_verge.iter().enumerate() {
             for c in cells {
                 let h = editor.world().get_ocean_floor_height_at(*c).expect("test cell in bounds");
-                editor.place_block(&verge_blocks[ti], Point3D::new(c.x, h - 1, c.y)).await;
+                editor.place_block(&verge_blocks[ti], LocalPoint3D::new(c.x, h - 1, c.y)).await;
                 verge_total += 1;
             }
         }
@@ -1396,12 +1393,12 @@ mod tests {
                     .unwrap_or(false);
 
                 if on_super_edge {
-                    editor.place_block(&bedrock, Point3D::new(x, height, z)).await;
+                    editor.place_block(&bedrock, LocalPoint3D::new(x, height, z)).await;
                 } else if on_parcel_edge && height >= 1 {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height - 1, z)).await;
                 } else {
-                    editor.place_block(block, Point3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height, z)).await;
                 }
             }
         }
@@ -1597,12 +1594,12 @@ mod tests {
                     .unwrap_or(false);
 
                 if on_super_edge {
-                    editor.place_block(&bedrock, Point3D::new(x, height, z)).await;
+                    editor.place_block(&bedrock, LocalPoint3D::new(x, height, z)).await;
                 } else if on_parcel_edge && height >= 1 {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height - 1, z)).await;
                 } else {
-                    editor.place_block(block, Point3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height, z)).await;
                 }
             }
         }
@@ -37,7 +37,7 @@
 
 use std::collections::HashMap;
 
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::data::load_yaml;
 use crate::editor::Editor;
@@ -63,7 +63,7 @@ const HOUSE_WEIGHT_DECAY: f32 = 0.5;
 
 /// Who may fill an anchor slot. Deserialized from furniture `anchors:` specs
 /// (snake_case: `resident`, `worker`, `idle`).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SlotRole {
     /// Any town resident from the roster.
@@ -80,7 +80,7 @@ pub enum SlotRole {
 /// `AdultOnly`: most posts are adult work (anvils, stalls, guard posts), so a
 /// slot opts *into* children. Mark domestic and social slots `any_age`, and
 /// child-led scenes (playing in the yard) `child_only`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Occupant {
     AdultOnly,
@@ -96,7 +96,7 @@ impl Default for Occupant {
 
 /// One person's spot within a scene: where they stand, which way they face, and
 /// what kind of NPC belongs there.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AnchorSlot {
     /// Build-area-local feet position — must be a walkable cell.
     pub pos: Point3D,
@@ -156,7 +156,7 @@ impl AnchorSlot {
 
 /// What a scene depicts. Deserialized from furniture `anchors:` specs
 /// (snake_case: `solo`, `conversation`, `table`, …).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SceneKind {
     Solo,
@@ -171,7 +171,7 @@ pub enum SceneKind {
 }
 
 /// A group of slots filled as a unit.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AnchorScene {
     pub kind: SceneKind,
     pub slots: Vec<AnchorSlot>,
@@ -294,7 +294,7 @@ pub type NpcId = u32;
 /// Hands out fresh [`NpcId`]s. One allocator threads through the whole town's
 /// generation (residents, workplace fixtures, guards) so every NPC has a
 /// unique id regardless of which subsystem spawned it.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct IdAllocator {
     next: u32,
 }
@@ -334,7 +334,7 @@ pub enum LifeStage {
 /// (wealthy households more often house servants/lodgers and multigen
 /// elders; poor lean solo / sibling / lodger). Ordered Poor < … < Elite so
 /// callers can compare with `>=`/`<` when expressing thresholds.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Wealth {
     /// Cottage tier — rural / outskirts / subsistence.
     Poor,
@@ -1820,6 +1820,7 @@ pub fn build_roster(
 /// `POPULATION_PER_BED` in `settlement`), so a double bed counts for two.
 /// `wealth` is the building's SizeClass mapped through
 /// [`Wealth::from_size_class`] and drives household-shape and employment skew.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct HouseAnchors {
     pub scenes: Vec<AnchorScene>,
     pub population: usize,
@@ -2149,6 +2150,7 @@ pub async fn populate_npcs(
 /// The settlement layer's claim scan produces these (see `discover_worker_slots`)
 /// and [`bind_workers`] consumes them; posts left unfilled are handed back so the
 /// caller can backfill them with anonymous fixtures.
+#[derive(Serialize, Deserialize)]
 pub struct WorkerSlot {
     pub stand: Point3D,
     pub facing: f32,
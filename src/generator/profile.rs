@@ -0,0 +1,160 @@
+//! Settlement profiles: the knobs of a [`generate_town`](super::settlement::generate_town)
+//! run that a designer tunes per map — population density, wall size, terrain
+//! grading, infrastructure materials, the fleet — read from YAML so a town can
+//! be tuned for a competition map without recompiling, and the profile checked
+//! in next to the seeds it was tuned on. Every field is optional; a missing one
+//! keeps the built-in value, so an empty profile is the default town.
+
+use anyhow::Context;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::data::load_yaml;
+use crate::generator::buildings_v2::Culture;
+use crate::generator::ships::fleet::FleetTuning;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettlementProfile {
+    /// Residents per bed of sleeping capacity. A house's population budget is
+    /// `max(1, round(beds * population_per_bed))`, so at 1.5 a single-bed
+    /// house houses ~2 and a double bed (which sleeps two) ~3.
+    pub population_per_bed: f32,
+    /// Blocks beyond the urban footprint the tree-clear reaches, so gate
+    /// approaches and rural-road on-ramps aren't left in standing forest.
+    pub urban_log_apron: u32,
+    /// Towns with at most this many urban super-parcels get a palisade;
+    /// larger ones the standard stone wall with an inner ring.
+    pub palisade_max_urban: usize,
+    /// Manors seeded on arterial lots, each with its own family colour.
+    pub manor_cap: usize,
+    /// Towns seeded in the build area, each with its own culture, wall, name and
+    /// population, joined by highways. Fewer are built where the terrain can't
+    /// fit them `town_spacing` apart.
+    pub towns: usize,
+    /// Least distance in blocks between two towns' cores.
+    pub town_spacing: i32,
+    pub flatten: FlattenProfile,
+    pub infrastructure: InfrastructureProfile,
+    pub ships: FleetTuning,
+}
+
+/// The feathered urban flatten
+/// ([`flatten_urban_area`](super::terrain::flatten_urban_area)).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlattenProfile {
+    /// Width of the blend from the graded town out to natural terrain.
+    pub feather: i32,
+    pub smooth_iters: usize,
+}
+
+/// Wall and road materials per culture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InfrastructureProfile {
+    pub medieval: InfrastructureMaterials,
+    pub desert: InfrastructureMaterials,
+    pub japanese: InfrastructureMaterials,
+}
+
+/// Material ids for a culture's stone wall (a palisade takes the local wood
+/// instead), its arterial roads and its collector roads.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfrastructureMaterials {
+    pub wall: String,
+    pub arterial: String,
+    pub collector: String,
+}
+
+impl Default for SettlementProfile {
+    fn default() -> Self {
+        Self {
+            population_per_bed: 1.5,
+            urban_log_apron: 10,
+            palisade_max_urban: 2,
+            manor_cap: 2,
+            towns: 1,
+            town_spacing: 160,
+            flatten: FlattenProfile::default(),
+            infrastructure: InfrastructureProfile::default(),
+            ships: FleetTuning::default(),
+        }
+    }
+}
+
+impl Default for FlattenProfile {
+    fn default() -> Self {
+        Self { feather: 16, smooth_iters: 12 }
+    }
+}
+
+impl Default for InfrastructureProfile {
+    /// A desert town gets sandstone roads and walls; a Japanese town a
+    /// blackstone wall to match its palette-skinned towers, with deepslate
+    /// roads (refined brick arterials, cobbled collectors) under the dark
+    /// town; everyone else the default stone/cobble.
+    fn default() -> Self {
+        Self {
+            medieval: InfrastructureMaterials::new("stone_bricks", "stone_bricks", "cobblestone"),
+            desert: InfrastructureMaterials::new("smooth_sandstone", "smooth_sandstone", "sandstone"),
+            japanese: InfrastructureMaterials::new("polished_blackstone_bricks", "deepslate_bricks", "cobbled_deepslate"),
+        }
+    }
+}
+
+impl Default for InfrastructureMaterials {
+    fn default() -> Self {
+        InfrastructureProfile::default().medieval
+    }
+}
+
+impl InfrastructureMaterials {
+    fn new(wall: &str, arterial: &str, collector: &str) -> Self {
+        Self { wall: wall.to_string(), arterial: arterial.to_string(), collector: collector.to_string() }
+    }
+}
+
+impl InfrastructureProfile {
+    pub fn for_culture(&self, culture: Culture) -> &InfrastructureMaterials {
+        match culture {
+            Culture::Medieval => &self.medieval,
+            Culture::Desert => &self.desert,
+            Culture::Japanese => &self.japanese,
+        }
+    }
+}
+
+impl SettlementProfile {
+    /// Reads a profile from `path`, relative to the data directory
+    /// (`profiles/competition.yaml`) unless absolute.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        load_yaml(path).with_context(|| format!("Failed to load settlement profile {path}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_overrides_only_what_it_names() {
+        let profile: SettlementProfile = serde_yaml::from_str(
+            "manor_cap: 4\nflatten:\n  feather: 8\ninfrastructure:\n  desert:\n    wall: red_sandstone\n    arterial: red_sandstone\n    collector: red_sandstone\nships:\n  ship_chance_per_district: 100\n",
+        )
+        .unwrap();
+        let default = SettlementProfile::default();
+        assert_eq!(profile.manor_cap, 4);
+        assert_eq!(profile.flatten, FlattenProfile { feather: 8, ..default.flatten.clone() });
+        assert_eq!(profile.infrastructure.for_culture(Culture::Desert).wall, "red_sandstone");
+        assert_eq!(profile.infrastructure.japanese, default.infrastructure.japanese);
+        assert_eq!(profile.ships.ship_chance_per_district, 100);
+        assert_eq!(profile.ships.ship_lengths, default.ships.ship_lengths);
+        assert_eq!(profile.population_per_bed, default.population_per_bed);
+    }
+
+    #[test]
+    fn example_profile_is_the_default() {
+        let example = SettlementProfile::load("profiles/default.yaml").unwrap();
+        assert_eq!(example, SettlementProfile::default(), "data/profiles/default.yaml drifted from the built-in values");
+    }
+}
@@ -0,0 +1,116 @@
+//! Run reports: what a [`generate_town`](super::settlement::generate_town) run
+//! did, as JSON written next to its town map (`output/town.json`, then
+//! `town_2.json` for a second town), so two runs can be diffed and a series of
+//! them charted. The phases record into the report as they go — how long they
+//! took, what they counted, what failed and why — and the run fills in the rest
+//! (seed, culture, wall, economy, population, [`TownMetrics`]) when it ends.
+
+use std::{collections::BTreeMap, path::Path, time::Duration};
+
+use anyhow::Context;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{ai::AiCall, generator::{buildings_v2::Culture, phases::TownPhase, sweep::TownMetrics}};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub seed: i64,
+    pub culture: Option<Culture>,
+    /// Which of the build area's towns this is, the first being 0.
+    pub town: usize,
+    pub name: Option<String>,
+    /// The wall the town got, `Palisade` or `StandardWithInner`.
+    pub wall: Option<String>,
+    /// The resource each rural district was given, and the building gathering it.
+    pub resources: Vec<ResourceAssignment>,
+    /// Residents the houses were budgeted for.
+    pub population: Option<usize>,
+    /// Every phase this run and the runs it resumed ran, in order.
+    pub phases: Vec<PhaseReport>,
+    pub metrics: Option<TownMetrics>,
+    /// Every model call the chronicle made, with the tokens it took.
+    #[serde(default)]
+    pub ai_calls: Vec<AiCall>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceAssignment {
+    pub district: usize,
+    pub resource: String,
+    pub building: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseReport {
+    pub phase: TownPhase,
+    pub seconds: f64,
+    /// Whatever the phase counted, by name: `"road_segments"`, `"houses_placed"`.
+    pub counts: BTreeMap<String, usize>,
+    /// Failures by reason.
+    pub failures: BTreeMap<String, usize>,
+}
+
+impl RunReport {
+    /// Starts recording `phase`; counts and failures go to it until the next.
+    pub fn begin(&mut self, phase: TownPhase) {
+        self.phases.push(PhaseReport { phase, seconds: 0.0, counts: BTreeMap::new(), failures: BTreeMap::new() });
+    }
+
+    /// Closes the phase being recorded, which took `elapsed`.
+    pub fn end(&mut self, elapsed: Duration) {
+        if let Some(phase) = self.phases.last_mut() {
+            phase.seconds = elapsed.as_secs_f64();
+        }
+    }
+
+    /// Records `value` under `name` for the current phase.
+    pub fn count(&mut self, name: &str, value: usize) {
+        if let Some(phase) = self.phases.last_mut() {
+            phase.counts.insert(name.to_string(), value);
+        }
+    }
+
+    /// Counts one failure of the current phase under `reason`.
+    pub fn fail(&mut self, reason: impl Into<String>) {
+        if let Some(phase) = self.phases.last_mut() {
+            *phase.failures.entry(reason.into()).or_default() += 1;
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_record_their_own_counts_and_failures() {
+        let mut report = RunReport::default();
+        // Nothing to record into before the first phase begins.
+        report.count("ignored", 1);
+        report.begin(TownPhase::Roads);
+        report.count("road_segments", 12);
+        report.end(Duration::from_millis(1500));
+        report.begin(TownPhase::Houses);
+        report.fail("invariant (b)");
+        report.fail("invariant (b)");
+        report.count("houses_placed", 30);
+
+        assert_eq!(report.phases.len(), 2);
+        assert_eq!(report.phases[0].seconds, 1.5);
+        assert_eq!(report.phases[0].counts, BTreeMap::from([("road_segments".to_string(), 12)]));
+        assert!(report.phases[0].failures.is_empty());
+        assert_eq!(report.phases[1].failures["invariant (b)"], 2);
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains(r#""phase":"roads""#), "{json}");
+        assert_eq!(serde_json::from_str::<RunReport>(&json).unwrap(), report);
+    }
+}
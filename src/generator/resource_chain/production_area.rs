@@ -12,7 +12,7 @@ use crate::{
         materials::{MaterialRole, PaletteId},
         terrain::{feathered_flatten, group_trees, log_trees},
     },
-    geometry::{cardinal_to_str, Point2D, Point3D, CARDINALS_2D, ALL_8},
+    geometry::{cardinal_to_str, Point2D, LocalPoint3D, Point3D, CARDINALS_2D, ALL_8},
     minecraft::{Block, BlockForm, BlockID},
     noise::RNG,
 };
@@ -306,7 +306,7 @@ async fn logging_production_painter(
             continue;
         };
         let stump_pos = tree.trunk.add_y(stump_y);
-        stumps.push((stump_pos, editor.get_block(stump_pos)));
+        stumps.push((stump_pos, editor.get_block(LocalPoint3D(stump_pos))));
         to_log.extend(tree.cells.iter().copied());
     }
 
@@ -317,7 +317,7 @@ async fn logging_production_painter(
     let stump_cells: Vec<Point2D> = stumps.iter().map(|(p, _)| p.drop_y()).collect();
 
     for (pos, block) in stumps {
-        editor.place_block(&block, pos).await;
+        editor.place_block(&block, LocalPoint3D(pos)).await;
     }
 
     // Claim all free cells for this production area.
@@ -604,9 +604,9 @@ async fn sugarcane_production_painter(
         };
         let y = ch - 1;
         let pos = Point3D::new(c.x, y, c.y);
-        let current = editor.get_block(pos);
+        let current = editor.get_block(LocalPoint3D(pos));
         if !can_support_sugar_cane(&current.id) {
-            editor.place_block_forced(&fallback_cane_soil(&current), pos).await;
+            editor.place_block_forced(&fallback_cane_soil(&current), LocalPoint3D(pos)).await;
         }
     }
     // Commit the soil walls before any water exists (no transient flow on flush).
@@ -631,14 +631,14 @@ async fn sugarcane_production_painter(
             };
             let wy = wh - 1;
             // Floor must be solid.
-            if !is_solid_support(&editor.get_block(Point3D::new(w.x, wy - 1, w.y))) {
+            if !is_solid_support(&editor.get_block(LocalPoint3D::new(w.x, wy - 1, w.y))) {
                 demote.push(w);
                 continue;
             }
             // Every side must be a solid wall, or a same-level water neighbour.
             let boxed = CARDINALS_2D.iter().all(|&d| {
                 let n = w + d;
-                if is_solid_support(&editor.get_block(Point3D::new(n.x, wy, n.y))) {
+                if is_solid_support(&editor.get_block(LocalPoint3D::new(n.x, wy, n.y))) {
                     return true;
                 }
                 water_set.contains(&n)
@@ -663,7 +663,7 @@ async fn sugarcane_production_painter(
             continue;
         };
         let wy = wh - 1;
-        editor.place_block_forced(&water_block, Point3D::new(w.x, wy, w.y)).await;
+        editor.place_block_forced(&water_block, LocalPoint3D::new(w.x, wy, w.y)).await;
     }
     editor.flush_buffer().await;
 
@@ -700,7 +700,7 @@ async fn sugarcane_production_painter(
                 Some(HashMap::from([("age".to_string(), age.to_string())])),
                 None,
             );
-            editor.place_block(&cane, Point3D::new(c.x, cy + i as i32, c.y)).await;
+            editor.place_block(&cane, LocalPoint3D::new(c.x, cy + i as i32, c.y)).await;
         }
         cane_cells.push(c);
     }
@@ -773,7 +773,7 @@ fn find_hive_spot(trunk: Point2D, editor: &Editor) -> Option<(Point3D, Point2D)>
     // Walk up the trunk's logs to find the top of the stem.
     let mut top_y = base_y;
     let mut y = base_y;
-    while editor.get_block(trunk.add_y(y)).id.is_log() {
+    while editor.get_block(LocalPoint3D(trunk.add_y(y))).id.is_log() {
         top_y = y;
         y += 1;
     }
@@ -781,12 +781,12 @@ fn find_hive_spot(trunk: Point2D, editor: &Editor) -> Option<(Point3D, Point2D)>
     for ly in base_y..=top_y {
         for d in CARDINALS_2D {
             let pos = Point3D::new(trunk.x + d.x, ly, trunk.y + d.y);
-            let here = editor.get_block(pos).id;
+            let here = editor.get_block(LocalPoint3D(pos)).id;
             if !(here.is_air() || here.is_leaves()) {
                 continue; // don't carve into a branch or neighbouring trunk
             }
             // Sheltered by the canopy roof directly overhead.
-            let above = editor.get_block(Point3D::new(pos.x, pos.y + 1, pos.z)).id;
+            let above = editor.get_block(LocalPoint3D::new(pos.x, pos.y + 1, pos.z)).id;
             if above.is_leaves() {
                 return Some((pos, d));
             }
@@ -857,7 +857,7 @@ async fn bee_area_production_painter(
         if let Some((pos, facing)) = find_hive_spot(tree.trunk, editor) {
             let hive = make_beehive(facing, bee_names, prefixes, suffixes, rng);
             // Forced so it can take a leaf cell as well as an air pocket.
-            editor.place_block_forced(&hive, pos).await;
+            editor.place_block_forced(&hive, LocalPoint3D(pos)).await;
             hive_trunks.push(tree.trunk);
         }
     }
@@ -923,7 +923,7 @@ fn detect_local_rock(ordered: &[Point2D], editor: &Editor) -> (String, bool) {
         for dy in 0..MINE_GEOLOGY_SCAN_DEPTH {
             // `try_get_block` (not `get_block`) so scanning below the world floor —
             // common for a mine near bedrock — returns None instead of panicking.
-            let Some(block) = editor.try_get_block(Point3D::new(c.x, top - dy, c.y)) else {
+            let Some(block) = editor.try_get_block(LocalPoint3D::new(c.x, top - dy, c.y)) else {
                 break;
             };
             if let Some(rock) = natural_rock_id(&block.id) {
@@ -959,7 +959,7 @@ fn detect_outcrop_rock(center: Point2D, editor: &Editor) -> (String, bool) {
             };
             let top = ch - 1;
             for dy in 0..MINE_GEOLOGY_SCAN_DEPTH {
-                let Some(block) = editor.try_get_block(Point3D::new(cell.x, top - dy, cell.y)) else {
+                let Some(block) = editor.try_get_block(LocalPoint3D::new(cell.x, top - dy, cell.y)) else {
                     break;
                 };
                 if let Some(rock) = natural_rock_id(&block.id) {
@@ -1051,7 +1051,7 @@ async fn place_outcrop(
                 } else {
                     rng.choose_weighted_vec(rocks).clone()
                 };
-                editor.place_block_forced(&block, Point3D::new(cell.x, top + i, cell.y)).await;
+                editor.place_block_forced(&block, LocalPoint3D::new(cell.x, top + i, cell.y)).await;
             }
             occupied.insert(cell);
             editor.world_mut().claim(cell, BuildClaim::ProductionArea(structure_id.clone()));
@@ -1137,7 +1137,7 @@ async fn mine_production_painter(
                 continue;
             };
             let top = ch - 1;
-            editor.place_block_forced(&seam_ore, Point3D::new(c.x, top, c.y)).await;
+            editor.place_block_forced(&seam_ore, LocalPoint3D::new(c.x, top, c.y)).await;
         }
     }
 
@@ -1404,9 +1404,9 @@ async fn pasture_production_painter(
                 .find(|&&d| !free_cells.contains(&(cell + d)))
                 .and_then(cardinal_to_str)
                 .map(|f| HashMap::from([("facing".to_string(), f)]));
-            editor.place_block(&Block::new(gate_id.clone(), state, None), pos).await;
+            editor.place_block(&Block::new(gate_id.clone(), state, None), LocalPoint3D(pos)).await;
         } else {
-            editor.place_block(&fence_block, pos).await;
+            editor.place_block(&fence_block, LocalPoint3D(pos)).await;
         }
         editor.world_mut().claim(cell, BuildClaim::ProductionArea(structure_id.clone()));
     }
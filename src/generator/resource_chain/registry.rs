@@ -1,3 +1,4 @@
+use serde_derive::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::env;
@@ -133,6 +134,7 @@ pub struct ProductionPlan {
 }
 
 /// Full production result for a settlement derived from a set of parcels.
+#[derive(Serialize, Deserialize)]
 pub struct SettlementProductionResult {
     /// The resource each parcel will gather and the building it needs to do so.
     pub parcel_assignments: HashMap<DistrictID, ParcelResourceAssignment>,
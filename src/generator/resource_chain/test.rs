@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use crate::geometry::LocalPoint3D;
     use std::collections::{HashMap, HashSet};
     use std::env;
 
@@ -502,7 +503,7 @@ mod tests {
         use crate::generator::districts::{generate_parcels, ParcelType};
         use crate::generator::resource_chain::ProductionPainter;
         use crate::generator::terrain::{feathered_flatten, flatten_urban_area};
-        use crate::geometry::{Point2D, Point3D};
+        use crate::geometry::Point2D;
         use crate::http_mod::GDMCHTTPProvider;
         use crate::noise::Seed;
         use crate::util::init_logger;
@@ -651,10 +652,10 @@ mod tests {
                 let on_edge = edge_cells.contains(&Point2D::new(x, z));
 
                 if on_edge {
-                    editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                    editor.place_block(block, Point3D::new(x, height - 1, z)).await;
+                    editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height - 1, z)).await;
                 } else {
-                    editor.place_block(block, Point3D::new(x, height, z)).await;
+                    editor.place_block(block, LocalPoint3D::new(x, height, z)).await;
                 }
             }
         }
@@ -698,7 +699,7 @@ mod tests {
         use crate::generator::placement::place_rural_building;
         use crate::generator::resource_chain::paint_production_area;
         use crate::generator::terrain::flatten_urban_area;
-        use crate::geometry::{Point2D, Point3D};
+        use crate::geometry::Point2D;
         use crate::http_mod::GDMCHTTPProvider;
         use crate::noise::Seed;
         use crate::util::init_logger;
@@ -845,17 +846,17 @@ mod tests {
                     ParcelType::Urban | ParcelType::OffLimits => {
                         let block = if matches!(ptype, ParcelType::Urban) { &blue } else { &red };
                         if on_edge {
-                            editor.place_block(&glass, Point3D::new(x, height, z)).await;
-                            editor.place_block(block, Point3D::new(x, height - 1, z)).await;
+                            editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
+                            editor.place_block(block, LocalPoint3D::new(x, height - 1, z)).await;
                         } else {
-                            editor.place_block(block, Point3D::new(x, height, z)).await;
+                            editor.place_block(block, LocalPoint3D::new(x, height, z)).await;
                         }
                     }
                     // Rural / Unknown: keep the painted surface, but cap the border
                     // with glass so district boundaries stay visible.
                     _ => {
                         if on_edge {
-                            editor.place_block(&glass, Point3D::new(x, height, z)).await;
+                            editor.place_block(&glass, LocalPoint3D::new(x, height, z)).await;
                         }
                     }
                 }
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResourceDef {
@@ -34,7 +34,7 @@ pub struct RecipeDef {
 }
 
 /// The resource and gathering building assigned to a single parcel after selection.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParcelResourceAssignment {
     /// The biome-selected raw resource used to identify this parcel's gather recipe
     /// (e.g. `"wood"`). For multi-output gather recipes (e.g. `gather_bees` → honey +
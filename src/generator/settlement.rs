@@ -1,18 +1,31 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use serde_derive::{Deserialize, Serialize};
+
+use crate::ai::AiLog;
 use crate::data::Loadable;
 use crate::editor::Editor;
+use crate::generator::buildings_v2::{Culture, RoomType};
+use crate::generator::buildings_v2::exterior::ManorSignSite;
+use crate::generator::checkpoint::TownCheckpoint;
+use crate::generator::chronicle_copies::{civic_lectern, BookHolder};
+use crate::generator::town_map_copies::{gate_boards, library_board, paint_boards, town_map_data, MapBoard};
 use crate::generator::data::LoadedData;
-use crate::generator::districts::{build_wall, generate_parcels, ParcelType, TowerSkin, WallType};
+use crate::generator::districts::{build_wall, generate_town_parcels, ParcelType, TowerSkin, TownSite, WallType};
 use crate::generator::buildings_v2::style::local_wood_palette;
 use crate::generator::materials::{Material, MaterialId, MaterialRole, Placer};
 use crate::generator::nbts::Structure;
-use crate::generator::paths::{build_paths_merged, build_road_network, build_rural_road_network, find_blocks, Path, PathPriority, RuralBuilding};
+use crate::generator::paths::{build_paths_merged, build_road_network, build_rural_road_network, find_blocks, Path, PathPriority, RoadNetwork, RuralBuilding};
+use crate::generator::phases::{PhaseSelection, TownPhase};
+use crate::generator::profile::SettlementProfile;
+use crate::generator::report::{ResourceAssignment, RunReport};
+use crate::generator::sweep::{failure_reason, TownMetrics};
+use crate::generator::population::{AnchorScene, HouseAnchors, IdAllocator, SceneKind, SlotRole, WorkerSlot};
 use crate::generator::placement::{resolve_rural_production, try_place_rural, PlacedRural};
-use crate::generator::resource_chain::paint_production_area_for;
+use crate::generator::resource_chain::{paint_production_area_for, SettlementProductionResult};
 use crate::generator::terrain::{clear_floating_logs, drain_liquids, flatten_urban_area, force_height, log_trees};
-use crate::geometry::{Point2D, Point3D};
-use crate::minecraft::{Block, Color};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D};
+use crate::minecraft::{Block, BlockForm, Color};
 use crate::noise::{Seed, RNG};
 
 /// Full town-generation pipeline: feathered urban flatten + tiered A* road
@@ -23,25 +36,23 @@ use crate::noise::{Seed, RNG};
 ///
 /// The caller is responsible for constructing the `Editor` (and the `World`
 /// behind it) and for flushing/finalising afterwards beyond the final
-/// `flush_buffer` performed here.
-/// Residents per bed of sleeping capacity. A house's population budget is
-/// `max(1, round(beds * POPULATION_PER_BED))`, so a single-bed house houses ~2
-/// and a double bed (which sleeps two) ~3 — enough to read as lived-in.
-const POPULATION_PER_BED: f32 = 1.5;
-
-/// Blocks beyond the urban footprint the tree-clear reaches, so gate approaches and
-/// rural-road on-ramps aren't left in standing forest. Widens only the *logged*
-/// area — drain/flatten stay on the urban footprint proper.
-const URBAN_LOG_APRON: u32 = 10;
+/// `save` (flush plus backend persist) performed here.
+/// Depth of the frontage ribbon reserved against each main road before a city
+/// block is subdivided, and how far a house probes for the road it fronts.
+/// Deep enough to absorb both the deepest House (depth_range 7..=10) AND
+/// the staircase rise of a diagonal frontage (an axis-aligned rect anchored
+/// at the slice's interior extreme reaches `rise + depth` into the band).
+const RIBBON_DEPTH: i32 = 14;
 
 /// The settlement's colour identity. Picked once per town from the culture's
 /// curated [`Culture::color_pool`], it gives the town two recurring colours plus
 /// a unique family colour per manor, so a street reads as a coherent palette
 /// with two dominant hues and occasional variety rather than 16 random dyes.
+#[derive(Serialize, Deserialize)]
 struct ColorScheme {
     /// The two recurring town colours: `town[0]` is dominant, `town[1]` second.
     town: [Color; 2],
-    /// One family colour per manor (`MANOR_CAP` entries), handed out in
+    /// One family colour per manor (`manor_cap` entries), handed out in
     /// placement order. Drawn freely from the pool — they may coincide with a
     /// town colour, but never with each other.
     manor: Vec<Color>,
@@ -88,6 +99,7 @@ impl ColorScheme {
 /// known after the population pass, so these are gathered when each manor sign is
 /// lettered (where surname + the house's `HouseAnchors` meet) and converted to a
 /// [`Landmark`] in [`assemble_dossier`].
+#[derive(Serialize, Deserialize)]
 struct ManorFact {
     surname: String,
     designation: String,
@@ -414,6 +426,7 @@ fn assemble_dossier(
         produces: produces.to_vec(),
         districts,
         landmarks,
+        neighbours: Vec::new(),
     }
 }
 
@@ -458,11 +471,268 @@ fn dominant_local_wood(biome_map: &[Vec<crate::minecraft::Biome>], data: &Loaded
 pub async fn generate_town(
     editor: &mut Editor,
     seed: Seed,
-    culture: Option<crate::generator::buildings_v2::Culture>,
+    culture: Option<Culture>,
 ) {
-    let mut rng = RNG::new(seed);
-    let mut rng2 = RNG::new(seed);
+    let profile = SettlementProfile::default();
+    if let Err(e) = generate_town_phases(editor, seed, culture, &profile, &PhaseSelection::default(), None).await {
+        log::error!("Town generation failed: {e}");
+    }
+}
+
+/// [`generate_town`] tuned by `profile` and limited to the phases `selection`
+/// picks, writing a
+/// [`TownCheckpoint`] to `checkpoint` after every phase when one is given, so
+/// a run that dies can be finished with [`resume_town`] instead of started
+/// over. Fails before placing anything if a picked phase needs one that isn't.
+/// Returns what every town built came out as, the first town first.
+pub async fn generate_town_phases(
+    editor: &mut Editor,
+    seed: Seed,
+    culture: Option<Culture>,
+    profile: &SettlementProfile,
+    selection: &PhaseSelection,
+    checkpoint: Option<&std::path::Path>,
+) -> anyhow::Result<Vec<TownMetrics>> {
+    let plan = selection.plan(&[])?;
+    let culture = select_culture(editor, seed, culture);
+    if profile.towns > 1 {
+        if checkpoint.is_some() {
+            anyhow::bail!("Checkpoints cover a single town; drop the checkpoint or build one town");
+        }
+        return Ok(run_region(editor, seed, culture, profile, &plan).await);
+    }
+    let run = TownRun::new(seed, culture, profile.clone(), RNG::new(seed), TownState::default());
+    Ok(vec![run_town(editor, run, &plan, checkpoint).await])
+}
+
+/// Continues the town saved in `checkpoint` with the seed, culture, profile
+/// and RNG state it was written with. `editor` must be on the world and build area
+/// the checkpoint came from: the blocks of every phase that ran are already
+/// there, read back with the chunks, so by default only the phases after them
+/// run. `selection` can instead re-run a single pass over the saved town
+/// (`--only open_spaces`). Each phase overwrites `checkpoint` as before.
+pub async fn resume_town(
+    editor: &mut Editor,
+    checkpoint: &std::path::Path,
+    selection: &PhaseSelection,
+) -> anyhow::Result<()> {
+    let TownCheckpoint { seed, culture, profile, world, rng, state } = TownCheckpoint::load(checkpoint)?;
+    let plan = selection.plan(&state.done)?;
+    editor.world_mut().restore(world)?;
+    println!(
+        "Resuming town with seed {} from {}: {}",
+        seed.0, checkpoint.display(), plan.iter().map(|phase| phase.name()).collect::<Vec<_>>().join(", "),
+    );
+    run_town(editor, TownRun::new(seed, culture, profile, rng, state), &plan, Some(checkpoint)).await;
+    Ok(())
+}
+
+/// Settlement culture: an explicit override (tests) wins; otherwise auto-select
+/// from the build area's climate so the town fits its biome while the cultures
+/// stay roughly even across worlds (see `buildings_v2::climate`). The selection
+/// RNG is keyed off the seed independently so it never perturbs the run's RNG.
+fn select_culture(editor: &Editor, seed: Seed, culture: Option<Culture>) -> Culture {
+    culture.unwrap_or_else(|| {
+        let mut culture_rng = RNG::from_seed_and_string(seed, "culture_select");
+        let c = crate::generator::buildings_v2::climate::select_culture(
+            editor.world().get_ground_biome_map(),
+            &mut culture_rng,
+        );
+        println!("Auto-selected culture {c:?} from build-area climate");
+        c
+    })
+}
+
+/// The culture of a town after the first, chosen from the ground inside its own
+/// footprint the way [`select_culture`] chooses from the whole build area.
+fn select_town_culture(editor: &Editor, seed: Seed, site: &TownSite) -> Culture {
+    let map = editor.world().get_ground_biome_map();
+    let biomes: Vec<crate::minecraft::Biome> = site.footprint.iter()
+        .filter_map(|p| map.get(p.x as usize).and_then(|column| column.get(p.y as usize)).cloned())
+        .collect();
+    let mut culture_rng = RNG::from_seed_and_string(seed, "culture_select");
+    crate::generator::buildings_v2::climate::select_culture(&[biomes], &mut culture_rng)
+}
+
+/// A run in progress: what it started with, the main RNG each phase draws
+/// from in turn, and what the phases so far have left.
+struct TownRun {
+    seed: Seed,
+    culture: Culture,
+    profile: SettlementProfile,
+    data: LoadedData,
+    rng: RNG,
+    state: TownState,
+    /// Which of the build area's towns this is (see `World::towns`).
+    town: usize,
+    /// Names the towns before this one took, so it doesn't take one again.
+    taken_names: Vec<String>,
+    /// The other towns, for the chronicle; filled in once all of them are named.
+    neighbours: Vec<crate::generator::chronicle::Neighbour>,
+}
+
+impl TownRun {
+    fn new(seed: Seed, culture: Culture, profile: SettlementProfile, rng: RNG, state: TownState) -> Self {
+        let data = LoadedData::load().expect("Failed to load data");
+        Self { seed, culture, profile, data, rng, state, town: 0, taken_names: Vec::new(), neighbours: Vec::new() }
+    }
+}
+
+/// What the phases of a run leave for the ones after them, each field set by
+/// the phase named on it. What a phase changes in the world (parcels, claims,
+/// gates, heightmaps) stays on the [`World`](crate::editor::World).
+#[derive(Default, Serialize, Deserialize)]
+pub struct TownState {
+    /// The phases that have run, in order.
+    done: Vec<TownPhase>,
+    /// `economy`
+    economy: Option<SettlementProductionResult>,
+    /// `rural_buildings`
+    rural: Option<Vec<PlacedRural>>,
+    /// `rural_buildings`: the parcels the economy gave a building, placed or not.
+    #[serde(default)]
+    rural_sites: usize,
+    /// `rural_roads`
+    rural_paths: Option<Vec<Path>>,
+    /// `industrial`
+    industry: Option<TownIndustry>,
+    /// `roads`
+    layout: Option<TownLayout>,
+    /// `houses`
+    houses: Option<TownHouses>,
+    /// `street_signs`
+    streets: Option<TownStreets>,
+    /// `open_spaces`
+    open_spaces: Option<TownOpenSpaces>,
+    /// `population`
+    population: Option<TownPopulation>,
+    /// `welcome`
+    name: Option<crate::generator::naming::SettlementName>,
+    /// `rural_roads`: the town a highway was laid to, when there are several.
+    #[serde(default)]
+    highway_to: Option<usize>,
+    /// Town-wide NPC id allocator, shared by every staffing phase so every NPC
+    /// has a unique id and kin relationships can reference any of them.
+    id_alloc: IdAllocator,
+    /// What every phase so far recorded, carried across resumes.
+    #[serde(default)]
+    report: RunReport,
+}
+
+/// The output `phase` left in the run's state. Only read by phases that
+/// declare `phase` as a need, which the plan has already checked ran.
+fn need<T>(output: &Option<T>, phase: TownPhase) -> &T {
+    output.as_ref().unwrap_or_else(|| panic!("The {phase} phase hasn't run"))
+}
 
+/// What the industrial phase leaves for the road network.
+#[derive(Serialize, Deserialize)]
+struct TownIndustry {
+    urban_industrial_count: usize,
+    /// Industrial footprints plus a margin, kept clear of roads and lots.
+    blocked: HashSet<Point2D>,
+    /// One network node per industrial building.
+    nodes: Vec<Point3D>,
+}
+
+/// What the roads phase leaves for the houses and the phases after them.
+#[derive(Serialize, Deserialize)]
+struct TownLayout {
+    road_network: RoadNetwork,
+    /// The routed main roads plus one width-1 path over every alley cell.
+    all_paths: Vec<Path>,
+    /// City blocks, the lots subdivided from them, and each lot's block index.
+    blocks: Vec<HashSet<Point2D>>,
+    sub_blocks: Vec<HashSet<Point2D>>,
+    lot_block: Vec<usize>,
+    /// Paved cells per road tier, the frontage each house faces.
+    alley_band: HashSet<Point2D>,
+    arterial_band: HashSet<Point2D>,
+    collector_band: HashSet<Point2D>,
+    /// Road surface height over every road's paved band.
+    #[serde(with = "crate::util::pairs")]
+    road_h: HashMap<Point2D, i32>,
+}
+
+/// What the house phase leaves for the phases after it.
+#[derive(Serialize, Deserialize)]
+struct TownHouses {
+    civic_blazon: String,
+    color_scheme: ColorScheme,
+    manor_sign_sites: Vec<ManorSignSite>,
+    town_anchors: Vec<HouseAnchors>,
+    total_buildings: usize,
+    /// `build_house` failures, bucketed by [`failure_reason`].
+    #[serde(default)]
+    failures: BTreeMap<String, usize>,
+    /// The chests and lecterns of every study and library, for the chronicle's copies.
+    #[serde(default)]
+    book_holders: Vec<BookHolder>,
+    /// A board for the town map on the bare wall of each manor library.
+    #[serde(default)]
+    library_boards: Vec<MapBoard>,
+}
+
+/// Road names by road label, and the cells of the signs naming them.
+#[derive(Serialize, Deserialize)]
+struct TownStreets {
+    road_names: HashMap<u32, String>,
+    signs: Vec<Point2D>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TownOpenSpaces {
+    /// Named plazas and parks, for the town map and the chronicle.
+    place_labels: Vec<(Point2D, String)>,
+    /// NPC standing-spot scenes harvested from the open spaces — plazas (stage
+    /// performers, market vendors, onlookers in the crowd) and parks (idle folk
+    /// strolling the green). Staffed as fixtures after furnishing, independent
+    /// of the resident bed budget — a market or park is busy regardless of how
+    /// many beds the town has.
+    plaza_scenes: Vec<AnchorScene>,
+    /// Open-space landmark keys (plaza/park `.key()`) gathered for the
+    /// settlement namer — a town with a market or graveyard can be named for it.
+    civic_features: Vec<String>,
+    /// Cells over every region furnished, of any type.
+    #[serde(default)]
+    cells: usize,
+    /// The centre of the largest plaza, where the chronicle's lectern stands.
+    #[serde(default)]
+    main_plaza: Option<Point2D>,
+}
+
+/// What the population phase leaves for the worker fixtures and the chronicle.
+#[derive(Serialize, Deserialize)]
+struct TownPopulation {
+    /// Manor families, each paired with the surname its household drew.
+    manor_facts: Vec<ManorFact>,
+    /// Resident headcount (sum of per-house bed budgets), so the chronicle can
+    /// quote the town's size. This is the deterministic target the crowd is
+    /// sized to, computed regardless of the live NPC placement (which is a
+    /// no-op offline).
+    population_count: usize,
+    /// Workplace posts binding couldn't fill from residents, for the worker
+    /// fixtures to backfill.
+    workplace_backfill: Vec<WorkerSlot>,
+    bound_worker_count: usize,
+    workplace_count: usize,
+    /// Every post, resident-filled or not, tallied by trade.
+    worker_by_job: HashMap<String, usize>,
+}
+
+/// Saves the world and, when `path` is set, a [`TownCheckpoint`] of the run to
+/// it. The save comes first so every block the checkpoint accounts for is in
+/// the world before the file says so.
+async fn write_checkpoint(editor: &Editor, path: Option<&std::path::Path>, run: &TownRun) {
+    let Some(path) = path else { return };
+    editor.save().await;
+    match TownCheckpoint::save(path, run.seed, run.culture, &run.profile, editor.world(), &run.rng, &run.state) {
+        Ok(()) => println!("Wrote checkpoint to {}", path.display()),
+        Err(e) => log::error!("Failed to write checkpoint {}: {e}", path.display()),
+    }
+}
+
+async fn prepare_server(editor: &Editor) {
     // Terraforming and block edits go through the HTTP interface with block updates
     // on, which can spawn block-drop item entities (e.g. when a placed block replaces
     // grass/flowers) that pile up and lag the world. Disable blockdrops for the run.
@@ -475,43 +745,323 @@ pub async fn generate_town(
     if let Err(e) = editor.set_difficulty("easy").await {
         log::warn!("Failed to set difficulty to easy: {e}");
     }
+}
 
-    // Settlement culture: an explicit override (tests) wins; otherwise auto-select
-    // from the build area's climate so the town fits its biome while the cultures
-    // stay roughly even across worlds (see `buildings_v2::climate`). The selection
-    // RNG is keyed off the seed independently so it never perturbs `rng`/`rng2`.
-    let culture = culture.unwrap_or_else(|| {
-        let mut culture_rng = RNG::from_seed_and_string(seed, "culture_select");
-        let c = crate::generator::buildings_v2::climate::select_culture(
-            editor.world().get_ground_biome_map(),
-            &mut culture_rng,
-        );
-        println!("Auto-selected culture {c:?} from build-area climate");
-        c
-    });
+async fn run_town(
+    editor: &mut Editor,
+    mut run: TownRun,
+    plan: &[TownPhase],
+    checkpoint: Option<&std::path::Path>,
+) -> TownMetrics {
+    prepare_server(editor).await;
+
+    for &phase in plan {
+        timed_phase(editor, &mut run, phase).await;
+        if !run.state.done.contains(&phase) {
+            run.state.done.push(phase);
+        }
+        write_checkpoint(editor, checkpoint, &run).await;
+    }
 
-    // Infrastructure materials follow the culture: a desert town gets sandstone
-    // roads and walls, a Japanese town a blackstone wall (matching its palette-
-    // skinned towers), everyone else the default stone/cobble.
-    let desert = matches!(culture, crate::generator::buildings_v2::Culture::Desert);
-    let japanese = matches!(culture, crate::generator::buildings_v2::Culture::Japanese);
-    let (wall_mat, arterial_mat, collector_mat): (&str, &str, &str) = if desert {
-        ("smooth_sandstone", "smooth_sandstone", "sandstone")
-    } else if japanese {
-        // Blackstone wall to match the towers (which take the culture palette's
-        // `polished_blackstone_bricks` stone); deepslate roads — refined brick
-        // arterials, cobbled-deepslate collectors — to sit under the dark town.
-        ("polished_blackstone_bricks", "deepslate_bricks", "cobbled_deepslate")
-    } else {
-        ("stone_bricks", "stone_bricks", "cobblestone")
-    };
+    editor.save().await;
+    let metrics = town_metrics(editor, &run);
+    write_report(&mut run, &metrics);
+    metrics
+}
+
+/// [`run_phase`], recorded in the run's report with how long it took.
+async fn timed_phase(editor: &mut Editor, run: &mut TownRun, phase: TownPhase) {
+    editor.begin_phase(phase.spec().subsystem);
+    run.state.report.begin(phase);
+    let started = std::time::Instant::now();
+    run_phase(editor, run, phase).await;
+    run.state.report.end(started.elapsed());
+}
 
-    generate_parcels(seed, editor).await;
+/// [`run_town`] for a build area with several towns (`profile.towns > 1`). The
+/// parcels phase seeds every town; then each runs the rest of the plan on its
+/// own seed, culture, wall, name and population, with the world entered into it
+/// (see [`World::enter_town`](crate::editor::World::enter_town)) and left again
+/// when its run ends. Every town after the first lays a highway to the nearest
+/// town built before it, so the towns end up joined in a tree. The chronicles
+/// come last, once every town has a name to be a neighbour by. Not checkpointed.
+async fn run_region(
+    editor: &mut Editor,
+    seed: Seed,
+    culture: Culture,
+    profile: &SettlementProfile,
+    plan: &[TownPhase],
+) -> Vec<TownMetrics> {
+    prepare_server(editor).await;
+
+    let mut first = TownRun::new(seed, culture, profile.clone(), RNG::new(seed), TownState::default());
+    if plan.contains(&TownPhase::Parcels) {
+        timed_phase(editor, &mut first, TownPhase::Parcels).await;
+        first.state.done.push(TownPhase::Parcels);
+    }
+    let town_count = editor.world().towns.len().max(1);
+    println!("Building {town_count} towns");
+
+    let mut runs: Vec<TownRun> = Vec::new();
+    let mut next = Some(first);
+    for town in 0..town_count {
+        editor.world_mut().enter_town(town);
+        let mut run = next.take().unwrap_or_else(|| {
+            let seed = Seed(RNG::from_seed_and_string(seed, &format!("town_{town}")).next_i64());
+            let culture = select_town_culture(editor, seed, &editor.world().towns[town]);
+            TownRun::new(seed, culture, profile.clone(), RNG::new(seed), TownState::default())
+        });
+        run.town = town;
+        run.taken_names = runs.iter().filter_map(|r| r.state.name.as_ref()).map(|n| n.name.clone()).collect();
+        if let Some(prev) = runs.last_mut() {
+            // One id space across the region, so no two NPCs share an id.
+            run.state.id_alloc = std::mem::take(&mut prev.state.id_alloc);
+        }
+        println!("Town {} of {town_count}: {:?}", town + 1, run.culture);
+        for &phase in plan {
+            // Ships scatter over every water district of the build area, so only
+            // the first town launches them.
+            if matches!(phase, TownPhase::Parcels | TownPhase::Chronicle) || (phase == TownPhase::Ships && town > 0) {
+                continue;
+            }
+            timed_phase(editor, &mut run, phase).await;
+            run.state.done.push(phase);
+        }
+        editor.world_mut().leave_town();
+        runs.push(run);
+    }
+
+    if plan.contains(&TownPhase::Chronicle) {
+        for town in 0..runs.len() {
+            runs[town].neighbours = neighbours_of(editor, &runs, town);
+            editor.world_mut().enter_town(town);
+            timed_phase(editor, &mut runs[town], TownPhase::Chronicle).await;
+            runs[town].state.done.push(TownPhase::Chronicle);
+            editor.world_mut().leave_town();
+        }
+    }
+
+    editor.save().await;
+    runs.iter_mut()
+        .enumerate()
+        .map(|(town, run)| {
+            editor.world_mut().enter_town(town);
+            let metrics = town_metrics(editor, run);
+            editor.world_mut().leave_town();
+            write_report(run, &metrics);
+            metrics
+        })
+        .collect()
+}
+
+/// Where the files of town `town` go: `output/town` for the first, then
+/// `output/town_2` and so on, given an extension.
+fn town_output_stem(town: usize) -> String {
+    match town {
+        0 => "output/town".to_string(),
+        town => format!("output/town_{}", town + 1),
+    }
+}
 
-    
-    let data = LoadedData::load().expect("Failed to load data");
+/// Fills in the rest of the run's report and writes it next to the town map.
+fn write_report(run: &mut TownRun, metrics: &TownMetrics) {
+    let report = &mut run.state.report;
+    report.seed = run.seed.0;
+    report.culture = Some(run.culture);
+    report.town = run.town;
+    report.name = run.state.name.as_ref().map(|named| named.name.clone());
+    report.population = run.state.population.as_ref().map(|p| p.population_count);
+    if let Some(economy) = &run.state.economy {
+        let mut resources: Vec<ResourceAssignment> = economy.parcel_assignments
+            .iter()
+            .map(|(district, a)| ResourceAssignment {
+                district: district.0,
+                resource: a.primary_resource.clone(),
+                building: a.building.clone(),
+            })
+            .collect();
+        resources.sort_by_key(|a| a.district);
+        report.resources = resources;
+    }
+    report.metrics = Some(metrics.clone());
+    let path = format!("{}.json", town_output_stem(run.town));
+    match report.write(std::path::Path::new(&path)) {
+        Ok(()) => println!("Wrote run report to {path}"),
+        Err(e) => log::warn!("{e:#}"),
+    }
+}
+
+/// How far from a paved road a cell still counts as served by it.
+const ROAD_REACH: usize = 8;
+
+/// Measures the town `run` built, in the world entered into it.
+fn town_metrics(editor: &Editor, run: &TownRun) -> TownMetrics {
+    let world = editor.world();
+    let urban = world.get_urban_points();
+    let share = |cells: usize| if urban.is_empty() { 0.0 } else { cells as f32 / urban.len() as f32 };
+
+    // Breadth-first out from the road surface, over urban cells only.
+    let mut served: HashSet<Point2D> = HashSet::new();
+    if let Some(layout) = &run.state.layout {
+        let mut frontier: Vec<Point2D> = layout.road_h.keys().filter(|p| urban.contains(p)).copied().collect();
+        served.extend(&frontier);
+        for _ in 0..ROAD_REACH {
+            frontier = frontier
+                .iter()
+                .flat_map(|&p| [Point2D::new(1, 0), Point2D::new(-1, 0), Point2D::new(0, 1), Point2D::new(0, -1)].map(|d| p + d))
+                .filter(|p| urban.contains(p) && served.insert(*p))
+                .collect();
+        }
+    }
+
+    let houses = run.state.houses.as_ref();
+    TownMetrics {
+        culture: Some(run.culture),
+        urban_cells: urban.len(),
+        houses_placed: houses.map_or(0, |h| h.total_buildings),
+        houses_failed: houses.map_or(0, |h| h.failures.values().sum()),
+        house_failures: houses.map(|h| h.failures.clone()).unwrap_or_default(),
+        rural_placed: run.state.rural.as_ref().map_or(0, Vec::len),
+        rural_sites: run.state.rural_sites,
+        road_coverage: share(served.len()),
+        open_space_share: share(run.state.open_spaces.as_ref().map_or(0, |o| o.cells)),
+        gates: world.gate_locations.len(),
+    }
+}
+
+/// The other named towns as `runs[town]`'s chronicle sees them.
+fn neighbours_of(editor: &Editor, runs: &[TownRun], town: usize) -> Vec<crate::generator::chronicle::Neighbour> {
+    let sites = &editor.world().towns;
+    let population = |run: &TownRun| run.state.population.as_ref().map_or(0, |p| p.population_count);
+    let me = &runs[town];
+    let core = sites[town].core();
+    runs.iter()
+        .enumerate()
+        .filter(|&(other, _)| other != town)
+        .filter_map(|(other, them)| {
+            let named = them.state.name.as_ref()?;
+            let their_core = sites[other].core();
+            let highway = me.state.highway_to == Some(other) || them.state.highway_to == Some(town);
+            Some(crate::generator::chronicle::Neighbour {
+                name: named.name.clone(),
+                subtitle: named.subtitle.clone(),
+                culture: culture_word(them.culture),
+                direction: compass_word(their_core, core).to_string(),
+                distance: (core.distance_squared(&their_core) as f64).sqrt().round() as i32,
+                relation: crate::generator::chronicle::neighbour_relation(
+                    me.culture == them.culture, population(me), population(them), highway,
+                ),
+                highway,
+            })
+        })
+        .collect()
+}
+
+async fn run_phase(editor: &mut Editor, run: &mut TownRun, phase: TownPhase) {
+    match phase {
+        TownPhase::Parcels => generate_town_parcels(run.seed, editor, run.profile.towns, run.profile.town_spacing).await,
+        TownPhase::Economy => plan_economy(editor, run),
+        TownPhase::Terrain => prepare_terrain(editor, &run.profile, &mut run.state.report).await,
+        TownPhase::Wall => raise_wall(editor, run).await,
+        TownPhase::RuralBuildings => place_rural_buildings(editor, run).await,
+        TownPhase::RuralRoads => lay_rural_roads(editor, run).await,
+        TownPhase::Torii => {
+            // A torii gate straddling each rural road, set a little way out from the
+            // gate — the threshold into the countryside. Japanese-only; no-op otherwise.
+            let rural_paths = need(&run.state.rural_paths, TownPhase::RuralRoads);
+            let torii = crate::generator::paths::place_rural_torii(&*editor, rural_paths, run.culture).await;
+            if torii > 0 {
+                println!("Placed {torii} rural torii gates");
+            }
+            run.state.report.count("torii", torii);
+        }
+        TownPhase::ProductionAreas => {
+            // ── R3: paint rural production areas (after the roads) ────────────────
+            for p in need(&run.state.rural, TownPhase::RuralBuildings) {
+                let Some(painter) = &p.painter else { continue };
+                let Some(district) = editor.world().districts.get(&p.district).cloned() else { continue };
+                paint_production_area_for(&district, painter, &p.resource, &p.structure, &run.data, editor, &mut run.rng).await;
+            }
+        }
+        TownPhase::Industrial => place_industry(editor, run).await,
+        TownPhase::Roads => lay_out_roads(editor, run).await,
+        TownPhase::Houses => {
+            let layout = need(&run.state.layout, TownPhase::Roads);
+            let houses = build_houses(
+                editor, run.seed, run.culture, &run.profile, layout, &run.data, &mut run.rng, &mut run.state.report,
+            ).await;
+            run.state.houses = Some(houses);
+        }
+        TownPhase::StreetLights => light_streets(editor, run).await,
+        TownPhase::StreetSigns => sign_streets(editor, run).await,
+        TownPhase::OpenSpaces => furnish_open_spaces(editor, run).await,
+        TownPhase::PlazaFixtures => staff_plazas(editor, run).await,
+        TownPhase::Population => populate(editor, run).await,
+        TownPhase::WorkerFixtures => staff_workplaces(editor, run).await,
+        TownPhase::TownMap => draw_town_map(editor, run).await,
+        TownPhase::Welcome => {
+            // Welcome banner: bury a command-block proximity sensor at the town centre
+            // that flashes the settlement name when a player crosses into the urban
+            // area. The name is derived procedurally from the place's features (iconic
+            // building, land shape, biome), seeded off the town seed so it's stable.
+            // Requires `enable-command-block=true` on the server.
+            let urban = editor.world().get_urban_points();
+            let houses = need(&run.state.houses, TownPhase::Houses);
+            let open_spaces = need(&run.state.open_spaces, TownPhase::OpenSpaces);
+            let mut name_rng = RNG::new(run.seed).derive();
+            let mut named = crate::generator::naming::generate_settlement_name(
+                editor.world(), &urban, &open_spaces.civic_features, run.culture, &houses.color_scheme.town, &mut name_rng,
+            );
+            // A town of several doesn't take a name one before it already took.
+            for _ in 0..8 {
+                if !run.taken_names.contains(&named.name) {
+                    break;
+                }
+                named = crate::generator::naming::generate_settlement_name(
+                    editor.world(), &urban, &open_spaces.civic_features, run.culture, &houses.color_scheme.town, &mut name_rng,
+                );
+            }
+            crate::generator::welcome::place_welcome_title(
+                editor, &urban, &named.name, &named.subtitle,
+            ).await;
+            println!("Placed welcome-title sensor for \"{}\" ({})", named.name, named.subtitle);
+            run.state.name = Some(named);
+        }
+        TownPhase::Chronicle => write_chronicle(editor, run).await,
+        TownPhase::Ships => {
+            // Scatter free-floating ships onto the settlement's water districts, then crew the
+            // afloat ones (a captain at the helm + sailors on deck) from the town roster — the same
+            // fixture path as plaza vendors / industry workers. Live-only: staffing is a no-op offline.
+            let (ships, crew_scenes) =
+                crate::generator::ships::fleet::scatter_ships(editor, &run.data, run.seed, &run.profile.ships).await;
+            println!("Placed {} ships across water districts", ships);
+            run.state.report.count("ships", ships);
+            if !crew_scenes.is_empty() {
+                match crate::generator::ships::crew::staff_crew(
+                    editor, crew_scenes, run.culture, &run.data, &mut run.state.id_alloc, &mut run.rng,
+                )
+                .await
+                {
+                    Ok(staffed) => {
+                        println!("Crewed ships with {} sailor/captain NPCs", staffed);
+                        run.state.report.count("crew", staffed);
+                    }
+                    Err(e) => {
+                        log::warn!("ship crew staffing failed: {e}");
+                        run.state.report.fail(e.to_string());
+                    }
+                }
+            }
+        }
+    }
+}
 
-    // ── Resource chain over rural districts ──────────────────────────────
+/// Resolves the rural economy with placement feasibility folded in: parcels
+/// that can't physically seat a resource's gather building (footprint too big
+/// for any flat enough pad) are excluded during assignment, so the plan never
+/// promises a building placement would later drop. (Rural terrain is still
+/// natural here — flatten/walls only touch urban.)
+fn plan_economy(editor: &mut Editor, run: &mut TownRun) {
     let rural_analysis: HashMap<_, _> = editor.world().district_analysis_data.iter()
         .filter(|(id, _)| {
             editor.world().districts.get(id)
@@ -520,21 +1070,19 @@ pub async fn generate_town(
         })
         .map(|(id, analysis)| (*id, analysis.clone()))
         .collect();
-    // Resolve the rural economy with placement feasibility folded in: parcels that
-    // can't physically seat a resource's gather building (footprint too big for any
-    // flat enough pad) are excluded during assignment, so the plan never promises a
-    // building placement would later drop. (Rural terrain is still natural here —
-    // flatten/walls only touch urban.)
-    let result = resolve_rural_production(&data, editor, &rural_analysis, &mut rng);
-
-    // Phase 1 — feathered urban flatten.
+    let result = resolve_rural_production(&run.data, editor, &rural_analysis, &mut run.rng);
+    run.state.economy = Some(result);
+}
+
+/// Feathered urban flatten, after clearing the ground of trees and liquids.
+async fn prepare_terrain(editor: &mut Editor, profile: &SettlementProfile, report: &mut RunReport) {
     let urban = editor.world().get_urban_points();
-    // Clear the urban area (plus an `URBAN_LOG_APRON` apron outside the wall for
-    // gate approaches / rural on-ramps) of trees so roads and buildings aren't
+    // Clear the urban area (plus the profile's `urban_log_apron` outside the wall
+    // for gate approaches / rural on-ramps) of trees so roads and buildings aren't
     // dropped into standing forest. Drain/flatten below stay on the urban footprint.
     let mut logged_area = urban.clone();
     logged_area.extend(
-        crate::geometry::get_surrounding_set(&urban, URBAN_LOG_APRON)
+        crate::geometry::get_surrounding_set(&urban, profile.urban_log_apron)
             .into_iter()
             .filter(|c| editor.world().is_in_bounds_2d(*c)),
     );
@@ -547,26 +1095,32 @@ pub async fn generate_town(
     // of trees rooted outside it.
     let floated = clear_floating_logs(&*editor, &logged_area).await;
     println!("Cleared {floated} floating log blocks");
+    report.count("logged_cells", logged_area.len());
+    report.count("floating_logs", floated);
     // Clear all standing water/lava from the city bounds BEFORE terraforming, so
     // the flatten only grades solid ground (and `is_water` no longer makes it
     // skip those cells).
     drain_liquids(editor, &urban).await;
     println!("Drained liquids from {} urban cells", urban.len());
-    flatten_urban_area(editor, &urban, 16, 12, true).await;
+    report.count("drained_cells", urban.len());
+    flatten_urban_area(editor, &urban, profile.flatten.feather, profile.flatten.smooth_iters, true).await;
+}
 
-    // Wall + gates — gates populate world.gate_locations, used by the network.
+/// Wall + gates — gates populate world.gate_locations, used by the network.
+async fn raise_wall(editor: &mut Editor, run: &mut TownRun) {
+    let culture = run.culture;
     let materials = Material::load().expect("Failed to load materials");
-    let wall_material = MaterialId::new(wall_mat.to_string());
-    let mut placer: Placer = Placer::new(&materials, &mut rng);
+    let wall_material = MaterialId::new(run.profile.infrastructure.for_culture(culture).wall.clone());
+    let mut placer: Placer = Placer::new(&materials, &mut run.rng);
     let structures = Structure::load().expect("Failed to load structures");
-    let data = LoadedData::load().expect("Failed to load data");
+    let data = &run.data;
     // Re-skin wall towers into the culture palette so the placed tower NBT
     // matches the rest of the settlement. The tower's oak cap maps to the roof
     // role, so each culture's tower roof follows its building roofs. Desert is
     // the exception: merge a dark-prismarine roof override so desert tower roofs
     // pop against the sandstone body instead of being sandstone-on-sandstone.
     let tower_palette = data.palettes.get(&culture.palette_id()).cloned().map(|p| {
-        if desert {
+        if culture == Culture::Desert {
             let roof = data.palettes.get(&"prismarine_roof".into())
                 .expect("prismarine_roof palette not found");
             p.merged_with(roof)
@@ -574,15 +1128,16 @@ pub async fn generate_town(
             p
         }
     });
-    let tower_skin = tower_palette.as_ref().map(|p| TowerSkin { data: &data, palette: p });
-    // City size = number of urban super-parcels. Small hamlets (≤2) get a cheap
-    // palisade; larger towns (3+) get the full standard-with-inner stone wall.
-    // Threshold sits below URBAN_SIZE_MIN so any city that reaches the normal
-    // growth minimum earns a stone wall; only stunted growth yields a palisade.
+    let tower_skin = tower_palette.as_ref().map(|p| TowerSkin { data, palette: p });
+    // City size = number of urban super-parcels. Small hamlets (≤2 by default)
+    // get a cheap palisade; larger towns get the full standard-with-inner stone
+    // wall. The default threshold sits below URBAN_SIZE_MIN so any city that
+    // reaches the normal growth minimum earns a stone wall; only stunted growth
+    // yields a palisade.
     let n_urban = editor.world().districts.values()
-        .filter(|sd| sd.data.parcel_type == crate::generator::districts::ParcelType::Urban)
+        .filter(|sd| sd.data.parcel_type == ParcelType::Urban)
         .count();
-    let wall_type = if n_urban <= 2 {
+    let wall_type = if n_urban <= run.profile.palisade_max_urban {
         WallType::Palisade
     } else {
         WallType::StandardWithInner
@@ -591,12 +1146,14 @@ pub async fn generate_town(
     // than the stone `wall_material` the standard wall takes. Falls back to the stone
     // material if the area has no wood palette (desert/badlands).
     let palisade_material = (wall_type == WallType::Palisade)
-        .then(|| dominant_local_wood(editor.world().get_ground_biome_map(), &data))
+        .then(|| dominant_local_wood(editor.world().get_ground_biome_map(), data))
         .flatten();
     let wall_material = palisade_material.as_ref().unwrap_or(&wall_material);
     println!("City size {n_urban} urban super-parcels -> {wall_type:?} wall ({})", wall_material.as_str());
+    // The wall draws from its own stream, seeded afresh from the town seed.
+    let mut wall_rng = RNG::new(run.seed);
     build_wall(
-        &editor.world().get_urban_points(), editor, &mut rng2,
+        &editor.world().get_urban_points(), editor, &mut wall_rng,
         &mut placer, wall_material, &structures, wall_type, tower_skin.as_ref(),
     ).await;
     drop(placer);
@@ -606,58 +1163,99 @@ pub async fn generate_town(
         let n_total = editor.world().districts.len();
         println!("URBAN super-parcels: {}/{} total | gates: {}", n_urban, n_total, editor.world().gate_locations.len());
     }
+    run.state.report.wall = Some(format!("{wall_type:?}"));
+    run.state.report.count("urban_districts", n_urban);
+    run.state.report.count("gates", editor.world().gate_locations.len());
+    run.state.report.count("towers", editor.world().tower_bases.len());
+}
 
+/// Seats each rural parcel's gather building. Their production areas are
+/// painted later, once the rural roads are down.
+async fn place_rural_buildings(editor: &mut Editor, run: &mut TownRun) {
+    let result = need(&run.state.economy, TownPhase::Economy);
     let mut sd_ids: Vec<_> = result.parcel_assignments.keys().cloned().collect();
-        sd_ids.sort_by_key(|id| id.0);
-        // Dropped-by-competition-cap parcels, ordered flattest-first per resource:
-        // promoted when a primary fails to seat, so a terrain miss costs us a different
-        // parcel rather than the building (and the planned economy) entirely.
-        let mut fallbacks: HashMap<String, std::collections::VecDeque<_>> = result
-            .fallback_assignments
-            .iter()
-            .map(|(res, list)| (res.clone(), list.iter().cloned().collect()))
-            .collect();
-        let mut placed = 0usize;
-        // Placed rural buildings, collected so the road network can connect them
-        // and the production painters can run *after* the roads (R3 below).
-        let mut placed_rural: Vec<PlacedRural> = Vec::new();
-        for sd_id in &sd_ids {
-            let assignment = result.parcel_assignments[sd_id].clone();
-            if let Some(p) = try_place_rural(*sd_id, &assignment, &data, editor, &mut rng).await {
+    sd_ids.sort_by_key(|id| id.0);
+    // Dropped-by-competition-cap parcels, ordered flattest-first per resource:
+    // promoted when a primary fails to seat, so a terrain miss costs us a different
+    // parcel rather than the building (and the planned economy) entirely.
+    let mut fallbacks: HashMap<String, std::collections::VecDeque<_>> = result
+        .fallback_assignments
+        .iter()
+        .map(|(res, list)| (res.clone(), list.iter().cloned().collect()))
+        .collect();
+    let mut placed = 0usize;
+    // Placed rural buildings, collected so the road network can connect them
+    // and the production painters can run *after* the roads (R3 below).
+    let mut placed_rural: Vec<PlacedRural> = Vec::new();
+    for sd_id in &sd_ids {
+        let assignment = result.parcel_assignments[sd_id].clone();
+        if let Some(p) = try_place_rural(*sd_id, &assignment, &run.data, editor, &mut run.rng).await {
+            placed += 1;
+            placed_rural.push(p);
+            continue;
+        }
+        run.state.report.fail(format!("{} didn't seat", assignment.building));
+        // Primary couldn't seat — promote the best dropped same-resource parcel(s)
+        // until one places, keeping the per-resource count at its cap.
+        while let Some((fb_id, fb_assignment)) = fallbacks
+            .get_mut(&assignment.primary_resource)
+            .and_then(|q| q.pop_front())
+        {
+            log::info!(
+                "[resource-chain]   promoting fallback {:?} for resource {} after {:?} failed to place",
+                fb_id, assignment.primary_resource, sd_id,
+            );
+            if let Some(p) = try_place_rural(fb_id, &fb_assignment, &run.data, editor, &mut run.rng).await {
                 placed += 1;
                 placed_rural.push(p);
-                continue;
-            }
-            // Primary couldn't seat — promote the best dropped same-resource parcel(s)
-            // until one places, keeping the per-resource count at its cap.
-            while let Some((fb_id, fb_assignment)) = fallbacks
-                .get_mut(&assignment.primary_resource)
-                .and_then(|q| q.pop_front())
-            {
-                log::info!(
-                    "[resource-chain]   promoting fallback {:?} for resource {} after {:?} failed to place",
-                    fb_id, assignment.primary_resource, sd_id,
-                );
-                if let Some(p) = try_place_rural(fb_id, &fb_assignment, &data, editor, &mut rng).await {
-                    placed += 1;
-                    placed_rural.push(p);
-                    break;
-                }
+                break;
             }
         }
-        log::info!("Placed {} of {} rural buildings", placed, sd_ids.len());
+    }
+    log::info!("Placed {} of {} rural buildings", placed, sd_ids.len());
+    run.state.rural_sites = sd_ids.len();
+    run.state.report.count("rural_placed", placed);
+    run.state.report.count("rural_sites", sd_ids.len());
+
+    // Their `Structure` claim ids follow the urban ones, so the worker-staffing
+    // pass picks them up alongside the urban shops.
+    let rural_parcel_count = editor.world().districts.values()
+        .filter(|sd| sd.data.parcel_type == ParcelType::Rural)
+        .count();
+    println!(
+        "Placed {} rural buildings across {} rural parcels",
+        placed_rural.len(), rural_parcel_count,
+    );
+    run.state.rural = Some(placed_rural);
+}
 
-    // ── Rural road network (built BEFORE the production painters) ─────────
-    // Connect every placed rural building to a town gate, predicting and reusing
-    // the `rural_road` border ring each painter will lay. Realise + claim the
-    // roads here so the painters' border rings skip the cells the road owns.
+/// Rural road network, built BEFORE the production painters: connect every
+/// placed rural building to a town gate, predicting and reusing the
+/// `rural_road` border ring each painter will lay. Realise + claim the roads
+/// here so the painters' border rings skip the cells the road owns.
+async fn lay_rural_roads(editor: &mut Editor, run: &mut TownRun) {
     let rural_material = MaterialId::new("rural_road".to_string());
-    let rural_buildings: Vec<RuralBuilding> = placed_rural.iter().map(|p| RuralBuilding {
+    let rural_buildings: Vec<RuralBuilding> = need(&run.state.rural, TownPhase::RuralBuildings).iter().map(|p| RuralBuilding {
         district: p.district,
         structure: p.structure.clone(),
         has_border_ring: p.has_border_ring,
     }).collect();
-    let rural_paths = build_rural_road_network(&*editor, &rural_buildings, rural_material, 4).await;
+    // A town after the first of several lays a highway to the nearest one built
+    // before it.
+    let world = editor.world();
+    let neighbour = world.towns.get(world.active_town).and_then(|site| {
+        let core = site.core();
+        world.towns[..world.active_town]
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| !other.gate_locations.is_empty())
+            .min_by_key(|(_, other)| other.core().distance_squared(&core))
+    });
+    let neighbours: Vec<&TownSite> = neighbour.iter().map(|&(_, site)| site).collect();
+    let rural_paths = build_rural_road_network(&*editor, &rural_buildings, &neighbours, rural_material, 4).await;
+    run.state.highway_to = neighbour
+        .filter(|_| rural_paths.iter().any(|path| path.priority() == PathPriority::High))
+        .map(|(town, _)| town);
     if !rural_paths.is_empty() {
         // Flatten the routed corridor to the road heights (skipping building /
         // wall cells so a placed structure isn't re-graded), then meld the
@@ -685,39 +1283,26 @@ pub async fn generate_town(
             .map(|(c, &y)| Point3D::new(c.x, y, c.y))
             .collect();
         force_height(editor, &corridor_pts, false).await;
-        build_paths_merged(&*editor, &data, &rural_paths, &mut rng).await;
+        build_paths_merged(&*editor, &run.data, &rural_paths, &mut run.rng).await;
         for path in &rural_paths {
             let centre: HashSet<Point2D> = path.points().iter().map(|p| p.drop_y()).collect();
             let mut paved = crate::geometry::get_surrounding_set(&centre, path.width().saturating_sub(1));
             paved.extend(centre);
-            for c in paved {
-                editor.world_mut().claim(c, crate::generator::BuildClaim::Path(crate::generator::paths::PathType::Road));
-            }
+            crate::generator::paths::claim_road(editor.world_mut(), paved, crate::generator::paths::PathType::Road);
         }
     }
     println!("Rural roads: {} segments", rural_paths.len());
+    run.state.report.count("rural_road_segments", rural_paths.len());
+    run.state.rural_paths = Some(rural_paths);
+}
 
-    // A torii gate straddling each rural road, set a little way out from the
-    // gate — the threshold into the countryside. Japanese-only; no-op otherwise.
-    let torii = crate::generator::paths::place_rural_torii(&*editor, &rural_paths, culture).await;
-    if torii > 0 {
-        println!("Placed {torii} rural torii gates");
-    }
-
-    // ── R3: paint rural production areas (after the roads) ────────────────
-    for p in &placed_rural {
-        let Some(painter) = &p.painter else { continue };
-        let Some(district) = editor.world().districts.get(&p.district).cloned() else { continue };
-        paint_production_area_for(&district, painter, &p.resource, &p.structure, &data, editor, &mut rng).await;
-    }
-
-
-    // ---- Industrial buildings FIRST ----
-    // Place a handful of big processing buildings on the flattened ground (no
-    // roads yet → sited by flatness). They become the destinations the arterial
-    // network connects, plus a `blocked` barrier so nothing — roads, the
-    // subdivision, alleys, or houses — ever runs through them. (Fixed set here;
-    // the resource chain's `resolve_for_parcels` can supply the real mix later.)
+/// Industrial buildings, before any urban road: place a handful of big
+/// processing buildings on the flattened ground (no roads yet → sited by
+/// flatness). They become the destinations the arterial network connects, plus
+/// a `blocked` barrier so nothing — roads, the subdivision, alleys, or houses —
+/// ever runs through them. (Fixed set here; the resource chain's
+/// `resolve_for_parcels` can supply the real mix later.)
+async fn place_industry(editor: &mut Editor, run: &mut TownRun) {
     use crate::generator::BuildClaim;
     use crate::generator::placement::place_urban_buildings;
 
@@ -726,45 +1311,33 @@ pub async fn generate_town(
         ind_counts.insert(b.to_string(), 1);
     }
     let urban_sds: Vec<_> = editor.world().districts.values()
-        .filter(|sd| sd.data.parcel_type == crate::generator::districts::ParcelType::Urban)
+        .filter(|sd| sd.data.parcel_type == ParcelType::Urban)
         .cloned()
         .collect();
     let urban_sd_refs: Vec<_> = urban_sds.iter().collect();
     let n_before = editor.world().structures.len();
     // Re-skin the industrial NBTs into the settlement's culture palette
     // (their baked `resource_base` blocks → medieval spruce/stone).
-    let ind_palette = data.palettes
-        .get(&culture.palette_id())
+    let ind_palette = run.data.palettes
+        .get(&run.culture.palette_id())
         .expect("industry palette not found").clone();
-    if let Err(e) = place_urban_buildings(&urban_sd_refs, &ind_counts, &mut rng, editor, &data, Some(&ind_palette)).await {
+    if let Err(e) = place_urban_buildings(&urban_sd_refs, &ind_counts, &mut run.rng, editor, &run.data, Some(&ind_palette)).await {
         log::warn!("industrial placement failed: {}", e);
+        run.state.report.fail(e.to_string());
     }
     println!(
         "Placed {} / {} industrial buildings",
         editor.world().structures.len() - n_before, ind_counts.values().sum::<u32>(),
     );
     let urban_industrial_count = editor.world().structures.len() - n_before;
-
-    // ---- Rural buildings ----
-    // The rural resource-chain pass above already placed each gathering/
-    // processing building (farm, mine, sawmill, ranch, ...) in its own parcel and
-    // painted its production area. Their `Structure` claim ids follow the urban
-    // ones, so the worker-staffing pass below picks them up alongside the urban
-    // shops.
-    let rural_building_count = placed_rural.len();
-    let rural_parcel_count = editor.world().districts.values()
-        .filter(|sd| sd.data.parcel_type == ParcelType::Rural)
-        .count();
-    println!(
-        "Placed {} rural buildings across {} rural parcels",
-        rural_building_count, rural_parcel_count,
-    );
+    run.state.report.count("industrial_placed", urban_industrial_count);
+    run.state.report.count("industrial_planned", ind_counts.values().sum::<u32>() as usize);
 
     // Footprints → a `blocked` barrier (footprint + margin) and one node per
     // building for the network to connect.
     const IND_MARGIN: i32 = 2;
     let mut ind_footprints: HashMap<u32, Vec<Point2D>> = HashMap::new();
-    for &p in &urban {
+    for p in editor.world().get_urban_points() {
         if let Some(BuildClaim::Structure(id)) = editor.world().get_claim(p) {
             ind_footprints.entry(id.id).or_default().push(p);
         }
@@ -777,24 +1350,34 @@ pub async fn generate_town(
             })
         })
         .collect();
-    let ind_nodes: Vec<Point3D> = ind_footprints.values()
+    let nodes: Vec<Point3D> = ind_footprints.values()
         .filter_map(|cells| {
             let c = cells.iter().fold(Point2D::ZERO, |a, p| a + *p) / cells.len().max(1) as i32;
             editor.world().add_height(c)
         })
         .collect();
+    run.state.industry = Some(TownIndustry { urban_industrial_count, blocked, nodes });
+}
+
+/// Tiered A* road network connecting the industrial buildings, the city blocks
+/// and lots it leaves, and the roads themselves, built before the houses.
+async fn lay_out_roads(editor: &mut Editor, run: &mut TownRun) {
+    let materials = run.profile.infrastructure.for_culture(run.culture);
+    let TownIndustry { blocked, nodes: ind_nodes, .. } = need(&run.state.industry, TownPhase::Industrial);
+    let urban = editor.world().get_urban_points();
 
     // Phase 2 — tiered A* road network, connecting the industrial buildings
     // (anchor nodes) and routed around them (the `blocked` barrier).
-    let arterial_material = MaterialId::new(arterial_mat.to_string());
-    let collector_material = MaterialId::new(collector_mat.to_string());
+    let arterial_material = MaterialId::new(materials.arterial.clone());
+    let collector_material = MaterialId::new(materials.collector.clone());
     // Keep the whole network (not just `.paths`) so the end-of-run town map can
     // overlay the abstract MST/node graph.
     let road_network = build_road_network(
-        &*editor, arterial_material, collector_material, true, &ind_nodes, &blocked, 1,
+        &*editor, arterial_material, collector_material, true, ind_nodes, blocked, 1,
     ).await;
     let paths = road_network.paths.clone();
     println!("Routed {} road segments", paths.len());
+    run.state.report.count("road_segments", paths.len());
 
     // DEBUG: Phase A merge check — how many of each path's cells coincide
     // with cells already laid by earlier paths? High overlap = routes are
@@ -870,7 +1453,7 @@ pub async fn generate_town(
     // Industrial buildings (footprint + margin) are barriers too, so blocks —
     // and the subdivision, alleys, and houses inside them — form *around* the
     // buildings, never through them.
-    barriers.extend(&blocked);
+    barriers.extend(blocked);
 
     // Don't let blocks (and the lots/alleys/houses inside them) span steep
     // terrain. A per-cell cliff test misses a *sustained* slope — a long
@@ -897,10 +1480,12 @@ pub async fn generate_town(
         .copied()
         .collect();
     println!("Marked {} steep cells as barriers", steep.len());
+    run.state.report.count("steep_cells", steep.len());
     barriers.extend(&steep);
 
     let blocks = find_blocks(&urban, &barriers, 12);
     println!("Found {} blocks", blocks.len());
+    run.state.report.count("blocks", blocks.len());
 
     // All main-road (arterial + collector) paved cells, used to peel a
     // frontage ribbon off each block before subdividing its interior.
@@ -918,10 +1503,6 @@ pub async fn generate_town(
     // by subdivision. Then subdivide only the interior with tier-3 alleys.
     // BSP cuts span the interior edge-to-edge, so an alley reaches its edge —
     // adjacent (barriers = paved) to either a main road or the ribbon.
-    // Deep enough to absorb both the deepest House (depth_range 7..=10) AND
-    // the staircase rise of a diagonal frontage (an axis-aligned rect anchored
-    // at the slice's interior extreme reaches `rise + depth` into the band).
-    const RIBBON_DEPTH: i32 = 14;
     let mut sub_blocks: Vec<HashSet<Point2D>> = Vec::new();
     // Block index each lot belongs to (parallel to `sub_blocks`), so a lot can be
     // mapped back to its district's style scheme. A "district" here is one city
@@ -933,7 +1514,7 @@ pub async fn generate_town(
     for (block_idx, block) in blocks.iter().enumerate() {
         let (mut ribbon_lots, interior) =
             crate::generator::districts::subdivide::reserve_road_ribbon(block, &main_road_cells, RIBBON_DEPTH);
-        let (subs, alleys) = crate::generator::districts::subdivide::subdivide_block(&interior, &mut rng, 24);
+        let (subs, alleys) = crate::generator::districts::subdivide::subdivide_block(&interior, &mut run.rng, 24);
 
         // Connect the interior alleys to the main roads by carving through the
         // ribbon, then convert those cells from frontage ribbon to alley.
@@ -960,13 +1541,16 @@ pub async fn generate_town(
         "Subdivided into {} lots ({} road-frontage ribbons), {} subdivider-road cells",
         sub_blocks.len(), ribbon_lot_count, alley_band.len(),
     );
+    run.state.report.count("lots", sub_blocks.len());
+    run.state.report.count("ribbon_lots", ribbon_lot_count);
+    run.state.report.count("alley_cells", alley_band.len());
 
     // Assemble every road into one path list (mains + a synthesised width-1
     // alley path), but DON'T build them yet — we build after the houses so
     // house-foundation earth can't bury the road. Houses are placed first and
     // sit their floor at the level of the road they front (see `road_h`).
     let alley_pts: Vec<Point3D> = alley_band.iter().filter_map(|c| editor.world().add_height(*c)).collect();
-    let alley_path = Path::new(alley_pts, 1, MaterialId::new(collector_mat.to_string()), PathPriority::Low);
+    let alley_path = Path::new(alley_pts, 1, MaterialId::new(materials.collector.clone()), PathPriority::Low);
     let mut all_paths = paths.clone();
     all_paths.push(alley_path);
 
@@ -1010,22 +1594,634 @@ pub async fn generate_town(
         .map(|(c, &y)| Point3D::new(c.x, y, c.y))
         .collect();
     force_height(editor, &corridor_pts, false).await;
-    // `build_paths_merged` returns the exact cells where it laid a half-step
-    // slab; we raise a house a block over a fronting slab off this set rather
-    // than reading the placed road back (the editor cache is keyed by local
-    // coords while get_block subtracts the build-area origin, so a read here
-    // returns world terrain, not the road).
-    let road_slabs: HashSet<Point3D> = build_paths_merged(&*editor, &data, &all_paths, &mut rng).await;
-    let slab_y_by_cell: HashMap<Point2D, i32> =
-        road_slabs.iter().map(|p| (p.drop_y(), p.y)).collect();
+    // Houses read the paved road back to seat their doors (see `door_sill_y`),
+    // so the half-step slabs `build_paths_merged` reports aren't kept.
+    build_paths_merged(&*editor, &run.data, &all_paths, &mut run.rng).await;
 
     // Claim every paved road cell so house-foundation terraforming can't
     // touch it (blend_terrain skips `BuildClaim::Path`).
     for path in &all_paths {
-        for c in paved(path) {
-            editor.world_mut().claim(c, crate::generator::BuildClaim::Path(crate::generator::paths::PathType::Pavement));
+        crate::generator::paths::claim_road(editor.world_mut(), paved(path), crate::generator::paths::PathType::Pavement);
+    }
+
+    run.state.layout = Some(TownLayout {
+        road_network, all_paths, blocks, sub_blocks, lot_block,
+        alley_band, arterial_band, collector_band, road_h,
+    });
+}
+
+/// Street lighting: run after houses have claimed their cells, so lamps line
+/// every road's verge without landing on a building. The city generator picks
+/// the lantern type city-wide.
+async fn light_streets(editor: &mut Editor, run: &mut TownRun) {
+    let city_rect = editor.world().world_rect_2d();
+    let city_centre = (city_rect.origin + city_rect.max()) / 2;
+    let cold = match editor.world().get_surface_biome_at(city_centre) {
+        Some(biome) => {
+            let n = biome.name();
+            n.contains("snowy") || n.contains("frozen") || n.contains("taiga")
+        }
+        None => false,
+    };
+    let street_lantern: crate::minecraft::Block = if cold {
+        "minecraft:soul_lantern".into()
+    } else {
+        "minecraft:lantern".into()
+    };
+    let all_paths = &need(&run.state.layout, TownPhase::Roads).all_paths;
+    let lamps = crate::generator::paths::place_street_lights(&*editor, all_paths, &street_lantern).await;
+    println!("Placed {} street lamps", lamps.len());
+    run.state.report.count("street_lamps", lamps.len());
+}
+
+/// Name the roads (layered: landmark → gate/centre → generic) now that all
+/// buildings have claimed their cells, then sign the intersections. Runs
+/// before the open-space pass; each sign cell is claimed as a path so
+/// plazas/parks/etc. won't furnish over it.
+async fn sign_streets(editor: &mut Editor, run: &mut TownRun) {
+    let layout = need(&run.state.layout, TownPhase::Roads);
+    let mut name_rng = RNG::new(run.seed).derive();
+    let road_names = crate::generator::paths::name_roads_layered(
+        editor.world(), &layout.road_network.road_labels, &layout.all_paths,
+        &editor.world().gate_locations.clone(), run.culture, &mut name_rng,
+    );
+    let signs = crate::generator::paths::place_street_signs(
+        editor, &layout.all_paths, &layout.road_network.road_labels, &road_names,
+    ).await;
+    println!("Placed {} street signs", signs.len());
+    run.state.report.count("street_signs", signs.len());
+    run.state.streets = Some(TownStreets { road_names, signs });
+}
+
+/// Open spaces: furnish the leftover gaps between buildings and roads. Detect
+/// the empty pockets inside the wall and furnish each by type: plazas
+/// (paved civic squares), nooks (small ringed gardens), parks (large green
+/// commons), and yards (perimeter kitchen gardens).
+async fn furnish_open_spaces(editor: &mut Editor, run: &mut TownRun) {
+    use crate::generator::open_space::{
+        detect_regions, furnish_nook, furnish_park, furnish_plaza, furnish_yard, OpenSpaceNames,
+        ParkType, Theme, RegionType,
+    };
+    let culture = run.culture;
+    let data = &run.data;
+    let mut place_labels: Vec<(Point2D, String)> = Vec::new();
+    let mut plaza_scenes: Vec<AnchorScene> = Vec::new();
+    let mut civic_features: Vec<String> = Vec::new();
+    let urban = editor.world().get_urban_points();
+    let regions = detect_regions(editor.world(), &urban);
+    let theme = Theme::for_culture(culture);
+    let mut os_rng = run.rng.derive();
+    // Names are picked alongside furnishing so a park is named for the type it
+    // was actually built as; `used` keeps every name unique within the town.
+    let names = OpenSpaceNames::load();
+    let mut used: HashSet<String> = HashSet::new();
+    let mut counts = [0usize; 4]; // plaza, nook, park, yard
+    // Stone lanterns (tōrō) scattered through the green spaces — Japanese
+    // only; the call is a no-op for other cultures, so we ring nooks and parks
+    // (but not yards, nor paved plazas, nor cemeteries) after furnishing. They
+    // are stoned to match each garden's own masonry (`theme.stone`).
+    let mut garden_lanterns = 0usize;
+    for region in &regions {
+        match region.region_type() {
+            RegionType::Plaza => {
+                let (plaza_type, scenes) = furnish_plaza(&*editor, region, &mut os_rng, &theme).await;
+                plaza_scenes.extend(scenes);
+                civic_features.push(plaza_type.key().to_string());
+                if let Some(name) = names.as_ref().and_then(|n| n.name_plaza(plaza_type, culture, &mut os_rng, &mut used)) {
+                    place_labels.push((region.centroid(), name));
+                }
+                counts[0] += 1;
+            }
+            RegionType::Nook => {
+                furnish_nook(&*editor, region, &mut os_rng, &theme).await;
+                garden_lanterns += crate::generator::paths::scatter_garden_lanterns(
+                    &*editor, region, data, culture, theme.stone, &mut os_rng,
+                ).await;
+                counts[1] += 1;
+            }
+            RegionType::Park => {
+                let (park_type, scenes) = furnish_park(editor, region, &mut os_rng, &theme).await;
+                plaza_scenes.extend(scenes);
+                civic_features.push(park_type.key().to_string());
+                if let Some(name) = names.as_ref().and_then(|n| n.name_park(park_type, culture, &mut os_rng, &mut used)) {
+                    place_labels.push((region.centroid(), name));
+                }
+                // Skip cemeteries — a glowing lantern doesn't suit a graveyard.
+                if park_type != ParkType::Cemetery {
+                    garden_lanterns += crate::generator::paths::scatter_garden_lanterns(
+                        &*editor, region, data, culture, theme.stone, &mut os_rng,
+                    ).await;
+                }
+                counts[2] += 1;
+            }
+            RegionType::Yard => {
+                furnish_yard(&*editor, region, &mut os_rng, &theme).await;
+                counts[3] += 1;
+            }
+        }
+    }
+    println!(
+        "Furnished open spaces — plaza {} nook {} park {} yard {} | {} garden lanterns",
+        counts[0], counts[1], counts[2], counts[3], garden_lanterns,
+    );
+
+    // Skin the plaza fixtures from data: a stage's performers and a stall's
+    // vendor each roll a look from their fixture pool. Onlookers/browsers in
+    // the crowd keep the roster's own look (their slots are left untouched).
+    let mut plaza_look_rng = run.rng.derive();
+    for scene in plaza_scenes.iter_mut() {
+        let performance = scene.kind == SceneKind::Performance;
+        for slot in scene.slots.iter_mut() {
+            let fixture = if performance {
+                &data.npc_data.performers
+            } else if slot.role == SlotRole::Worker {
+                &data.npc_data.vendors
+            } else {
+                continue;
+            };
+            slot.look = Some(*plaza_look_rng.choose(&fixture.looks));
+        }
+    }
+    let cells = regions.iter().map(|region| region.area).sum();
+    let main_plaza = regions
+        .iter()
+        .filter(|region| region.region_type() == RegionType::Plaza)
+        .max_by_key(|region| region.area)
+        .map(|region| region.centroid());
+    for (name, n) in [("plazas", counts[0]), ("nooks", counts[1]), ("parks", counts[2]), ("yards", counts[3])] {
+        run.state.report.count(name, n);
+    }
+    run.state.report.count("garden_lanterns", garden_lanterns);
+    run.state.report.count("open_space_cells", cells);
+    run.state.open_spaces = Some(TownOpenSpaces { place_labels, plaza_scenes, civic_features, cells, main_plaza });
+}
+
+/// Plaza fixtures: staff every harvested plaza scene. Stage performers, market
+/// vendors, and onlookers are fixtures like the industrial workers — always
+/// placed, independent of the resident bed budget. Each scene already carries
+/// its own position, facing, dialogue key, and bubble volume (criers/performers
+/// yell), so we just hand them a roster and staff them all. Live-only: no-op
+/// offline.
+async fn staff_plazas(editor: &mut Editor, run: &mut TownRun) {
+    use crate::generator::population::{build_roster, populate_npcs, Occupant};
+    let plaza_scenes = &need(&run.state.open_spaces, TownPhase::OpenSpaces).plaza_scenes;
+    if plaza_scenes.is_empty() {
+        return;
+    }
+    let npc_data = &run.data.npc_data;
+    let budget = plaza_scenes.len();
+    // Some market/stage onlookers are kid slots; mint exactly that many
+    // children in the roster so those scenes can be staffed (rest adults).
+    let kids = plaza_scenes
+        .iter()
+        .flat_map(|s| &s.slots)
+        .filter(|sl| sl.occupant == Occupant::ChildOnly)
+        .count();
+    let roster = build_roster(budget, kids, run.culture, npc_data, &mut run.state.id_alloc, &mut run.rng.derive());
+    match populate_npcs(editor, plaza_scenes.clone(), roster, budget, npc_data, &mut run.rng).await {
+        Ok(staffed) => {
+            println!("Staffed {} plaza NPCs", staffed);
+            run.state.report.count("plaza_npcs", staffed);
+        }
+        Err(e) => {
+            log::warn!("plaza staffing failed: {e}");
+            run.state.report.fail(e.to_string());
+        }
+    }
+}
+
+/// Population: size the resident crowd to beds, scatter it town-wide. Each
+/// house's budget is max(1, beds); the town total is their sum. Residents come
+/// from generated households (kin reciprocally wired, then cross-household
+/// links, then employment), and the town-wide draw seeds one resident per
+/// house, then fills the rest weighted by anchor weight, halving a house's
+/// weights each time it gains a resident so the crowd spreads instead of
+/// clustering. Live-only: no-op offline.
+async fn populate(editor: &mut Editor, run: &mut TownRun) {
+    use crate::generator::population::{
+        assign_employment, bind_workers, build_households, link_cross_household,
+        log_population_stats, log_sample_households, populate_town,
+    };
+    let TownHouses { manor_sign_sites, town_anchors, .. } = need(&run.state.houses, TownPhase::Houses);
+    let culture = run.culture;
+    let data = &run.data;
+    let rng = &mut run.rng;
+    let population_count: usize = town_anchors.iter().map(|h| h.population).sum();
+    let candidate_anchors: usize = town_anchors.iter().map(|h| h.scenes.len()).sum();
+    println!(
+        "Population target: {} residents across {} houses ({} candidate anchors)",
+        population_count,
+        town_anchors.len(),
+        candidate_anchors,
+    );
+    let npc_data = &data.npc_data;
+    // Four passes: shape households per house, link kin across town,
+    // assign professions, place at anchors. Each pass derives its own
+    // RNG so reordering or inserting a future pass doesn't shift
+    // downstream rolls.
+    let mut population = build_households(
+        town_anchors, culture, npc_data, &mut run.state.id_alloc, &mut rng.derive(),
+    );
+    link_cross_household(&mut population, &mut rng.derive());
+    assign_employment(&mut population, &mut rng.derive());
+
+    // Diagnostics: stats + a handful of sampled households so the kin graph
+    // is legible in the console without needing a debugger.
+    log_population_stats(&population);
+    log_sample_households(&population, 8);
+
+    // Letter each manor's name sign now its household surname is known, and
+    // record the family for the chronicle (surname + the manor's location,
+    // colour, and blazon from its `HouseAnchors`).
+    let mut manor_facts: Vec<ManorFact> = Vec::new();
+    for site in manor_sign_sites {
+        if let Some(hh) = population.households.iter().find(|h| h.home == site.anchor_idx) {
+            println!("Manor sign: {} {}", hh.surname, site.designation());
+            let anchor = &town_anchors[site.anchor_idx];
+            manor_facts.push(ManorFact {
+                surname: hh.surname.clone(),
+                designation: site.designation().to_string(),
+                pos: anchor.pos,
+                color: anchor.family_color,
+                blazon: anchor.banner_blazon.clone(),
+            });
+            crate::generator::buildings_v2::exterior::place_manor_sign(
+                editor, site, &hh.surname,
+            ).await;
+        }
+    }
+
+    // Bind residents to workplaces before seating anyone at home. The draft
+    // weights each unplaced adult by qualification (proximity-led) and spawns
+    // the winner at the post; posts with no taker left fall through to
+    // anonymous fixtures (`workplace_backfill`). Bound workers are marked
+    // `placed`, so `populate_town` skips them — each resident appears once.
+    // Every post (resident-filled or not) is tallied by trade for the summary.
+    let (work_slots, workplace_count) = discover_worker_slots(editor, data);
+    let mut worker_by_job: HashMap<String, usize> = HashMap::new();
+    for s in &work_slots {
+        *worker_by_job.entry(s.employment.clone()).or_insert(0) += 1;
+    }
+    let mut workplace_backfill: Vec<WorkerSlot> = Vec::new();
+    let mut bound_worker_count = 0usize;
+    match bind_workers(editor, &mut population, work_slots, npc_data, &mut rng.derive()).await {
+        Ok((bound, unfilled)) => {
+            println!(
+                "Bound {} residents to workplaces; {} posts need fixtures",
+                bound,
+                unfilled.len(),
+            );
+            bound_worker_count = bound;
+            workplace_backfill = unfilled;
+        }
+        Err(e) => {
+            log::warn!("worker binding failed: {e}");
+            run.state.report.fail(e.to_string());
+        }
+    }
+
+    let report = &mut run.state.report;
+    report.count("population", population_count);
+    report.count("houses", town_anchors.len());
+    report.count("workplaces", workplace_count);
+    report.count("bound_workers", bound_worker_count);
+    match populate_town(editor, town_anchors.clone(), population, npc_data, rng).await {
+        Ok(placed) => {
+            println!("Populated {} NPCs", placed);
+            report.count("npcs", placed);
+        }
+        Err(e) => {
+            log::warn!("NPC population failed: {e}");
+            report.fail(e.to_string());
+        }
+    }
+    run.state.population = Some(TownPopulation {
+        manor_facts, population_count, workplace_backfill, bound_worker_count, workplace_count, worker_by_job,
+    });
+}
+
+/// Worker fixtures: staff every workplace. Stand a small crew of worker NPCs
+/// just outside each placed building (urban processing shop or rural gather
+/// building), facing it, wearing the trade outfit that matches its type. These
+/// are fixtures: always placed, independent of the resident budget. The NBT
+/// interiors are opaque, so workers stand on clear ground cells at the
+/// footprint edge — never inside, never on a road or another building. A
+/// workplace can employ several hands (see the per-kind `workers` count in
+/// `data/npcs.yaml`).
+async fn staff_workplaces(editor: &mut Editor, run: &mut TownRun) {
+    use crate::generator::population::{build_roster, populate_npcs, AnchorSlot};
+
+    let TownPopulation {
+        workplace_backfill, bound_worker_count, workplace_count, worker_by_job, ..
+    } = need(&run.state.population, TownPhase::Population);
+    let bound_worker_count = *bound_worker_count;
+    let plaza_scenes = &need(&run.state.open_spaces, TownPhase::OpenSpaces).plaza_scenes;
+    let urban_industrial_count = need(&run.state.industry, TownPhase::Industrial).urban_industrial_count;
+    let rural_building_count = need(&run.state.rural, TownPhase::RuralBuildings).len();
+    let urban = editor.world().get_urban_points();
+
+    // Count plaza employment for the jobs summary: a stall is any scene with a
+    // `Worker` slot (market vendors), a stage is a `Performance` scene, and its
+    // performer slots are the per-stage cast. Onlookers/browsers aren't jobs.
+    let market_stall_count = plaza_scenes.iter()
+        .filter(|s| s.slots.iter().any(|sl| sl.role == SlotRole::Worker))
+        .count();
+    let stage_count = plaza_scenes.iter()
+        .filter(|s| s.kind == SceneKind::Performance)
+        .count();
+    let performer_slot_count: usize = plaza_scenes.iter()
+        .filter(|s| s.kind == SceneKind::Performance)
+        .map(|s| s.slots.len())
+        .sum();
+
+    // Workplace posts that binding couldn't fill from residents get an
+    // anonymous fixture: a fresh skin rolled from the post's pool, standing
+    // where binding would have seated the resident. (Residents bound to
+    // workplaces were already spawned in the population pass; the claim
+    // scan + stand-cell geometry lives in `discover_worker_slots`.)
+    let npc_data = &run.data.npc_data;
+    let mut worker_rng = run.rng.derive();
+
+    let mut worker_scenes: Vec<AnchorScene> = Vec::new();
+    for slot in workplace_backfill {
+        let look = *worker_rng.choose(&slot.looks);
+        worker_scenes.push(AnchorScene::worker(slot.stand, slot.facing, look, &slot.employment));
+    }
+
+    // Total worker posts = residents bound in the population pass + the
+    // anonymous backfill scenes just built; guards are appended below.
+    let backfill_slots = worker_scenes.len();
+    let industrial_job_slots = bound_worker_count + backfill_slots;
+
+    // ---- Guard posts: gates + wall towers ----
+    // Gates get 1–2 guards each; each tower has a 10% chance of 2 guards and a
+    // 20% chance of 1 (else none). Guards carry their own `guarding` dialogue,
+    // watching the approaches. Each guard's appearance is rolled from the
+    // guards fixture's `looks` pool (villager professions and/or mobs like
+    // pillagers), so a post can mix both.
+    use crate::generator::npc::NpcLook;
+    let guard_looks = &npc_data.guards.looks;
+    let guard_scene = |feet: Point3D, facing: f32, look: NpcLook| -> AnchorScene {
+        let mut slot = AnchorSlot::new(feet, facing, SlotRole::Worker);
+        slot.look = Some(look);
+        slot.dialogue = Some("guarding".to_string());
+        AnchorScene::group(SceneKind::Solo, vec![slot])
+    };
+    let town_centre = {
+        let n = urban.len().max(1) as i32;
+        urban.iter().fold(Point2D::ZERO, |a, &p| a + p) / n
+    };
+    // Gates: one guard a couple cells inside the opening; when a gate gets a
+    // second, it stands the same distance outside — a guard on each side of
+    // the gate. Both face the opening.
+    for (gate_point, dir) in editor.world().gate_locations.clone() {
+        let base = gate_point.drop_y();
+        let fwd: Point2D = dir.into();
+        // One cell to each side of the gate centre — in the opening, not the
+        // wall a couple cells away.
+        let inside = Point2D::new(base.x - fwd.x, base.y - fwd.y);
+        let outside = Point2D::new(base.x + fwd.x, base.y + fwd.y);
+        let stands: Vec<Point2D> = if worker_rng.percent(50) {
+            vec![inside, outside]
+        } else {
+            vec![inside]
+        };
+        for s in stands {
+            // Stand on the gate's own cleared floor (`gate_point.y`), which is
+            // where the gateway punched its air column upward. Re-deriving the
+            // height from the ocean-floor heightmap dropped feet below the
+            // threshold, burying — and suffocating — the guard in gate blocks.
+            let y = gate_point.y;
+            let feet = Point3D::new(s.x, y, s.y);
+            let facing =
+                crate::generator::population::yaw_toward(feet, Point3D::new(base.x, y, base.y));
+            let look = *worker_rng.choose(guard_looks);
+            let mut scene = guard_scene(feet, facing, look);
+            // Gates often sit on a slabbed threshold (sandstone slabs on a
+            // desert gateway, stair-and-slab approach on a stone one). When
+            // the block beneath the guard's feet is a slab, lift them half
+            // a block so they stand on the slab top rather than sunk in it.
+            let underfoot = editor.try_get_block(LocalPoint3D::new(s.x, y - 1, s.y));
+            if matches!(
+                underfoot.map(|b| crate::minecraft::BlockForm::infer_from_block(&b.id)),
+                Some(crate::minecraft::BlockForm::Slab),
+            ) {
+                scene.slots[0].y_offset = 0.5;
+            }
+            worker_scenes.push(scene);
+        }
+    }
+    // Towers: weighted small chance of 1–2 guards on the walkway beside each.
+    for posts in editor.world().tower_guard_posts.clone() {
+        let roll = worker_rng.rand_i32(100);
+        let n: usize = if roll < 10 { 2 } else if roll < 30 { 1 } else { 0 };
+        for feet in posts.into_iter().take(n) {
+            let facing = crate::generator::population::yaw_toward(
+                Point3D::new(town_centre.x, feet.y, town_centre.y),
+                feet,
+            );
+            let look = *worker_rng.choose(guard_looks);
+            let mut scene = guard_scene(feet, facing, look);
+            scene.slots[0].y_offset = 0.5; // stand on the battlement slab, not sunk in it
+            worker_scenes.push(scene);
         }
     }
+    let guard_count = worker_scenes.len() - backfill_slots;
+
+    if !worker_scenes.is_empty() {
+        // Roster supplies names/dialogue/biome; each scene's slot
+        // overrides the profession, so the roll here is incidental.
+        let worker_roster = build_roster(
+            worker_scenes.len(), 0, run.culture, npc_data, &mut run.state.id_alloc, &mut run.rng.derive(),
+        );
+        let budget = worker_scenes.len();
+        match populate_npcs(editor, worker_scenes, worker_roster, budget, npc_data, &mut run.rng).await {
+            Ok(staffed) => {
+                println!(
+                    "Staffed {} fixture NPCs ({} backfill workers + {} guards); {} of {} posts across {} workplaces filled by residents",
+                    staffed, backfill_slots, guard_count, bound_worker_count, industrial_job_slots, workplace_count,
+                );
+                run.state.report.count("fixture_npcs", staffed);
+            }
+            Err(e) => {
+                log::warn!("worker/guard staffing failed: {}", e);
+                run.state.report.fail(e.to_string());
+            }
+        }
+    }
+
+    // ---- Jobs summary ----
+    let total_industrial = urban_industrial_count + rural_building_count;
+    let approx_jobs =
+        industrial_job_slots + guard_count + market_stall_count + performer_slot_count;
+    let report = &mut run.state.report;
+    report.count("worker_posts", industrial_job_slots);
+    report.count("guards", guard_count);
+    report.count("market_stalls", market_stall_count);
+    report.count("performers", performer_slot_count);
+    println!("=== JOBS SUMMARY ===");
+    println!(
+        "Industrial/resource buildings: {} ({} urban + {} rural)",
+        total_industrial, urban_industrial_count, rural_building_count,
+    );
+    println!("Workers: {}", industrial_job_slots);
+    println!("Guards: {} (gates + towers)", guard_count);
+    println!("Market stalls: {}", market_stall_count);
+    println!("Stages: {} ({} performer slots)", stage_count, performer_slot_count);
+    println!(
+        "Approx jobs available: {} (workers {} + guards {} + vendors {} + performers {})",
+        approx_jobs, industrial_job_slots, guard_count, market_stall_count, performer_slot_count,
+    );
+
+    // Employment by job: building trades (sorted by count), then the
+    // non-building roles (guards, vendors, performers).
+    println!("--- Employment by job ---");
+    let mut by_job: Vec<(String, usize)> = worker_by_job.iter().map(|(job, n)| (job.clone(), *n)).collect();
+    if guard_count > 0 {
+        by_job.push((npc_data.guards.employment.clone(), guard_count));
+    }
+    if market_stall_count > 0 {
+        by_job.push((npc_data.vendors.employment.clone(), market_stall_count));
+    }
+    if performer_slot_count > 0 {
+        by_job.push((npc_data.performers.employment.clone(), performer_slot_count));
+    }
+    by_job.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    for (job, count) in &by_job {
+        println!("  {:<14} {}", job, count);
+    }
+}
+
+/// Top-down town map (SVG) for inspection: footprints + named roads coloured
+/// by id + the abstract MST/node overlay, with sign posts marked. Its plain
+/// plan goes into the world too: as a map item's `.dat` beside the SVG, and
+/// painted on boards at the gates and in the manor libraries.
+async fn draw_town_map(editor: &mut Editor, run: &mut TownRun) {
+    let layout = need(&run.state.layout, TownPhase::Roads);
+    let streets = need(&run.state.streets, TownPhase::StreetSigns);
+    let open_spaces = need(&run.state.open_spaces, TownPhase::OpenSpaces);
+    let urban = editor.world().get_urban_points();
+    let road_network = &layout.road_network;
+    let svg = crate::generator::paths::render_town_map(
+        editor.world(), &urban, &road_network.paths, &road_network.road_labels,
+        &streets.road_names, &layout.alley_band, Some(road_network), &streets.signs, &open_spaces.place_labels,
+    );
+    let stem = town_output_stem(run.town);
+    std::fs::create_dir_all("output").ok();
+    match std::fs::write(format!("{stem}.svg"), &svg) {
+        Ok(()) => println!("Wrote town map to {stem}.svg"),
+        Err(e) => log::warn!("failed to write town map: {e}"),
+    }
+    match crate::generator::paths::rasterize_to_png(&svg, &format!("{stem}.png")) {
+        Ok(()) => println!("Wrote town map to {stem}.png"),
+        Err(e) => log::warn!("failed to render {stem}.png: {e}"),
+    }
+
+    let plan = crate::generator::paths::render_town_plan(
+        editor.world(), &urban, &road_network.paths, &road_network.road_labels, &layout.alley_band,
+    );
+    let written = town_map_data(&plan, &urban, editor.world().origin())
+        .and_then(|map| Ok(std::fs::write(format!("{stem}_map.dat"), map.to_bytes()?)?));
+    match written {
+        Ok(()) => println!("Wrote town map item to {stem}_map.dat (copy it into a save's data folder as map_<id>.dat)"),
+        Err(e) => {
+            log::warn!("failed to write {stem}_map.dat: {e}");
+            run.state.report.fail(format!("map item: {}", failure_reason(&e.to_string())));
+        }
+    }
+
+    let houses = need(&run.state.houses, TownPhase::Houses);
+    let mut boards = gate_boards(editor, cells_centroid(&urban.iter().copied().collect::<Vec<_>>()));
+    let at_gates = boards.len();
+    boards.extend(houses.library_boards.iter().cloned());
+    match paint_boards(editor, &plan, &boards).await {
+        Ok(painted) => {
+            println!("Painted {painted} town map boards ({at_gates} at the gates)");
+            run.state.report.count("map_boards", painted);
+        }
+        Err(e) => {
+            log::warn!("failed to paint the town map boards: {e}");
+            run.state.report.fail(format!("map boards: {}", failure_reason(&e.to_string())));
+        }
+    }
+}
+
+/// Chronicle: digest the finished town into a dossier (name, colours, biomes,
+/// roads, trades, families, greens, gates) and have the AI write a guidebook,
+/// dropped into the player's inventory. Live-only — `give_player_book` posts
+/// to the server; failures are non-fatal.
+async fn write_chronicle(editor: &mut Editor, run: &mut TownRun) {
+    let result = need(&run.state.economy, TownPhase::Economy);
+    let houses = need(&run.state.houses, TownPhase::Houses);
+    let layout = need(&run.state.layout, TownPhase::Roads);
+    let streets = need(&run.state.streets, TownPhase::StreetSigns);
+    let open_spaces = need(&run.state.open_spaces, TownPhase::OpenSpaces);
+    let population = need(&run.state.population, TownPhase::Population);
+    let named = need(&run.state.name, TownPhase::Welcome);
+    let urban = editor.world().get_urban_points();
+    let mut chronicle_rng = RNG::from_seed_and_string(run.seed, "district_names");
+    // The settlement's economy as lowercased English nouns: harvests are the
+    // raw resources the rural parcels gathered (`supply`), produces are the
+    // finished goods the chains turned them into. Pretty names come from the
+    // resource registry; ids fall back to underscore-stripped form. Sorted +
+    // deduped so the chronicle's fact list is stable across runs.
+    let reg = &run.data.resource_registry;
+    let pretty = |id: &str| reg.resources().get(id)
+        .map(|r| r.name.to_lowercase())
+        .unwrap_or_else(|| id.replace('_', " "));
+    let mut harvests: Vec<String> = result.supply.keys().map(|id| pretty(id)).collect();
+    harvests.sort();
+    harvests.dedup();
+    let mut produces: Vec<String> = result.finished_goods.iter().map(|(id, _)| pretty(id)).collect();
+    produces.sort();
+    produces.dedup();
+    let mut dossier = assemble_dossier(
+        editor, &urban, run.culture, named, &houses.color_scheme.town, &houses.civic_blazon,
+        &streets.road_names, &layout.road_network.road_labels, &open_spaces.place_labels, &population.manor_facts,
+        houses.total_buildings, population.population_count, &harvests, &produces, &mut chronicle_rng,
+    );
+    dossier.neighbours = run.neighbours.clone();
+    // Copies for the studies and libraries, and one on a lectern of its own
+    // at the main plaza or a gate.
+    let centre = cells_centroid(&urban.iter().copied().collect::<Vec<_>>());
+    let mut holders = houses.book_holders.clone();
+    holders.extend(civic_lectern(editor, open_spaces.main_plaza, centre));
+    let mut ai_log = AiLog::default();
+    match crate::generator::chronicle::generate_chronicle(&*editor, &dossier, &holders, &mut ai_log).await {
+        Ok(copies) => run.state.report.count("chronicle_copies", copies),
+        Err(e) => {
+            log::warn!("Chronicle generation failed: {e}");
+            run.state.report.fail(e.to_string());
+        }
+    }
+    let usage = ai_log.usage();
+    run.state.report.count("ai_calls", ai_log.calls.len());
+    run.state.report.count("prompt_tokens", usage.prompt_tokens);
+    run.state.report.count("completion_tokens", usage.completion_tokens);
+    for failure in ai_log.calls.iter().flat_map(|call| &call.errors) {
+        run.state.report.fail(format!("ai reply: {}", failure_reason(failure)));
+    }
+    run.state.report.ai_calls.extend(ai_log.calls);
+}
+
+/// Seats the houses lot by lot, densest road tier first, then paves the verge
+/// between each main road and the house fronts.
+#[allow(clippy::too_many_arguments)]
+async fn build_houses(
+    editor: &mut Editor,
+    seed: Seed,
+    culture: Culture,
+    profile: &SettlementProfile,
+    layout: &TownLayout,
+    data: &LoadedData,
+    rng: &mut RNG,
+    report: &mut RunReport,
+) -> TownHouses {
+    let TownLayout {
+        blocks, sub_blocks, lot_block, alley_band, arterial_band, collector_band, road_h, ..
+    } = layout;
+    let materials = profile.infrastructure.for_culture(culture);
+    let urban = editor.world().get_urban_points();
 
     // ---- Phase 4: hierarchical house placement ----
     // Per lot, walk frontage densest-tier first: arterial → collector →
@@ -1109,13 +2305,13 @@ pub async fn generate_town(
     // Densest tier first; size pool per tier (houses on the main roads,
     // cottages on the back lanes).
     // House + Hall on every tier. Manor is no longer opportunistic — it's
-    // seeded in a deliberate pre-pass: pick MANOR_CAP arterial-eligible lots
+    // seeded in a deliberate pre-pass: pick `manor_cap` arterial-eligible lots
     // up front and process them first with their arterial tier forced to
     // Manor-only. Lots without an arterial frontage long enough for a Manor
     // are ineligible. If a chosen Manor build fails we just continue (no
     // fallback to House/Hall on that slice); the lot still gets its other
     // tiers placed normally below.
-    const MANOR_CAP: usize = 2;
+    let manor_cap = profile.manor_cap;
     let mut manors_placed = 0usize;
     let manor_min_front = *SizeClass::Manor.front_width_range().start();
     // Manors prefer arterial frontage, but fall back to collector if no lot
@@ -1136,12 +2332,12 @@ pub async fn generate_town(
             .map(|(i, _)| i)
             .collect()
     };
-    let arterial_eligible = eligible_for_band(&arterial_band);
+    let arterial_eligible = eligible_for_band(arterial_band);
     let (eligible, manor_tier_idx, manor_tier_label): (Vec<usize>, usize, &str) =
         if !arterial_eligible.is_empty() {
             (arterial_eligible, 0, "arterial")
         } else {
-            (eligible_for_band(&collector_band), 1, "collector (arterial empty)")
+            (eligible_for_band(collector_band), 1, "collector (arterial empty)")
         };
     // Japanese manors are always engawa, which insets the interior by 2 cells a
     // side and so makes the default Manor footprint feel cramped. For that
@@ -1161,7 +2357,7 @@ pub async fn generate_town(
             const ENGAWA_MANOR_FW: std::ops::RangeInclusive<i32> = 11..=14;
             const ENGAWA_MANOR_DEPTH_HI: i32 = 16;
             let manor_band: &HashSet<Point2D> =
-                if manor_tier_idx == 0 { &arterial_band } else { &collector_band };
+                if manor_tier_idx == 0 { arterial_band } else { collector_band };
             let mut best_by_lot: Vec<(usize, ManorChoice)> = Vec::new();
             for &lot_idx in &eligible {
                 let Some(plot) = plot_from_block(&sub_blocks[lot_idx]) else { continue; };
@@ -1192,7 +2388,7 @@ pub async fn generate_town(
             }
             // Highest usable interior first; lot index breaks ties for determinism.
             best_by_lot.sort_by(|a, b| b.1.score.cmp(&a.1.score).then(a.0.cmp(&b.0)));
-            best_by_lot.truncate(MANOR_CAP);
+            best_by_lot.truncate(manor_cap);
             println!(
                 "Manor pre-pass (engawa scan): {} {}-eligible lots, chose {} by largest interior (scores {:?})",
                 eligible.len(), manor_tier_label, best_by_lot.len(),
@@ -1204,7 +2400,7 @@ pub async fn generate_town(
         } else {
             let mut pool = eligible.clone();
             rng.derive().shuffle(&mut pool);
-            let lots: HashSet<usize> = pool.into_iter().take(MANOR_CAP).collect();
+            let lots: HashSet<usize> = pool.into_iter().take(manor_cap).collect();
             println!(
                 "Manor pre-pass: {} {}-eligible lots, chose {} to host Manors",
                 eligible.len(), manor_tier_label, lots.len(),
@@ -1224,7 +2420,7 @@ pub async fn generate_town(
     // (50/25/25 with a random accent) plus a unique family colour per manor. A
     // dedicated derived RNG keeps colour draws from shifting the placement
     // streams. The two town colours feed the settlement namer further below.
-    let color_scheme = ColorScheme::new(culture, MANOR_CAP, &mut rng.derive());
+    let color_scheme = ColorScheme::new(culture, manor_cap, &mut rng.derive());
     let mut color_rng = rng.derive();
     println!(
         "Town colours: dominant={:?}, second={:?}; manor family colours={:?}",
@@ -1254,14 +2450,14 @@ pub async fn generate_town(
     let mut sign_rng = rng.derive();
     let mut manor_sign_sites: Vec<crate::generator::buildings_v2::exterior::ManorSignSite> =
         Vec::new();
-    // Manor families for the chronicle, paired with their surname once the
-    // population pass names each household (see the manor-sign lettering loop).
-    let mut manor_facts: Vec<ManorFact> = Vec::new();
 
     let mut total_buildings = 0usize;
+    let mut failures: BTreeMap<String, usize> = BTreeMap::new();
     // Per-house NPC anchors + bed-derived population budget, gathered from every
     // house and fed to the town-wide population pass once the town is built.
     let mut town_anchors: Vec<crate::generator::population::HouseAnchors> = Vec::new();
+    let mut book_holders: Vec<BookHolder> = Vec::new();
+    let mut library_boards: Vec<MapBoard> = Vec::new();
     // Houses placed per SizeClass — used to size the wealth distribution
     // (Cottage/House = common, Hall = wealthy craftsman, Manor = elite).
     let mut size_counts: HashMap<String, usize> = HashMap::new();
@@ -1289,7 +2485,7 @@ pub async fn generate_town(
         // had eligible frontages; otherwise collector) gets a Manor-only
         // pool until the cap is reached. Other tiers — and other lots —
         // stay House+Hall. Alley never hosts Manors.
-        let is_manor_lot = manor_lots.contains(&lot_idx) && manors_placed < MANOR_CAP;
+        let is_manor_lot = manor_lots.contains(&lot_idx) && manors_placed < manor_cap;
         let arterial_pool: &[SizeClass] = if is_manor_lot && manor_tier_idx == 0 {
             &[SizeClass::Manor]
         } else {
@@ -1301,9 +2497,9 @@ pub async fn generate_town(
             &[SizeClass::House, SizeClass::Hall]
         };
         let tiers_local: [(&HashSet<Point2D>, &[SizeClass]); 3] = [
-            (&arterial_band, arterial_pool),
-            (&collector_band, collector_pool),
-            (&alley_band, &[SizeClass::House, SizeClass::Hall]),
+            (arterial_band, arterial_pool),
+            (collector_band, collector_pool),
+            (alley_band, &[SizeClass::House, SizeClass::Hall]),
         ];
 
         'tier_loop: for (ti, (band, pool)) in tiers_local.iter().enumerate() {
@@ -1393,7 +2589,7 @@ pub async fn generate_town(
                         .unwrap_or(&style_scheme);
                     let mut palette = scheme
                         .next_style(&mut style_rng)
-                        .roll_palette(&mut style_rng, &data, local_wood.as_ref());
+                        .roll_palette(&mut style_rng, data, local_wood.as_ref());
                     // Apply the town colour identity: a manor flies its unique
                     // family colour; every other building draws from the weighted
                     // town scheme. This `primary_color` then tints the building's
@@ -1428,7 +2624,7 @@ pub async fn generate_town(
                     let roof_styles = culture.roof_styles_for(size_class);
                     let roof_style = roof_styles[rng.rand_i32_range(0, roof_styles.len() as i32) as usize];
                     let footprint = crate::generator::buildings_v2::footprint::generate::generate_footprint_from_core(
-                        &mut rng, &plot, rect, frontage.outward, &size_class, culture.square_bias(),
+                        rng, &plot, rect, frontage.outward, &size_class, culture.square_bias(),
                     );
                     // Door scoring needs the full footprint bounds, not just
                     // the core rect, so a wing extending rearward doesn't
@@ -1450,15 +2646,7 @@ pub async fn generate_town(
                                 }
                             }
                         }
-                        best.map(|(_, y, cell)| {
-                            // If the fronting road cell carries a half-step slab,
-                            // raise the floor one block above the slab so the door
-                            // steps down onto it instead of opening onto a lip.
-                            match slab_y_by_cell.get(&cell) {
-                                Some(&slab_y) => slab_y + 1,
-                                None => y,
-                            }
-                        })
+                        best.map(|(_, y, cell)| door_sill_y(editor, cell, y))
                     };
                     let mut bctx = BuildingContext::new(culture, size_class, roof_style);
                     bctx.base_y_override = base_lvl;
@@ -1476,7 +2664,7 @@ pub async fn generate_town(
                     // (jetty is medieval-only, engawa Japanese-only).
                     let (jn, jd) = culture.jetty_chance();
                     bctx.jetty = jd > 0 && rng.rand_i32_range(0, jd as i32) < jn as i32;
-                    let mut bctx_editor = BuildCtx::new(editor, &data, &palette, &mut rng);
+                    let mut bctx_editor = BuildCtx::new(editor, data, &palette, rng);
                     match build_house(&mut bctx_editor, footprint, &bctx, plot_bounds).await {
                         Ok(output) => {
                             // A manor flies its family colour: banners flanking
@@ -1518,9 +2706,22 @@ pub async fn generate_town(
                                         .max(1)
                                 })
                                 .sum();
+                            book_holders.extend(
+                                output.room_plan.rooms.iter()
+                                    .filter(|r| matches!(r.room_type, RoomType::Library | RoomType::Study))
+                                    .flat_map(|r| &r.furniture)
+                                    .flat_map(|f| &f.containers)
+                                    .filter(|(_, block)| BookHolder::can_hold(block))
+                                    .map(|(pos, block)| BookHolder { pos: *pos, block: block.clone() }),
+                            );
+                            library_boards.extend(
+                                output.room_plan.rooms.iter()
+                                    .filter(|r| r.room_type == RoomType::Library)
+                                    .filter_map(|r| library_board(editor, r, output.frame.floor_y(r.floor), output.frame.ceiling_y(r.floor))),
+                            );
                             // Scale capacity so houses feel lived-in, floored at 1.
                             let population =
-                                ((beds as f32 * POPULATION_PER_BED).round() as usize).max(1);
+                                ((beds as f32 * profile.population_per_bed).round() as usize).max(1);
                             town_anchors.push(crate::generator::population::HouseAnchors {
                                 scenes: output.npc_anchors,
                                 population,
@@ -1572,6 +2773,8 @@ pub async fn generate_town(
                         }
                         Err(msg) => {
                             tier_fail[ti] += 1;
+                            *failures.entry(failure_reason(&msg)).or_default() += 1;
+                            report.fail(failure_reason(&msg));
                             log::warn!("placement build_house failed: {}", msg);
                             // A scanned manor gets one shot; on failure give up its
                             // tier rather than retrying the forced size shifted over.
@@ -1610,546 +2813,55 @@ pub async fn generate_town(
         tier_short[1], tier_unfit[1],
         tier_short[2], tier_unfit[2],
     );
+    report.count("houses_placed", total_buildings);
+    for (size, n) in &size_counts {
+        report.count(&size.to_lowercase(), *n);
+    }
+    for (ti, tier) in ["arterial", "collector", "subdivider"].iter().enumerate() {
+        report.count(&format!("{tier}_frontage_cells"), tier_cells[ti]);
+        report.count(&format!("{tier}_placed"), tier_placed[ti]);
+        report.count(&format!("{tier}_failed"), tier_fail[ti]);
+        report.count(&format!("{tier}_short"), tier_short[ti]);
+        report.count(&format!("{tier}_unfit"), tier_unfit[ti]);
+    }
 
     // Pave the verge: a forecourt of the road's own material in the gap
     // between each main road and its houses, so the diagonal set-back reads
     // as a paved shoulder. Painted at the live ground top (h-1), matching the
-    // post-flatten/foundation surface. Arterial verge = stone bricks (its
-    // road material), collector verge = cobblestone.
+    // post-flatten/foundation surface. Arterial verge = the arterial road
+    // material (stone bricks by default), collector verge = the collector's.
     let verge_blocks = [
-        Block { id: arterial_mat.into(), data: None, state: None },
-        Block { id: collector_mat.into(), data: None, state: None },
+        Block { id: materials.arterial.as_str().into(), data: None, state: None },
+        Block { id: materials.collector.as_str().into(), data: None, state: None },
     ];
     let mut verge_total = 0usize;
     for (ti, cells) in tier_verge.iter().enumerate() {
         for c in cells {
             let Some(h) = editor.world().get_ocean_floor_height_at(*c) else { continue; };
-            editor.place_block(&verge_blocks[ti], Point3D::new(c.x, h - 1, c.y)).await;
+            editor.place_block(&verge_blocks[ti], LocalPoint3D::new(c.x, h - 1, c.y)).await;
             verge_total += 1;
         }
     }
     println!("Paved {} verge cells (arterial {} + collector {})", verge_total, tier_verge[0].len(), tier_verge[1].len());
+    report.count("verge_cells", verge_total);
 
-    // Street lighting: run last, after houses have claimed their cells, so
-    // lamps line every road's verge without landing on a building. The city
-    // generator picks the lantern type city-wide.
-    let city_rect = editor.world().world_rect_2d();
-    let city_centre = (city_rect.origin + city_rect.max()) / 2;
-    let cold = match editor.world().get_surface_biome_at(city_centre) {
-        Some(biome) => {
-            let n = biome.name();
-            n.contains("snowy") || n.contains("frozen") || n.contains("taiga")
-        }
-        None => false,
-    };
-    let street_lantern: crate::minecraft::Block = if cold {
-        "minecraft:soul_lantern".into()
-    } else {
-        "minecraft:lantern".into()
-    };
-    let lamps = crate::generator::paths::place_street_lights(&*editor, &all_paths, &street_lantern).await;
-    println!("Placed {} street lamps", lamps.len());
-
-    // Name the roads (layered: landmark → gate/centre → generic) now that all
-    // buildings have claimed their cells, then sign the intersections. Runs
-    // before the open-space pass; each sign cell is claimed as a path so
-    // plazas/parks/etc. won't furnish over it.
-    let mut name_rng = RNG::new(seed).derive();
-    let road_names = crate::generator::paths::name_roads_layered(
-        editor.world(), &road_network.road_labels, &all_paths,
-        &editor.world().gate_locations.clone(), culture, &mut name_rng,
-    );
-    let signs = crate::generator::paths::place_street_signs(
-        editor, &all_paths, &road_network.road_labels, &road_names,
-    ).await;
-    println!("Placed {} street signs", signs.len());
-
-    // ---- Open spaces: furnish the leftover gaps between buildings and roads ----
-    // Detect the empty pockets inside the wall and furnish each by type: plazas
-    // (paved civic squares), nooks (small ringed gardens), parks (large green
-    // commons), and yards (perimeter kitchen gardens).
-    let mut place_labels: Vec<(Point2D, String)> = Vec::new();
-    // NPC standing-spot scenes harvested from the open spaces — plazas (stage
-    // performers, market vendors, onlookers in the crowd) and parks (idle folk
-    // strolling the green). Staffed as fixtures after furnishing, independent of
-    // the resident bed budget — a market or park is busy regardless of how many
-    // beds the town has.
-    let mut plaza_scenes: Vec<crate::generator::population::AnchorScene> = Vec::new();
-    // Open-space landmark keys (plaza/park `.key()`) gathered for the settlement
-    // namer — a town with a market or graveyard can be named for it.
-    let mut civic_features: Vec<String> = Vec::new();
-    {
-        use crate::generator::open_space::{
-            detect_regions, furnish_nook, furnish_park, furnish_plaza, furnish_yard, OpenSpaceNames,
-            ParkType, Theme, RegionType,
-        };
-        let regions = detect_regions(editor.world(), &urban);
-        let theme = Theme::for_culture(culture);
-        let mut os_rng = rng.derive();
-        // Names are picked alongside furnishing so a park is named for the type it
-        // was actually built as; `used` keeps every name unique within the town.
-        let names = OpenSpaceNames::load();
-        let mut used: HashSet<String> = HashSet::new();
-        let mut counts = [0usize; 4]; // plaza, nook, park, yard
-        // Stone lanterns (tōrō) scattered through the green spaces — Japanese
-        // only; the call is a no-op for other cultures, so we ring nooks and parks
-        // (but not yards, nor paved plazas, nor cemeteries) after furnishing. They
-        // are stoned to match each garden's own masonry (`theme.stone`).
-        let mut garden_lanterns = 0usize;
-        for region in &regions {
-            match region.region_type() {
-                RegionType::Plaza => {
-                    let (plaza_type, scenes) = furnish_plaza(&*editor, region, &mut os_rng, &theme).await;
-                    plaza_scenes.extend(scenes);
-                    civic_features.push(plaza_type.key().to_string());
-                    if let Some(name) = names.as_ref().and_then(|n| n.name_plaza(plaza_type, culture, &mut os_rng, &mut used)) {
-                        place_labels.push((region.centroid(), name));
-                    }
-                    counts[0] += 1;
-                }
-                RegionType::Nook => {
-                    furnish_nook(&*editor, region, &mut os_rng, &theme).await;
-                    garden_lanterns += crate::generator::paths::scatter_garden_lanterns(
-                        &*editor, region, &data, culture, theme.stone, &mut os_rng,
-                    ).await;
-                    counts[1] += 1;
-                }
-                RegionType::Park => {
-                    let (park_type, scenes) = furnish_park(editor, region, &mut os_rng, &theme).await;
-                    plaza_scenes.extend(scenes);
-                    civic_features.push(park_type.key().to_string());
-                    if let Some(name) = names.as_ref().and_then(|n| n.name_park(park_type, culture, &mut os_rng, &mut used)) {
-                        place_labels.push((region.centroid(), name));
-                    }
-                    // Skip cemeteries — a glowing lantern doesn't suit a graveyard.
-                    if park_type != ParkType::Cemetery {
-                        garden_lanterns += crate::generator::paths::scatter_garden_lanterns(
-                            &*editor, region, &data, culture, theme.stone, &mut os_rng,
-                        ).await;
-                    }
-                    counts[2] += 1;
-                }
-                RegionType::Yard => {
-                    furnish_yard(&*editor, region, &mut os_rng, &theme).await;
-                    counts[3] += 1;
-                }
-            }
-        }
-        println!(
-            "Furnished open spaces — plaza {} nook {} park {} yard {} | {} garden lanterns",
-            counts[0], counts[1], counts[2], counts[3], garden_lanterns,
-        );
-
-        // Skin the plaza fixtures from data: a stage's performers and a stall's
-        // vendor each roll a look from their fixture pool. Onlookers/browsers in
-        // the crowd keep the roster's own look (their slots are left untouched).
-        use crate::generator::population::{SceneKind, SlotRole};
-        let mut plaza_look_rng = rng.derive();
-        for scene in plaza_scenes.iter_mut() {
-            let performance = scene.kind == SceneKind::Performance;
-            for slot in scene.slots.iter_mut() {
-                let fixture = if performance {
-                    &data.npc_data.performers
-                } else if slot.role == SlotRole::Worker {
-                    &data.npc_data.vendors
-                } else {
-                    continue;
-                };
-                slot.look = Some(*plaza_look_rng.choose(&fixture.looks));
-            }
-        }
-    }
-
-    // Count plaza employment for the jobs summary: a stall is any scene with a
-    // `Worker` slot (market vendors), a stage is a `Performance` scene, and its
-    // performer slots are the per-stage cast. Onlookers/browsers aren't jobs.
-    let (market_stall_count, stage_count, performer_slot_count) = {
-        use crate::generator::population::{SceneKind, SlotRole};
-        let stalls = plaza_scenes.iter()
-            .filter(|s| s.slots.iter().any(|sl| sl.role == SlotRole::Worker))
-            .count();
-        let stages = plaza_scenes.iter()
-            .filter(|s| s.kind == SceneKind::Performance)
-            .count();
-        let performers: usize = plaza_scenes.iter()
-            .filter(|s| s.kind == SceneKind::Performance)
-            .map(|s| s.slots.len())
-            .sum();
-        (stalls, stages, performers)
-    };
-
-    // Town-wide NPC id allocator. Shared across every staffing call below
-    // (plaza fixtures, residents, workplace workers, guards) so every NPC has
-    // a unique id and kin relationships can reference any of them.
-    let mut id_alloc = crate::generator::population::IdAllocator::new();
-
-    // ---- Plaza fixtures: staff every harvested plaza scene ----
-    // Stage performers, market vendors, and onlookers are fixtures like the
-    // industrial workers below — always placed, independent of the resident bed
-    // budget. Each scene already carries its own position, facing, dialogue key,
-    // and bubble volume (criers/performers yell), so we just hand them a roster
-    // and staff them all. Live-only: no-op offline.
-    if !plaza_scenes.is_empty() {
-        use crate::generator::population::{build_roster, populate_npcs, Occupant};
-        let npc_data = &data.npc_data;
-        let budget = plaza_scenes.len();
-        // Some market/stage onlookers are kid slots; mint exactly that many
-        // children in the roster so those scenes can be staffed (rest adults).
-        let kids = plaza_scenes
-            .iter()
-            .flat_map(|s| &s.slots)
-            .filter(|sl| sl.occupant == Occupant::ChildOnly)
-            .count();
-        let roster = build_roster(budget, kids, culture, npc_data, &mut id_alloc, &mut rng.derive());
-        match populate_npcs(editor, plaza_scenes, roster, budget, npc_data, &mut rng).await {
-            Ok(staffed) => println!("Staffed {} plaza NPCs", staffed),
-            Err(e) => log::warn!("plaza staffing failed: {e}"),
-        }
-    }
-
-    // Worker binding runs inside the population pass below (before residential
-    // placement). These outlive that scope so the worker-fixture block can
-    // backfill the posts binding couldn't fill from residents, and the jobs
-    // summary can report the full per-trade tally.
-    let mut workplace_backfill: Vec<crate::generator::population::WorkerSlot> = Vec::new();
-    let mut bound_worker_count = 0usize;
-    let mut workplace_count = 0usize;
-    let mut worker_by_job: HashMap<String, usize> = HashMap::new();
-    // Resident headcount (sum of per-house bed budgets), captured from the
-    // population block below so the chronicle can quote the town's size. This is
-    // the deterministic target the crowd is sized to, computed regardless of the
-    // live NPC placement (which is a no-op offline).
-    let mut population_count = 0usize;
-
-    // ---- Population: size the resident crowd to beds, scatter it town-wide ----
-    // Each house's budget is max(1, beds); the town total is their sum.
-    // Residents come from generated households (kin reciprocally wired, then
-    // cross-household links, then employment), and the town-wide draw seeds
-    // one resident per house, then fills the rest weighted by anchor weight,
-    // halving a house's weights each time it gains a resident so the crowd
-    // spreads instead of clustering. Live-only: no-op offline.
-    {
-        use crate::generator::population::{
-            assign_employment, build_households, link_cross_household,
-            log_population_stats, log_sample_households, populate_town,
-        };
-        let budget: usize = town_anchors.iter().map(|h| h.population).sum();
-        population_count = budget;
-        let candidate_anchors: usize = town_anchors.iter().map(|h| h.scenes.len()).sum();
-        println!(
-            "Population target: {} residents across {} houses ({} candidate anchors)",
-            budget,
-            town_anchors.len(),
-            candidate_anchors,
-        );
-        let npc_data = &data.npc_data;
-        // Four passes: shape households per house, link kin across town,
-        // assign professions, place at anchors. Each pass derives its own
-        // RNG so reordering or inserting a future pass doesn't shift
-        // downstream rolls.
-        let mut population = build_households(
-            &town_anchors, culture, npc_data, &mut id_alloc, &mut rng.derive(),
-        );
-        link_cross_household(&mut population, &mut rng.derive());
-        assign_employment(&mut population, &mut rng.derive());
-
-        // Diagnostics: stats + a handful of sampled households so the kin graph
-        // is legible in the console without needing a debugger.
-        log_population_stats(&population);
-        log_sample_households(&population, 8);
-
-        // Letter each manor's name sign now its household surname is known, and
-        // record the family for the chronicle (surname + the manor's location,
-        // colour, and blazon from its `HouseAnchors`).
-        for site in &manor_sign_sites {
-            if let Some(hh) = population.households.iter().find(|h| h.home == site.anchor_idx) {
-                println!("Manor sign: {} {}", hh.surname, site.designation());
-                let anchor = &town_anchors[site.anchor_idx];
-                manor_facts.push(ManorFact {
-                    surname: hh.surname.clone(),
-                    designation: site.designation().to_string(),
-                    pos: anchor.pos,
-                    color: anchor.family_color,
-                    blazon: anchor.banner_blazon.clone(),
-                });
-                crate::generator::buildings_v2::exterior::place_manor_sign(
-                    editor, site, &hh.surname,
-                ).await;
-            }
-        }
-
-        // Bind residents to workplaces before seating anyone at home. The draft
-        // weights each unplaced adult by qualification (proximity-led) and spawns
-        // the winner at the post; posts with no taker left fall through to
-        // anonymous fixtures (`workplace_backfill`). Bound workers are marked
-        // `placed`, so `populate_town` skips them — each resident appears once.
-        // Every post (resident-filled or not) is tallied by trade for the summary.
-        use crate::generator::population::bind_workers;
-        let (work_slots, n_workplaces) = discover_worker_slots(editor, &data);
-        workplace_count = n_workplaces;
-        for s in &work_slots {
-            *worker_by_job.entry(s.employment.clone()).or_insert(0) += 1;
-        }
-        match bind_workers(editor, &mut population, work_slots, npc_data, &mut rng.derive()).await {
-            Ok((bound, unfilled)) => {
-                println!(
-                    "Bound {} residents to workplaces; {} posts need fixtures",
-                    bound,
-                    unfilled.len(),
-                );
-                bound_worker_count = bound;
-                workplace_backfill = unfilled;
-            }
-            Err(e) => log::warn!("worker binding failed: {e}"),
-        }
-
-        match populate_town(editor, town_anchors, population, npc_data, &mut rng).await {
-            Ok(placed) => println!("Populated {} NPCs", placed),
-            Err(e) => log::warn!("NPC population failed: {e}"),
-        }
-    }
-
-    // ---- Worker fixtures: staff every workplace ----
-    // Stand a small crew of worker NPCs just outside each placed building (urban
-    // processing shop or rural gather building), facing it, wearing the trade
-    // outfit that matches its type. These are fixtures: always placed, independent
-    // of the resident budget above. The NBT interiors are opaque, so workers stand
-    // on clear ground cells at the footprint edge — never inside, never on a road
-    // or another building. A workplace can employ several hands (see the per-kind
-    // `workers` count in `data/npcs.yaml`).
-    {
-        use crate::generator::population::{
-            build_roster, populate_npcs, AnchorScene, AnchorSlot, SceneKind, SlotRole,
-        };
-
-        // Workplace posts that binding couldn't fill from residents get an
-        // anonymous fixture: a fresh skin rolled from the post's pool, standing
-        // where binding would have seated the resident. (Residents bound to
-        // workplaces were already spawned in the population pass above; the claim
-        // scan + stand-cell geometry now lives in `discover_worker_slots`.)
-        let npc_data = &data.npc_data;
-        let mut worker_rng = rng.derive();
-
-        let mut worker_scenes: Vec<AnchorScene> = Vec::new();
-        for slot in &workplace_backfill {
-            let look = *worker_rng.choose(&slot.looks);
-            worker_scenes.push(AnchorScene::worker(slot.stand, slot.facing, look, &slot.employment));
-        }
-
-        // Total worker posts = residents bound in the population pass + the
-        // anonymous backfill scenes just built; guards are appended below.
-        let backfill_slots = worker_scenes.len();
-        let industrial_job_slots = bound_worker_count + backfill_slots;
-
-        // ---- Guard posts: gates + wall towers ----
-        // Gates get 1–2 guards each; each tower has a 10% chance of 2 guards and a
-        // 20% chance of 1 (else none). Guards carry their own `guarding` dialogue,
-        // watching the approaches. Each guard's appearance is rolled from the
-        // guards fixture's `looks` pool (villager professions and/or mobs like
-        // pillagers), so a post can mix both.
-        use crate::generator::npc::NpcLook;
-        let guard_looks = &npc_data.guards.looks;
-        let guard_scene = |feet: Point3D, facing: f32, look: NpcLook| -> AnchorScene {
-            let mut slot = AnchorSlot::new(feet, facing, SlotRole::Worker);
-            slot.look = Some(look);
-            slot.dialogue = Some("guarding".to_string());
-            AnchorScene::group(SceneKind::Solo, vec![slot])
-        };
-        let town_centre = {
-            let n = urban.len().max(1) as i32;
-            urban.iter().fold(Point2D::ZERO, |a, &p| a + p) / n
-        };
-        // Gates: one guard a couple cells inside the opening; when a gate gets a
-        // second, it stands the same distance outside — a guard on each side of
-        // the gate. Both face the opening.
-        for (gate_point, dir) in editor.world().gate_locations.clone() {
-            let base = gate_point.drop_y();
-            let fwd: Point2D = dir.into();
-            // One cell to each side of the gate centre — in the opening, not the
-            // wall a couple cells away.
-            let inside = Point2D::new(base.x - fwd.x, base.y - fwd.y);
-            let outside = Point2D::new(base.x + fwd.x, base.y + fwd.y);
-            let stands: Vec<Point2D> = if worker_rng.percent(50) {
-                vec![inside, outside]
-            } else {
-                vec![inside]
-            };
-            for s in stands {
-                // Stand on the gate's own cleared floor (`gate_point.y`), which is
-                // where the gateway punched its air column upward. Re-deriving the
-                // height from the ocean-floor heightmap dropped feet below the
-                // threshold, burying — and suffocating — the guard in gate blocks.
-                let y = gate_point.y;
-                let feet = Point3D::new(s.x, y, s.y);
-                let facing =
-                    crate::generator::population::yaw_toward(feet, Point3D::new(base.x, y, base.y));
-                let look = *worker_rng.choose(guard_looks);
-                let mut scene = guard_scene(feet, facing, look);
-                // Gates often sit on a slabbed threshold (sandstone slabs on a
-                // desert gateway, stair-and-slab approach on a stone one). When
-                // the block beneath the guard's feet is a slab, lift them half
-                // a block so they stand on the slab top rather than sunk in it.
-                let underfoot = editor.try_get_block(Point3D::new(s.x, y - 1, s.y));
-                if matches!(
-                    underfoot.map(|b| crate::minecraft::BlockForm::infer_from_block(&b.id)),
-                    Some(crate::minecraft::BlockForm::Slab),
-                ) {
-                    scene.slots[0].y_offset = 0.5;
-                }
-                worker_scenes.push(scene);
-            }
-        }
-        // Towers: weighted small chance of 1–2 guards on the walkway beside each.
-        for posts in editor.world().tower_guard_posts.clone() {
-            let roll = worker_rng.rand_i32(100);
-            let n: usize = if roll < 10 { 2 } else if roll < 30 { 1 } else { 0 };
-            for feet in posts.into_iter().take(n) {
-                let facing = crate::generator::population::yaw_toward(
-                    Point3D::new(town_centre.x, feet.y, town_centre.y),
-                    feet,
-                );
-                let look = *worker_rng.choose(guard_looks);
-                let mut scene = guard_scene(feet, facing, look);
-                scene.slots[0].y_offset = 0.5; // stand on the battlement slab, not sunk in it
-                worker_scenes.push(scene);
-            }
-        }
-        let guard_count = worker_scenes.len() - backfill_slots;
-
-        if !worker_scenes.is_empty() {
-            // Roster supplies names/dialogue/biome; each scene's slot
-            // overrides the profession, so the roll here is incidental.
-            let worker_roster = build_roster(
-                worker_scenes.len(), 0, culture, npc_data, &mut id_alloc, &mut rng.derive(),
-            );
-            let budget = worker_scenes.len();
-            match populate_npcs(editor, worker_scenes, worker_roster, budget, npc_data, &mut rng).await {
-                Ok(staffed) => println!(
-                    "Staffed {} fixture NPCs ({} backfill workers + {} guards); {} of {} posts across {} workplaces filled by residents",
-                    staffed, backfill_slots, guard_count, bound_worker_count, industrial_job_slots, workplace_count,
-                ),
-                Err(e) => log::warn!("worker/guard staffing failed: {}", e),
-            }
-        }
-
-        // ---- Jobs summary ----
-        let total_industrial = urban_industrial_count + rural_building_count;
-        let approx_jobs =
-            industrial_job_slots + guard_count + market_stall_count + performer_slot_count;
-        println!("=== JOBS SUMMARY ===");
-        println!(
-            "Industrial/resource buildings: {} ({} urban + {} rural)",
-            total_industrial, urban_industrial_count, rural_building_count,
-        );
-        println!("Workers: {}", industrial_job_slots);
-        println!("Guards: {} (gates + towers)", guard_count);
-        println!("Market stalls: {}", market_stall_count);
-        println!("Stages: {} ({} performer slots)", stage_count, performer_slot_count);
-        println!(
-            "Approx jobs available: {} (workers {} + guards {} + vendors {} + performers {})",
-            approx_jobs, industrial_job_slots, guard_count, market_stall_count, performer_slot_count,
-        );
-
-        // Employment by job: building trades (sorted by count), then the
-        // non-building roles (guards, vendors, performers).
-        println!("--- Employment by job ---");
-        let mut by_job: Vec<(String, usize)> = worker_by_job.into_iter().collect();
-        if guard_count > 0 {
-            by_job.push((npc_data.guards.employment.clone(), guard_count));
-        }
-        if market_stall_count > 0 {
-            by_job.push((npc_data.vendors.employment.clone(), market_stall_count));
-        }
-        if performer_slot_count > 0 {
-            by_job.push((npc_data.performers.employment.clone(), performer_slot_count));
-        }
-        by_job.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
-        for (job, count) in &by_job {
-            println!("  {:<14} {}", job, count);
-        }
-    }
-
-    // Top-down town map (SVG) for inspection: footprints + named roads coloured
-    // by id + the abstract MST/node overlay, with sign posts marked.
-    {
-        let svg = crate::generator::paths::render_town_map(
-            editor.world(), &urban, &road_network.paths, &road_network.road_labels,
-            &road_names, &alley_band, Some(&road_network), &signs, &place_labels,
-        );
-        std::fs::create_dir_all("output").ok();
-        match std::fs::write("output/town.svg", &svg) {
-            Ok(()) => println!("Wrote town map to output/town.svg"),
-            Err(e) => log::warn!("failed to write town map: {e}"),
-        }
-        match crate::generator::paths::rasterize_to_png(&svg, "output/town.png") {
-            Ok(()) => println!("Wrote town map to output/town.png"),
-            Err(e) => log::warn!("failed to render town.png: {e}"),
-        }
-    }
-
-    // Welcome banner: bury a command-block proximity sensor at the town centre
-    // that flashes the settlement name when a player crosses into the urban
-    // area. The name is derived procedurally from the place's features (iconic
-    // building, land shape, biome), seeded off the town seed so it's stable.
-    // Requires `enable-command-block=true` on the server.
-    {
-        let mut name_rng = RNG::new(seed).derive();
-        let named = crate::generator::naming::generate_settlement_name(
-            editor.world(), &urban, &civic_features, culture, &color_scheme.town, &mut name_rng,
-        );
-        crate::generator::welcome::place_welcome_title(
-            editor, &urban, &named.name, &named.subtitle,
-        ).await;
-        println!("Placed welcome-title sensor for \"{}\" ({})", named.name, named.subtitle);
-
-        // Chronicle: digest the finished town into a dossier (name, colours,
-        // biomes, roads, trades, families, greens, gates) and have the AI write a
-        // guidebook, dropped into the player's inventory. Live-only — `give_player
-        // _book` posts to the server; failures are non-fatal.
-        let mut chronicle_rng = RNG::from_seed_and_string(seed, "district_names");
-        // The settlement's economy as lowercased English nouns: harvests are the
-        // raw resources the rural parcels gathered (`supply`), produces are the
-        // finished goods the chains turned them into. Pretty names come from the
-        // resource registry; ids fall back to underscore-stripped form. Sorted +
-        // deduped so the chronicle's fact list is stable across runs.
-        let reg = &data.resource_registry;
-        let pretty = |id: &str| reg.resources().get(id)
-            .map(|r| r.name.to_lowercase())
-            .unwrap_or_else(|| id.replace('_', " "));
-        let mut harvests: Vec<String> = result.supply.keys().map(|id| pretty(id)).collect();
-        harvests.sort();
-        harvests.dedup();
-        let mut produces: Vec<String> = result.finished_goods.iter().map(|(id, _)| pretty(id)).collect();
-        produces.sort();
-        produces.dedup();
-        let dossier = assemble_dossier(
-            editor, &urban, culture, &named, &color_scheme.town, &civic_blazon,
-            &road_names, &road_network.road_labels, &place_labels, &manor_facts,
-            total_buildings, population_count, &harvests, &produces, &mut chronicle_rng,
-        );
-        if let Err(e) = crate::generator::chronicle::generate_chronicle(&*editor, &dossier).await {
-            log::warn!("Chronicle generation failed: {e}");
-        }
-    }
+    report.count("book_holders", book_holders.len());
+    report.count("library_boards", library_boards.len());
+    TownHouses { civic_blazon, color_scheme, manor_sign_sites, town_anchors, total_buildings, failures, book_holders, library_boards }
+}
 
-    // Scatter free-floating ships onto the settlement's water districts, then crew the
-    // afloat ones (a captain at the helm + sailors on deck) from the town roster — the same
-    // fixture path as plaza vendors / industry workers. Live-only: staffing is a no-op offline.
-    let (ships, crew_scenes) =
-        crate::generator::ships::fleet::scatter_ships(editor, &data, seed).await;
-    println!("Placed {} ships across water districts", ships);
-    if !crew_scenes.is_empty() {
-        match crate::generator::ships::crew::staff_crew(
-            editor, crew_scenes, culture, &data, &mut id_alloc, &mut rng,
-        )
-        .await
-        {
-            Ok(staffed) => println!("Crewed ships with {} sailor/captain NPCs", staffed),
-            Err(e) => log::warn!("ship crew staffing failed: {e}"),
-        }
+/// The floor height for a door fronting road `cell` at height `y`: one over
+/// the half-step slab the road laid there, read back from the editor, so the
+/// door steps down onto it instead of opening onto a lip; else `y`.
+fn door_sill_y(editor: &Editor, cell: Point2D, y: i32) -> i32 {
+    let top = (y - 2..=y + 1).rev().find_map(|top| {
+        let block = editor.try_get_block(LocalPoint3D::new(cell.x, top, cell.y))?;
+        (!block.id.is_air()).then_some((top, block))
+    });
+    match top {
+        Some((slab_y, block)) if BlockForm::infer_from_block(&block.id) == BlockForm::Slab => slab_y + 1,
+        _ => y,
     }
-
-    editor.flush_buffer().await;
 }
 
 /// Scan the claim map for every placed workplace (urban shop or rural gather
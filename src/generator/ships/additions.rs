@@ -14,7 +14,7 @@
 use std::collections::HashMap;
 
 use crate::generator::materials::{MaterialPlacer, Placer};
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::BlockForm;
 
 use super::deck::DeckModel;
@@ -371,7 +371,7 @@ pub async fn restamp_gun_ports(
     } else {
         let air = crate::minecraft::Block::from("minecraft:air");
         for (cell, _) in &ports {
-            ctx.editor.place_block_forced(&air, place.to_world(*cell)).await;
+            ctx.editor.place_block_forced(&air, LocalPoint3D(place.to_world(*cell))).await;
         }
     }
 }
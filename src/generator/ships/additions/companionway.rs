@@ -10,7 +10,7 @@
 use std::collections::HashMap;
 
 use crate::generator::materials::{MaterialId, MaterialPlacer, Placer};
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::{string_to_block, BlockForm};
 
 use super::super::levels::{build_ship_levels, ShipLevel};
@@ -90,16 +90,16 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                     if let Some(td) = string_to_block(&format!(
                         "minecraft:oak_trapdoor[half=top,open=false,facing={face}]"
                     )) {
-                        ctx.editor.place_block_forced(&td, dc.placement.to_world(Point3D::new(cx, level.ceiling_y, cz))).await;
+                        ctx.editor.place_block_forced(&td, LocalPoint3D(dc.placement.to_world(Point3D::new(cx, level.ceiling_y, cz)))).await;
                     }
                 } else if let Some(a) = &air {
-                    ctx.editor.place_block_forced(a, dc.placement.to_world(Point3D::new(cx, level.ceiling_y, cz))).await;
+                    ctx.editor.place_block_forced(a, LocalPoint3D(dc.placement.to_world(Point3D::new(cx, level.ceiling_y, cz)))).await;
                 }
                 state.hatch_cells.push(Point3D::new(cx, level.ceiling_y, cz));
                 // Ladder up to just under the opening.
                 for y in (level.floor_y + 1)..level.ceiling_y {
                     if let Some(b) = string_to_block(&format!("minecraft:ladder[facing={face}]")) {
-                        ctx.editor.place_block_forced(&b, dc.placement.to_world(Point3D::new(cx, y, cz))).await;
+                        ctx.editor.place_block_forced(&b, LocalPoint3D(dc.placement.to_world(Point3D::new(cx, y, cz)))).await;
                     }
                 }
                 state.hatch_cells.push(Point3D::new(cx, level.floor_y, cz));
@@ -128,14 +128,14 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
             // Cut a hole in each hold floor at the ladder cell so it's a continuous shaft.
             if let Some(a) = &air {
                 for &fy in &hold_floors {
-                    ctx.editor.place_block_forced(a, dc.placement.to_world(Point3D::new(lx, fy, 0))).await;
+                    ctx.editor.place_block_forced(a, LocalPoint3D(dc.placement.to_world(Point3D::new(lx, fy, 0)))).await;
                     state.hatch_cells.push(Point3D::new(lx, fy, 0));
                 }
             }
             // Ladder from just above the lowest floor up to the main hold floor (step-off points).
             for y in (lowest + 1)..=main_hold {
                 if let Some(b) = string_to_block(&format!("minecraft:ladder[facing={face}]")) {
-                    ctx.editor.place_block_forced(&b, dc.placement.to_world(Point3D::new(lx, y, 0))).await;
+                    ctx.editor.place_block_forced(&b, LocalPoint3D(dc.placement.to_world(Point3D::new(lx, y, 0)))).await;
                 }
             }
         }
@@ -193,10 +193,10 @@ async fn place_stair_flight(
             if let Some(td) = string_to_block(&format!(
                 "minecraft:oak_trapdoor[half=top,open=false,facing={lid_face}]"
             )) {
-                ctx.editor.place_block_forced(&td, place.to_world(Point3D::new(x, level.ceiling_y, cz))).await;
+                ctx.editor.place_block_forced(&td, LocalPoint3D(place.to_world(Point3D::new(x, level.ceiling_y, cz)))).await;
             }
         } else if let Some(a) = &air {
-            ctx.editor.place_block_forced(a, place.to_world(Point3D::new(x, level.ceiling_y, cz))).await;
+            ctx.editor.place_block_forced(a, LocalPoint3D(place.to_world(Point3D::new(x, level.ceiling_y, cz)))).await;
         }
         state.hatch_cells.push(Point3D::new(x, level.ceiling_y, cz));
     }
@@ -7,7 +7,7 @@
 //! hardcoded oak for now (the ship palette is `ship_oak`), like the quartz/wool sails — a palette
 //! role can replace them later. Built for all ships for now (Medium+ gating deferred, like masts).
 
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::string_to_block;
 
 use super::super::palette::ShipPart;
@@ -57,7 +57,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
 
     // 1) Lectern base.
     if let Some(b) = string_to_block(&format!("minecraft:lectern[facing={stern}]")) {
-        ctx.editor.place_block(&b, place.to_world(Point3D::new(hx, base_y + 1, 0))).await;
+        ctx.editor.place_block(&b, LocalPoint3D(place.to_world(Point3D::new(hx, base_y + 1, 0)))).await;
     }
     // 2) Fence post on top (palette wood — matches the railing).
     let mut rng = ctx.rng.derive();
@@ -68,7 +68,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
         &mut rng,
     ) {
         let fence = crate::minecraft::Block::from_id(id.clone());
-        ctx.editor.place_block(&fence, place.to_world(Point3D::new(hx, base_y + 2, 0))).await;
+        ctx.editor.place_block(&fence, LocalPoint3D(place.to_world(Point3D::new(hx, base_y + 2, 0)))).await;
     }
     // 3) Trapdoor as the wheel — **folded up (open) on the rear/stern side of the post**, in the
     // cell one step toward the stern at the fence-top height. A trapdoor hinges to the block on the
@@ -77,6 +77,6 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
     if let Some(b) =
         string_to_block(&format!("minecraft:oak_trapdoor[facing={stern},half=top,open=true]"))
     {
-        ctx.editor.place_block(&b, place.to_world(Point3D::new(hx - 1, base_y + 2, 0))).await;
+        ctx.editor.place_block(&b, LocalPoint3D(place.to_world(Point3D::new(hx - 1, base_y + 2, 0)))).await;
     }
 }
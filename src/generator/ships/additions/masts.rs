@@ -15,7 +15,7 @@
 use std::collections::HashMap;
 
 use crate::generator::materials::{MaterialPlacer, Placer};
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::{string_to_block, Block, BlockForm};
 
 use super::super::palette::ShipPart;
@@ -960,7 +960,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                         "minecraft:quartz_stairs[facing={stern_face},half={half}]"
                     )) {
                         ctx.editor
-                            .place_block(&b, place.to_world(Point3D::new(yard.x, yard.y - 1, z)))
+                            .place_block(&b, LocalPoint3D(place.to_world(Point3D::new(yard.x, yard.y - 1, z))))
                             .await;
                     }
                 }
@@ -1005,7 +1005,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                 }
                 for (block, z) in &swag {
                     if let Some(b) = string_to_block(block) {
-                        ctx.editor.place_block(&b, place.to_world(Point3D::new(yard.x, yb, *z))).await;
+                        ctx.editor.place_block(&b, LocalPoint3D(place.to_world(Point3D::new(yard.x, yb, *z)))).await;
                     }
                 }
             }
@@ -1043,7 +1043,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                     for yi in 0..ny {
                         let y = bottom_y + yi as i32;
                         let cell = Point3D::new(yard.x + b[zi * ny + yi] * sign, y, z);
-                        ctx.editor.place_block(&sail_block, place.to_world(cell)).await;
+                        ctx.editor.place_block(&sail_block, LocalPoint3D(place.to_world(cell))).await;
                     }
                 }
             }
@@ -1057,7 +1057,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
         // Masthead pennant (rippling wool ribbon, the ship's colours).
         if let Some(flag_block) = string_to_block(&format!("minecraft:{flag_color}_wool")) {
             for &cell in &mast.flag.cells {
-                ctx.editor.place_block(&flag_block, place.to_world(cell)).await;
+                ctx.editor.place_block(&flag_block, LocalPoint3D(place.to_world(cell))).await;
             }
         }
         // Spanker (boom slabs + gaff double-stairs).
@@ -1093,7 +1093,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                         string_to_block(&format!("minecraft:quartz_stairs[facing={facing},half=bottom]"))
                     {
                         let pos = Point3D::new(sc.local.x, sc.local.y + 1, sc.local.z);
-                        ctx.editor.place_block(&b, place.to_world(pos)).await;
+                        ctx.editor.place_block(&b, LocalPoint3D(place.to_world(pos))).await;
                     }
                 }
             }
@@ -1121,7 +1121,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                     }
                     let z = depth.get(&(c.x, c.y)).copied().unwrap_or(0) * side;
                     ctx.editor
-                        .place_block(&sail_block, place.to_world(Point3D::new(c.x, c.y, z)))
+                        .place_block(&sail_block, LocalPoint3D(place.to_world(Point3D::new(c.x, c.y, z))))
                         .await;
                 }
             }
@@ -1185,7 +1185,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                             continue; // don't carve the mast poles/finials/flags
                         }
                         ctx.editor
-                            .place_block_forced(ch, place.to_world(Point3D::new(x, yy, 0)))
+                            .place_block_forced(ch, LocalPoint3D(place.to_world(Point3D::new(x, yy, 0))))
                             .await;
                     }
                 }
@@ -1300,11 +1300,11 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                         // Foot anchor (one of the two ends): the canvas corner drapes on the
                         // centreline (z = 0) over the bowsprit, raised clear of the spar, and a
                         // **hanger tie** (chain/fence) drops from just under it to the spar top.
-                        ctx.editor.place_block(&sail_block, place.to_world(Point3D::new(x, y, 0))).await;
+                        ctx.editor.place_block(&sail_block, LocalPoint3D(place.to_world(Point3D::new(x, y, 0)))).await;
                         if let (Some(ch), Some(&st)) = (&rig_block, spar_top.get(&x)) {
                             for yy in (st + 1)..y {
                                 ctx.editor
-                                    .place_block_forced(ch, place.to_world(Point3D::new(x, yy, 0)))
+                                    .place_block_forced(ch, LocalPoint3D(place.to_world(Point3D::new(x, yy, 0))))
                                     .await;
                             }
                         }
@@ -1319,10 +1319,10 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                         let wp = place.to_world(Point3D::new(x, y, d * spanker_side));
                         let occupied = ctx
                             .editor
-                            .get_cached_block(wp)
+                            .get_cached_block(LocalPoint3D(wp))
                             .map_or(false, |b| b.id.as_str().contains("wool"));
                         if !occupied {
-                            ctx.editor.place_block(&sail_block, wp).await;
+                            ctx.editor.place_block(&sail_block, LocalPoint3D(wp)).await;
                         }
                     }
                 }
@@ -1352,10 +1352,10 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                         continue; // never carve the mast pole/yards
                     }
                     let wp = place.to_world(Point3D::new(x, yy, 0));
-                    if ctx.editor.get_cached_block(wp).map_or(false, |b2| b2.id.as_str().contains("wool")) {
+                    if ctx.editor.get_cached_block(LocalPoint3D(wp)).map_or(false, |b2| b2.id.as_str().contains("wool")) {
                         continue; // sit on **top** of the canvas, don't replace it
                     }
-                    ctx.editor.place_block_forced(ch, wp).await;
+                    ctx.editor.place_block_forced(ch, LocalPoint3D(wp)).await;
                 }
             }
             // No canvas: tie the stay's forward end down to the bowsprit so it isn't left floating
@@ -1364,7 +1364,7 @@ pub async fn build(ctx: &mut ShipCtx<'_>, dc: &DeckContext<'_>, state: &mut Deck
                 if let Some(&st) = spar_top.get(&b.x) {
                     for yy in (st + 1)..b.y {
                         ctx.editor
-                            .place_block_forced(ch, place.to_world(Point3D::new(b.x, yy, 0)))
+                            .place_block_forced(ch, LocalPoint3D(place.to_world(Point3D::new(b.x, yy, 0))))
                             .await;
                     }
                 }
@@ -8,9 +8,10 @@
 //!
 //! Looks, dialogue, and rank are data-driven: the `sailors` / `captains` fixtures in
 //! `npcs.yaml` supply the skin pool (rolled per crew member), the job label (which doubles
-//! as the dialogue key), and the captain's `title`. Crew *counts* live in [`tuning`]
-//! ([`CREW_SAILORS_SMALL`] …). Names come from the roster the settlement layer hands the
-//! scenes.
+//! as the dialogue key), and the captain's `title`. Crew *counts* default to [`tuning`]
+//! ([`CREW_SAILORS_SMALL`](super::tuning::CREW_SAILORS_SMALL) …) and can be overridden by
+//! a settlement profile ([`CrewSailors`]). Names come from the roster the settlement layer
+//! hands the scenes.
 
 use std::collections::HashSet;
 
@@ -24,17 +25,17 @@ use crate::geometry::Point3D;
 use crate::noise::RNG;
 
 use super::additions::SizeTier;
-use super::tuning::{CREW_SAILORS_HUGE, CREW_SAILORS_LARGE, CREW_SAILORS_MEDIUM, CREW_SAILORS_SMALL};
+use super::fleet::CrewSailors;
 use super::ShipOutput;
 
-/// Sailors (*beyond* the lone captain) a ship carries, by size tier. The dial lives on the
-/// central tuning surface ([`tuning`](super::tuning)).
-fn sailor_count(tier: SizeTier) -> usize {
+/// Sailors (*beyond* the lone captain) a ship carries, by size tier. The defaults live on
+/// the central tuning surface ([`tuning`](super::tuning)).
+fn sailor_count(tier: SizeTier, sailors: &CrewSailors) -> usize {
     match tier {
-        SizeTier::Small => CREW_SAILORS_SMALL,
-        SizeTier::Medium => CREW_SAILORS_MEDIUM,
-        SizeTier::Large => CREW_SAILORS_LARGE,
-        SizeTier::Huge => CREW_SAILORS_HUGE,
+        SizeTier::Small => sailors.small,
+        SizeTier::Medium => sailors.medium,
+        SizeTier::Large => sailors.large,
+        SizeTier::Huge => sailors.huge,
     }
 }
 
@@ -42,7 +43,7 @@ fn sailor_count(tier: SizeTier) -> usize {
 /// gang of sailors spread along the weather deck. Empty for land hulks (`!on_water`). Looks
 /// and rank are rolled from the `sailors` / `captains` fixtures; the employment label doubles
 /// as the dialogue key. `rng` should be derived per ship so the crew is deterministic per seed.
-pub fn crew_scenes(out: &ShipOutput, npc_data: &NpcData, rng: &mut RNG) -> Vec<AnchorScene> {
+pub fn crew_scenes(out: &ShipOutput, npc_data: &NpcData, sailors: &CrewSailors, rng: &mut RNG) -> Vec<AnchorScene> {
     if !out.on_water {
         return Vec::new(); // land hulks stay crewless
     }
@@ -106,7 +107,7 @@ pub fn crew_scenes(out: &ShipOutput, npc_data: &NpcData, rng: &mut RNG) -> Vec<A
         }
     }
 
-    let want = sailor_count(out.tier).min(candidates.len());
+    let want = sailor_count(out.tier, sailors).min(candidates.len());
     if want > 0 {
         // Even stride across the unique candidates; `(want - 1) * stride < len` always, so
         // every index is in bounds and each sailor lands on a distinct cell.
@@ -13,6 +13,8 @@
 
 use std::collections::{HashSet, VecDeque};
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::editor::{Editor, World};
 use crate::generator::BuildClaim;
 use crate::generator::data::LoadedData;
@@ -29,12 +31,73 @@ use super::keel::keel_depth;
 use super::tuning::*;
 use super::{build_ship, HullShape, SailState, ShipCtx, ShipSpec};
 
+/// The fleet-placement knobs a settlement profile can override, defaulting to the
+/// constants on the [`tuning`](super::tuning) surface (see there for what each does and
+/// which way to push it).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FleetTuning {
+    /// [`SHIP_LENGTHS`], largest first.
+    pub ship_lengths: Vec<i32>,
+    pub river_max_length: i32,
+    pub keel_clearance: i32,
+    pub hull_margin: i32,
+    pub min_centre_shore: i32,
+    pub vertical_headroom: i32,
+    pub place_attempts: usize,
+    pub furled_chance: i32,
+    pub min_water_cells: usize,
+    pub ship_chance_per_district: i32,
+    pub palettes: Vec<String>,
+    pub crew_sailors: CrewSailors,
+}
+
+/// Sailors beyond the captain, by size tier ([`CREW_SAILORS_SMALL`] …).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrewSailors {
+    pub small: usize,
+    pub medium: usize,
+    pub large: usize,
+    pub huge: usize,
+}
+
+impl Default for FleetTuning {
+    fn default() -> Self {
+        Self {
+            ship_lengths: SHIP_LENGTHS.to_vec(),
+            river_max_length: RIVER_MAX_LENGTH,
+            keel_clearance: KEEL_CLEARANCE,
+            hull_margin: HULL_MARGIN,
+            min_centre_shore: MIN_CENTRE_SHORE,
+            vertical_headroom: VERTICAL_HEADROOM,
+            place_attempts: PLACE_ATTEMPTS,
+            furled_chance: FURLED_CHANCE,
+            min_water_cells: MIN_WATER_CELLS,
+            ship_chance_per_district: SHIP_CHANCE_PER_DISTRICT,
+            palettes: SHIP_PALETTES.iter().map(|id| id.to_string()).collect(),
+            crew_sailors: CrewSailors::default(),
+        }
+    }
+}
+
+impl Default for CrewSailors {
+    fn default() -> Self {
+        Self {
+            small: CREW_SAILORS_SMALL,
+            medium: CREW_SAILORS_MEDIUM,
+            large: CREW_SAILORS_LARGE,
+            huge: CREW_SAILORS_HUGE,
+        }
+    }
+}
+
 /// Largest keel length allowed for a water district, from its **dominant biome**: open
 /// ocean / deep-ocean bodies get the full [`SHIP_LENGTHS`] range, while rivers / lakes /
 /// anything else are capped to [`RIVER_MAX_LENGTH`]. Falls back to the full range when no
 /// analysis exists for the district (e.g. a synthetic/test world).
-fn size_cap_for_body(world: &World, district_id: usize) -> i32 {
-    let full = SHIP_LENGTHS.iter().copied().max().unwrap_or(0);
+fn size_cap_for_body(world: &World, district_id: usize, tuning: &FleetTuning) -> i32 {
+    let full = tuning.ship_lengths.iter().copied().max().unwrap_or(0);
     let Some(analysis) = world.district_analysis_data.get(&DistrictID(district_id)) else {
         return full;
     };
@@ -48,20 +111,22 @@ fn size_cap_for_body(world: &World, district_id: usize) -> i32 {
         .map(|(biome, _)| biome.name());
     match dominant {
         Some(name) if name.contains("ocean") => full,
-        _ => RIVER_MAX_LENGTH,
+        _ => tuning.river_max_length,
     }
 }
 
 /// Scatter ships onto every sufficiently large Water district in the build area.
 ///
-/// Deterministic for a given `seed`, independent of the town RNG stream. Returns the number
-/// of ships placed **and** the crew [`AnchorScene`]s for the afloat ones (a captain at the
-/// helm + sailors on deck) — the caller staffs them with the town roster, like any other
-/// fixture. Crew looks/dialogue come from the `sailors` / `captains` fixtures in `npcs.yaml`.
+/// Deterministic for a given `seed` and `tuning`, independent of the town RNG stream.
+/// Returns the number of ships placed **and** the crew [`AnchorScene`]s for the afloat ones
+/// (a captain at the helm + sailors on deck) — the caller staffs them with the town roster,
+/// like any other fixture. Crew looks/dialogue come from the `sailors` / `captains`
+/// fixtures in `npcs.yaml`.
 pub async fn scatter_ships(
     editor: &mut Editor,
     data: &LoadedData,
     seed: Seed,
+    tuning: &FleetTuning,
 ) -> (usize, Vec<AnchorScene>) {
     // ── Plan inputs (owned, so no World borrow is held across build_ship) ──────
     let (size, build_height, bodies) = {
@@ -80,7 +145,7 @@ pub async fn scatter_ships(
                     d.data.points_2d.iter().copied().filter(|p| world.is_water(*p)).collect();
                 (id.0, cells)
             })
-            .filter(|(_, cells)| cells.len() >= MIN_WATER_CELLS)
+            .filter(|(_, cells)| cells.len() >= tuning.min_water_cells)
             .collect();
         bodies.sort_by_key(|(id, _)| *id); // stable order so a seed reproduces the fleet
         (size, build_height, bodies)
@@ -99,12 +164,13 @@ pub async fn scatter_ships(
     // requested id can be missing: collect only the palettes that actually loaded, and bail
     // gracefully if none did. This is the **last** pass in `generate_town`, so a panic here
     // would throw away a fully-generated settlement (and skip its final flush).
-    let palettes: Vec<Palette> = SHIP_PALETTES
+    let palettes: Vec<Palette> = tuning
+        .palettes
         .iter()
-        .filter_map(|id| data.palettes.get(&PaletteId::from(*id)).cloned())
+        .filter_map(|id| data.palettes.get(&PaletteId::from(id.as_str())).cloned())
         .collect();
     if palettes.is_empty() {
-        log::warn!("no ship palettes loaded ({:?}); skipping ship placement", SHIP_PALETTES);
+        log::warn!("no ship palettes loaded ({:?}); skipping ship placement", tuning.palettes);
         return (0, Vec::new());
     }
 
@@ -120,7 +186,7 @@ pub async fn scatter_ships(
         let mut centres: Vec<Point2D> = water_cells
             .iter()
             .copied()
-            .filter(|c| shore_at(&shore, *c) >= MIN_CENTRE_SHORE)
+            .filter(|c| shore_at(&shore, *c) >= tuning.min_centre_shore)
             .collect();
         if centres.is_empty() {
             continue;
@@ -130,17 +196,17 @@ pub async fn scatter_ships(
         // Roll whether this district gets a ship at all (SHIP_CHANCE_PER_DISTRICT, currently
         // 50%). Districts with no eligible centre are already skipped above, so this only
         // decides among placeable bodies.
-        if !rng.percent(SHIP_CHANCE_PER_DISTRICT) {
+        if !rng.percent(tuning.ship_chance_per_district) {
             continue;
         }
 
         // Size ceiling from the body's dominant biome: open ocean → full range, otherwise
         // (river / lake / other) capped to a modest hull.
-        let max_length = size_cap_for_body(editor.world(), *id);
+        let max_length = size_cap_for_body(editor.world(), *id, tuning);
 
         // One ship per water district.
         let placed = place_one_ship(
-            editor, data, &palettes, &mut rng, &centres, build_height, max_length,
+            editor, data, tuning, &palettes, &mut rng, &centres, build_height, max_length,
             &mut placed_cells, &mut crew,
         )
         .await;
@@ -166,6 +232,7 @@ pub async fn scatter_ships(
 async fn place_one_ship(
     editor: &mut Editor,
     data: &LoadedData,
+    tuning: &FleetTuning,
     palettes: &[Palette],
     rng: &mut RNG,
     centres: &[Point2D],
@@ -174,13 +241,13 @@ async fn place_one_ship(
     placed: &mut HashSet<Point2D>,
     crew: &mut Vec<AnchorScene>,
 ) -> bool {
-    for _ in 0..PLACE_ATTEMPTS {
+    for _ in 0..tuning.place_attempts {
         let centre = centres[rng.rand_i32_range(0, centres.len() as i32) as usize];
 
         // Plan against the world immutably; the borrow ends before build_ship.
         let fit = {
             let world = editor.world();
-            try_fit(world, centre, build_height, max_length, placed)
+            try_fit(world, tuning, centre, build_height, max_length, placed)
         };
         let Some((heading, length, footprint)) = fit else { continue };
 
@@ -193,7 +260,7 @@ async fn place_one_ship(
         // the stream). One palette per vessel.
         let palette = &palettes[rng.rand_i32_range(0, palettes.len() as i32) as usize];
         let hull_shape = if rng.percent(50) { HullShape::Teardrop } else { HullShape::Oval };
-        let sail_state = if rng.percent(FURLED_CHANCE) { SailState::Furled } else { SailState::Full };
+        let sail_state = if rng.percent(tuning.furled_chance) { SailState::Furled } else { SailState::Full };
         let spec = ShipSpec::new(heading, length)
             .with_hull_shape(hull_shape)
             .with_sail_state(sail_state);
@@ -205,7 +272,7 @@ async fn place_one_ship(
         // Crew NPC scenes (afloat ships only); the settlement layer staffs them. Looks and
         // dialogue come from the ship-crew fixtures in `npcs.yaml`.
         let mut crew_rng = rng.derive();
-        crew.extend(crew_scenes(&out, &data.npc_data, &mut crew_rng));
+        crew.extend(crew_scenes(&out, &data.npc_data, &tuning.crew_sailors, &mut crew_rng));
 
         for cell in &footprint {
             placed.insert(*cell);
@@ -222,6 +289,7 @@ async fn place_one_ship(
 /// `(heading, length, footprint cells)` or `None` if nothing fits here.
 fn try_fit(
     world: &World,
+    tuning: &FleetTuning,
     centre: Point2D,
     build_height: i32,
     max_length: i32,
@@ -245,17 +313,17 @@ fn try_fit(
     // fit under the world top. (Mast top ≈ surface + ~0.82·length; using `length` is a safe
     // over-estimate.)
     let surface = world.get_motion_blocking_height_at(centre)?;
-    let length_ceiling = (build_height - surface - VERTICAL_HEADROOM).min(max_length);
+    let length_ceiling = (build_height - surface - tuning.vertical_headroom).min(max_length);
 
     for heading in headings {
-        for &length in SHIP_LENGTHS {
+        for &length in &tuning.ship_lengths {
             if length > length_ceiling {
                 continue;
             }
-            let min_depth = keel_depth(length) + KEEL_CLEARANCE;
+            let min_depth = keel_depth(length) + tuning.keel_clearance;
             let bow_reach = bowsprit_reach(length);
             if let Some(footprint) =
-                footprint_cells(world, centre, heading, length, min_depth, bow_reach, placed)
+                footprint_cells(world, centre, heading, length, tuning.hull_margin, min_depth, bow_reach, placed)
             {
                 return Some((heading, length, footprint));
             }
@@ -266,26 +334,28 @@ fn try_fit(
 
 /// The footprint cells of a hull `length` long centred on `centre`, heading `heading`, or
 /// `None` if any cell fails. The rect covers the hull (length × max-beam) plus
-/// [`HULL_MARGIN`] of clear water on every side, **and** the bowsprit's forward overhang
+/// `hull_margin` ([`HULL_MARGIN`]) of clear water on every side, **and** the bowsprit's forward overhang
 /// (`bow_reach` cells past the bow). Two predicates apply:
 /// - **hull cells** must be in-bounds, water, unclaimed, unoccupied, *and* deep enough that
 ///   the keel clears the seabed (`depth ≥ min_depth`);
 /// - **bow-overhang cells** (the spar/jib hang at/above the surface) only need to be open
 ///   water — in-bounds, water, unclaimed, unoccupied — with no depth requirement, so a
 ///   bowsprit never embeds in the shore or pokes through another ship.
+#[allow(clippy::too_many_arguments)]
 fn footprint_cells(
     world: &World,
     centre: Point2D,
     heading: Cardinal,
     length: i32,
+    hull_margin: i32,
     min_depth: i32,
     bow_reach: i32,
     placed: &HashSet<Point2D>,
 ) -> Option<Vec<Point2D>> {
     let dir: Point2D = heading.into();
     let perp: Point2D = heading.rotate_right().into();
-    let half_len = length / 2 + HULL_MARGIN;
-    let half_w = max_beam(length, DEFAULT_BEAM_RATIO) / 2 + HULL_MARGIN;
+    let half_len = length / 2 + hull_margin;
+    let half_w = max_beam(length, DEFAULT_BEAM_RATIO) / 2 + hull_margin;
 
     // The hull spans `a ∈ [-half_len, half_len]` (bow toward `+dir`); the bowsprit overhangs
     // the bow by `bow_reach` more cells.
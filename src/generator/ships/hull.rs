@@ -13,7 +13,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::generator::materials::{MaterialPlacer, Placer};
-use crate::geometry::Point3D;
+use crate::geometry::{LocalPoint3D, Point3D};
 use crate::minecraft::{Block, BlockForm};
 
 use super::palette::{ShipPalette, ShipPart};
@@ -254,7 +254,7 @@ pub async fn place_hull(
     if on_water {
         let air = Block::from("minecraft:air");
         for &cell in &model.interior {
-            ctx.editor.place_block(&air, placement.to_world(cell)).await;
+            ctx.editor.place_block(&air, LocalPoint3D(placement.to_world(cell))).await;
         }
     }
 }
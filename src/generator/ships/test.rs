@@ -2,6 +2,7 @@
 //! water world + offline editor, no server. The live `build_ship` places keels
 //! into the running server's build area for the screenshot loop.
 
+use crate::geometry::LocalPoint3D;
 use crate::generator::ships::keel;
 
 /// Scan small hulls for watertightness leaks (the stern-hole bug). Pure geometry — no
@@ -428,7 +429,7 @@ async fn jib_places_rigging_and_wool() {
         for x in 0..180 {
             for y in 40..110 {
                 for z in 0..180 {
-                    if let Some(b) = editor.try_get_block(Point3D::new(x, y, z)) {
+                    if let Some(b) = editor.try_get_block(LocalPoint3D::new(x, y, z)) {
                         let id = b.id.as_str();
                         if id.contains("chain") {
                             chains += 1;
@@ -489,7 +490,7 @@ async fn jib_furled_is_rigging_only() {
         for x in 0..180 {
             for y in 40..110 {
                 for z in 0..180 {
-                    if let Some(b) = editor.try_get_block(Point3D::new(x, y, z)) {
+                    if let Some(b) = editor.try_get_block(LocalPoint3D::new(x, y, z)) {
                         let id = b.id.as_str();
                         if id.contains("chain") {
                             chains += 1;
@@ -552,7 +553,7 @@ async fn mast_stays_connect_tops() {
     for dx in -1..=1 {
         for dy in -2..=2 {
             let local = Point3D::new(midx + dx, midy + dy, 0);
-            if let Some(b) = editor.get_cached_block(ship.placement.to_world(local)) {
+            if let Some(b) = editor.get_cached_block(LocalPoint3D(ship.placement.to_world(local))) {
                 if b.id.as_str().contains("chain") {
                     found = true;
                 }
@@ -587,7 +588,7 @@ async fn helm_places_wheel() {
     for x in 0..180 {
         for y in 40..127 {
             for z in 0..180 {
-                if let Some(b) = editor.try_get_block(Point3D::new(x, y, z)) {
+                if let Some(b) = editor.try_get_block(LocalPoint3D::new(x, y, z)) {
                     let id = b.id.as_str();
                     if id.contains("lectern") {
                         lecterns += 1;
@@ -631,7 +632,7 @@ async fn companionways_connect_levels() {
     // (Recorded cells are the x/z footprint at `floor_y`; the ceiling cells carry the hole/lid.)
     let (mut holes, mut trapdoors) = (0, 0);
     for cell in &ship.hatch_cells {
-        if let Some(b) = editor.try_get_block(ship.placement.to_world(*cell)) {
+        if let Some(b) = editor.try_get_block(LocalPoint3D(ship.placement.to_world(*cell))) {
             let id = b.id.as_str();
             if id.contains("air") {
                 holes += 1;
@@ -670,7 +671,7 @@ async fn ship_interior_furnished() {
     for x in 0..180 {
         for y in 40..90 {
             for z in 0..180 {
-                if let Some(b) = editor.try_get_block(Point3D::new(x, y, z)) {
+                if let Some(b) = editor.try_get_block(LocalPoint3D::new(x, y, z)) {
                     let id = b.id.as_str();
                     if id.contains("barrel") || id.ends_with("chest") || id.contains("hay") {
                         cargo += 1;
@@ -723,7 +724,7 @@ async fn giant_ship_length_100() {
     for x in 0..256 {
         for y in 60..130 {
             for z in 0..256 {
-                if let Some(b) = editor.try_get_block(Point3D::new(x, y, z)) {
+                if let Some(b) = editor.try_get_block(LocalPoint3D::new(x, y, z)) {
                     if b.id.as_str().contains("ladder") {
                         ladders += 1;
                     }
@@ -928,7 +929,7 @@ async fn build_ship_offline() {
     let place = &ship.placement;
     let is_air = |b: &Block| b.id == "air".into() || b.id == "minecraft:air".into();
     let solid_at = |p: Point3D| {
-        editor.try_get_block(p).as_ref().map_or(false, |b| !is_air(b))
+        editor.try_get_block(LocalPoint3D(p)).as_ref().map_or(false, |b| !is_air(b))
     };
 
     // Sternpost base is solid.
@@ -984,9 +985,9 @@ async fn build_ship_offline() {
     if let Some(cell) = air_probe {
         let inside = place.to_world(cell);
         assert!(
-            editor.try_get_block(inside).as_ref().map_or(false, |b| is_air(b)),
+            editor.try_get_block(LocalPoint3D(inside)).as_ref().map_or(false, |b| is_air(b)),
             "hull interior should be cleared to air at {inside:?} (local {cell:?}), got {:?}",
-            editor.try_get_block(inside),
+            editor.try_get_block(LocalPoint3D(inside)),
         );
     }
 
@@ -1001,9 +1002,9 @@ async fn build_ship_offline() {
     );
     let fence_world = place.to_world(rud.fences[0]);
     assert!(
-        editor.try_get_block(fence_world).map_or(false, |b| b.id.as_str().contains("fence")),
+        editor.try_get_block(LocalPoint3D(fence_world)).map_or(false, |b| b.id.as_str().contains("fence")),
         "expected a fence at {fence_world:?}, got {:?}",
-        editor.try_get_block(fence_world).map(|b| b.id),
+        editor.try_get_block(LocalPoint3D(fence_world)).map(|b| b.id),
     );
 
     // Additional deck: blocks are placed above the main deck (topsides + floor).
@@ -1026,9 +1027,9 @@ async fn build_ship_offline() {
         assert!(!railing.cap.is_empty(), "railing should have a fence rail cap");
         let cap_world = place.to_world(railing.cap[0]);
         assert!(
-            editor.try_get_block(cap_world).map_or(false, |b| b.id.as_str().contains("fence")),
+            editor.try_get_block(LocalPoint3D(cap_world)).map_or(false, |b| b.id.as_str().contains("fence")),
             "expected a fence rail at {cap_world:?}, got {:?}",
-            editor.try_get_block(cap_world).map(|b| b.id),
+            editor.try_get_block(LocalPoint3D(cap_world)).map(|b| b.id),
         );
     }
 
@@ -1040,9 +1041,9 @@ async fn build_ship_offline() {
         assert!(!bowsprit.prow.is_empty(), "bowsprit should have a solid prow");
         let prow_world = place.to_world(bowsprit.prow[0]);
         assert!(
-            editor.try_get_block(prow_world).as_ref().map_or(false, |b| !is_air(b)),
+            editor.try_get_block(LocalPoint3D(prow_world)).as_ref().map_or(false, |b| !is_air(b)),
             "expected a solid prow block at {prow_world:?}, got {:?}",
-            editor.try_get_block(prow_world).map(|b| b.id),
+            editor.try_get_block(LocalPoint3D(prow_world)).map(|b| b.id),
         );
         assert!(
             bowsprit.tip.x > keel.length - 1,
@@ -1053,12 +1054,12 @@ async fn build_ship_offline() {
         // The spar is a block/slab beam (no stairs) — its mid cell is a solid plank or slab.
         let spar_world = place.to_world(bowsprit.spar[bowsprit.spar.len() / 2].local);
         assert!(
-            editor.try_get_block(spar_world).map_or(false, |b| {
+            editor.try_get_block(LocalPoint3D(spar_world)).map_or(false, |b| {
                 let id = b.id.as_str();
                 id.contains("slab") || id.contains("plank") || id.contains("log")
             }),
             "expected a block/slab spar at {spar_world:?}, got {:?}",
-            editor.try_get_block(spar_world).map(|b| b.id),
+            editor.try_get_block(LocalPoint3D(spar_world)).map(|b| b.id),
         );
     }
 
@@ -1067,9 +1068,9 @@ async fn build_ship_offline() {
     assert!(!deck.cells.is_empty(), "deck should have slabs");
     let deck_world = place.to_world(deck.cells[0]);
     assert!(
-        editor.try_get_block(deck_world).map_or(false, |b| b.id.as_str().contains("slab")),
+        editor.try_get_block(LocalPoint3D(deck_world)).map_or(false, |b| b.id.as_str().contains("slab")),
         "expected a deck slab at {deck_world:?}, got {:?}",
-        editor.try_get_block(deck_world).map(|b| b.id),
+        editor.try_get_block(LocalPoint3D(deck_world)).map(|b| b.id),
     );
 
     // Masts: keel-stepped log poles rising above the deck.
@@ -1083,9 +1084,9 @@ async fn build_ship_offline() {
         let main = &masts.masts[0];
         let log_world = place.to_world(main.cells[main.cells.len() / 2]);
         assert!(
-            editor.try_get_block(log_world).map_or(false, |b| b.id.as_str().contains("log")),
+            editor.try_get_block(LocalPoint3D(log_world)).map_or(false, |b| b.id.as_str().contains("log")),
             "expected a mast log at {log_world:?}, got {:?}",
-            editor.try_get_block(log_world).map(|b| b.id),
+            editor.try_get_block(LocalPoint3D(log_world)).map(|b| b.id),
         );
     }
 
@@ -1190,7 +1191,7 @@ async fn build_ship_offline_land() {
     let bottom_world = place.to_world(Point3D::new(ship.keel.length / 2, 0, 0));
     assert_eq!(bottom_world.y, ground_y, "land keel bottom should rest on the ground");
     assert!(
-        editor.try_get_block(bottom_world).as_ref().map_or(false, |b| !is_air(b)),
+        editor.try_get_block(LocalPoint3D(bottom_world)).as_ref().map_or(false, |b| !is_air(b)),
         "land keel bottom should be solid at the ground surface",
     );
 
@@ -1209,7 +1210,7 @@ async fn scatter_ships_offline() {
     use crate::editor::World;
     use crate::generator::data::LoadedData;
     use crate::generator::districts::{District, DistrictID, ParcelType};
-    use crate::generator::ships::fleet::scatter_ships;
+    use crate::generator::ships::fleet::{scatter_ships, FleetTuning};
     use crate::generator::ships::keel::keel_depth;
     use crate::generator::BuildClaim;
     use crate::geometry::{Point2D, Point3D, Rect3D};
@@ -1233,7 +1234,7 @@ async fn scatter_ships_offline() {
     let mut editor = world.get_offline_editor();
     let data = LoadedData::load().expect("data");
 
-    let (n, _crew) = scatter_ships(&mut editor, &data, Seed(7)).await;
+    let (n, _crew) = scatter_ships(&mut editor, &data, Seed(7), &FleetTuning::default()).await;
     assert!(n > 0, "expected ships scattered onto the water district");
 
     // Every cell claimed for a ship must be water; the deepest keel we could place needs
@@ -1271,6 +1272,7 @@ async fn crew_scenes_offline() {
     use crate::generator::population::SlotRole;
     use crate::generator::ships::additions::SizeTier;
     use crate::generator::ships::crew::crew_scenes;
+    use crate::generator::ships::fleet::CrewSailors;
     use crate::generator::ships::{build_ship, ShipCtx, ShipSpec};
     use crate::geometry::{Cardinal, Point2D, Point3D, Rect3D};
     use crate::noise::RNG;
@@ -1293,7 +1295,7 @@ async fn crew_scenes_offline() {
     assert!(ship.on_water);
     assert_eq!(ship.tier, SizeTier::Large);
 
-    let scenes = crew_scenes(&ship, &data.npc_data, &mut RNG::new(7));
+    let scenes = crew_scenes(&ship, &data.npc_data, &CrewSailors::default(), &mut RNG::new(7));
     let key = |s: &crate::generator::population::AnchorScene| s.slots[0].dialogue.clone();
     let captains = scenes.iter().filter(|s| key(s).as_deref() == Some("captain")).count();
     let sailors = scenes.iter().filter(|s| key(s).as_deref() == Some("sailor")).count();
@@ -1321,7 +1323,7 @@ async fn crew_scenes_offline() {
     let hulk = build_ship(&mut ctx2, &spec, Point2D::new(128, 128)).await;
     assert!(!hulk.on_water, "anchor over dry land should not be water");
     assert!(
-        crew_scenes(&hulk, &data.npc_data, &mut RNG::new(7)).is_empty(),
+        crew_scenes(&hulk, &data.npc_data, &CrewSailors::default(), &mut RNG::new(7)).is_empty(),
         "a grounded hulk is crewless",
     );
 }
@@ -1496,7 +1498,7 @@ async fn scatter_ships_live() {
     use crate::generator::buildings_v2::Culture;
     use crate::generator::population::IdAllocator;
     use crate::generator::ships::crew::staff_crew;
-    use crate::generator::ships::fleet::scatter_ships;
+    use crate::generator::ships::fleet::{scatter_ships, FleetTuning};
     use crate::http_mod::GDMCHTTPProvider;
     use crate::noise::{Seed, RNG};
     use crate::util::init_logger;
@@ -1522,7 +1524,7 @@ async fn scatter_ships_live() {
         .count();
     println!("Found {water_districts} water district(s)");
 
-    let (n, crew) = scatter_ships(&mut editor, &data, seed).await;
+    let (n, crew) = scatter_ships(&mut editor, &data, seed, &FleetTuning::default()).await;
     println!("Scattered {n} ship(s) across {water_districts} water district(s)");
 
     // Crew the ships: staff the captain/sailor anchor scenes from a roster, exactly as the
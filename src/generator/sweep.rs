@@ -0,0 +1,202 @@
+//! Seed sweeps: build the same map offline under many seeds, score every town
+//! and rank the seeds, so the seed a map is submitted with is the best one
+//! rather than the first that looked right. A town is measured by
+//! [`TownMetrics`], which [`generate_town_phases`](super::settlement::generate_town_phases)
+//! returns for every town it builds; `tome sweep` runs the seeds and writes the
+//! ranked [`SweepReport`].
+
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::generator::buildings_v2::Culture;
+
+/// Open-space share of the urban area a town scores best at: enough squares and
+/// greens to break up the streets, not so many that it reads as empty.
+const OPEN_SPACE_TARGET: f32 = 0.15;
+/// Houses past which a bigger town scores no better.
+const HOUSE_COUNT_TARGET: usize = 40;
+/// Gates past which more don't score.
+const GATE_TARGET: usize = 2;
+
+/// What one town came out as. Counts the phases that didn't run leave at zero.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TownMetrics {
+    pub culture: Option<Culture>,
+    /// Cells inside the wall.
+    pub urban_cells: usize,
+    /// Houses built, and `build_house` failures, over every frontage tier.
+    pub houses_placed: usize,
+    pub houses_failed: usize,
+    /// `build_house` failures by reason: the error up to its first colon, so
+    /// "invariant (b)" counts every unreachable-cell failure together.
+    pub house_failures: BTreeMap<String, usize>,
+    /// Rural gather buildings seated, of the parcels the economy gave one.
+    pub rural_placed: usize,
+    pub rural_sites: usize,
+    /// Share of urban cells within a short walk of a paved road.
+    pub road_coverage: f32,
+    /// Share of urban cells in plazas, parks, nooks and yards.
+    pub open_space_share: f32,
+    pub gates: usize,
+}
+
+impl TownMetrics {
+    /// The town's quality out of 100:
+    ///
+    /// | weight | measure |
+    /// |--------|---------|
+    /// | 30 | houses placed of houses attempted |
+    /// | 15 | houses placed, up to 40 |
+    /// | 15 | rural buildings seated of their parcels |
+    /// | 20 | road coverage |
+    /// | 10 | open-space share, best at 15% |
+    /// | 10 | gates, up to 2 |
+    ///
+    /// A town with no houses scores nothing for them, however few failed.
+    pub fn score(&self) -> f32 {
+        let attempted = self.houses_placed + self.houses_failed;
+        let house_rate = if attempted == 0 { 0.0 } else { self.houses_placed as f32 / attempted as f32 };
+        let house_count = self.houses_placed.min(HOUSE_COUNT_TARGET) as f32 / HOUSE_COUNT_TARGET as f32;
+        let rural_rate = if self.rural_sites == 0 { 1.0 } else { self.rural_placed as f32 / self.rural_sites as f32 };
+        let open_space = (1.0 - (self.open_space_share - OPEN_SPACE_TARGET).abs() / OPEN_SPACE_TARGET).max(0.0);
+        let gates = self.gates.min(GATE_TARGET) as f32 / GATE_TARGET as f32;
+        30.0 * house_rate
+            + 15.0 * house_count
+            + 15.0 * rural_rate
+            + 20.0 * self.road_coverage.clamp(0.0, 1.0)
+            + 10.0 * open_space
+            + 10.0 * gates
+    }
+}
+
+/// The bucket a `build_house` error counts under in [`TownMetrics::house_failures`].
+pub fn failure_reason(error: &str) -> String {
+    error.split(':').next().unwrap_or(error).trim().to_string()
+}
+
+/// One seed of a sweep: every town it built, and their mean score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepEntry {
+    pub seed: i64,
+    pub score: f32,
+    pub towns: Vec<TownMetrics>,
+    /// Why the run stopped before building, when it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SweepEntry {
+    pub fn new(seed: i64, towns: Vec<TownMetrics>) -> Self {
+        let score = if towns.is_empty() { 0.0 } else { towns.iter().map(TownMetrics::score).sum::<f32>() / towns.len() as f32 };
+        Self { seed, score, towns, error: None }
+    }
+
+    pub fn failed(seed: i64, error: String) -> Self {
+        Self { seed, score: 0.0, towns: Vec::new(), error: Some(error) }
+    }
+}
+
+/// The seeds of a sweep, best first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SweepReport {
+    pub entries: Vec<SweepEntry>,
+}
+
+impl SweepReport {
+    /// Ranks `entries` by score, ties to the lower seed so a report is stable.
+    pub fn ranked(mut entries: Vec<SweepEntry>) -> Self {
+        entries.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.seed.cmp(&b.seed)));
+        Self { entries }
+    }
+
+    pub fn best(&self) -> Option<&SweepEntry> {
+        self.entries.first()
+    }
+
+    /// One line per seed, best first, with the measures behind its score and
+    /// the commonest house failure.
+    pub fn table(&self) -> String {
+        let mut out = String::from(
+            "rank  seed                  score  houses  failed  rural   roads  open   gates  top failure\n",
+        );
+        for (rank, entry) in self.entries.iter().enumerate() {
+            if let Some(error) = &entry.error {
+                out += &format!("{:<5} {:<21} {:>5}  failed: {error}\n", rank + 1, entry.seed, "-");
+                continue;
+            }
+            let sum = |f: fn(&TownMetrics) -> usize| entry.towns.iter().map(f).sum::<usize>();
+            let mean = |f: fn(&TownMetrics) -> f32| {
+                entry.towns.iter().map(f).sum::<f32>() / entry.towns.len().max(1) as f32
+            };
+            let mut failures: BTreeMap<&str, usize> = BTreeMap::new();
+            for (reason, n) in entry.towns.iter().flat_map(|town| &town.house_failures) {
+                *failures.entry(reason).or_default() += n;
+            }
+            let top = failures
+                .iter()
+                .max_by_key(|&(reason, n)| (*n, std::cmp::Reverse(*reason)))
+                .map_or(String::from("-"), |(reason, n)| format!("{reason} ×{n}"));
+            out += &format!(
+                "{:<5} {:<21} {:>5.1}  {:>6}  {:>6}  {:>6}  {:>4.0}%  {:>4.0}%  {:>5}  {top}\n",
+                rank + 1,
+                entry.seed,
+                entry.score,
+                sum(|t| t.houses_placed),
+                sum(|t| t.houses_failed),
+                format!("{}/{}", sum(|t| t.rural_placed), sum(|t| t.rural_sites)),
+                100.0 * mean(|t| t.road_coverage),
+                100.0 * mean(|t| t.open_space_share),
+                sum(|t| t.gates),
+            );
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn town(houses_placed: usize, houses_failed: usize) -> TownMetrics {
+        TownMetrics {
+            houses_placed,
+            houses_failed,
+            rural_placed: 4,
+            rural_sites: 5,
+            road_coverage: 0.8,
+            open_space_share: 0.15,
+            gates: 2,
+            ..TownMetrics::default()
+        }
+    }
+
+    #[test]
+    fn a_full_town_outscores_a_failing_one() {
+        let good = town(40, 0);
+        assert!((good.score() - 93.0).abs() < 1e-3, "{}", good.score());
+        assert!(town(40, 10).score() < good.score());
+        assert!(town(5, 0).score() < good.score());
+        // No houses attempted is no houses, not a perfect placement rate.
+        assert!(town(0, 0).score() < town(1, 3).score());
+    }
+
+    #[test]
+    fn reports_rank_best_first_and_bucket_failures() {
+        assert_eq!(failure_reason("invariant (b): room Kitchen floor 0 ..."), "invariant (b)");
+        assert_eq!(failure_reason("no door"), "no door");
+
+        let report = SweepReport::ranked(vec![
+            SweepEntry::new(3, vec![town(10, 5)]),
+            SweepEntry::failed(9, "no towns".into()),
+            SweepEntry::new(7, vec![town(40, 0), town(30, 2)]),
+            SweepEntry::new(1, vec![town(10, 5)]),
+        ]);
+        let seeds: Vec<i64> = report.entries.iter().map(|e| e.seed).collect();
+        assert_eq!(seeds, vec![7, 1, 3, 9]);
+        assert_eq!(report.best().unwrap().seed, 7);
+        let table = report.table();
+        assert_eq!(table.lines().count(), 5);
+        assert!(table.lines().nth(4).unwrap().contains("failed: no towns"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::{editor::Editor, geometry::{average_to_neighbours_5_away_multi, Point2D, Point3D, CARDINALS_2D}, minecraft::Block};
+use crate::{editor::Editor, geometry::{average_to_neighbours_5_away_multi, Point2D, LocalPoint3D, Point3D, CARDINALS_2D}, minecraft::Block};
 
 /// Flatten the urban interior toward a smoothed height field, feathering back to
 /// natural terrain over `feather` cells near the city edge so the settlement
@@ -161,7 +161,7 @@ pub async fn drain_liquids(editor: &mut Editor, area: &HashSet<Point2D>) {
         // surrounding ground, defaulting to dirt if the column isn't loaded.
         let surface = editor
             .world()
-            .get_block(Point3D::new(xz.x, floor - 1, xz.y))
+            .get_block(LocalPoint3D::new(xz.x, floor - 1, xz.y))
             .unwrap_or_else(|| Block::from_id("minecraft:dirt".into()));
         let (fill, top) = terraform_layers(&surface);
 
@@ -172,17 +172,17 @@ pub async fn drain_liquids(editor: &mut Editor, area: &HashSet<Point2D>) {
         let mut filled = false;
         while editor
             .world()
-            .get_block(Point3D::new(xz.x, y, xz.y))
+            .get_block(LocalPoint3D::new(xz.x, y, xz.y))
             .is_some_and(|b| b.id.is_liquid())
         {
-            editor.place_block_forced(&fill, Point3D::new(xz.x, y, xz.y)).await;
+            editor.place_block_forced(&fill, LocalPoint3D::new(xz.x, y, xz.y)).await;
             filled = true;
             y += 1;
         }
         if filled {
             // Cap the plug in the floor's surface material and record the new dry
             // surface (first air at `y`).
-            editor.place_block_forced(&top, Point3D::new(xz.x, y - 1, xz.y)).await;
+            editor.place_block_forced(&top, LocalPoint3D::new(xz.x, y - 1, xz.y)).await;
             updated.insert(xz, (y, top));
         }
     }
@@ -215,19 +215,21 @@ pub async fn force_height(editor: &mut Editor, points: &HashSet<Point3D>, skip_w
         if editor.world().is_water(xz) && skip_water {
             continue;
         }
-        // Never grade a built wall cell: a road corridor's width ring can reach
-        // the solid wall beside a gate, and clearing air down to road level there
-        // would punch a hole through the wall. Gate openings (claimed `Gate`) are
-        // still graded so the road passes through them flush.
-        if matches!(editor.world().get_claim(xz), Some(crate::generator::BuildClaim::Wall)) {
-            continue;
-        }
         // Heightmap convention (see World::new / blend_terrain): the surface
         // value is the first air block; the top solid sits at `value - 1`.
         let Some(terrain_y) = editor.world().get_ocean_floor_height_at(xz) else {
             continue;
         };
         let target_y = point.y;
+        // Never grade a built wall cell: a road corridor's width ring can reach
+        // the solid wall beside a gate, and clearing air down to road level there
+        // would punch a hole through the wall. Gate openings (claimed `Gate`) are
+        // still graded so the road passes through them flush. Only the cells the
+        // grade touches count, so a wall-walk spanning overhead doesn't stop it.
+        let graded = terrain_y.min(target_y) - 1..terrain_y.max(target_y) + 1;
+        if editor.world().claims_in(xz, graded).any(|claim| matches!(claim, crate::generator::BuildClaim::Wall)) {
+            continue;
+        }
 
         // Keep the terraformed cap in the natural surface material: grass stays
         // grass, sand stays sand, stone stays stone. Gravity surfaces (sand/
@@ -240,7 +242,7 @@ pub async fn force_height(editor: &mut Editor, points: &HashSet<Point3D>, skip_w
         // mis-detect every surface and cap with air (a 1-deep hole).
         let surface = editor
             .world()
-            .get_block(point.with_y(terrain_y - 1))
+            .get_block(LocalPoint3D(point.with_y(terrain_y - 1)))
             .unwrap_or_else(|| Block::from_id("minecraft:dirt".into()));
         let (fill, top) = terraform_layers(&surface);
         let is_snow = surface.id.as_str().contains("snow");
@@ -250,20 +252,20 @@ pub async fn force_height(editor: &mut Editor, points: &HashSet<Point3D>, skip_w
             if target_y > terrain_y {
                 // Raise: subsurface fill from the old surface up to the new top.
                 for y in terrain_y..target_y {
-                    editor.place_block_forced(&fill, point.with_y(y)).await;
+                    editor.place_block_forced(&fill, LocalPoint3D(point.with_y(y))).await;
                 }
             } else {
                 // Lower: clear down to the new surface.
                 for y in target_y..=terrain_y {
-                    editor.place_block_forced(&"air".into(), point.with_y(y)).await;
+                    editor.place_block_forced(&"air".into(), LocalPoint3D(point.with_y(y))).await;
                 }
             }
 
             // Cap with the surface material at the new top (target_y - 1),
             // re-laying a snow layer above it if the original ground was snowy.
-            editor.place_block_forced(&top, point.with_y(target_y - 1)).await;
+            editor.place_block_forced(&top, LocalPoint3D(point.with_y(target_y - 1))).await;
             if is_snow {
-                editor.place_block_forced(&surface, point.with_y(target_y)).await;
+                editor.place_block_forced(&surface, LocalPoint3D(point.with_y(target_y))).await;
             }
         }
 
@@ -279,27 +281,27 @@ pub async fn force_height(editor: &mut Editor, points: &HashSet<Point3D>, skip_w
             let is_liquid_at = |editor: &Editor, y: i32| {
                 editor
                     .world()
-                    .get_block(point.with_y(y))
+                    .get_block(LocalPoint3D(point.with_y(y)))
                     .is_some_and(|b| b.id.is_liquid())
             };
 
             // Submerged liquid just below the graded surface → solid fill.
             let mut y = target_y - 1;
             while is_liquid_at(editor, y) {
-                editor.place_block_forced(&fill, point.with_y(y)).await;
+                editor.place_block_forced(&fill, LocalPoint3D(point.with_y(y))).await;
                 changed = true;
                 y -= 1;
             }
             // Liquid at/above the graded surface → solid fill, tracking the top.
             let mut y = target_y;
             while is_liquid_at(editor, y) {
-                editor.place_block_forced(&fill, point.with_y(y)).await;
+                editor.place_block_forced(&fill, LocalPoint3D(point.with_y(y))).await;
                 changed = true;
                 y += 1;
             }
             if y > target_y {
                 // Liquid rose above grade; cap the raised solid and lift the surface.
-                editor.place_block_forced(&top, point.with_y(y - 1)).await;
+                editor.place_block_forced(&top, LocalPoint3D(point.with_y(y - 1))).await;
                 surface_air = y;
             }
         }
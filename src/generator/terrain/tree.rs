@@ -2,7 +2,7 @@ use log::info;
 use serde_derive::{Deserialize, Serialize};
 use std::{collections::HashMap};
 
-use crate::{editor::Editor, geometry::Point3D, noise::{RNG, Seed}, minecraft::{string_to_block,Block}};
+use crate::{editor::Editor, geometry::{LocalPoint3D, Point3D}, noise::{RNG, Seed}, minecraft::{string_to_block,Block}};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Tree {
@@ -192,12 +192,12 @@ pub async fn generate_tree(
 async fn generate_cactus(editor: &Editor, point: Point3D, rng: &mut RNG) {
     let (x, y, z) = (point.x, point.y, point.z);
     editor
-        .place_block_forced(&"minecraft:sand".into(), Point3D { x, y: y - 1, z })
+        .place_block_forced(&"minecraft:sand".into(), LocalPoint3D::new(x, y - 1, z))
         .await;
     let height = 1 + rng.rand_i32(3); // 1..=3 tall
     for i in 0..height {
         editor
-            .place_block(&"minecraft:cactus".into(), Point3D { x, y: y + i, z })
+            .place_block(&"minecraft:cactus".into(), LocalPoint3D::new(x, y + i, z))
             .await;
     }
 }
@@ -216,25 +216,25 @@ async fn generate_small_birch(
 
     for y in y0..=(height + 2) {
         if y >= height {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
         } else {
-            editor.place_block_chance(wood, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(wood, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
         }
         let mid = ((height - y0) / 2 + y0) - 1;
         if y == mid || y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         } else if y > mid && y < height + 2 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         }
     }
 }
@@ -257,11 +257,11 @@ async fn generate_medium_birch(
 
     // Trunk and base
     for y in y0..height {
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         if y == y0 {
             for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
                 if rng.rand_i32_range(1, 5) != 4 {
-                    editor.place_block(wood, Point3D { x: x0 + dx, y, z: z0 + dz }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + dx, y, z0 + dz)).await;
                 }
             }
         }
@@ -275,130 +275,130 @@ async fn generate_medium_birch(
         match branch_pos {
             1 => {
                 branches.push((x0 + 2, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             2 => {
                 branches.push((x0 + 2, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 1)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
                 }
             }
             3 => {
                 branches.push((x0 + 2, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             4 => {
                 branches.push((x0 + 2, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 1)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
                 }
             }
             5 => {
                 branches.push((x0 + 2, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             6 => {
                 branches.push((x0 - 2, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             7 => {
                 branches.push((x0 - 2, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 1)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
                 }
             }
             8 => {
                 branches.push((x0 - 2, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             9 => {
                 branches.push((x0 - 2, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 1)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
                 }
             }
             10 => {
                 branches.push((x0 - 2, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             11 => {
                 branches.push((x0 + 1, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 2)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
                 }
             }
             12 => {
                 branches.push((x0, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             13 => {
                 branches.push((x0 - 1, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 2)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
                 }
             }
             14 => {
                 branches.push((x0 + 1, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 2)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
                 }
             }
             15 => {
                 branches.push((x0, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             16 => {
                 branches.push((x0 - 1, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 2)).await;
                 if rng.rand_i32_range(1, 3) == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
                 }
             }
             _ => {}
@@ -408,11 +408,11 @@ async fn generate_medium_birch(
     // Leaves for each branch
     for &(x1, y1, z1) in &branches {
         // Top leaf
-        editor.place_block_chance(leaf, Point3D { x: x1, y: y1 + 3, z: z1 }, rng, leaf_chance).await;
+        editor.place_block_chance(leaf, LocalPoint3D::new(x1, y1 + 3, z1), rng, leaf_chance).await;
 
         // Second layer
         for (dx, dz) in &[(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 2, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 2, z1 + dz), rng, leaf_chance).await;
         }
 
         // Third layer
@@ -421,7 +421,7 @@ async fn generate_medium_birch(
             (1, 0), (-1, 0), (0, 1), (0, -1),
         ];
         for (dx, dz) in &third_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 1, z1 + dz), rng, leaf_chance).await;
         }
 
         // Fourth layer
@@ -430,12 +430,12 @@ async fn generate_medium_birch(
             (1, 0), (-1, 0), (0, 1), (0, -1),
         ];
         for (dx, dz) in &fourth_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1, z1 + dz), rng, leaf_chance).await;
         }
 
         // Fifth layer (below)
         for (dx, dz) in &[(1, 0), (-1, 0), (0, 1), (0, -1)] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 - 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 - 1, z1 + dz), rng, leaf_chance).await;
         }
     }
 }
@@ -457,11 +457,11 @@ async fn generate_large_birch(
 
     // Trunk and base
     for y in y0..height {
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         if y == y0 {
             for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
                 if rng.rand_i32_range(1, 5) != 4 {
-                    editor.place_block(wood, Point3D { x: x0 + dx, y, z: z0 + dz }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + dx, y, z0 + dz)).await;
                 }
             }
         }
@@ -474,338 +474,338 @@ async fn generate_large_birch(
         match branch_pos {
             1 => {
                 branches.push((x0 + 3, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
             }
             2 => {
                 branches.push((x0 + 3, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 }
             }
             3 => {
                 branches.push((x0 + 3, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
             }
             4 => {
                 branches.push((x0 + 3, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 }
             }
             5 => {
                 branches.push((x0 + 3, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
             }
             6 => {
                 branches.push((x0 - 3, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
             }
             7 => {
                 branches.push((x0 - 3, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 }
             }
             8 => {
                 branches.push((x0 - 3, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
             }
             9 => {
                 branches.push((x0 - 3, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 }
             }
             10 => {
                 branches.push((x0 - 3, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
             }
             11 => {
                 branches.push((x0 + 1, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 }
             }
             12 => {
                 branches.push((x0, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
             }
             13 => {
                 branches.push((x0 - 1, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 }
             }
             14 => {
                 branches.push((x0 + 1, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 }
             }
             15 => {
                 branches.push((x0, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
             }
             16 => {
                 branches.push((x0 - 1, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 }
             }
             17 => {
                 branches.push((x0 + 3, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 2)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 }
             }
             18 => {
                 branches.push((x0 + 3, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 2)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0)).await;
                 }
             }
             19 => {
                 branches.push((x0 - 3, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 2)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 }
             }
             20 => {
                 branches.push((x0 - 3, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 2)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 1 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 1)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0)).await;
                 }
             }
             21 => {
                 branches.push((x0 + 2, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 }
             }
             22 => {
                 branches.push((x0 - 2, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 + 1)).await;
                 }
             }
             23 => {
                 branches.push((x0 + 2, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 }
             }
             24 => {
                 branches.push((x0 - 2, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 4, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 3, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 4, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 3, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 4, z0 - 1)).await;
                 }
             }
             _ => {}
@@ -815,13 +815,13 @@ async fn generate_large_birch(
     // Leaves for each branch
     for &(x1, y1, z1) in &branches {
         // Top leaf
-        editor.place_block_chance(leaf, Point3D { x: x1, y: y1 + 4, z: z1 }, rng, leaf_chance).await;
+        editor.place_block_chance(leaf, LocalPoint3D::new(x1, y1 + 4, z1), rng, leaf_chance).await;
 
         // 2nd layer
         for (dx, dz) in &[
             (0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)
         ] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 3, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 3, z1 + dz), rng, leaf_chance).await;
         }
 
         // 3rd layer
@@ -831,7 +831,7 @@ async fn generate_large_birch(
             (2, 0), (-2, 0), (0, 2), (0, -2)
         ];
         for (dx, dz) in &third_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 2, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 2, z1 + dz), rng, leaf_chance).await;
         }
 
         // 4th layer
@@ -843,7 +843,7 @@ async fn generate_large_birch(
             (1, 2), (-1, -2), (-1, 2), (1, -2)
         ];
         for (dx, dz) in &fourth_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 1, z1 + dz), rng, leaf_chance).await;
         }
 
         // 5th layer
@@ -853,7 +853,7 @@ async fn generate_large_birch(
             (2, 0), (-2, 0), (0, 2), (0, -2)
         ];
         for (dx, dz) in &fifth_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1, z1 + dz), rng, leaf_chance).await;
         }
 
         // 6th layer (below)
@@ -861,12 +861,12 @@ async fn generate_large_birch(
             (1, 0), (-1, 0), (0, 1), (0, -1),
             (1, 1), (-1, -1), (-1, 1), (1, -1)
         ] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 - 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 - 1, z1 + dz), rng, leaf_chance).await;
         }
         for (dx, dz) in &[
             (1, 0), (-1, 0), (0, 1), (0, -1)
         ] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 - 2, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 - 2, z1 + dz), rng, leaf_chance).await;
         }
     }
 }
@@ -901,17 +901,17 @@ async fn generate_small_pine(
 
     for y in y0..=(height + 1) {
         if y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         }
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
 
         if y % 2 == height % 2 && y > y0 + 1 {
             let positions = [
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y == height - 1 {
             let positions = [
@@ -919,7 +919,7 @@ async fn generate_small_pine(
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 2 == (height - 1) % 2 && y > y0 {
             let positions = [
@@ -928,7 +928,7 @@ async fn generate_small_pine(
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         }
     }
@@ -947,16 +947,16 @@ async fn generate_medium_pine(
 
     for y in y0..=(height + 1) {
         if y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         }
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
 
         if y == height {
             for (dx, dz) in [
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ] {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y == height - 1 {
             let positions = [
@@ -966,7 +966,7 @@ async fn generate_medium_pine(
                 (1, 2), (-1, -2), (-1, 2), (1, -2)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 2 == height % 2 && y > y0 + 2 {
             let positions = [
@@ -975,7 +975,7 @@ async fn generate_medium_pine(
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 2 == (height - 1) % 2 && y > y0 + 1 {
             let positions = [
@@ -987,7 +987,7 @@ async fn generate_medium_pine(
                 (2, 2), (-2, -2), (-2, 2), (2, -2)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         }
     }
@@ -1010,21 +1010,21 @@ async fn generate_large_pine(
                 (0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
             continue;
         } else if y == height + 2 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         }
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
 
         if y == height {
             let positions = [
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 3 == (height - 1) % 3 && y > y0 + 5 {
             let positions = [
@@ -1033,7 +1033,7 @@ async fn generate_large_pine(
                 (1, 0), (-1, 0), (0, 1), (0, -1)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 3 == (height - 2) % 3 && y > y0 + 4 {
             let positions = [
@@ -1045,7 +1045,7 @@ async fn generate_large_pine(
                 (2, 2), (-2, -2), (-2, 2), (2, -2)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y % 3 == height % 3 && y > y0 + 3 {
             let positions = [
@@ -1061,7 +1061,7 @@ async fn generate_large_pine(
                 (4, 0), (-4, 0), (0, 4), (0, -4)
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         }
     }
@@ -1095,16 +1095,16 @@ async fn generate_small_hedge(
 
     for y in y0..=(height + 1) {
         if y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         } else {
-            editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+            editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         }
         if y > y0 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         }
     }
 }
@@ -1122,22 +1122,22 @@ async fn generate_medium_hedge(
 
     for y in y0..=(height + 1) {
         if y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         } else {
-            editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+            editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         }
         if y > y0 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         }
         if y > y0 + 2 && y < height {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 - 1), rng, leaf_chance).await;
         }
     }
 }
@@ -1155,38 +1155,38 @@ async fn generate_large_hedge(
 
     for y in y0..=(height + 1) {
         if y == height + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
             continue;
         } else {
-            editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+            editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         }
         if y > y0 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         }
         if y > y0 + 2 && y < height {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 - 1), rng, leaf_chance).await;
         }
         if y > y0 + 3 && y < height - 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 2, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 2, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 2 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 2 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 2, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 2, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 2), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 2), rng, leaf_chance).await;
         }
         if y > y0 + 5 && y < height - 3 {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 2, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 2, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 + 2 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 - 2 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 2, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 2, y, z: z0 - 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 + 2 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 - 2 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 2, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 2, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 + 2), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0 - 2), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 2, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 2, y, z0 - 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 + 2), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0 - 2), rng, leaf_chance).await;
         }
     }
 }
@@ -1218,20 +1218,20 @@ async fn generate_small_oak(
 
     for y in y0..=(stem_height + y0 + 1) {
         if y == stem_height + y0 + 1 {
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
             continue;
         }
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         let mid = ((stem_height as f32) / 2.0).floor() as i32 + y0 - 1;
         if y == mid {
-            editor.place_block_chance(leaf, Point3D { x: x0 + 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0 - 1, y, z: z0 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 + 1 }, rng, leaf_chance).await;
-            editor.place_block_chance(leaf, Point3D { x: x0, y, z: z0 - 1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 + 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0 - 1, y, z0), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 + 1), rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x0, y, z0 - 1), rng, leaf_chance).await;
         } else if y == stem_height + y0 || y == ((stem_height as f32) / 2.0).floor() as i32 + y0 {
             let positions = [
                 (2, 0), (-2, 0), (0, 2), (0, -2),
@@ -1239,7 +1239,7 @@ async fn generate_small_oak(
                 (1, 0), (-1, 0), (0, 1), (0, -1),
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         } else if y > ((stem_height as f32) / 2.0).floor() as i32 + y0 && y < stem_height + y0 {
             let positions = [
@@ -1250,7 +1250,7 @@ async fn generate_small_oak(
                 (1, 0), (-1, 0), (0, 1), (0, -1),
             ];
             for (dx, dz) in positions {
-                editor.place_block_chance(leaf, Point3D { x: x0 + dx, y, z: z0 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x0 + dx, y, z0 + dz), rng, leaf_chance).await;
             }
         }
     }
@@ -1273,11 +1273,11 @@ async fn generate_medium_oak(
 
     // Trunk and base
     for y in y0..height {
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         if y == y0 {
             for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
                 if rng.rand_i32_range(1, 5) != 4 {
-                    editor.place_block(wood, Point3D { x: x0 + dx, y, z: z0 + dz }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + dx, y, z0 + dz)).await;
                 }
             }
         }
@@ -1290,227 +1290,227 @@ async fn generate_medium_oak(
         match branch_pos {
             1 => {
                 branches.push((x0 + 3, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0)).await;
             }
             2 => {
                 branches.push((x0 + 3, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0)).await;
             }
             3 => {
                 branches.push((x0 + 3, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 2)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 1)).await;
             }
             4 => {
                 branches.push((x0 + 3, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 1)).await;
             }
             5 => {
                 branches.push((x0 + 2, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 1)).await;
             }
             6 => {
                 branches.push((x0 + 1, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 1)).await;
             }
             7 => {
                 branches.push((x0, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 1)).await;
             }
             8 => {
                 branches.push((x0 - 1, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 1)).await;
             }
             9 => {
                 branches.push((x0 - 2, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 1)).await;
             }
             10 => {
                 branches.push((x0 - 3, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 1)).await;
             }
             11 => {
                 branches.push((x0 - 3, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 2)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 1)).await;
             }
             12 => {
                 branches.push((x0 - 3, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0)).await;
             }
             13 => {
                 branches.push((x0 - 3, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0)).await;
             }
             14 => {
                 branches.push((x0 - 3, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0)).await;
             }
             15 => {
                 branches.push((x0 - 3, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 2)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 1)).await;
             }
             16 => {
                 branches.push((x0 - 3, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 1)).await;
             }
             17 => {
                 branches.push((x0 - 2, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 1)).await;
             }
             18 => {
                 branches.push((x0 - 1, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 1)).await;
             }
             19 => {
                 branches.push((x0, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 1)).await;
             }
             20 => {
                 branches.push((x0 + 1, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 1)).await;
             }
             21 => {
                 branches.push((x0 + 2, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 1)).await;
             }
             22 => {
                 branches.push((x0 + 3, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 1)).await;
             }
             23 => {
                 branches.push((x0 + 3, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 2 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 2)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 1)).await;
             }
             24 => {
                 branches.push((x0 + 3, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0)).await;
             }
             _ => {}
         }
@@ -1520,7 +1520,7 @@ async fn generate_medium_oak(
     for &(x1, y1, z1) in &branches {
         // Top layer
         for (dx, dz) in &[(1, 0), (-1, 0), (0, 1), (0, -1), (0, 0)] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 2, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 2, z1 + dz), rng, leaf_chance).await;
         }
 
         // Second layer
@@ -1532,7 +1532,7 @@ async fn generate_medium_oak(
             (1, 2), (-1, -2), (-1, 2), (1, -2),
         ];
         for (dx, dz) in &second_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 1, z1 + dz), rng, leaf_chance).await;
         }
 
         // Third layer
@@ -1542,7 +1542,7 @@ async fn generate_medium_oak(
             (2, 0), (-2, 0), (0, 2), (0, -2),
         ];
         for (dx, dz) in &third_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1, z1 + dz), rng, leaf_chance).await;
         }
     }
 }
@@ -1564,11 +1564,11 @@ async fn generate_large_oak(
 
     // Trunk and base
     for y in y0..height {
-        editor.place_block(wood, Point3D { x: x0, y, z: z0 }).await;
+        editor.place_block(wood, LocalPoint3D::new(x0, y, z0)).await;
         if y == y0 {
             for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
                 if rng.rand_i32_range(1, 5) != 4 {
-                    editor.place_block(wood, Point3D { x: x0 + dx, y, z: z0 + dz }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + dx, y, z0 + dz)).await;
                 }
             }
         }
@@ -1582,507 +1582,507 @@ async fn generate_large_oak(
         match branch_pos {
             1 => {
                 branches.push((x0 + 5, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             2 => {
                 branches.push((x0 + 5, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             3 => {
                 branches.push((x0 + 5, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             4 => {
                 branches.push((x0 + 5, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             5 => {
                 branches.push((x0 + 5, branch_height, z0 + 4));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             6 => {
                 branches.push((x0 + 5, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             7 => {
                 branches.push((x0 + 4, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             8 => {
                 branches.push((x0 + 3, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 1)).await;
             }
             9 => {
                 branches.push((x0 + 2, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             10 => {
                 branches.push((x0 + 1, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 + 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 + 3)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 3)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             11 => {
                 branches.push((x0, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             12 => {
                 branches.push((x0 - 1, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 3)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 3)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             13 => {
                 branches.push((x0 - 2, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 + 1)).await;
             }
             14 => {
                 branches.push((x0 - 3, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             15 => {
                 branches.push((x0 - 4, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 + 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 + 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             16 => {
                 branches.push((x0 - 5, branch_height, z0 + 5));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 + 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 + 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             17 => {
                 branches.push((x0 - 5, branch_height, z0 + 4));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 + 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 + 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             18 => {
                 branches.push((x0 - 5, branch_height, z0 + 3));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 + 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 + 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 + 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 + 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 + 1)).await;
             }
             19 => {
                 branches.push((x0 - 5, branch_height, z0 + 2));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 + 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 + 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             20 => {
                 branches.push((x0 - 5, branch_height, z0 + 1));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 + 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 + 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 + 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 + 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 + 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 + 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             21 => {
                 branches.push((x0 - 5, branch_height, z0));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             22 => {
                 branches.push((x0 - 5, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             23 => {
                 branches.push((x0 - 5, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0)).await;
             }
             24 => {
                 branches.push((x0 - 5, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             25 => {
                 branches.push((x0 - 5, branch_height, z0 - 4));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             26 => {
                 branches.push((x0 - 5, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 - 5, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height - 1, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 5, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height - 1, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             27 => {
                 branches.push((x0 + 5, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             28 => {
                 branches.push((x0 + 4, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             29 => {
                 branches.push((x0 + 3, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             30 => {
                 branches.push((x0 + 2, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 1, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 1, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             31 => {
                 branches.push((x0 + 1, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 3)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 3)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             32 => {
                 branches.push((x0, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 1, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 1, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             33 => {
                 branches.push((x0 - 1, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 3)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 3 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 3)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             34 => {
                 branches.push((x0 - 2, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 1, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 1, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0, branch_height - 2, z0 - 1)).await;
             }
             35 => {
                 branches.push((x0 - 3, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             36 => {
                 branches.push((x0 - 4, branch_height, z0 - 5));
-                editor.place_block(wood, Point3D { x: x0 - 4, y: branch_height, z: z0 - 5 }).await;
-                editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 1, z: z0 - 4 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 4, branch_height, z0 - 5)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 1, z0 - 4)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 - 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 - 2, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 2, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 2)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 - 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 - 1, branch_height - 2, z0 - 1)).await;
             }
             37 => {
                 branches.push((x0 + 5, branch_height, z0 - 1));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 - 1)).await;
                 let b = rng.rand_i32_range(1, 3);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 1)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             38 => {
                 branches.push((x0 + 5, branch_height, z0 - 2));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 - 2 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 1 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 - 2)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 1)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0)).await;
             }
             39 => {
                 branches.push((x0 + 5, branch_height, z0 - 3));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 - 3 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 - 3)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             40 => {
                 branches.push((x0 + 5, branch_height, z0 - 4));
-                editor.place_block(wood, Point3D { x: x0 + 5, y: branch_height, z: z0 - 4 }).await;
-                editor.place_block(wood, Point3D { x: x0 + 4, y: branch_height - 1, z: z0 - 3 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 5, branch_height, z0 - 4)).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 4, branch_height - 1, z0 - 3)).await;
                 let b = rng.rand_i32_range(1, 4);
                 if b == 1 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 3 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 3)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else if b == 2 {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 2 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 2)).await;
                 } else {
-                    editor.place_block(wood, Point3D { x: x0 + 3, y: branch_height - 2, z: z0 - 2 }).await;
-                    editor.place_block(wood, Point3D { x: x0 + 2, y: branch_height - 2, z: z0 - 1 }).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 3, branch_height - 2, z0 - 2)).await;
+                    editor.place_block(wood, LocalPoint3D::new(x0 + 2, branch_height - 2, z0 - 1)).await;
                 }
-                editor.place_block(wood, Point3D { x: x0 + 1, y: branch_height - 2, z: z0 - 1 }).await;
+                editor.place_block(wood, LocalPoint3D::new(x0 + 1, branch_height - 2, z0 - 1)).await;
             }
             _ => { // No branch placed
             }
@@ -2096,13 +2096,13 @@ async fn generate_large_oak(
             for (dx, dz) in &[
                 (1, 1), (-1, -1), (-1, 1), (1, -1)
             ] {
-                editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 3, z: z1 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 3, z1 + dz), rng, leaf_chance).await;
             }
             for (dx, dz) in &[
                 (3, 0), (-3, 0), (0, 3), (0, -3),
                 (2, 2), (-2, -2), (-2, 2), (2, -2)
             ] {
-                editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 2, z: z1 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 2, z1 + dz), rng, leaf_chance).await;
             }
             for (dx, dz) in &[
                 (4, 1), (-4, -1), (-4, 1), (4, -1),
@@ -2112,23 +2112,23 @@ async fn generate_large_oak(
                 (2, 3), (-2, 3), (2, -3), (-2, -3),
                 (3, 2), (-3, 2), (3, -2), (-3, -2)
             ] {
-                editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 1, z: z1 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 1, z1 + dz), rng, leaf_chance).await;
             }
             for (dx, dz) in &[
                 (2, -1), (-2, -1), (-1, 2), (-1, -2),
                 (2, 1), (-2, 1), (1, 2), (1, -2)
             ] {
-                editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1, z: z1 + dz }, rng, leaf_chance).await;
+                editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1, z1 + dz), rng, leaf_chance).await;
             }
         } else {
-            editor.place_block_chance(leaf, Point3D { x: x1, y: y1 - 1, z: z1 }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1, y1 - 1, z1), rng, leaf_chance).await;
         }
 
         // Top leaf cluster
         for (dx, dz) in &[
             (1, 0), (-1, 0), (0, 1), (0, -1), (0, 0)
         ] {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 3, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 3, z1 + dz), rng, leaf_chance).await;
         }
 
         // Second layer
@@ -2140,7 +2140,7 @@ async fn generate_large_oak(
             (1, 2), (-1, -2), (-1, 2), (1, -2),
         ];
         for (dx, dz) in &second_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 2, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 2, z1 + dz), rng, leaf_chance).await;
         }
 
         // Third layer
@@ -2150,7 +2150,7 @@ async fn generate_large_oak(
             (2, 0), (-2, 0), (0, 2), (0, -2),
         ];
         for (dx, dz) in &third_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1 + 1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1 + 1, z1 + dz), rng, leaf_chance).await;
         }
 
         // Fourth layer
@@ -2160,7 +2160,7 @@ async fn generate_large_oak(
             (2, 0), (-2, 0), (0, 2), (0, -2),
         ];
         for (dx, dz) in &fourth_layer {
-            editor.place_block_chance(leaf, Point3D { x: x1 + dx, y: y1, z: z1 + dz }, rng, leaf_chance).await;
+            editor.place_block_chance(leaf, LocalPoint3D::new(x1 + dx, y1, z1 + dz), rng, leaf_chance).await;
         }
     }
 }
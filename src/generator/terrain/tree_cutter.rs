@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    editor::Editor, geometry::{Point2D, Point3D, ALL_8}
+    editor::Editor, geometry::{Point2D, LocalPoint3D, Point3D, ALL_8}
 };
 
 /// A single tree: its canonical trunk cell (where a stump is placed) and every
@@ -35,7 +35,7 @@ pub fn group_trees(cells: &HashSet<Point2D>, editor: &Editor) -> Vec<TreeGroup>
             let Some(base_y) = world.get_non_tree_height(p) else {
                 return false;
             };
-            editor.get_block(p.add_y(base_y)).id.is_log()
+            editor.get_block(LocalPoint3D(p.add_y(base_y))).id.is_log()
         })
         .collect();
 
@@ -75,7 +75,7 @@ pub fn group_trees(cells: &HashSet<Point2D>, editor: &Editor) -> Vec<TreeGroup>
         let Some(top_y) = world.get_motion_blocking_height_at(p).map(|h| h - 1) else {
             continue; // out of bounds — not our cell
         };
-        if !editor.get_block(p.add_y(top_y)).id.is_tree() {
+        if !editor.get_block(LocalPoint3D(p.add_y(top_y))).id.is_tree() {
             continue; // not a canopy cell
         }
         if let Some(tree) = trees
@@ -96,19 +96,19 @@ pub async fn log_stems(editor: &Editor, points: HashSet<Point2D>) {
             continue;
         };
         let height = height - 1; // checking ground
-        let mut block_id = editor.get_block(Point3D::new(point.x, height, point.y)).id;
+        let mut block_id = editor.get_block(LocalPoint3D::new(point.x, height, point.y)).id;
 
         if !block_id.is_tree() {
             continue;
         }
-        editor.place_block(&"air".into(), Point3D::new(point.x, height, point.y)).await;
+        editor.place_block(&"air".into(), LocalPoint3D::new(point.x, height, point.y)).await;
 
         for y in 1..40 {
-            block_id = editor.get_block(Point3D::new(point.x, height - y, point.y)).id;
+            block_id = editor.get_block(LocalPoint3D::new(point.x, height - y, point.y)).id;
             if block_id.is_tree() {
-                editor.place_block(&"air".into(), Point3D::new(point.x, height - y, point.y)).await;
+                editor.place_block(&"air".into(), LocalPoint3D::new(point.x, height - y, point.y)).await;
             } else if block_id == "dirt".into() {
-                editor.place_block(&"grass_block".into(), Point3D::new(point.x, height - y, point.y)).await;
+                editor.place_block(&"grass_block".into(), LocalPoint3D::new(point.x, height - y, point.y)).await;
             } else if block_id != "air".into() {
                 continue;
             }
@@ -144,7 +144,7 @@ pub async fn log_trees(editor: &Editor, points: HashSet<Point2D>) {
             // `try_get_block`, not `get_block`: the deep scan can run past the world
             // floor (low column, ravine), where `get_block` would panic. None means
             // out of bounds below — nothing deeper to find, so stop this column.
-            let Some(block) = editor.try_get_block(pos) else { break; };
+            let Some(block) = editor.try_get_block(LocalPoint3D(pos)) else { break; };
             if block.id.is_log() && visited_logs.insert(pos) {
                 stack.push(pos);
             }
@@ -164,7 +164,7 @@ pub async fn log_trees(editor: &Editor, points: HashSet<Point2D>) {
                     let n = Point3D::new(pos.x + dx, pos.y + dy, pos.z + dz);
                     // `try_get_block`: a neighbour can fall outside the build area
                     // (edge column) or below the world floor; None is simply not a log.
-                    if editor.try_get_block(n).map_or(false, |b| b.id.is_log())
+                    if editor.try_get_block(LocalPoint3D(n)).map_or(false, |b| b.id.is_log())
                         && visited_logs.insert(n)
                     {
                         stack.push(n);
@@ -182,16 +182,16 @@ pub async fn log_trees(editor: &Editor, points: HashSet<Point2D>) {
         let Some(top) = editor.world().get_motion_blocking_height_at(point).map(|h| h - 1) else {
             continue; // out of bounds — not our column
         };
-        if !editor.get_block(point.add_y(top)).id.is_tree() {
+        if !editor.get_block(LocalPoint3D(point.add_y(top))).id.is_tree() {
             continue; // column surface isn't a tree — leave it untouched
         }
         let mut lowest_cleared: Option<i32> = None;
         for y in (top - MAX_SCAN_DEPTH..=top).rev() {
             let pos = Point3D::new(point.x, y, point.y);
             // `try_get_block`: stop at the world floor rather than panicking below it.
-            let Some(block) = editor.try_get_block(pos) else { break; };
+            let Some(block) = editor.try_get_block(LocalPoint3D(pos)) else { break; };
             if block.id.is_tree() {
-                editor.place_block(&"air".into(), pos).await;
+                editor.place_block(&"air".into(), LocalPoint3D(pos)).await;
                 lowest_cleared = Some(y);
             }
         }
@@ -199,8 +199,8 @@ pub async fn log_trees(editor: &Editor, points: HashSet<Point2D>) {
         // tree block (the trunk base) — never deeper, to avoid grassing buried dirt.
         if let Some(base) = lowest_cleared {
             let ground = Point3D::new(point.x, base - 1, point.y);
-            if editor.try_get_block(ground).map_or(false, |b| b.id == "dirt".into()) {
-                editor.place_block(&"grass_block".into(), ground).await;
+            if editor.try_get_block(LocalPoint3D(ground)).map_or(false, |b| b.id == "dirt".into()) {
+                editor.place_block(&"grass_block".into(), LocalPoint3D(ground)).await;
             }
         }
     }
@@ -208,8 +208,8 @@ pub async fn log_trees(editor: &Editor, points: HashSet<Point2D>) {
     // 4. Safety net: any flood-fill log whose column was skipped by the surface
     //    guard above (rare) still gets cleared.
     for &pos in &visited_logs {
-        if editor.get_block(pos).id.is_log() {
-            editor.place_block(&"air".into(), pos).await;
+        if editor.get_block(LocalPoint3D(pos)).id.is_log() {
+            editor.place_block(&"air".into(), LocalPoint3D(pos)).await;
         }
     }
 }
@@ -231,14 +231,14 @@ pub async fn clear_floating_logs(editor: &Editor, points: &HashSet<Point2D>) ->
         };
         // Seed from the surface block (one under `ground`, the first-air height).
         let mut below_is_air = editor
-            .try_get_block(Point3D::new(point.x, ground - 1, point.y))
+            .try_get_block(LocalPoint3D::new(point.x, ground - 1, point.y))
             .map_or(true, |b| b.id == "air".into());
         for y in ground..=(ground + SCAN_HEIGHT) {
             let pos = Point3D::new(point.x, y, point.y);
-            let Some(block) = editor.try_get_block(pos) else { break; };
+            let Some(block) = editor.try_get_block(LocalPoint3D(pos)) else { break; };
             below_is_air = if block.id.is_log() && below_is_air {
                 // Only air below — fell it, leaving any log resting on top floating.
-                editor.place_block(&"air".into(), pos).await;
+                editor.place_block(&"air".into(), LocalPoint3D(pos)).await;
                 removed += 1;
                 true
             } else {
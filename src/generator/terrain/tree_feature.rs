@@ -9,7 +9,7 @@
 //! reproducible from our seed), and it **bypasses the editor's block cache**
 //! (so it's invisible to later `get_block` reads and to offline/dry-run mode).
 
-use crate::{editor::Editor, geometry::Point3D, noise::RNG};
+use crate::{editor::Editor, geometry::{LocalPoint3D, Point3D}, noise::RNG};
 
 use super::Tree;
 
@@ -58,12 +58,12 @@ pub fn tree_feature_id(tree: Tree) -> &'static str {
 async fn place_cactus_column(editor: &Editor, point: Point3D, rng: &mut RNG) {
     let (x, y, z) = (point.x, point.y, point.z);
     editor
-        .place_block_forced(&"minecraft:sand".into(), Point3D { x, y: y - 1, z })
+        .place_block_forced(&"minecraft:sand".into(), LocalPoint3D::new(x, y - 1, z))
         .await;
     let height = 1 + rng.rand_i32(3); // 1..=3
     for i in 0..height {
         editor
-            .place_block(&"minecraft:cactus".into(), Point3D { x, y: y + i, z })
+            .place_block(&"minecraft:cactus".into(), LocalPoint3D::new(x, y + i, z))
             .await;
     }
 }
@@ -0,0 +1,313 @@
+//! Copies of the town map for players in the world. The map is the same one
+//! written to `output/town.svg`, drawn plain (no lettering) and quantized to
+//! the palette of Minecraft maps, two ways:
+//!
+//! - as a locked map's `map_<id>.dat`, written beside the SVG. A live server
+//!   has no way to take one, so it's there to drop into a save's `data`
+//!   folder by hand;
+//! - as map-art boards: walls of blocks whose map colours draw the town, one
+//!   framed beside the road at each gate and one against a free wall of each
+//!   manor library.
+//!
+//! Boards paint each block the palette colour most of its patch of the map
+//! rounds to, so thin roads survive being drawn a dozen cells to a block.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::editor::Editor;
+use crate::generator::BuildClaim;
+use crate::generator::buildings_v2::rooms::Room;
+use crate::generator::paths::{rasterize_to_pixels, town_map_bounds, PIXELS_PER_CELL};
+use crate::geometry::{Cardinal, Point2D, LocalPoint3D, Point3D};
+use crate::minecraft::{nearest_block_color, nearest_pixel, Block, BlockForm, MapData, MAP_SIZE};
+
+/// Frames gate boards, and backs any part of a board the map doesn't reach.
+const FRAME: &str = "minecraft:dark_oak_planks";
+/// Gate board sizes to try, frame included, largest first.
+const GATE_BOARD_SIZES: [(i32, i32); 2] = [(15, 11), (11, 8)];
+/// How far from the road's centre a gate board stands.
+const GATE_BOARD_SIDE: i32 = 3;
+/// How far along the road from the gate a board may stand.
+const GATE_BOARD_REACH: std::ops::RangeInclusive<i32> = 4..=10;
+/// Narrowest and widest a library board may be.
+const LIBRARY_BOARD_WIDTH: std::ops::RangeInclusive<i32> = 4..=9;
+/// Map samples along each side of a board block.
+const SAMPLES: u32 = 4;
+
+/// A flat wall of blocks showing the map: its bottom-left block as seen from
+/// the front, the way its face looks, and its size in blocks. An unframed
+/// board sits against a wall, which frames it instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapBoard {
+    pub corner: Point3D,
+    pub facing: Cardinal,
+    pub width: i32,
+    pub height: i32,
+    pub framed: bool,
+}
+
+impl MapBoard {
+    /// The block `across` to the right of and `up` above the corner.
+    fn at(&self, across: i32, up: i32) -> Point3D {
+        let right: Point3D = self.facing.rotate_left().into();
+        self.corner + Point3D::new(right.x * across, up, right.z * across)
+    }
+
+    /// The board's blocks, painted with `plan`, north up.
+    pub fn blocks(&self, plan: &str) -> anyhow::Result<Vec<(Point3D, Block)>> {
+        let frame = Block::from(FRAME);
+        let border = i32::from(self.framed);
+        let (w, h) = (self.width - 2 * border, self.height - 2 * border);
+        anyhow::ensure!(w > 0 && h > 0, "a {}×{} board has no room for a map", self.width, self.height);
+        let pixels = rasterize_to_pixels(plan, w as u32 * SAMPLES, h as u32 * SAMPLES, None)?;
+
+        let mut blocks = Vec::new();
+        for up in 0..self.height {
+            for across in 0..self.width {
+                let (x, y) = (across - border, self.height - 1 - border - up);
+                let inside = (0..w).contains(&x) && (0..h).contains(&y);
+                let block = inside
+                    .then(|| patch_block(&pixels, w as u32 * SAMPLES, x as u32, y as u32))
+                    .flatten()
+                    .unwrap_or_else(|| frame.clone());
+                blocks.push((self.at(across, up), block));
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+/// The block most of the `SAMPLES`² patch at board cell `(x, y)` rounds to,
+/// or `None` where the map doesn't cover it.
+fn patch_block(pixels: &[[u8; 4]], stride: u32, x: u32, y: u32) -> Option<Block> {
+    let mut votes: HashMap<&'static str, usize> = HashMap::new();
+    for dy in 0..SAMPLES {
+        for dx in 0..SAMPLES {
+            let [r, g, b, a] = pixels[((y * SAMPLES + dy) * stride + x * SAMPLES + dx) as usize];
+            if a >= 128 {
+                if let Some(block) = nearest_block_color([r, g, b]).block {
+                    *votes.entry(block).or_default() += 1;
+                }
+            }
+        }
+    }
+    // Ties go to the first block in name order, so a board paints the same every run.
+    let block = votes.into_iter().max_by_key(|&(block, n)| (n, std::cmp::Reverse(block)))?.0;
+    Some(Block::from(block))
+}
+
+/// The town `plan` as a locked 128×128 map centred on the town, at the finest
+/// scale that fits it all. `origin` is the build area's world corner.
+pub fn town_map_data(plan: &str, urban: &HashSet<Point2D>, origin: Point3D) -> anyhow::Result<MapData> {
+    let (min, max) = town_map_bounds(urban).ok_or_else(|| anyhow::anyhow!("no town to map"))?;
+    let (w, h) = (max.x - min.x + 1, max.y - min.y + 1);
+    let scale = (0..=4u8).find(|&scale| (MAP_SIZE as i32) << scale >= w.max(h)).unwrap_or(4);
+    // One map pixel is 2^scale cells.
+    let zoom = 1.0 / (PIXELS_PER_CELL << scale) as f32;
+    let pixels = rasterize_to_pixels(plan, MAP_SIZE as u32, MAP_SIZE as u32, Some(zoom))?;
+    Ok(MapData {
+        center: (origin.x + min.x + w / 2, origin.z + min.y + h / 2),
+        scale,
+        colors: pixels.into_iter().map(nearest_pixel).collect(),
+    })
+}
+
+/// Paints every board with the town `plan`, returning how many went up. Each
+/// claims the columns it stands in for its height, where nothing else (a
+/// library's house) already holds them, so later phases build around it.
+pub async fn paint_boards(editor: &mut Editor, plan: &str, boards: &[MapBoard]) -> anyhow::Result<usize> {
+    for board in boards {
+        for (pos, block) in board.blocks(plan)? {
+            editor.place_block(&block, LocalPoint3D(pos)).await;
+        }
+        let ys = board.corner.y..board.corner.y + board.height;
+        for across in 0..board.width {
+            let column = board.at(across, 0).drop_y();
+            if !editor.world().is_claimed_in(column, ys.clone()) {
+                editor.world_mut().claim_range(column, ys.clone(), BuildClaim::MapBoard);
+            }
+        }
+    }
+    Ok(boards.len())
+}
+
+/// A framed board beside the road at each gate, facing the road, outside the
+/// wall where there's room and inside where there isn't. `centre` tells in
+/// from out.
+pub fn gate_boards(editor: &Editor, centre: Point2D) -> Vec<MapBoard> {
+    let mut boards = Vec::new();
+    for (gate, dir) in &editor.world().gate_locations {
+        let opening = gate.drop_y();
+        // The passage runs along `dir` turned clockwise (see `civic_banner`).
+        let along = dir.rotate_right();
+        let step: Point2D = along.into();
+        let outward = if (opening + step).distance_squared(&centre) > (opening - step).distance_squared(&centre) {
+            along
+        } else {
+            along.opposite()
+        };
+        let sites = [outward, outward.opposite()].into_iter().flat_map(|road| {
+            GATE_BOARD_SIZES.into_iter().flat_map(move |size| {
+                GATE_BOARD_REACH.flat_map(move |reach| [*dir, dir.opposite()].map(|side| (road, size, reach, side)))
+            })
+        });
+        let found = sites.into_iter().find_map(|(road, (width, height), reach, side)| {
+            let road_step: Point2D = road.into();
+            let side_step: Point2D = side.into();
+            let start = opening + road_step * reach + side_step * GATE_BOARD_SIDE;
+            let span: Vec<Point2D> = (0..width).map(|k| start + road_step * k).collect();
+            let y = open_span(editor, &span, height)?;
+            // It faces back across the road.
+            let facing = side.opposite();
+            let corner = if facing.rotate_left() == road { span[0] } else { span[span.len() - 1] };
+            Some(MapBoard { corner: Point3D::new(corner.x, y, corner.y), facing, width, height, framed: true })
+        });
+        boards.extend(found);
+    }
+    boards
+}
+
+/// The ground level of `span` if it's level, unclaimed, dry open ground with
+/// `height` blocks of air above every cell.
+fn open_span(editor: &Editor, span: &[Point2D], height: i32) -> Option<i32> {
+    let world = editor.world();
+    let y = world.get_height_at(*span.first()?)?;
+    span.iter().all(|&cell| {
+        !world.is_claimed(cell)
+            && world.get_height_at(cell) == Some(y)
+            && editor.try_get_block(LocalPoint3D::new(cell.x, y - 1, cell.y)).is_some_and(|below| !below.id.is_air() && !below.id.is_liquid())
+            && (y..y + height).all(|y| editor.try_get_block(LocalPoint3D::new(cell.x, y, cell.y)).is_some_and(|block| block.id.is_air()))
+    })
+    .then_some(y)
+}
+
+/// An unframed board against the longest stretch of a library's wall its
+/// furniture left bare: solid wall behind, air in front from floor to
+/// ceiling, and the room deep enough to still walk past it. `floor_y` is the
+/// room's first air level and `ceiling_y` its ceiling block.
+pub fn library_board(editor: &Editor, room: &Room, floor_y: i32, ceiling_y: i32) -> Option<MapBoard> {
+    let interior = room.interior;
+    let (min, max) = (interior.min(), interior.max());
+    let height = ceiling_y - floor_y;
+    let mut best: Option<(Vec<Point2D>, Cardinal)> = None;
+    for out in [Cardinal::North, Cardinal::East, Cardinal::South, Cardinal::West] {
+        let (strip, depth): (Vec<Point2D>, i32) = match out {
+            Cardinal::North => ((min.x..=max.x).map(|x| Point2D::new(x, min.y)).collect(), interior.size.y),
+            Cardinal::South => ((min.x..=max.x).map(|x| Point2D::new(x, max.y)).collect(), interior.size.y),
+            Cardinal::West => ((min.y..=max.y).map(|z| Point2D::new(min.x, z)).collect(), interior.size.x),
+            Cardinal::East => ((min.y..=max.y).map(|z| Point2D::new(max.x, z)).collect(), interior.size.x),
+        };
+        if depth < 4 {
+            continue;
+        }
+        let behind: Point2D = out.into();
+        let bare = |cell: Point2D| {
+            room.constraints.is_open((cell.x, cell.y))
+                && (floor_y..ceiling_y).all(|y| {
+                    let front = editor.try_get_block(LocalPoint3D::new(cell.x, y, cell.y));
+                    let wall = editor.try_get_block(LocalPoint3D::new(cell.x + behind.x, y, cell.y + behind.y));
+                    front.is_some_and(|b| b.id.is_air()) && wall.is_some_and(|b| is_plain_wall(&b))
+                })
+        };
+        for run in strip.split(|&cell| !bare(cell)) {
+            if best.as_ref().is_none_or(|(longest, _)| run.len() > longest.len()) {
+                best = Some((run.to_vec(), out));
+            }
+        }
+    }
+
+    let (run, out) = best?;
+    if (run.len() as i32) < *LIBRARY_BOARD_WIDTH.start() {
+        return None;
+    }
+    let width = (run.len() as i32).min(*LIBRARY_BOARD_WIDTH.end());
+    let run = &run[(run.len() - width as usize) / 2..][..width as usize];
+    let facing = out.opposite();
+    let right: Point2D = facing.rotate_left().into();
+    // The run goes east or south; the corner is whichever end the board starts from.
+    let corner = if right.x + right.y > 0 { run[0] } else { run[run.len() - 1] };
+    Some(MapBoard { corner: Point3D::new(corner.x, floor_y, corner.y), facing, width, height, framed: false })
+}
+
+/// A full, opaque block: not a window, door or anything half-height.
+fn is_plain_wall(block: &Block) -> bool {
+    let id = block.id.as_str();
+    !id.contains("glass") && BlockForm::infer_from_block(&block.id).density() >= 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minecraft::pixel_rgb;
+
+    /// A `w`×`h` cell plan drawn as the town map is: water to the west, white
+    /// to the east.
+    fn plan(w: i32, h: i32) -> String {
+        let (px, half) = (PIXELS_PER_CELL, w / 2);
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n\
+             <rect x=\"0\" y=\"0\" width=\"{half}\" height=\"{h}\" fill=\"#4040ff\"/>\n\
+             <rect x=\"{half}\" y=\"0\" width=\"{half}\" height=\"{h}\" fill=\"#ffffff\"/>\n\
+             </svg>\n",
+            w * px,
+            h * px,
+        )
+    }
+
+    fn block_at(blocks: &[(Point3D, Block)], pos: Point3D) -> &str {
+        blocks.iter().find(|(p, _)| *p == pos).map_or("none", |(_, b)| b.id.as_str())
+    }
+
+    #[test]
+    fn boards_paint_the_map_north_up_inside_their_frame() {
+        // Facing south, so its right runs east.
+        let board = MapBoard { corner: Point3D::new(0, 70, 5), facing: Cardinal::South, width: 6, height: 4, framed: true };
+        let blocks = board.blocks(&plan(20, 10)).unwrap();
+        assert_eq!(blocks.len(), 24);
+        let at = |across: i32, up: i32| block_at(&blocks, Point3D::new(across, 70 + up, 5));
+        assert_eq!(at(0, 0), FRAME);
+        assert_eq!(at(5, 3), FRAME);
+        assert_eq!(at(1, 1), "minecraft:lapis_block");
+        assert_eq!(at(4, 2), "minecraft:white_concrete");
+
+        // Facing north, its right runs west: the east half is on the left.
+        let board = MapBoard { facing: Cardinal::North, framed: false, ..board };
+        let blocks = board.blocks(&plan(20, 10)).unwrap();
+        assert_eq!(block_at(&blocks, Point3D::new(0, 71, 5)), "minecraft:lapis_block");
+        assert_eq!(block_at(&blocks, Point3D::new(-5, 71, 5)), "minecraft:white_concrete");
+    }
+
+    #[tokio::test]
+    async fn painted_boards_claim_their_columns() {
+        use crate::editor::World;
+        use crate::geometry::Rect3D;
+
+        let area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(31, 255, 31));
+        let mut editor = Editor::new_offline(area, World::synthetic(area, 64));
+        let board = MapBoard { corner: Point3D::new(2, 64, 5), facing: Cardinal::South, width: 6, height: 4, framed: true };
+        assert_eq!(paint_boards(&mut editor, &plan(20, 10), &[board]).await.unwrap(), 1);
+        let world = editor.world();
+        assert!((2..8).all(|x| world.claims_in(Point2D::new(x, 5), 64..68).any(|c| *c == BuildClaim::MapBoard)));
+        assert!(!world.is_claimed(Point2D::new(8, 5)));
+        // The ground under it is left to others.
+        assert!(!world.is_claimed_in(Point2D::new(2, 5), 0..64));
+    }
+
+    #[test]
+    fn map_data_covers_the_town_at_the_finest_scale_that_fits() {
+        let urban: HashSet<Point2D> = (0..150).map(|x| Point2D::new(x, 20)).collect();
+        // 156 cells wide with the padding: two cells to a pixel.
+        let map = town_map_data(&plan(156, 7), &urban, Point3D::new(1000, 0, -500)).unwrap();
+        assert_eq!(map.scale, 1);
+        assert_eq!(map.center, (1000 - 3 + 78, -500 + 17 + 3));
+        assert_eq!(map.colors.len(), MAP_SIZE * MAP_SIZE);
+        let row = &map.colors[64 * MAP_SIZE..65 * MAP_SIZE];
+        assert_eq!(pixel_rgb(row[40]), Some([64, 64, 255]));
+        assert_eq!(pixel_rgb(row[80]), Some([255, 255, 255]));
+        // The town is 78 pixels wide, centred; the rest is blank.
+        assert_eq!(row[10], crate::minecraft::TRANSPARENT);
+        assert_eq!(map.colors[10 * MAP_SIZE + 64], crate::minecraft::TRANSPARENT);
+    }
+}
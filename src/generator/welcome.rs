@@ -28,7 +28,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::editor::Editor;
-use crate::geometry::{Point2D, Point3D};
+use crate::geometry::{Point2D, LocalPoint3D, Point3D};
 use crate::minecraft::Block;
 
 /// Depth below the surface to bury the command blocks at the town centre.
@@ -139,24 +139,24 @@ pub async fn place_welcome_title(
     // Chain (west→east): subtitle → title → tag. Each fires the next on the same
     // tick. The untag block sits on its own, one row over.
     editor
-        .place_block(&command_block("repeating_command_block", &subtitle_cmd), base)
+        .place_block(&command_block("repeating_command_block", &subtitle_cmd), LocalPoint3D(base))
         .await;
     editor
         .place_block(
             &command_block("chain_command_block", &title_cmd),
-            base + Point3D::new(1, 0, 0),
+            LocalPoint3D(base + Point3D::new(1, 0, 0)),
         )
         .await;
     editor
         .place_block(
             &command_block("chain_command_block", &tag_cmd),
-            base + Point3D::new(2, 0, 0),
+            LocalPoint3D(base + Point3D::new(2, 0, 0)),
         )
         .await;
     editor
         .place_block(
             &command_block("repeating_command_block", &untag_cmd),
-            base + Point3D::new(0, 0, 2),
+            LocalPoint3D(base + Point3D::new(0, 0, 2)),
         )
         .await;
 }
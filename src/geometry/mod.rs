@@ -5,10 +5,12 @@ mod functions;
 mod cardinal;
 mod partition;
 mod deformation;
+mod space;
 
 pub use point2d::*;
 pub use point3d::*;
 pub use rect::{Rect2D, Rect3D};
+pub use space::{LocalPoint3D, WorldPoint3D};
 pub use functions::*;
 pub use cardinal::Cardinal;
 pub use partition::{voronoi, voronoi_with_points, voronoi_fill, voronoi_fill_with_points, voronoi_fill_with_recenter};
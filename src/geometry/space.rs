@@ -0,0 +1,47 @@
+use super::Point3D;
+
+/// A point in build-area-local coordinates: the build area's minimum corner is
+/// `(0, 0, 0)`. Generators work in this space, and it is what the editor's
+/// block cache and [`World`](crate::editor::World)'s chunk lookups are keyed by.
+///
+/// Nothing converts into it implicitly: build one from local coordinates with
+/// [`LocalPoint3D::new`], and turn a [`WorldPoint3D`] into one with
+/// [`WorldPoint3D::to_local`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalPoint3D(pub Point3D);
+
+/// A point in absolute Minecraft world coordinates, as the server, region files
+/// and commands see them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorldPoint3D(pub Point3D);
+
+impl LocalPoint3D {
+    /// The build-area-local point at `(x, y, z)`.
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        LocalPoint3D(Point3D::new(x, y, z))
+    }
+
+    /// The absolute point, for a build area whose minimum corner is `origin`.
+    pub fn to_world(self, origin: Point3D) -> WorldPoint3D {
+        WorldPoint3D(self.0 + origin)
+    }
+}
+
+impl WorldPoint3D {
+    /// The build-area-local point, for a build area whose minimum corner is `origin`.
+    pub fn to_local(self, origin: Point3D) -> LocalPoint3D {
+        LocalPoint3D(self.0 - origin)
+    }
+}
+
+impl From<LocalPoint3D> for Point3D {
+    fn from(point: LocalPoint3D) -> Self {
+        point.0
+    }
+}
+
+impl From<WorldPoint3D> for Point3D {
+    fn from(point: WorldPoint3D) -> Self {
+        point.0
+    }
+}
@@ -0,0 +1,470 @@
+use std::{collections::{HashMap, HashSet}, path::{Path, PathBuf}, sync::{Mutex, MutexGuard}};
+
+use anyhow::bail;
+use async_trait::async_trait;
+use fastnbt::Value;
+use log::{info, warn};
+
+use crate::{geometry::{Point2D, Point3D, Rect3D}, minecraft::{region_file_name, Block, BlockStates, Chunk, ChunkBlock, ChunkSection, HeightMaps, RegionFile, REGION_SIZE, SECTION_BIOME_VOLUME, SECTION_SIZE, SECTION_VOLUME}};
+
+use super::{BlockPlacementResponse, CommandResponse, Coordinate, EntityResponse, HeightMapType, PositionedBiome, PositionedBlock, PositionedEntity, WorldBackend};
+
+const SIZE: i32 = SECTION_SIZE as i32;
+
+/// A [`WorldBackend`] over a saved world folder — generation with no server.
+///
+/// Chunks are read from the `region/*.mca` files on first use and held in
+/// memory; placed blocks edit those copies, and [`save`](WorldBackend::save)
+/// re-encodes the touched sections and writes their region files back. The
+/// world must not be open in a running game while this writes.
+///
+/// Only what a region file can express is supported: commands and entities
+/// (which live in separate `entities/` files) return errors. Heightmaps of
+/// edited columns are recomputed here with a simple block classification, and
+/// written chunks get `isLightOn` cleared so the game relights them on load.
+#[derive(Debug)]
+pub struct AnvilBackend {
+    world_dir: PathBuf,
+    build_area: Rect3D,
+    state: Mutex<AnvilState>,
+}
+
+#[derive(Debug, Default)]
+struct AnvilState {
+    /// Region files read so far, keyed by region coordinate; `None` if absent.
+    regions: HashMap<Point2D, Option<RegionFile>>,
+    /// Chunks read so far, keyed by chunk coordinate; `None` if ungenerated.
+    chunks: HashMap<Point2D, Option<LoadedChunk>>,
+}
+
+#[derive(Debug)]
+struct LoadedChunk {
+    /// The full chunk compound, so writing back preserves every tag we don't model.
+    raw: HashMap<String, Value>,
+    chunk: Chunk,
+    /// Sections decoded for block access, keyed by section Y.
+    sections: HashMap<i32, Vec<ChunkBlock>>,
+    /// Sections edited since the last re-encode.
+    dirty: HashSet<i32>,
+    /// Columns (chunk-local `x, z`) with edited blocks, whose stored heightmaps are stale.
+    touched: HashSet<(i32, i32)>,
+    /// Whether this chunk has edits not yet written to its region file.
+    unsaved: bool,
+}
+
+impl AnvilBackend {
+    /// Opens the save at `world_dir` (the folder holding `level.dat`). Saves
+    /// don't record a GDMC build area, so the caller supplies it.
+    pub fn open(world_dir: impl AsRef<Path>, build_area: Rect3D) -> anyhow::Result<Self> {
+        let world_dir = world_dir.as_ref().to_path_buf();
+        if !world_dir.join("region").is_dir() {
+            bail!("{} has no region folder", world_dir.display());
+        }
+        Ok(Self { world_dir, build_area, state: Mutex::new(AnvilState::default()) })
+    }
+
+    fn region_path(&self, chunk: Point2D) -> PathBuf {
+        self.world_dir.join("region").join(region_file_name(chunk.x, chunk.y))
+    }
+
+    fn state(&self) -> MutexGuard<'_, AnvilState> {
+        self.state.lock().expect("Anvil backend lock poisoned")
+    }
+}
+
+fn chunk_of(x: i32, z: i32) -> Point2D {
+    Point2D::new(x.div_euclid(SIZE), z.div_euclid(SIZE))
+}
+
+fn block_index(point: Point3D) -> usize {
+    (point.y.rem_euclid(SIZE) * SIZE * SIZE + point.z.rem_euclid(SIZE) * SIZE + point.x.rem_euclid(SIZE)) as usize
+}
+
+fn resolve(coordinate: Coordinate) -> i32 {
+    match coordinate {
+        Coordinate::Absolute(value) | Coordinate::Relative(value) => value,
+        Coordinate::AbsoluteF(value) => value.round() as i32,
+    }
+}
+
+/// A raw section's `Y`: a byte in game saves, though other writers use an int.
+fn section_y(section: &HashMap<String, Value>) -> Option<i32> {
+    match section.get("Y")? {
+        Value::Byte(y) => Some(*y as i32),
+        Value::Int(y) => Some(*y),
+        _ => None,
+    }
+}
+
+fn air() -> ChunkBlock {
+    ChunkBlock { name: "minecraft:air".to_string(), properties: None }
+}
+
+/// Blocks a heightmap looks through: plants, torches, snow layers and the like.
+/// A name-based approximation of the game's "blocks motion" test, used only
+/// for columns this backend has edited.
+fn is_passable(name: &str) -> bool {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    name.ends_with("air")
+        || name.contains("sapling")
+        || name.contains("flower")
+        || name.contains("torch")
+        || name.contains("button")
+        || name.contains("pressure_plate")
+        || name.contains("rail")
+        || name.ends_with("_carpet")
+        || matches!(name, "short_grass" | "grass" | "tall_grass" | "fern" | "large_fern" | "dead_bush" | "snow" | "vine" | "sugar_cane" | "wheat" | "carrots" | "potatoes" | "beetroots" | "lever" | "ladder" | "redstone_wire" | "tripwire" | "dandelion" | "poppy")
+}
+
+impl AnvilState {
+    fn chunk(&mut self, backend: &AnvilBackend, key: Point2D) -> Option<&mut LoadedChunk> {
+        if !self.chunks.contains_key(&key) {
+            let loaded = self.load_chunk(backend, key);
+            self.chunks.insert(key, loaded);
+        }
+        self.chunks.get_mut(&key).and_then(|c| c.as_mut())
+    }
+
+    fn region(&mut self, backend: &AnvilBackend, key: Point2D) -> Option<&mut RegionFile> {
+        let region_key = Point2D::new(key.x.div_euclid(REGION_SIZE), key.y.div_euclid(REGION_SIZE));
+        self.regions
+            .entry(region_key)
+            .or_insert_with(|| {
+                let path = backend.region_path(key);
+                if !path.exists() {
+                    return None;
+                }
+                match RegionFile::read(&path) {
+                    Ok(region) => Some(region),
+                    Err(e) => {
+                        warn!("Failed to read region file {}: {e}", path.display());
+                        None
+                    }
+                }
+            })
+            .as_mut()
+    }
+
+    fn load_chunk(&mut self, backend: &AnvilBackend, key: Point2D) -> Option<LoadedChunk> {
+        let nbt = self.region(backend, key)?.chunk(key.x, key.y)?.to_vec();
+        let parsed = fastnbt::from_bytes::<Value>(&nbt).and_then(|raw| Ok((raw, fastnbt::from_bytes::<Chunk>(&nbt)?)));
+        match parsed {
+            Ok((Value::Compound(raw), chunk)) => Some(LoadedChunk {
+                raw,
+                chunk,
+                sections: HashMap::new(),
+                dirty: HashSet::new(),
+                touched: HashSet::new(),
+                unsaved: false,
+            }),
+            Ok(_) => None,
+            Err(e) => {
+                // Proto-chunks at the world's generated edge lack full heightmaps; skip them.
+                warn!("Skipping unreadable chunk {:?}: {e}", key);
+                None
+            }
+        }
+    }
+
+    fn get_block(&mut self, backend: &AnvilBackend, point: Point3D) -> Option<ChunkBlock> {
+        let chunk = self.chunk(backend, chunk_of(point.x, point.z))?;
+        Some(chunk.section(point.y.div_euclid(SIZE))?[block_index(point)].clone())
+    }
+}
+
+impl LoadedChunk {
+    fn min_y(&self) -> i32 {
+        self.chunk.y_pos * SIZE
+    }
+
+    /// The decoded blocks of section `y`, or `None` outside the chunk's height.
+    fn section(&mut self, y: i32) -> Option<&mut Vec<ChunkBlock>> {
+        if !self.sections.contains_key(&y) {
+            let stored = self.chunk.sections.iter().find(|s| s.y == y);
+            let in_range = stored.is_some() || (y >= self.chunk.y_pos && self.chunk.sections.iter().any(|s| s.y > y));
+            if !in_range {
+                return None;
+            }
+            let blocks = stored
+                .and_then(|s| s.block_states.as_ref())
+                .and_then(|states| states.decode())
+                .unwrap_or_else(|| vec![air(); SECTION_VOLUME]);
+            self.sections.insert(y, blocks);
+        }
+        self.sections.get_mut(&y)
+    }
+
+    /// Writes `block` at `point`, returning whether anything changed.
+    fn set_block(&mut self, point: Point3D, block: &Block) -> bool {
+        let section_y = point.y.div_euclid(SIZE);
+        let new = ChunkBlock::from(block);
+        let Some(section) = self.section(section_y) else { return false };
+        let cell = &mut section[block_index(point)];
+        let changed = *cell != new || block.data.is_some();
+        if !changed {
+            return false;
+        }
+        *cell = new;
+        self.dirty.insert(section_y);
+        self.touched.insert((point.x.rem_euclid(SIZE), point.z.rem_euclid(SIZE)));
+        self.unsaved = true;
+        self.set_block_entity(point, block);
+        true
+    }
+
+    /// Replaces whatever block entity sat at `point` with the one described by
+    /// `block.data` (if any). The entity `id` is taken from the SNBT when given,
+    /// else from the block id — right for chests, barrels, lecterns and the like.
+    fn set_block_entity(&mut self, point: Point3D, block: &Block) {
+        let entities = match self.raw.entry("block_entities".to_string()).or_insert_with(|| Value::List(Vec::new())) {
+            Value::List(entities) => entities,
+            _ => return,
+        };
+        let at = |v: &Value, key: &str, want: i32| matches!(v, Value::Compound(c) if matches!(c.get(key), Some(Value::Int(n)) if *n == want));
+        entities.retain(|e| !(at(e, "x", point.x) && at(e, "y", point.y) && at(e, "z", point.z)));
+
+        let Some(snbt) = &block.data else { return };
+        let mut compound = match fastsnbt::from_str::<Value>(snbt) {
+            Ok(Value::Compound(compound)) => compound,
+            _ => {
+                warn!("Dropping unparseable block entity data at {:?}: {}", point, snbt);
+                return;
+            }
+        };
+        compound.entry("id".to_string()).or_insert_with(|| Value::String(block.id.as_str().to_string()));
+        compound.insert("x".to_string(), Value::Int(point.x));
+        compound.insert("y".to_string(), Value::Int(point.y));
+        compound.insert("z".to_string(), Value::Int(point.z));
+        compound.insert("keepPacked".to_string(), Value::Byte(0));
+        entities.push(Value::Compound(compound));
+    }
+
+    /// Re-encodes dirty sections into both the typed chunk and the raw compound.
+    fn sync_sections(&mut self) {
+        for y in std::mem::take(&mut self.dirty) {
+            let states = BlockStates::encode(&self.sections[&y]);
+            match self.chunk.sections.iter_mut().find(|s| s.y == y) {
+                Some(section) => section.block_states = Some(states.clone()),
+                None => self.chunk.sections.push(ChunkSection { y, block_states: Some(states.clone()), biomes: None }),
+            }
+
+            let Some(Value::List(raw_sections)) = self.raw.get_mut("sections") else { continue };
+            // Round-trip through bytes: fastnbt's `to_value` can't skip `None` fields.
+            let states = fastnbt::to_bytes(&states)
+                .and_then(|bytes| fastnbt::from_bytes::<Value>(&bytes))
+                .expect("Block states always serialize");
+            let section = raw_sections.iter_mut().find_map(|s| match s {
+                Value::Compound(c) if section_y(c) == Some(y) => Some(c),
+                _ => None,
+            });
+            match section {
+                Some(section) => {
+                    section.insert("block_states".to_string(), states);
+                }
+                None => raw_sections.push(Value::Compound(HashMap::from([
+                    ("Y".to_string(), Value::Byte(y as i8)),
+                    ("block_states".to_string(), states),
+                ]))),
+            }
+        }
+    }
+
+    /// Column height (first air above the top counted block) in absolute Y.
+    fn height(&mut self, x: i32, z: i32, height_map_type: HeightMapType) -> i32 {
+        let (local_x, local_z) = (x.rem_euclid(SIZE), z.rem_euclid(SIZE));
+        let min_y = self.min_y();
+        if !self.touched.contains(&(local_x, local_z)) {
+            let maps = &self.chunk.heightmaps;
+            let stored = match height_map_type {
+                HeightMapType::WorldSurface => &maps.world_surface,
+                HeightMapType::OceanFloorNoPlants => &maps.ocean_floor,
+                HeightMapType::MotionBlockingNoPlants => &maps.motion_blocking_no_leaves,
+                HeightMapType::MotionBlocking => &maps.motion_blocking,
+            };
+            return HeightMaps::unpack(stored, min_y)[(local_z * SIZE + local_x) as usize];
+        }
+
+        let top = self.chunk.sections.iter().map(|s| s.y).max().unwrap_or(0) * SIZE + SIZE - 1;
+        for y in (min_y..=top).rev() {
+            let point = Point3D::new(x, y, z);
+            let Some(section) = self.section(y.div_euclid(SIZE)) else { continue };
+            let id = Block::from(&section[block_index(point)]).id;
+            let counts = match height_map_type {
+                HeightMapType::WorldSurface => !id.is_air(),
+                HeightMapType::MotionBlocking => !is_passable(id.as_str()),
+                HeightMapType::MotionBlockingNoPlants => !is_passable(id.as_str()) && !id.is_leaves(),
+                HeightMapType::OceanFloorNoPlants => !is_passable(id.as_str()) && !id.is_liquid(),
+            };
+            if counts {
+                return y + 1;
+            }
+        }
+        min_y
+    }
+
+    /// The raw compound ready to write back: current sections, heightmaps
+    /// recomputed for edited columns, and lighting flagged for the game to redo.
+    fn encode(&mut self) -> anyhow::Result<Vec<u8>> {
+        self.sync_sections();
+        if !self.touched.is_empty() {
+            let min_y = self.min_y();
+            let mut pack = |height_map_type| {
+                let heights: Vec<i32> = (0..SIZE)
+                    .flat_map(|z| (0..SIZE).map(move |x| (x, z)))
+                    .map(|(x, z)| self.height(x, z, height_map_type))
+                    .collect();
+                HeightMaps::pack(&heights, min_y)
+            };
+            let maps = HeightMaps {
+                motion_blocking: pack(HeightMapType::MotionBlocking),
+                motion_blocking_no_leaves: pack(HeightMapType::MotionBlockingNoPlants),
+                ocean_floor: pack(HeightMapType::OceanFloorNoPlants),
+                world_surface: pack(HeightMapType::WorldSurface),
+                ..self.chunk.heightmaps.clone()
+            };
+            if let Some(Value::Compound(raw_maps)) = self.raw.get_mut("Heightmaps") {
+                for (key, map) in [
+                    ("MOTION_BLOCKING", &maps.motion_blocking),
+                    ("MOTION_BLOCKING_NO_LEAVES", &maps.motion_blocking_no_leaves),
+                    ("OCEAN_FLOOR", &maps.ocean_floor),
+                    ("WORLD_SURFACE", &maps.world_surface),
+                ] {
+                    raw_maps.insert(key.to_string(), Value::LongArray(map.clone()));
+                }
+            }
+            self.chunk.heightmaps = maps;
+            self.touched.clear();
+            self.raw.insert("isLightOn".to_string(), Value::Byte(0));
+        }
+        Ok(fastnbt::to_bytes(&Value::Compound(self.raw.clone()))?)
+    }
+}
+
+#[async_trait]
+impl WorldBackend for AnvilBackend {
+    async fn get_build_area(&self) -> anyhow::Result<Rect3D> {
+        Ok(self.build_area)
+    }
+
+    async fn get_blocks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBlock>> {
+        let mut state = self.state();
+        let mut blocks = Vec::new();
+        for point in Rect3D::from_points(Point3D::new(x, y, z), Point3D::new(x + dx - dx.signum(), y + dy - dy.signum(), z + dz - dz.signum())).iter() {
+            if let Some(block) = state.get_block(self, point) {
+                blocks.push(PositionedBlock::from_block(Block::from(&block), point.into()));
+            }
+        }
+        Ok(blocks)
+    }
+
+    async fn put_blocks_options(&self, blocks: &[PositionedBlock], _do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+        let mut state = self.state();
+        Ok(blocks
+            .iter()
+            .map(|positioned| {
+                let point = Point3D::new(resolve(positioned.x), resolve(positioned.y), resolve(positioned.z));
+                let changed = match state.chunk(self, chunk_of(point.x, point.z)) {
+                    Some(chunk) => chunk.set_block(point, &positioned.get_block()),
+                    None => {
+                        warn!("Cannot place block at {:?}: chunk was never generated", point);
+                        false
+                    }
+                };
+                BlockPlacementResponse { status: changed as i32 }
+            })
+            .collect())
+    }
+
+    async fn get_heightmap(&self, x: i32, z: i32, dx: i32, dz: i32, height_map_type: HeightMapType) -> anyhow::Result<Vec<Vec<i32>>> {
+        let mut state = self.state();
+        let mut heights = vec![vec![0; dz as usize]; dx as usize];
+        for (ix, column) in heights.iter_mut().enumerate() {
+            for (iz, height) in column.iter_mut().enumerate() {
+                let (wx, wz) = (x + ix as i32, z + iz as i32);
+                match state.chunk(self, chunk_of(wx, wz)) {
+                    Some(chunk) => *height = chunk.height(wx, wz, height_map_type),
+                    None => bail!("Heightmap column ({wx}, {wz}) lies in an ungenerated chunk"),
+                }
+            }
+        }
+        Ok(heights)
+    }
+
+    async fn get_biomes(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBiome>> {
+        let mut state = self.state();
+        let mut biomes = Vec::new();
+        for point in Rect3D::from_points(Point3D::new(x, y, z), Point3D::new(x + dx - dx.signum(), y + dy - dy.signum(), z + dz - dz.signum())).iter() {
+            let Some(chunk) = state.chunk(self, chunk_of(point.x, point.z)) else { continue };
+            let section_y = point.y.div_euclid(SIZE);
+            let Some(cells) = chunk.chunk.sections.iter().find(|s| s.y == section_y).and_then(|s| s.biomes.as_ref()).and_then(|b| b.decode()) else { continue };
+            let local = Point3D::new(point.x.rem_euclid(SIZE) / 4, point.y.rem_euclid(SIZE) / 4, point.z.rem_euclid(SIZE) / 4);
+            let index = (local.y * 16 + local.z * 4 + local.x) as usize;
+            debug_assert!(index < SECTION_BIOME_VOLUME);
+            biomes.push(PositionedBiome { x: point.x, y: point.y, z: point.z, id: cells[index].clone() });
+        }
+        Ok(biomes)
+    }
+
+    async fn get_chunks(&self, x: i32, _y: i32, z: i32, dx: i32, _dy: i32, dz: i32) -> anyhow::Result<Vec<Chunk>> {
+        let mut state = self.state();
+        let mut chunks = Vec::new();
+        for chunk_x in x..x + dx {
+            for chunk_z in z..z + dz {
+                if let Some(chunk) = state.chunk(self, Point2D::new(chunk_x, chunk_z)) {
+                    chunk.sync_sections();
+                    chunks.push(chunk.chunk.clone());
+                }
+            }
+        }
+        Ok(chunks)
+    }
+
+    async fn command(&self, commands: Vec<String>) -> anyhow::Result<Vec<CommandResponse>> {
+        bail!("The Anvil backend cannot run commands ({} skipped)", commands.len())
+    }
+
+    async fn get_entities(&self, _x: i32, _y: i32, _z: i32, _dx: i32, _dy: i32, _dz: i32) -> anyhow::Result<Vec<EntityResponse>> {
+        bail!("The Anvil backend does not read entities")
+    }
+
+    async fn put_entities(&self, _x: i32, _y: i32, _z: i32, entities: &[PositionedEntity]) -> anyhow::Result<()> {
+        bail!("The Anvil backend cannot spawn entities ({} skipped)", entities.len())
+    }
+
+    async fn save(&self) -> anyhow::Result<()> {
+        let mut state = self.state();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as u32);
+
+        // Encode every dirty chunk before touching anything, so a chunk that
+        // fails to encode leaves the whole save to be retried.
+        let mut by_region: HashMap<Point2D, Vec<(Point2D, Vec<u8>)>> = HashMap::new();
+        for (key, chunk) in state.chunks.iter_mut() {
+            let Some(chunk) = chunk.as_mut().filter(|c| c.unsaved) else { continue };
+            let region_key = Point2D::new(key.x.div_euclid(REGION_SIZE), key.y.div_euclid(REGION_SIZE));
+            by_region.entry(region_key).or_default().push((*key, chunk.encode()?));
+        }
+
+        // One write per region file; a chunk counts as saved only once its
+        // region is on disk.
+        for chunks in by_region.into_values() {
+            let first = chunks[0].0;
+            let Some(region) = state.region(self, first) else {
+                bail!("No region file to save chunk ({}, {}) into", first.x, first.y);
+            };
+            let keys: Vec<Point2D> = chunks.iter().map(|(key, _)| *key).collect();
+            for (key, nbt) in chunks {
+                region.set_chunk(key.x, key.y, nbt, timestamp);
+            }
+            region.write(&self.region_path(first))?;
+            info!("Wrote region file {}", region_file_name(first.x, first.y));
+            for key in keys {
+                if let Some(Some(chunk)) = state.chunks.get_mut(&key) {
+                    chunk.unsaved = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::{generator::nbts::NBTStructure, geometry::{Point3D, Rect3D}, minecraft::Chunk};
+
+use super::{biome::PositionedBiome, command_response::CommandResponse, entity::{EntityResponse, PositionedEntity}, height_map::HeightMapType, positioned_block::{BlockPlacementResponse, PositionedBlock}, GDMCHTTPProvider};
+
+/// The world a generation run reads from and writes to.
+///
+/// Covers every call `Editor` and `World::new` make, so the same pipeline can
+/// target the live GDMC HTTP interface ([`GDMCHTTPProvider`]) or any stand-in
+/// — an in-memory world, a save folder on disk, a recorded session. All
+/// coordinates are absolute world coordinates, exactly as the GDMC endpoints
+/// take them.
+#[async_trait]
+pub trait WorldBackend: Debug + Send + Sync {
+    async fn get_build_area(&self) -> anyhow::Result<Rect3D>;
+
+    async fn get_blocks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBlock>>;
+
+    /// Places `blocks`, returning one response per block in order. With
+    /// `do_block_updates` false the blocks are placed verbatim, without
+    /// neighbour updates (see `Editor::set_block_updates`).
+    async fn put_blocks_options(&self, blocks: &[PositionedBlock], do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>>;
+
+    /// Heightmap over the `dx × dz` columns starting at `(x, z)`, indexed
+    /// `[x][z]` and holding absolute Y values (first air above the surface).
+    async fn get_heightmap(&self, x: i32, z: i32, dx: i32, dz: i32, height_map_type: HeightMapType) -> anyhow::Result<Vec<Vec<i32>>>;
+
+    async fn get_biomes(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBiome>>;
+
+    /// Chunks covering the given range in **chunk** coordinates.
+    async fn get_chunks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<Chunk>>;
+
+    async fn command(&self, commands: Vec<String>) -> anyhow::Result<Vec<CommandResponse>>;
+
+    async fn get_entities(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<EntityResponse>>;
+
+    async fn put_entities(&self, x: i32, y: i32, z: i32, entities: &[PositionedEntity]) -> anyhow::Result<()>;
+
+    /// Pastes `structure` with its corner at `(x, y, z)`. Cells the structure
+    /// doesn't list are left alone. Backends without a structure endpoint fall
+    /// back to placing the blocks one by one, which is what this default does.
+    async fn put_structure(&self, x: i32, y: i32, z: i32, structure: &NBTStructure, do_block_updates: bool) -> anyhow::Result<()> {
+        let blocks = structure.to_positioned_blocks(Point3D::new(x, y, z));
+        let responses = self.put_blocks_options(&blocks, do_block_updates).await?;
+        log::debug!("Pasted structure as {} blocks ({} changed)", blocks.len(), responses.iter().filter(|r| r.status == 1).count());
+        Ok(())
+    }
+
+    /// Persists everything written so far. A no-op for live servers, which
+    /// apply each request immediately; file-backed worlds write out here.
+    async fn save(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn put_blocks(&self, blocks: &[PositionedBlock]) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+        self.put_blocks_options(blocks, true).await
+    }
+
+    async fn put_blocks_no_updates(&self, blocks: &[PositionedBlock]) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+        self.put_blocks_options(blocks, false).await
+    }
+
+    /// Gives every player a written book. Built on [`command`](Self::command),
+    /// so backends get it for free.
+    async fn give_player_book(&self, pages: &[&str], title: &str, author: &str) -> anyhow::Result<CommandResponse> {
+        let command = format!("give @a written_book[written_book_content={{title:\"{}\",author:\"{}\",pages:[{}]}}]", title, author, pages.join(","));
+        log::info!("Command: {}", command);
+        self.command(vec![command]).await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No response to give command"))
+    }
+}
+
+#[async_trait]
+impl WorldBackend for GDMCHTTPProvider {
+    async fn get_build_area(&self) -> anyhow::Result<Rect3D> {
+        GDMCHTTPProvider::get_build_area(self).await
+    }
+
+    async fn get_blocks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBlock>> {
+        GDMCHTTPProvider::get_blocks(self, x, y, z, dx, dy, dz).await
+    }
+
+    async fn put_blocks_options(&self, blocks: &[PositionedBlock], do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+        GDMCHTTPProvider::put_blocks_options(self, blocks, do_block_updates).await
+    }
+
+    async fn get_heightmap(&self, x: i32, z: i32, dx: i32, dz: i32, height_map_type: HeightMapType) -> anyhow::Result<Vec<Vec<i32>>> {
+        GDMCHTTPProvider::get_heightmap(self, x, z, dx, dz, height_map_type).await
+    }
+
+    async fn get_biomes(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBiome>> {
+        GDMCHTTPProvider::get_biomes(self, x, y, z, dx, dy, dz).await
+    }
+
+    async fn get_chunks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<Chunk>> {
+        GDMCHTTPProvider::get_chunks(self, x, y, z, dx, dy, dz).await
+    }
+
+    async fn command(&self, commands: Vec<String>) -> anyhow::Result<Vec<CommandResponse>> {
+        GDMCHTTPProvider::command(self, commands).await
+    }
+
+    async fn get_entities(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<EntityResponse>> {
+        GDMCHTTPProvider::get_entities(self, x, y, z, dx, dy, dz).await
+    }
+
+    async fn put_entities(&self, x: i32, y: i32, z: i32, entities: &[PositionedEntity]) -> anyhow::Result<()> {
+        GDMCHTTPProvider::put_entities(self, x, y, z, entities).await
+    }
+
+    async fn put_structure(&self, x: i32, y: i32, z: i32, structure: &NBTStructure, do_block_updates: bool) -> anyhow::Result<()> {
+        GDMCHTTPProvider::put_structure(self, x, y, z, structure, do_block_updates).await
+    }
+}
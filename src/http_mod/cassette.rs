@@ -0,0 +1,108 @@
+use std::{collections::{HashMap, VecDeque}, fs::{File, OpenOptions}, io::{BufWriter, Read, Write}, path::Path, sync::{Arc, Mutex}};
+
+use anyhow::{anyhow, bail};
+use fastnbt::ByteArray;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde_derive::{Deserialize, Serialize};
+
+/// One recorded GDMC read: the request path (everything after the base URL,
+/// query included) and the raw response body.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CassetteEntry {
+    request: String,
+    response: ByteArray,
+}
+
+/// Appends every read [`GDMCHTTPProvider`](super::GDMCHTTPProvider) makes to a
+/// cassette file as it happens, so a run that crashes halfway still leaves a
+/// usable recording.
+///
+/// The file is a sequence of frames, each a big-endian `u32` length followed by
+/// one gzip-compressed NBT entry. Only reads are recorded (build area, chunks,
+/// heightmaps, biomes, blocks, entities, structures); writes are not needed to
+/// reproduce a run.
+#[derive(Debug)]
+pub struct CassetteRecorder {
+    file: Mutex<BufWriter<File>>,
+}
+
+impl CassetteRecorder {
+    /// Starts a fresh cassette at `path`, replacing any existing file.
+    pub fn create(path: &Path) -> anyhow::Result<Self> {
+        let file = OpenOptions::new().create(true).write(true).truncate(true).open(path)?;
+        Ok(Self { file: Mutex::new(BufWriter::new(file)) })
+    }
+
+    pub fn record(&self, request: &str, response: &[u8]) -> anyhow::Result<()> {
+        let entry = CassetteEntry {
+            request: request.to_string(),
+            response: ByteArray::new(response.iter().map(|&b| b as i8).collect()),
+        };
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(&fastnbt::to_bytes(&entry)?)?;
+        let frame = encoder.finish()?;
+
+        let mut file = self.file.lock().expect("Cassette lock poisoned");
+        file.write_all(&(frame.len() as u32).to_be_bytes())?;
+        file.write_all(&frame)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// A loaded cassette, serving recorded responses back in place of a server.
+///
+/// Responses to the same request are replayed in the order they were
+/// recorded — a block read after the run's own writes gets the post-write
+/// answer — and the last one repeats once they run out.
+#[derive(Debug)]
+pub struct CassettePlayer {
+    responses: Mutex<HashMap<String, VecDeque<Vec<u8>>>>,
+}
+
+impl CassettePlayer {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(mut bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut responses: HashMap<String, VecDeque<Vec<u8>>> = HashMap::new();
+        while !bytes.is_empty() {
+            if bytes.len() < 4 {
+                bail!("truncated cassette frame header");
+            }
+            let length = u32::from_be_bytes(bytes[..4].try_into()?) as usize;
+            let frame = bytes.get(4..4 + length).ok_or_else(|| anyhow!("truncated cassette frame"))?;
+            bytes = &bytes[4 + length..];
+
+            let mut nbt = Vec::new();
+            GzDecoder::new(frame).read_to_end(&mut nbt)?;
+            let entry: CassetteEntry = fastnbt::from_bytes(&nbt)?;
+            let response = entry.response.iter().map(|&b| b as u8).collect();
+            responses.entry(entry.request).or_default().push_back(response);
+        }
+        Ok(Self { responses: Mutex::new(responses) })
+    }
+
+    /// The next recorded response to `request`.
+    pub fn play(&self, request: &str) -> anyhow::Result<Vec<u8>> {
+        let mut responses = self.responses.lock().expect("Cassette lock poisoned");
+        let queue = responses
+            .get_mut(request)
+            .ok_or_else(|| anyhow!("cassette has no recorded response to {request}"))?;
+        Ok(match queue.len() {
+            1 => queue[0].clone(),
+            _ => queue.pop_front().expect("recorded queues are never empty"),
+        })
+    }
+}
+
+/// Whether a provider talks to its server directly, records what it reads, or
+/// replays a recording with no server at all.
+#[derive(Debug, Clone, Default)]
+pub enum Cassette {
+    #[default]
+    Off,
+    Record(Arc<CassetteRecorder>),
+    Replay(Arc<CassettePlayer>),
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+/// Connection settings for [`GDMCHTTPProvider`](super::GDMCHTTPProvider).
+///
+/// Defaults match the stock GDMC HTTP interface on this machine. Each field can
+/// be overridden from the environment (and so from `.env`, which `main` loads
+/// through dotenv) or from command-line flags:
+///
+/// | field          | env var                | flag                      |
+/// |----------------|------------------------|---------------------------|
+/// | `host`         | `GDMC_HOST`            | `--gdmc-host`             |
+/// | `port`         | `GDMC_PORT`            | `--gdmc-port`             |
+/// | `timeout`      | `GDMC_TIMEOUT_SECS`    | `--gdmc-timeout` (s)      |
+/// | `max_retries`  | `GDMC_RETRIES`         | `--gdmc-retries`          |
+/// | `min_backoff`  | `GDMC_BACKOFF_MIN_MS`  | `--gdmc-backoff-min` (ms) |
+/// | `max_backoff`  | `GDMC_BACKOFF_MAX_MS`  | `--gdmc-backoff-max` (ms) |
+#[derive(Debug, Clone, PartialEq)]
+pub struct GDMCConfig {
+    pub host: String,
+    pub port: u16,
+    /// Per-request timeout. Whole-area chunk reads on a big build area are
+    /// slow, so this is generous by default.
+    pub timeout: Duration,
+    /// Retries on transient failures (connection errors, 5xx) before giving up.
+    pub max_retries: u32,
+    /// Bounds on the exponential backoff between retries.
+    pub min_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for GDMCConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 9000,
+            timeout: Duration::from_secs(300),
+            max_retries: 3,
+            min_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl GDMCConfig {
+    /// Defaults overridden by any `GDMC_*` environment variables. Unparseable
+    /// values are logged and ignored rather than aborting the run.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        for (key, field) in [
+            ("GDMC_HOST", "host"),
+            ("GDMC_PORT", "port"),
+            ("GDMC_TIMEOUT_SECS", "timeout"),
+            ("GDMC_RETRIES", "retries"),
+            ("GDMC_BACKOFF_MIN_MS", "backoff_min"),
+            ("GDMC_BACKOFF_MAX_MS", "backoff_max"),
+        ] {
+            if let Ok(value) = std::env::var(key) {
+                config.set(field, &value);
+            }
+        }
+        config
+    }
+
+    /// Applies the `--gdmc-*` flags in `args`, in either `--flag value` or
+    /// `--flag=value` form. Other arguments are ignored.
+    pub fn with_args<I: IntoIterator<Item = String>>(mut self, args: I) -> Self {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--gdmc-") else { continue };
+            let (field, value) = match flag.split_once('=') {
+                Some((field, value)) => (field.to_string(), Some(value.to_string())),
+                None => (flag.to_string(), args.next()),
+            };
+            match value {
+                Some(value) => self.set(&field.replace('-', "_"), &value),
+                None => log::warn!("--gdmc-{} needs a value", field),
+            }
+        }
+        self
+    }
+
+    /// `http://host:port`, the prefix every endpoint path is appended to.
+    pub fn base_url(&self) -> String {
+        format!("http://{}:{}", self.host, self.port)
+    }
+
+    fn set(&mut self, field: &str, value: &str) {
+        let parsed = match field {
+            "host" => {
+                self.host = value.to_string();
+                true
+            }
+            "port" => value.parse().map(|port| self.port = port).is_ok(),
+            "timeout" => value.parse().map(|secs| self.timeout = Duration::from_secs(secs)).is_ok(),
+            "retries" => value.parse().map(|n| self.max_retries = n).is_ok(),
+            "backoff_min" => value.parse().map(|ms| self.min_backoff = Duration::from_millis(ms)).is_ok(),
+            "backoff_max" => value.parse().map(|ms| self.max_backoff = Duration::from_millis(ms)).is_ok(),
+            _ => {
+                log::warn!("Unknown GDMC setting '{}'", field);
+                true
+            }
+        };
+        if !parsed {
+            log::warn!("Ignoring invalid GDMC {} value '{}'", field, value);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::{collections::HashMap, io::{Read, Write}, net::SocketAddr, sync::{Arc, Mutex, MutexGuard}};
+
+use axum::{body::Bytes, extract::{Query, State}, http::StatusCode, response::{IntoResponse, Response}, routing::get, Json, Router};
+use flate2::{write::GzEncoder, Compression};
+use serde_derive::Deserialize;
+use serde_json::json;
+
+use crate::{generator::nbts::NBTStructure, geometry::{Point3D, Rect3D}, minecraft::{Biome, Biomes, Block, BlockStates, Chunk, ChunkBlock, ChunkSection, Chunks, HeightMaps, SECTION_BIOME_VOLUME, SECTION_SIZE}};
+
+use super::{Coordinate, GDMCHTTPProvider, PositionedBlock, PositionedEntity};
+
+/// Bottom and top (exclusive) of the mock world, matching a vanilla overworld.
+const MIN_Y: i32 = -64;
+const MAX_Y: i32 = 320;
+
+/// A synthetic voxel world served by [`MockServer`].
+///
+/// Terrain is flat: bedrock at the world floor, stone up to four blocks below
+/// the surface, then dirt and a grass top at `surface_y`. Blocks written over
+/// HTTP are kept as overrides on top of that, so every read endpoint — blocks,
+/// chunks, heightmaps, structures — sees them. All coordinates are absolute.
+#[derive(Debug)]
+pub struct MockWorld {
+    pub build_area: Rect3D,
+    pub surface_y: i32,
+    pub biome: Biome,
+    blocks: HashMap<Point3D, Block>,
+    entities: Vec<(String, PositionedEntity)>,
+    commands: Vec<String>,
+    structure_pastes: usize,
+}
+
+impl MockWorld {
+    pub fn flat(build_area: Rect3D, surface_y: i32) -> Self {
+        Self {
+            build_area,
+            surface_y,
+            biome: Biome::from("minecraft:plains"),
+            blocks: HashMap::new(),
+            entities: Vec::new(),
+            commands: Vec::new(),
+            structure_pastes: 0,
+        }
+    }
+
+    fn terrain_block(&self, y: i32) -> Block {
+        let id = match y {
+            y if y == MIN_Y => "minecraft:bedrock",
+            y if y < self.surface_y - 3 => "minecraft:stone",
+            y if y < self.surface_y => "minecraft:dirt",
+            y if y == self.surface_y => "minecraft:grass_block",
+            _ => "minecraft:air",
+        };
+        Block::from_id(id.into())
+    }
+
+    pub fn get_block(&self, point: Point3D) -> Block {
+        self.blocks.get(&point).cloned().unwrap_or_else(|| self.terrain_block(point.y))
+    }
+
+    /// Writes `block`, returning whether the world changed (the GDMC status).
+    pub fn set_block(&mut self, point: Point3D, block: Block) -> bool {
+        let block = Block { id: namespaced(block.id.as_str()).as_str().into(), ..block };
+        if self.get_block(point) == block {
+            return false;
+        }
+        self.blocks.insert(point, block);
+        true
+    }
+
+    /// The first air cell above the highest non-air block in the column.
+    pub fn height(&self, x: i32, z: i32) -> i32 {
+        let top = self.blocks.keys()
+            .filter(|p| p.x == x && p.z == z)
+            .map(|p| p.y + 1)
+            .fold(self.surface_y + 1, i32::max);
+        (MIN_Y..top).rev()
+            .find(|&y| !self.get_block(Point3D::new(x, y, z)).id.is_air())
+            .map_or(MIN_Y, |y| y + 1)
+    }
+
+    /// Every command received on `/command`, in order.
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    /// How many structures arrived on `POST /structure`.
+    pub fn structure_pastes(&self) -> usize {
+        self.structure_pastes
+    }
+
+    /// Every entity received on `PUT /entities`, in order.
+    pub fn entities(&self) -> impl Iterator<Item = &PositionedEntity> {
+        self.entities.iter().map(|(_, entity)| entity)
+    }
+
+    /// Chunk `(chunk_x, chunk_z)` as the game would store it.
+    pub fn chunk(&self, chunk_x: i32, chunk_z: i32) -> Chunk {
+        let size = SECTION_SIZE as i32;
+        let sections = (MIN_Y / size..MAX_Y / size)
+            .map(|section_y| {
+                let blocks: Vec<ChunkBlock> = (0..size)
+                    .flat_map(|y| (0..size).flat_map(move |z| (0..size).map(move |x| (x, y, z))))
+                    .map(|(x, y, z)| {
+                        let point = Point3D::new(chunk_x * size + x, section_y * size + y, chunk_z * size + z);
+                        ChunkBlock::from(&self.get_block(point))
+                    })
+                    .collect();
+                ChunkSection {
+                    y: section_y,
+                    block_states: Some(BlockStates::encode(&blocks)),
+                    biomes: Some(Biomes::encode(&vec![self.biome.clone(); SECTION_BIOME_VOLUME])),
+                }
+            })
+            .collect();
+        let heights: Vec<i32> = (0..size)
+            .flat_map(|z| (0..size).map(move |x| (x, z)))
+            .map(|(x, z)| self.height(chunk_x * size + x, chunk_z * size + z))
+            .collect();
+        Chunk {
+            x_pos: chunk_x,
+            y_pos: MIN_Y / size,
+            z_pos: chunk_z,
+            sections,
+            heightmaps: HeightMaps::uniform(&heights, MIN_Y),
+            block_entities: None,
+        }
+    }
+}
+
+/// Block ids as the server reports them: always namespaced.
+fn namespaced(id: &str) -> String {
+    if id.contains(':') { id.to_string() } else { format!("minecraft:{}", id) }
+}
+
+type SharedWorld = Arc<Mutex<MockWorld>>;
+
+/// An in-process stand-in for the GDMC HTTP interface, serving a [`MockWorld`]
+/// on an ephemeral localhost port. Speaks the endpoints `GDMCHTTPProvider`
+/// uses — `/buildarea`, `/blocks`, `/chunks`, `/heightmap`, `/biomes`,
+/// `/command`, `/entities` and `/structure` — so tests that need "a server"
+/// run without Minecraft. The server stops when this is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    world: SharedWorld,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+    pub async fn start(world: MockWorld) -> Self {
+        let world = Arc::new(Mutex::new(world));
+        let app = Router::new()
+            .route("/buildarea", get(get_build_area))
+            .route("/blocks", get(get_blocks).put(put_blocks))
+            .route("/chunks", get(get_chunks))
+            .route("/heightmap", get(get_heightmap))
+            .route("/biomes", get(get_biomes))
+            .route("/command", axum::routing::post(post_command))
+            .route("/entities", get(get_entities).put(put_entities))
+            .route("/structure", get(get_structure).post(post_structure))
+            .with_state(world.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock GDMC server");
+        let addr = listener.local_addr().expect("Mock GDMC server has no address");
+        let task = tokio::spawn(async move {
+            axum::serve(listener, app).await.expect("Mock GDMC server failed");
+        });
+
+        Self { addr, world, task }
+    }
+
+    /// A one-chunk, 256-tall build area over flat grassland at y=63. Kept
+    /// small so whole-area `/blocks` and `/biomes` reads stay quick.
+    pub async fn flat() -> Self {
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(15, 255, 15));
+        Self::start(MockWorld::flat(build_area, 63)).await
+    }
+
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// A provider talking to this server.
+    pub fn provider(&self) -> GDMCHTTPProvider {
+        GDMCHTTPProvider::new().with_base_url(self.url())
+    }
+
+    /// The served world, for seeding terrain or asserting on what was written.
+    pub fn world(&self) -> MutexGuard<'_, MockWorld> {
+        self.world.lock().expect("Mock world lock poisoned")
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn default_one() -> i32 {
+    1
+}
+
+#[derive(Deserialize)]
+struct RegionQuery {
+    #[serde(default)]
+    x: i32,
+    #[serde(default)]
+    y: i32,
+    #[serde(default)]
+    z: i32,
+    #[serde(default = "default_one")]
+    dx: i32,
+    #[serde(default = "default_one")]
+    dy: i32,
+    #[serde(default = "default_one")]
+    dz: i32,
+}
+
+impl RegionQuery {
+    /// The queried cells. Like the real server, a negative extent counts
+    /// backwards from the corner.
+    fn points(&self) -> Vec<Point3D> {
+        fn span(start: i32, len: i32) -> std::ops::Range<i32> {
+            if len >= 0 { start..start + len } else { start + len + 1..start + 1 }
+        }
+        span(self.x, self.dx)
+            .flat_map(|x| span(self.y, self.dy).flat_map(move |y| span(self.z, self.dz).map(move |z| Point3D::new(x, y, z))))
+            .collect()
+    }
+}
+
+fn lock(world: &SharedWorld) -> Result<MutexGuard<'_, MockWorld>, StatusCode> {
+    world.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// NBT payloads go out gzipped, as the real server sends them.
+fn gzip_nbt<T: serde::Serialize>(value: &T) -> Result<Response, StatusCode> {
+    let nbt = fastnbt::to_bytes(value).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder.write_all(&nbt).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let body = encoder.finish().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(([("content-type", "application/octet-stream")], body).into_response())
+}
+
+async fn get_build_area(State(world): State<SharedWorld>) -> Result<Json<serde_json::Value>, StatusCode> {
+    let area = lock(&world)?.build_area;
+    let max = area.max();
+    Ok(Json(json!({
+        "xFrom": area.origin.x, "yFrom": area.origin.y, "zFrom": area.origin.z,
+        "xTo": max.x, "yTo": max.y, "zTo": max.z,
+    })))
+}
+
+async fn get_blocks(State(world): State<SharedWorld>, Query(query): Query<RegionQuery>) -> Result<Json<Vec<PositionedBlock>>, StatusCode> {
+    let world = lock(&world)?;
+    let blocks = query.points()
+        .into_iter()
+        .map(|point| PositionedBlock::from_block(world.get_block(point), point.into()))
+        .collect();
+    Ok(Json(blocks))
+}
+
+async fn put_blocks(State(world): State<SharedWorld>, Query(query): Query<HashMap<String, String>>, body: Bytes) -> Result<Json<serde_json::Value>, StatusCode> {
+    let blocks: Vec<PositionedBlock> = serde_json::from_slice(&body).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let offset = |key: &str| query.get(key).and_then(|v| v.parse::<i32>().ok()).unwrap_or(0);
+    let resolve = |coordinate: Coordinate, base: i32| match coordinate {
+        Coordinate::Absolute(value) => value,
+        Coordinate::AbsoluteF(value) => value.floor() as i32,
+        Coordinate::Relative(value) => base + value,
+    };
+
+    let mut world = lock(&world)?;
+    let statuses: Vec<_> = blocks
+        .into_iter()
+        .map(|positioned| {
+            let point = Point3D::new(
+                resolve(positioned.x, offset("x")),
+                resolve(positioned.y, offset("y")),
+                resolve(positioned.z, offset("z")),
+            );
+            let changed = world.set_block(point, positioned.get_block());
+            json!({ "status": changed as i32 })
+        })
+        .collect();
+    Ok(Json(serde_json::Value::Array(statuses)))
+}
+
+async fn get_chunks(State(world): State<SharedWorld>, Query(query): Query<RegionQuery>) -> Result<Response, StatusCode> {
+    let world = lock(&world)?;
+    let chunks = query.points()
+        .into_iter()
+        .filter(|point| point.y == query.y)
+        .map(|point| world.chunk(point.x, point.z))
+        .collect();
+    gzip_nbt(&Chunks { chunks })
+}
+
+#[derive(Deserialize)]
+struct HeightmapQuery {
+    x: i32,
+    z: i32,
+    dx: i32,
+    dz: i32,
+}
+
+async fn get_heightmap(State(world): State<SharedWorld>, Query(query): Query<HeightmapQuery>) -> Result<Json<Vec<Vec<i32>>>, StatusCode> {
+    let world = lock(&world)?;
+    let heights = (query.x..query.x + query.dx)
+        .map(|x| (query.z..query.z + query.dz).map(|z| world.height(x, z)).collect())
+        .collect();
+    Ok(Json(heights))
+}
+
+async fn get_biomes(State(world): State<SharedWorld>, Query(query): Query<RegionQuery>) -> Result<Json<serde_json::Value>, StatusCode> {
+    let world = lock(&world)?;
+    let biomes = query.points()
+        .into_iter()
+        .map(|p| json!({ "x": p.x, "y": p.y, "z": p.z, "id": world.biome }))
+        .collect();
+    Ok(Json(serde_json::Value::Array(biomes)))
+}
+
+async fn post_command(State(world): State<SharedWorld>, body: String) -> Result<Json<serde_json::Value>, StatusCode> {
+    let mut world = lock(&world)?;
+    let responses = body
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            world.commands.push(line.to_string());
+            json!({ "status": 1, "message": "" })
+        })
+        .collect();
+    Ok(Json(serde_json::Value::Array(responses)))
+}
+
+async fn get_entities(State(world): State<SharedWorld>) -> Result<Json<serde_json::Value>, StatusCode> {
+    let world = lock(&world)?;
+    let entities = world.entities
+        .iter()
+        .map(|(uuid, entity)| json!({ "uuid": uuid, "data": format!("{{id:\"{}\"}}", entity.id) }))
+        .collect();
+    Ok(Json(serde_json::Value::Array(entities)))
+}
+
+async fn put_entities(State(world): State<SharedWorld>, body: Bytes) -> Result<Json<serde_json::Value>, StatusCode> {
+    let entities: Vec<PositionedEntity> = serde_json::from_slice(&body).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let mut world = lock(&world)?;
+    let responses = entities
+        .into_iter()
+        .map(|entity| {
+            let uuid = format!("00000000-0000-0000-0000-{:012x}", world.entities.len());
+            world.entities.push((uuid.clone(), entity));
+            json!({ "status": 1, "uuid": uuid })
+        })
+        .collect();
+    Ok(Json(serde_json::Value::Array(responses)))
+}
+
+async fn get_structure(State(world): State<SharedWorld>, Query(query): Query<RegionQuery>) -> Result<Response, StatusCode> {
+    let world = lock(&world)?;
+    let points = query.points();
+    let origin = points.iter().fold(Point3D::new(query.x, query.y, query.z), |acc, p| Point3D::new(acc.x.min(p.x), acc.y.min(p.y), acc.z.min(p.z)));
+    let blocks = points
+        .into_iter()
+        .map(|point| (world.get_block(point), point - origin))
+        .collect();
+    gzip_nbt(&NBTStructure::from_blocks(blocks))
+}
+
+#[derive(Deserialize)]
+struct PasteQuery {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+async fn post_structure(State(world): State<SharedWorld>, Query(query): Query<PasteQuery>, body: Bytes) -> Result<Json<serde_json::Value>, StatusCode> {
+    let mut nbt = Vec::new();
+    flate2::read::GzDecoder::new(&body[..]).read_to_end(&mut nbt).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let structure: NBTStructure = fastnbt::from_bytes(&nbt).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let mut world = lock(&world)?;
+    for block in structure.to_positioned_blocks(Point3D::new(query.x, query.y, query.z)) {
+        world.set_block(block.get_coordinate().into(), block.get_block());
+    }
+    world.structure_pastes += 1;
+    Ok(Json(json!({ "status": 1 })))
+}
@@ -7,10 +7,20 @@ mod test;
 mod entity;
 mod height_map;
 mod coordinate;
+mod backend;
+mod config;
+mod anvil;
+mod cassette;
+#[cfg(test)]
+pub mod mock_server;
 
 pub use provider::GDMCHTTPProvider;
-pub use positioned_block::PositionedBlock;
-pub use entity::PositionedEntity;
+pub use config::GDMCConfig;
+pub use backend::WorldBackend;
+pub use anvil::AnvilBackend;
+pub use positioned_block::{BlockPlacementResponse, PositionedBlock};
+pub use entity::{EntityResponse, PositionedEntity};
+pub use biome::PositionedBiome;
 pub use coordinate::Coordinate;
 pub use command_response::CommandResponse;
 pub use height_map::HeightMapType;
\ No newline at end of file
@@ -1,11 +1,11 @@
-use std::io::Read;
+use std::{io::Read, path::Path, sync::Arc, time::Duration};
 
 use crate::{generator::nbts::NBTStructure, geometry::Rect3D, http_mod::buildarea, minecraft::{Chunk, Chunks}};
 
-use super::{biome::PositionedBiome, command_response::CommandResponse, entity::{EntityResponse, PositionedEntity}, height_map::HeightMapType, positioned_block::{BlockPlacementResponse, PositionedBlock}};
+use super::{cassette::{Cassette, CassettePlayer, CassetteRecorder}, config::GDMCConfig, biome::PositionedBiome, command_response::CommandResponse, entity::{EntityResponse, PositionedEntity}, height_map::HeightMapType, positioned_block::{BlockPlacementResponse, PositionedBlock}};
 use anyhow::Ok;
 use flate2::read::GzDecoder;
-use log::{debug, info};
+use log::{debug, info, warn};
 use reqwest_middleware::ClientBuilder;
 use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
 
@@ -22,25 +22,120 @@ fn try_gunzip(bytes: &[u8]) -> Vec<u8> {
 }
 
 
+/// Build the HTTP client for `config`: its request timeout, wrapped in the
+/// transient-failure retry policy.
+fn build_client(config: &GDMCConfig) -> reqwest_middleware::ClientWithMiddleware {
+    let retry_policy = ExponentialBackoff::builder()
+        .retry_bounds(config.min_backoff, config.max_backoff.max(config.min_backoff))
+        .build_with_max_retries(config.max_retries);
+    let client = reqwest::Client::builder()
+        .timeout(config.timeout)
+        .build()
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to build HTTP client with timeout ({e}); using defaults");
+            reqwest::Client::new()
+        });
+    ClientBuilder::new(client)
+        .with(RetryTransientMiddleware::new_with_policy(retry_policy))
+        .build()
+}
+
 #[derive(Debug, Clone)]
 pub struct GDMCHTTPProvider {
     base_url: String,
+    config: GDMCConfig,
     client: reqwest_middleware::ClientWithMiddleware,
     log_responses: bool,
+    cassette: Cassette,
 }
 
 impl GDMCHTTPProvider {
+    /// A provider configured from the environment (see [`GDMCConfig::from_env`]);
+    /// with nothing set, this is the stock server at `http://localhost:9000`.
     pub fn new() -> Self {
-        let retry_policy = ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = ClientBuilder::new(reqwest::Client::new())
-            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-            .build();
+        Self::from_config(GDMCConfig::from_env())
+    }
 
+    pub fn from_config(config: GDMCConfig) -> Self {
         GDMCHTTPProvider {
-            base_url: "http://localhost:9000".to_string(),
-            client,
+            base_url: config.base_url(),
+            client: build_client(&config),
+            config,
             log_responses: false,
+            cassette: Cassette::Off,
+        }
+    }
+
+    /// A provider that needs no server: every read is answered from the
+    /// cassette at `path` (see [`with_recording`](Self::with_recording)), and
+    /// writes are acknowledged as successful without going anywhere.
+    pub fn replay(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let mut provider = Self::from_config(GDMCConfig::default());
+        provider.cassette = Cassette::Replay(Arc::new(CassettePlayer::open(path.as_ref())?));
+        Ok(provider)
+    }
+
+    /// Records every read this provider makes to a cassette file at `path`, for
+    /// [`replay`](Self::replay) later. Pair the cassette with the run's seed to
+    /// reproduce a town exactly on another machine.
+    pub fn with_recording(mut self, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        self.cassette = Cassette::Record(Arc::new(CassetteRecorder::create(path.as_ref())?));
+        Ok(self)
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.cassette, Cassette::Replay(_))
+    }
+
+    pub fn config(&self) -> &GDMCConfig {
+        &self.config
+    }
+
+    /// Point the provider at a different GDMC server, e.g. `http://localhost:9001`.
+    /// The host and port in the config follow the URL, so it still reports the
+    /// endpoint in use.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        let url = reqwest::Url::parse(&self.base_url)
+            .inspect_err(|e| warn!("GDMC base URL '{}' doesn't parse ({e}); the config keeps its old host and port", self.base_url))
+            .ok();
+        if let Some(url) = url {
+            if let Some(host) = url.host_str() {
+                self.config.host = host.to_string();
+            }
+            if let Some(port) = url.port_or_known_default() {
+                self.config.port = port;
+            }
         }
+        self
+    }
+
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.config.host = host.into();
+        self.base_url = self.config.base_url();
+        self
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.config.port = port;
+        self.base_url = self.config.base_url();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self.client = build_client(&self.config);
+        self
+    }
+
+    /// Retry transient failures up to `max_retries` times, backing off
+    /// exponentially between `min_backoff` and `max_backoff`.
+    pub fn with_retries(mut self, max_retries: u32, min_backoff: Duration, max_backoff: Duration) -> Self {
+        self.config.max_retries = max_retries;
+        self.config.min_backoff = min_backoff;
+        self.config.max_backoff = max_backoff;
+        self.client = build_client(&self.config);
+        self
     }
 
     pub fn with_response_logging(mut self, log_responses: bool) -> Self {
@@ -52,7 +147,43 @@ impl GDMCHTTPProvider {
         format!("{}/{}", self.base_url, path)
     }
 
+    /// GETs `path` and returns the raw body — from the cassette when replaying,
+    /// and onto it when recording. Cassettes key on the path alone, so a
+    /// recording replays against any host. Error responses aren't recorded, so
+    /// replaying that request fails instead of passing off the error as data.
+    async fn get_body(&self, path: &str, gzip: bool) -> anyhow::Result<Vec<u8>> {
+        if let Cassette::Replay(player) = &self.cassette {
+            return player.play(path);
+        }
+
+        let mut request = self.client.get(self.url(path));
+        if gzip {
+            request = request.header("Accept-Encoding", "gzip");
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.bytes().await?.to_vec();
+        if let Cassette::Record(recorder) = &self.cassette {
+            if !status.is_success() {
+                warn!("Not recording {} response to {} on cassette", status, path);
+            } else if let Err(e) = recorder.record(path, &body) {
+                warn!("Failed to record response to {} on cassette: {}", path, e);
+            }
+        }
+        Ok(body)
+    }
+
+    async fn get_text(&self, path: &str) -> anyhow::Result<String> {
+        let text = String::from_utf8(self.get_body(path, false).await?)?;
+        self.log_response(&text);
+        Ok(text)
+    }
+
     pub async fn command(&self, commands : Vec<String>) -> anyhow::Result<Vec<CommandResponse>> {
+        if self.is_replaying() {
+            return Ok(commands.iter().map(|_| CommandResponse { status: 1, ..Default::default() }).collect());
+        }
+
         let url = self.url("command"); 
         let client = &self.client;
         let response= client.post(&url)
@@ -80,27 +211,24 @@ impl GDMCHTTPProvider {
         let include_state = true;
         let include_data = true;
 
-        let url = self.url(&format!("blocks?x={}&y={}&z={}&dx={}&dy={}&dz={}&includeState={}&includeData={}", x, y, z, dx, dy, dz, include_state, include_data));
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let text = response.text().await?;
-        self.log_response(&text);
+        let text = self.get_text(&format!("blocks?x={}&y={}&z={}&dx={}&dy={}&dz={}&includeState={}&includeData={}", x, y, z, dx, dy, dz, include_state, include_data)).await?;
         let blocks: Vec<PositionedBlock> = serde_json::from_str(&text)?;
         Ok(blocks)
     }
 
-    pub async fn put_blocks(&self, blocks : &Vec<PositionedBlock>) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+    pub async fn put_blocks(&self, blocks : &[PositionedBlock]) -> anyhow::Result<Vec<BlockPlacementResponse>> {
         self.put_blocks_options(blocks, true).await
     }
 
-    pub async fn put_blocks_no_updates(&self, blocks : &Vec<PositionedBlock>) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+    pub async fn put_blocks_no_updates(&self, blocks : &[PositionedBlock]) -> anyhow::Result<Vec<BlockPlacementResponse>> {
         self.put_blocks_options(blocks, false).await
     }
 
-    pub async fn put_blocks_options(&self, blocks : &Vec<PositionedBlock>, do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+    pub async fn put_blocks_options(&self, blocks : &[PositionedBlock], do_block_updates: bool) -> anyhow::Result<Vec<BlockPlacementResponse>> {
+        if self.is_replaying() {
+            return Ok(blocks.iter().map(|_| BlockPlacementResponse { status: 1 }).collect());
+        }
+
         let url = if do_block_updates {
             self.url("blocks")
         } else {
@@ -122,53 +250,25 @@ impl GDMCHTTPProvider {
     }
 
     pub async fn get_build_area(&self) -> anyhow::Result<Rect3D> {
-        let url = self.url("buildarea");
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let text = response.text().await?;
-        self.log_response(&text);
+        let text = self.get_text("buildarea").await?;
         let buildarea_response : buildarea::BuildAreaResponse = serde_json::from_str(&text)?;
         Ok(buildarea_response.to_rect())
     }
 
     pub async fn get_heightmap(&self, x: i32, z: i32, dx: i32, dz: i32, height_map_type : HeightMapType) -> anyhow::Result<Vec<Vec<i32>>> {
-        let url = self.url(&format!("heightmap?x={}&z={}&dx={}&dz={}&type={}", x, z, dx, dz, height_map_type));
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let text = response.text().await?;
-        self.log_response(&text);
+        let text = self.get_text(&format!("heightmap?x={}&z={}&dx={}&dz={}&type={}", x, z, dx, dz, height_map_type)).await?;
         let heightmap: Vec<Vec<i32>> = serde_json::from_str(&text)?;
         Ok(heightmap)
     }
 
     pub async fn get_biomes(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<PositionedBiome>> {
-        let url = self.url(&format!("biomes?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz));
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let text = response.text().await?;
-        self.log_response(&text);
+        let text = self.get_text(&format!("biomes?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz)).await?;
         let biomes: Vec<PositionedBiome> = serde_json::from_str(&text)?;
         Ok(biomes)
     }
 
      pub async fn get_chunks(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<Chunk>> {
-        let url = self.url(&format!("chunks?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz));
-        let response = self.client
-            .get(&url)
-            .header("Accept-Encoding", "gzip")
-            .send()
-            .await?;
-
-        let raw_bytes = response.bytes().await?;
+        let raw_bytes = self.get_body(&format!("chunks?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz), true).await?;
         let data = try_gunzip(&raw_bytes);
         debug!("Chunk data: {} bytes (gzip: {})", data.len(), data.len() != raw_bytes.len());
 
@@ -184,19 +284,16 @@ impl GDMCHTTPProvider {
 
 
     pub async fn get_entities(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32) -> anyhow::Result<Vec<EntityResponse>> {
-        let url = self.url(&format!("entities?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz));
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let text = response.text().await?;
-        self.log_response(&text);
+        let text = self.get_text(&format!("entities?x={}&y={}&z={}&dx={}&dy={}&dz={}", x, y, z, dx, dy, dz)).await?;
         let entities: Vec<EntityResponse> = serde_json::from_str(&text)?;
         Ok(entities)
     }
 
-    pub async fn put_entities(&self, x: i32, y: i32, z: i32, entities : &Vec<PositionedEntity>) -> anyhow::Result<()> {
+    pub async fn put_entities(&self, x: i32, y: i32, z: i32, entities : &[PositionedEntity]) -> anyhow::Result<()> {
+        if self.is_replaying() {
+            return Ok(());
+        }
+
         let url = self.url(&format!("entities?x={}&y={}&z={}", x, y, z));
 
         let body = serde_json::to_string(&entities)?;
@@ -212,6 +309,32 @@ impl GDMCHTTPProvider {
         Ok(())
     }
 
+    /// Pastes `structure` with its corner at `(x, y, z)` through `POST /structure`.
+    pub async fn put_structure(&self, x: i32, y: i32, z: i32, structure: &NBTStructure, do_block_updates: bool) -> anyhow::Result<()> {
+        if self.is_replaying() {
+            return Ok(());
+        }
+
+        let url = self.url(&format!("structure?x={}&y={}&z={}&doBlockUpdates={}&entities=false", x, y, z, do_block_updates));
+        let response = self.client
+            .post(&url)
+            .body(structure.to_bytes()?)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        self.log_response(&text);
+        if !status.is_success() {
+            anyhow::bail!("structure paste failed with {}: {}", status, text);
+        }
+        let body: serde_json::Value = serde_json::from_str(&text).unwrap_or_default();
+        if body.get("status").and_then(|s| s.as_i64()) == Some(0) {
+            anyhow::bail!("structure paste rejected: {}", text);
+        }
+        Ok(())
+    }
+
     fn log_response(&self, text : &str) {
         if !self.log_responses {
             return;
@@ -225,16 +348,10 @@ impl GDMCHTTPProvider {
     }
 
     pub async fn get_nbt(&self, x: i32, y: i32, z: i32, dx: i32, dy: i32, dz: i32, entities: bool) -> anyhow::Result<NBTStructure> {
-        let url = self.url(&format!(
+        let raw_bytes = self.get_body(&format!(
             "structure?x={}&y={}&z={}&dx={}&dy={}&dz={}&entities={}",
             x, y, z, dx, dy, dz, entities
-        ));
-        let response = self.client
-            .get(&url)
-            .send()
-            .await?;
-
-        let raw_bytes = response.bytes().await?;
+        ), false).await?;
         let data = try_gunzip(&raw_bytes);
 
         if let std::result::Result::Ok(val) = fastnbt::from_bytes::<NBTStructure>(&data) {
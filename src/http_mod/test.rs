@@ -1,14 +1,24 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::http_mod::{GDMCHTTPProvider, PositionedBlock};
+    use crate::geometry::{LocalPoint3D, Point3D};
+    use crate::http_mod::mock_server::MockServer;
+    use std::time::Duration;
+
+    use crate::http_mod::mock_server::MockWorld;
+    use crate::http_mod::{AnvilBackend, GDMCConfig, GDMCHTTPProvider, HeightMapType, PositionedBlock, WorldBackend};
+    use crate::geometry::Rect3D;
+    use crate::editor::World;
+    use crate::minecraft::{Block, RegionFile};
+    use crate::minecraft::util::point_to_chunk_coordinates;
     use crate::util::init_logger;
 
     #[tokio::test]
     async fn get_blocks() {
         init_logger();
 
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = server.provider();
         let build_area = provider.get_build_area()
             .await
             .expect("Failed to get build area");
@@ -23,7 +33,8 @@ mod tests {
     async fn put_blocks() {
         init_logger();
 
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = server.provider();
         let build_area = provider.get_build_area()
             .await
             .expect("Failed to get build area");
@@ -51,6 +62,7 @@ mod tests {
             .expect("Failed to put blocks");
 
         assert_eq!(response.len(), 2, "Expected 2 block placement responses");
+        assert_eq!(server.world().get_block(Point3D::new(build_area.origin.x + 1, build_area.origin.y, build_area.origin.z)).id, "minecraft:stone".into());
 
     }
 
@@ -58,7 +70,8 @@ mod tests {
     async fn get_biomes() {
         init_logger();
 
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = server.provider();
         let build_area = provider.get_build_area()
             .await
             .expect("Failed to get build area");
@@ -74,22 +87,27 @@ mod tests {
     async fn get_chunks() {
         init_logger();
 
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = server.provider();
         let build_area = provider.get_build_area()
             .await
             .expect("Failed to get build area");
-        let chunks = provider.get_chunks(build_area.origin.x, build_area.origin.y, build_area.origin.z, build_area.size.x, build_area.size.y, build_area.size.z)
+        let from = point_to_chunk_coordinates(build_area.origin);
+        let size = point_to_chunk_coordinates(build_area.max()) - from + Point3D::new(1, 1, 1);
+        let chunks = provider.get_chunks(from.x, from.y, from.z, size.x, size.y, size.z)
             .await
             .expect("Failed to get chunks");
 
         log::info!("a section: {:?}", chunks[0].sections[0]);
+        assert!(chunks.iter().all(|c| c.sections.iter().all(|s| s.block_states.as_ref().and_then(|b| b.decode()).is_some())));
     }
 
     
     #[tokio::test]
     async fn test_give_book() {
         init_logger();
-        let provider = GDMCHTTPProvider::new();
+        let server = MockServer::flat().await;
+        let provider = server.provider();
         let title = "Test Book";
         let author = "Test Author";
         let pages = vec![
@@ -103,5 +121,203 @@ mod tests {
             .expect("Failed to give book");
 
         println!("Book given: {:?}", book);
+        assert!(server.world().commands()[0].starts_with("give @a written_book"));
+    }
+
+    #[test]
+    fn config_from_args() {
+        let args = ["tome", "--visualize", "--gdmc-host", "10.0.0.2", "--gdmc-port=9100", "--gdmc-retries", "5", "--gdmc-backoff-max=2500", "--gdmc-timeout", "soon"];
+        let config = GDMCConfig::default().with_args(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(config.base_url(), "http://10.0.0.2:9100");
+        assert_eq!(config.max_retries, 5);
+        assert_eq!(config.max_backoff, Duration::from_millis(2500));
+        // Unparseable values keep the default.
+        assert_eq!(config.timeout, GDMCConfig::default().timeout);
+    }
+
+    #[test]
+    fn base_url_updates_config() {
+        let provider = GDMCHTTPProvider::from_config(GDMCConfig::default()).with_base_url("http://10.0.0.2:9100/");
+        assert_eq!(provider.config().base_url(), "http://10.0.0.2:9100");
+    }
+
+    #[tokio::test]
+    async fn provider_from_config() {
+        init_logger();
+        let server = MockServer::flat().await;
+        let provider = GDMCHTTPProvider::from_config(GDMCConfig::default())
+            .with_host("127.0.0.1")
+            .with_port(server.port())
+            .with_timeout(Duration::from_secs(5))
+            .with_retries(0, Duration::from_millis(10), Duration::from_millis(10));
+
+        let build_area = provider.get_build_area().await.expect("Failed to get build area");
+        assert_eq!(build_area.origin, server.world().build_area.origin);
+        assert_eq!(build_area.size, server.world().build_area.size);
+    }
+
+    /// A save folder holding the mock world's chunks `(0..2, 0)` in one region file.
+    fn anvil_world(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("tome-anvil-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("region")).expect("Failed to create region folder");
+
+        let world = MockWorld::flat(Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(31, 255, 15)), 63);
+        let mut region = RegionFile::default();
+        for chunk_x in 0..2 {
+            let nbt = fastnbt::to_bytes(&world.chunk(chunk_x, 0)).expect("Failed to encode chunk");
+            region.set_chunk(chunk_x, 0, nbt, 0);
+        }
+        region.write(&dir.join("region/r.0.0.mca")).expect("Failed to write region");
+        dir
+    }
+
+    #[test]
+    fn region_file_roundtrip() {
+        let mut region = RegionFile::default();
+        region.set_chunk(-1, 33, vec![10, 0, 0, 0], 7);
+
+        let bytes = region.to_bytes().expect("Failed to encode region");
+        assert_eq!(bytes.len() % 4096, 0);
+        let read = RegionFile::from_bytes(&bytes).expect("Failed to decode region");
+        assert_eq!(read.chunk(31, 1), Some(&[10u8, 0, 0, 0][..]));
+        assert_eq!(read.chunk(0, 0), None);
+        assert!(RegionFile::from_bytes(&[]).expect("Empty files are valid").chunk(0, 0).is_none());
+    }
+
+    #[test]
+    fn region_file_external_chunks() {
+        use std::io::Write;
+
+        let mut region = RegionFile::default();
+        region.set_chunk(0, 0, vec![10, 0, 0, 0], 0);
+        let mut bytes = region.to_bytes().expect("Failed to encode region");
+        // Point slot 1, chunk (1, 0), at a zlib `.mcc` file.
+        let sector = (bytes.len() / 4096) as u32;
+        bytes[4..7].copy_from_slice(&sector.to_be_bytes()[1..]);
+        bytes[7] = 1;
+        let mut stub = vec![0, 0, 0, 1, 2 | 128];
+        stub.resize(4096, 0);
+        bytes.extend_from_slice(&stub);
+
+        // With no folder the external chunk is skipped, not the region.
+        let read = RegionFile::from_bytes(&bytes).expect("One external chunk shouldn't fail the region");
+        assert_eq!(read.chunk(0, 0), Some(&[10u8, 0, 0, 0][..]));
+        assert_eq!(read.chunk(1, 0), None);
+        // Written back, it still points at its `.mcc`.
+        let rewritten = read.to_bytes().expect("Failed to re-encode region");
+        assert_eq!(rewritten[4 + 3], 1);
+
+        let dir = std::env::temp_dir().join(format!("tome-mcc-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Failed to create region folder");
+        std::fs::write(dir.join("r.0.0.mca"), &rewritten).expect("Failed to write region");
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&[10, 0, 0, 1]).unwrap();
+        std::fs::write(dir.join("c.1.0.mcc"), encoder.finish().unwrap()).expect("Failed to write .mcc");
+        let read = RegionFile::read(&dir.join("r.0.0.mca")).expect("Failed to read region");
+        assert_eq!(read.chunk(1, 0), Some(&[10u8, 0, 0, 1][..]));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn anvil_backend_reads_and_writes_region_files() {
+        init_logger();
+        let dir = anvil_world("roundtrip");
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(31, 255, 15));
+        let backend = AnvilBackend::open(&dir, build_area).expect("Failed to open save");
+
+        let blocks = backend.get_blocks(5, 63, 5, 1, 2, 1).await.expect("Failed to get blocks");
+        assert_eq!(blocks[0].get_block().id, "minecraft:grass_block".into());
+        assert_eq!(blocks[1].get_block().id, "minecraft:air".into());
+        let heights = backend.get_heightmap(0, 0, 2, 2, HeightMapType::MotionBlocking).await.expect("Failed to get heightmap");
+        assert_eq!(heights, vec![vec![64, 64], vec![64, 64]]);
+
+        let chest = Block::new("minecraft:chest".into(), None, Some("{CustomName:'\"Ledger\"'}".to_string()));
+        let placed = vec![
+            PositionedBlock::from_block(Block::from_id("minecraft:stone".into()), Point3D::new(20, 70, 3).into()),
+            PositionedBlock::from_block(chest, Point3D::new(20, 64, 4).into()),
+            PositionedBlock::from_block(Block::from_id("minecraft:stone".into()), Point3D::new(40, 70, 3).into()),
+        ];
+        let responses = backend.put_blocks(&placed).await.expect("Failed to place blocks");
+        assert_eq!(responses.iter().map(|r| r.status).collect::<Vec<_>>(), vec![1, 1, 0], "chunk (2, 0) was never generated");
+        let heights = backend.get_heightmap(20, 3, 1, 1, HeightMapType::WorldSurface).await.expect("Failed to get heightmap");
+        assert_eq!(heights, vec![vec![71]]);
+        assert!(backend.command(vec!["say hi".to_string()]).await.is_err());
+        backend.save().await.expect("Failed to save");
+
+        let reopened = AnvilBackend::open(&dir, build_area).expect("Failed to reopen save");
+        let blocks = reopened.get_blocks(20, 70, 3, 1, 1, 1).await.expect("Failed to get blocks");
+        assert_eq!(blocks[0].get_block().id, "minecraft:stone".into());
+        let chunks = reopened.get_chunks(1, 0, 0, 1, 1, 1).await.expect("Failed to get chunks");
+        let entities = chunks[0].block_entities.as_ref().expect("Chest should have a block entity");
+        assert_eq!(entities.len(), 1);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn anvil_save_keeps_edits_until_their_region_is_written() {
+        init_logger();
+        let dir = anvil_world("retry");
+        let build_area = Rect3D::from_points(Point3D::new(0, 0, 0), Point3D::new(31, 255, 15));
+        let backend = AnvilBackend::open(&dir, build_area).expect("Failed to open save");
+        let placed = vec![PositionedBlock::from_block(Block::from_id("minecraft:stone".into()), Point3D::new(5, 70, 5).into())];
+        backend.put_blocks(&placed).await.expect("Failed to place blocks");
+
+        // A directory in the region file's place makes the write fail.
+        let region = dir.join("region/r.0.0.mca");
+        std::fs::remove_file(&region).expect("Failed to remove region");
+        std::fs::create_dir(&region).expect("Failed to block region");
+        assert!(backend.save().await.is_err());
+
+        std::fs::remove_dir(&region).expect("Failed to unblock region");
+        backend.save().await.expect("The failed edits should be saved on retry");
+        let reopened = AnvilBackend::open(&dir, build_area).expect("Failed to reopen save");
+        let blocks = reopened.get_blocks(5, 70, 5, 1, 1, 1).await.expect("Failed to get blocks");
+        assert_eq!(blocks[0].get_block().id, "minecraft:stone".into());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn record_then_replay_without_server() {
+        init_logger();
+        let cassette = std::env::temp_dir().join(format!("tome-cassette-{}.bin", std::process::id()));
+        let point = Point3D::new(4, 63, 9);
+        let (heights, recorded) = {
+            let server = MockServer::flat().await;
+            server.world().set_block(point, "minecraft:gold_block".into());
+            let provider = server.provider().with_recording(&cassette).expect("Failed to create cassette");
+            let world = World::new(&provider).await.expect("Failed to create world");
+            let heights = provider.get_heightmap(0, 0, 4, 4, HeightMapType::MotionBlocking).await.expect("Failed to get heightmap");
+            (heights, world.get_block(LocalPoint3D(point)).expect("Block should be in a loaded chunk"))
+        };
+        assert_eq!(recorded.id, "minecraft:gold_block".into());
+
+        // The server is gone; everything now comes off the cassette.
+        let provider = GDMCHTTPProvider::replay(&cassette).expect("Failed to load cassette");
+        let world = World::new(&provider).await.expect("Failed to replay world");
+        assert_eq!(world.get_block(LocalPoint3D(point)).expect("Block should be replayed").id, recorded.id);
+        assert_eq!(provider.get_heightmap(0, 0, 4, 4, HeightMapType::MotionBlocking).await.expect("Failed to replay heightmap"), heights);
+        let placed = provider.put_blocks(&[PositionedBlock::from_block(Block::from_id("stone".into()), point.into())]).await.expect("Writes succeed in replay");
+        assert_eq!(placed[0].status, 1);
+        assert!(provider.get_heightmap(1, 1, 4, 4, HeightMapType::MotionBlocking).await.is_err(), "unrecorded requests fail");
+        let _ = std::fs::remove_file(&cassette);
+    }
+
+    #[tokio::test]
+    async fn error_responses_are_not_recorded() {
+        init_logger();
+        let cassette = std::env::temp_dir().join(format!("tome-cassette-errors-{}.bin", std::process::id()));
+        let server = MockServer::flat().await;
+        // Nothing is served under this prefix, so every read is a 404.
+        let provider = GDMCHTTPProvider::new()
+            .with_base_url(format!("{}/missing", server.url()))
+            .with_retries(0, Duration::from_millis(10), Duration::from_millis(10))
+            .with_recording(&cassette)
+            .expect("Failed to create cassette");
+        assert!(provider.get_heightmap(0, 0, 4, 4, HeightMapType::MotionBlocking).await.is_err());
+        drop(provider);
+        assert_eq!(std::fs::metadata(&cassette).expect("Cassette should exist").len(), 0);
+        let _ = std::fs::remove_file(&cassette);
     }
-}
\ No newline at end of file
+}
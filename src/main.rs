@@ -1,5 +1,6 @@
-use crate::{data::Loadable, editor::World, generator::{buildings::place_buildings, chronicle::generate_chronicle, data::LoadedData, districts::{build_wall, generate_parcels, WallType}, materials::{Material, MaterialId, Placer}, style::Style, terrain::log_trees}, http_mod::GDMCHTTPProvider, noise::RNG, util::init_logger};
-
+use crate::{cli::{Args, Command}, util::init_logger};
+#[cfg(feature = "visualizer")]
+use crate::{data::Loadable, editor::World, generator::{buildings::place_buildings, chronicle::generate_chronicle, data::LoadedData, districts::{build_wall, generate_parcels, WallType}, materials::{Material, MaterialId, Placer}, style::Style, terrain::log_trees}, noise::RNG};
 
 pub mod geometry;
 pub mod minecraft;
@@ -11,14 +12,22 @@ pub mod util;
 pub mod data;
 pub mod config;
 pub mod ai;
+pub mod cli;
 
 #[cfg(feature = "visualizer")]
 pub mod visualizer;
 
 #[cfg(feature = "visualizer")]
-async fn run_generation(server: &visualizer::VisualizerServer) {
-    let provider = GDMCHTTPProvider::new();
-    let world = match World::new(&provider).await {
+async fn run_generation(server: &visualizer::VisualizerServer, args: &Args) {
+    let provider = match cli::connect(args) {
+        Ok(provider) => provider,
+        Err(e) => {
+            log::error!("Failed to connect: {e:#}");
+            server.update_error(format!("Failed to connect: {e:#}"));
+            return;
+        }
+    };
+    let world = match World::new(provider.as_ref()).await {
         Ok(w) => w,
         Err(e) => {
             log::error!("Failed to create world: {e}");
@@ -26,7 +35,7 @@ async fn run_generation(server: &visualizer::VisualizerServer) {
             return;
         }
     };
-    let mut editor = world.get_editor();
+    let mut editor = world.get_editor_with_backend(provider);
     let mut rng = RNG::new(32);
 
     // === Parcels ===
@@ -63,14 +72,14 @@ async fn run_generation(server: &visualizer::VisualizerServer) {
 
     // === Flush ===
     server.update_phase(visualizer::GenerationPhase::Flush);
-    editor.flush_buffer().await;
+    editor.save().await;
 
     // === Chronicle ===
     server.update_phase(visualizer::GenerationPhase::Chronicle);
     let dossier = crate::generator::settlement::minimal_dossier(
         &editor, crate::generator::buildings_v2::Culture::Medieval, &mut rng.derive(),
     );
-    if let Err(e) = generate_chronicle(&mut editor, &dossier).await {
+    if let Err(e) = generate_chronicle(&mut editor, &dossier, &[], &mut crate::ai::AiLog::default()).await {
         server.log("warn", &format!("Chronicle generation failed: {e}"));
     }
 
@@ -80,36 +89,22 @@ async fn run_generation(server: &visualizer::VisualizerServer) {
     server.update_phase(visualizer::GenerationPhase::Done);
 }
 
-/// A fresh seed for an interactive run, taken from the wall clock. Generation is
-/// fully deterministic in the seed, so the value printed by [`run_generation_once`]
-/// reproduces the exact town (pass it to `generate_town` to rebuild it).
-fn random_seed() -> i64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos() as i64)
-        .unwrap_or(12345)
-}
-
-async fn run_generation_once() {
-    let provider = GDMCHTTPProvider::new();
-    let world = World::new(&provider).await.unwrap();
-    let mut editor = world.get_editor();
-    // Random per-run seed so each interactive run is a different town; printed so
-    // a good one can be reproduced. `generate_town` takes the seed as a parameter,
-    // so tests can still pin it to a fixed value for determinism.
-    let seed = crate::noise::Seed(random_seed());
-    println!("Generating town with seed {}", seed.0);
-    // None → auto-select the culture from the build area's climate.
-    crate::generator::settlement::generate_town(&mut editor, seed, None).await;
-}
-
 #[tokio::main]
 async fn main() {
     dotenv::dotenv().ok();
     init_logger();
     log::info!("Running placement_in_parcels test");
 
-    let use_visualizer = std::env::args().any(|arg| arg == "--visualize");
+    let args = Args::from_env();
+    let command = match Command::from_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            log::error!("{e}");
+            std::process::exit(2);
+        }
+    };
+
+    let use_visualizer = command == Command::Generate && args.has("--visualize");
 
     #[cfg(feature = "visualizer")]
     if use_visualizer {
@@ -122,7 +117,7 @@ async fn main() {
         loop {
             server.wait_for_generate().await;
             log::info!("Generation requested, starting...");
-            run_generation(&server).await;
+            run_generation(&server, &args).await;
             log::info!("Generation complete. Waiting for next request...");
         }
     }
@@ -133,6 +128,9 @@ async fn main() {
     }
 
     if !use_visualizer {
-        run_generation_once().await;
+        if let Err(e) = command.run(&args).await {
+            log::error!("{e:#}");
+            std::process::exit(1);
+        }
     }
 }
@@ -5,6 +5,60 @@ use serde_derive::{Serialize, Deserialize};
 
 use crate::minecraft::Biome;
 
+/// Blocks per section edge; a section holds `SECTION_SIZE³` blocks.
+pub const SECTION_SIZE: usize = 16;
+/// Cells in one section, indexed `y * 256 + z * 16 + x`.
+pub const SECTION_VOLUME: usize = SECTION_SIZE * SECTION_SIZE * SECTION_SIZE;
+/// Biomes are stored per 4×4×4 cell: 64 per section, indexed `y * 16 + z * 4 + x`.
+pub const SECTION_BIOME_VOLUME: usize = 64;
+
+/// Bits per palette index for a palette of `len` entries, never below `min_bits`.
+fn bits_for(len: usize, min_bits: usize) -> usize {
+    let needed = (usize::BITS - len.saturating_sub(1).leading_zeros()) as usize;
+    needed.max(min_bits)
+}
+
+/// Packs indices `bits` wide into longs the way Minecraft (1.16+) does: as many
+/// whole entries per long as fit, low bits first, never spanning two longs.
+pub fn pack_indices(indices: &[usize], bits: usize) -> LongArray {
+    let per_long = 64 / bits;
+    let longs = indices
+        .chunks(per_long)
+        .map(|group| {
+            group.iter().enumerate().fold(0u64, |acc, (i, &index)| acc | ((index as u64) << (i * bits))) as i64
+        })
+        .collect();
+    LongArray::new(longs)
+}
+
+/// Inverse of [`pack_indices`]: reads `count` indices `bits` wide.
+pub fn unpack_indices(data: &LongArray, bits: usize, count: usize) -> Vec<usize> {
+    let per_long = 64 / bits;
+    let mask = (1u64 << bits) - 1;
+    (0..count)
+        .map(|i| {
+            let long = data.get(i / per_long).copied().unwrap_or(0) as u64;
+            ((long >> ((i % per_long) * bits)) & mask) as usize
+        })
+        .collect()
+}
+
+/// Builds a palette over `values`, returning it with each value's palette index.
+fn palettize<T: Clone + PartialEq>(values: &[T]) -> (Vec<T>, Vec<usize>) {
+    let mut palette: Vec<T> = Vec::new();
+    let indices = values
+        .iter()
+        .map(|value| match palette.iter().position(|p| p == value) {
+            Some(index) => index,
+            None => {
+                palette.push(value.clone());
+                palette.len() - 1
+            }
+        })
+        .collect();
+    (palette, indices)
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Chunks {
     #[serde(rename = "Chunks")]
@@ -49,7 +103,30 @@ pub struct BlockStates {
     pub data: Option<LongArray>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl BlockStates {
+    /// Encodes one section's `SECTION_VOLUME` blocks (in `y, z, x` order) into a
+    /// palette plus packed indices. A uniform section has no `data`, matching
+    /// what the game writes.
+    pub fn encode(blocks: &[Block]) -> Self {
+        let (palette, indices) = palettize(blocks);
+        let data = (palette.len() > 1).then(|| pack_indices(&indices, bits_for(palette.len(), 4)));
+        BlockStates { palette, data }
+    }
+
+    /// Inverse of [`encode`](Self::encode): all `SECTION_VOLUME` blocks in
+    /// `y, z, x` order. `None` if an index points outside the palette.
+    pub fn decode(&self) -> Option<Vec<Block>> {
+        match &self.data {
+            None => Some(vec![self.palette.first()?.clone(); SECTION_VOLUME]),
+            Some(data) => unpack_indices(data, bits_for(self.palette.len(), 4), SECTION_VOLUME)
+                .into_iter()
+                .map(|index| self.palette.get(index).cloned())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Block {
     #[serde(rename = "Name")]
     pub name: String,
@@ -57,6 +134,18 @@ pub struct Block {
     pub properties : Option<HashMap<String, String>>,
 }
 
+impl From<&crate::minecraft::Block> for Block {
+    fn from(block: &crate::minecraft::Block) -> Self {
+        Block { name: block.id.as_str().to_string(), properties: block.state.clone() }
+    }
+}
+
+impl From<&Block> for crate::minecraft::Block {
+    fn from(block: &Block) -> Self {
+        crate::minecraft::Block { id: block.name.as_str().into(), state: block.properties.clone(), data: None }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Biomes {
     #[serde(rename = "palette")]
@@ -67,6 +156,27 @@ pub struct Biomes {
     pub data: Option<LongArray>, 
 }
 
+impl Biomes {
+    /// Encodes one section's `SECTION_BIOME_VOLUME` biome cells.
+    pub fn encode(biomes: &[Biome]) -> Self {
+        let (palette, indices) = palettize(biomes);
+        let data = (palette.len() > 1).then(|| pack_indices(&indices, bits_for(palette.len(), 1)));
+        Biomes { biomes: palette, data }
+    }
+
+    /// Inverse of [`encode`](Self::encode): all `SECTION_BIOME_VOLUME` cells in
+    /// `y, z, x` order (each cell 4×4×4 blocks).
+    pub fn decode(&self) -> Option<Vec<Biome>> {
+        match &self.data {
+            None => Some(vec![self.biomes.first()?.clone(); SECTION_BIOME_VOLUME]),
+            Some(data) => unpack_indices(data, bits_for(self.biomes.len(), 1), SECTION_BIOME_VOLUME)
+                .into_iter()
+                .map(|index| self.biomes.get(index).cloned())
+                .collect(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct HeightMaps {
     #[serde(rename = "MOTION_BLOCKING")]
@@ -82,3 +192,39 @@ pub struct HeightMaps {
     #[serde(rename = "WORLD_SURFACE_WG")]
     pub world_surface_wg: Option<LongArray>,
 }
+
+impl HeightMaps {
+    /// Bits per column in a packed chunk heightmap (enough for a 384-block world).
+    const BITS: usize = 9;
+
+    /// Unpacks one stored heightmap into its 256 column heights (indexed
+    /// `z * 16 + x`) as absolute Y, the inverse of [`uniform`](Self::uniform).
+    pub fn unpack(data: &LongArray, min_y: i32) -> Vec<i32> {
+        unpack_indices(data, Self::BITS, SECTION_SIZE * SECTION_SIZE)
+            .into_iter()
+            .map(|h| h as i32 + min_y)
+            .collect()
+    }
+
+    /// Packs 256 absolute column heights (indexed `z * 16 + x`) into one stored
+    /// heightmap; the inverse of [`unpack`](Self::unpack).
+    pub fn pack(heights: &[i32], min_y: i32) -> LongArray {
+        let indices: Vec<usize> = heights.iter().map(|h| (h - min_y).max(0) as usize).collect();
+        pack_indices(&indices, Self::BITS)
+    }
+
+    /// Packs a chunk's 256 column heights (indexed `z * 16 + x`, each the first
+    /// air cell above the surface as an absolute Y) into every heightmap slot.
+    /// Stored values are relative to `min_y`, the world's bottom.
+    pub fn uniform(heights: &[i32], min_y: i32) -> Self {
+        let packed = Self::pack(heights, min_y);
+        HeightMaps {
+            motion_blocking: packed.clone(),
+            motion_blocking_no_leaves: packed.clone(),
+            ocean_floor: packed.clone(),
+            ocean_floor_wg: None,
+            world_surface: packed,
+            world_surface_wg: None,
+        }
+    }
+}
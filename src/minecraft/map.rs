@@ -0,0 +1,242 @@
+//! Map items: the fixed colour palette a map's pixels come from, and the
+//! `map_<id>.dat` file a filled map reads its 128×128 pixels from.
+//!
+//! A pixel is one byte, `base * 4 + shade`: one of the 61 base colours below
+//! (0 is transparent) at one of four brightnesses. The game shades by terrain
+//! height; painted maps use all four to get closer to the colours they copy.
+
+use std::io::Write;
+
+use flate2::{write::GzEncoder, Compression};
+use serde_derive::Serialize;
+
+use super::DATA_VERSION;
+
+/// Pixels along each side of a map.
+pub const MAP_SIZE: usize = 128;
+
+/// The transparent pixel, where the map shows the frame or paper behind it.
+pub const TRANSPARENT: u8 = 0;
+
+/// A base colour of the map palette: its id, its full-brightness RGB, and a
+/// solid block the game draws in that colour, if there's a sensible one to
+/// build a wall of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapColor {
+    pub id: u8,
+    pub rgb: [u8; 3],
+    pub block: Option<&'static str>,
+}
+
+const fn c(id: u8, r: u8, g: u8, b: u8, block: Option<&'static str>) -> MapColor {
+    MapColor { id, rgb: [r, g, b], block }
+}
+
+/// Every base colour but transparent, by id. Grass, foliage, water and the
+/// like have no block: their blocks are see-through, fall, spread or don't
+/// show their map colour on the side.
+pub const MAP_COLORS: [MapColor; 61] = [
+    c(1, 127, 178, 56, None),
+    c(2, 247, 233, 163, Some("minecraft:smooth_sandstone")),
+    c(3, 199, 199, 199, Some("minecraft:mushroom_stem")),
+    c(4, 255, 0, 0, Some("minecraft:redstone_block")),
+    c(5, 160, 160, 255, Some("minecraft:packed_ice")),
+    c(6, 167, 167, 167, Some("minecraft:iron_block")),
+    c(7, 0, 124, 0, None),
+    c(8, 255, 255, 255, Some("minecraft:white_concrete")),
+    c(9, 164, 168, 184, Some("minecraft:clay")),
+    c(10, 151, 109, 77, Some("minecraft:packed_mud")),
+    c(11, 112, 112, 112, Some("minecraft:stone")),
+    c(12, 64, 64, 255, None),
+    c(13, 143, 119, 72, Some("minecraft:oak_planks")),
+    c(14, 255, 252, 245, Some("minecraft:quartz_block")),
+    c(15, 216, 127, 51, Some("minecraft:orange_concrete")),
+    c(16, 178, 76, 216, Some("minecraft:magenta_concrete")),
+    c(17, 102, 153, 216, Some("minecraft:light_blue_concrete")),
+    c(18, 229, 229, 51, Some("minecraft:yellow_concrete")),
+    c(19, 127, 204, 25, Some("minecraft:lime_concrete")),
+    c(20, 242, 127, 165, Some("minecraft:pink_concrete")),
+    c(21, 76, 76, 76, Some("minecraft:gray_concrete")),
+    c(22, 153, 153, 153, Some("minecraft:light_gray_concrete")),
+    c(23, 76, 127, 153, Some("minecraft:cyan_concrete")),
+    c(24, 127, 63, 178, Some("minecraft:purple_concrete")),
+    c(25, 51, 76, 178, Some("minecraft:blue_concrete")),
+    c(26, 102, 76, 51, Some("minecraft:brown_concrete")),
+    c(27, 102, 127, 51, Some("minecraft:green_concrete")),
+    c(28, 153, 51, 51, Some("minecraft:red_concrete")),
+    c(29, 25, 25, 25, Some("minecraft:black_concrete")),
+    c(30, 250, 238, 77, None),
+    c(31, 92, 219, 213, Some("minecraft:prismarine_bricks")),
+    c(32, 74, 128, 255, Some("minecraft:lapis_block")),
+    c(33, 0, 217, 58, None),
+    c(34, 129, 86, 49, Some("minecraft:spruce_planks")),
+    c(35, 112, 2, 0, Some("minecraft:nether_bricks")),
+    c(36, 209, 177, 161, Some("minecraft:white_terracotta")),
+    c(37, 159, 82, 36, Some("minecraft:orange_terracotta")),
+    c(38, 149, 87, 108, Some("minecraft:magenta_terracotta")),
+    c(39, 112, 108, 138, Some("minecraft:light_blue_terracotta")),
+    c(40, 186, 133, 36, Some("minecraft:yellow_terracotta")),
+    c(41, 103, 117, 53, Some("minecraft:lime_terracotta")),
+    c(42, 160, 77, 78, Some("minecraft:pink_terracotta")),
+    c(43, 57, 41, 35, Some("minecraft:gray_terracotta")),
+    c(44, 135, 107, 98, Some("minecraft:light_gray_terracotta")),
+    c(45, 87, 92, 92, Some("minecraft:cyan_terracotta")),
+    c(46, 122, 73, 88, Some("minecraft:purple_terracotta")),
+    c(47, 76, 62, 92, Some("minecraft:blue_terracotta")),
+    c(48, 76, 50, 35, Some("minecraft:brown_terracotta")),
+    c(49, 76, 82, 42, Some("minecraft:green_terracotta")),
+    c(50, 142, 60, 46, Some("minecraft:red_terracotta")),
+    c(51, 37, 22, 16, Some("minecraft:black_terracotta")),
+    c(52, 189, 48, 49, None),
+    c(53, 148, 63, 97, Some("minecraft:crimson_planks")),
+    c(54, 92, 25, 29, Some("minecraft:crimson_hyphae")),
+    c(55, 22, 126, 134, None),
+    c(56, 58, 142, 140, Some("minecraft:warped_planks")),
+    c(57, 86, 44, 62, Some("minecraft:warped_hyphae")),
+    c(58, 20, 180, 133, Some("minecraft:warped_wart_block")),
+    c(59, 100, 100, 100, Some("minecraft:polished_deepslate")),
+    c(60, 216, 175, 147, Some("minecraft:raw_iron_block")),
+    c(61, 127, 167, 150, None),
+];
+
+/// Brightness of each shade, out of 255.
+const SHADES: [u32; 4] = [180, 220, 255, 135];
+
+/// The RGB the game shows for pixel `pixel`, or `None` if it's transparent or
+/// not in the palette.
+pub fn pixel_rgb(pixel: u8) -> Option<[u8; 3]> {
+    let base = MAP_COLORS.get((pixel / 4).checked_sub(1)? as usize)?;
+    let shade = SHADES[(pixel % 4) as usize];
+    Some(base.rgb.map(|v| (v as u32 * shade / 255) as u8))
+}
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter().zip(b).map(|(&a, b)| (a as i32 - b as i32).pow(2) as u32).sum()
+}
+
+/// The map pixel closest to `rgba`, over every base colour and shade;
+/// transparent where `rgba` is mostly so.
+pub fn nearest_pixel(rgba: [u8; 4]) -> u8 {
+    if rgba[3] < 128 {
+        return TRANSPARENT;
+    }
+    let rgb = [rgba[0], rgba[1], rgba[2]];
+    (4..MAP_COLORS.len() as u8 * 4 + 4)
+        .min_by_key(|&pixel| pixel_rgb(pixel).map_or(u32::MAX, |shown| distance(rgb, shown)))
+        .unwrap_or(TRANSPARENT)
+}
+
+/// The base colour with a block that's closest to `rgb`. A wall shows each
+/// block's colour at full brightness, so only that shade is compared.
+pub fn nearest_block_color(rgb: [u8; 3]) -> &'static MapColor {
+    MAP_COLORS
+        .iter()
+        .filter(|color| color.block.is_some())
+        .min_by_key(|color| distance(rgb, color.rgb))
+        .expect("the palette has blocks")
+}
+
+/// A locked map's saved data: what `map_<id>.dat` holds. `colors` is the
+/// 128×128 pixels row by row from the north-west corner, each covering
+/// `2^scale` blocks square, centred on `center` in world coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapData {
+    pub center: (i32, i32),
+    pub scale: u8,
+    pub colors: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct MapFile {
+    data: MapFileData,
+    #[serde(rename = "DataVersion")]
+    data_version: i32,
+}
+
+#[derive(Serialize)]
+struct MapFileData {
+    scale: i8,
+    dimension: String,
+    #[serde(rename = "xCenter")]
+    x_center: i32,
+    #[serde(rename = "zCenter")]
+    z_center: i32,
+    // Flags are bytes: this fastnbt can't write a `bool` field.
+    locked: i8,
+    #[serde(rename = "trackingPosition")]
+    tracking_position: i8,
+    #[serde(rename = "unlimitedTracking")]
+    unlimited_tracking: i8,
+    colors: fastnbt::ByteArray,
+}
+
+impl MapData {
+    /// The map as the gzip-compressed NBT of `data/map_<id>.dat`, locked so
+    /// the game never redraws it from the world.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        anyhow::ensure!(self.colors.len() == MAP_SIZE * MAP_SIZE, "a map has {} pixels, not {}", MAP_SIZE * MAP_SIZE, self.colors.len());
+        let file = MapFile {
+            data: MapFileData {
+                scale: self.scale as i8,
+                dimension: "minecraft:overworld".to_string(),
+                x_center: self.center.0,
+                z_center: self.center.1,
+                locked: 1,
+                tracking_position: 0,
+                unlimited_tracking: 0,
+                colors: fastnbt::ByteArray::new(self.colors.iter().map(|&p| p as i8).collect()),
+            },
+            data_version: DATA_VERSION,
+        };
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&fastnbt::to_bytes(&file)?)?;
+        Ok(encoder.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+    use fastnbt::Value;
+
+    use super::*;
+
+    #[test]
+    fn colours_quantize_to_their_own_pixel() {
+        for color in &MAP_COLORS {
+            for shade in 0..4 {
+                let pixel = color.id * 4 + shade;
+                let [r, g, b] = pixel_rgb(pixel).unwrap();
+                assert_eq!(pixel_rgb(nearest_pixel([r, g, b, 255])), Some([r, g, b]), "pixel {pixel}");
+            }
+        }
+        assert_eq!(pixel_rgb(TRANSPARENT), None);
+        assert_eq!(nearest_pixel([255, 255, 255, 0]), TRANSPARENT);
+        // Water has no block; a wall shows it in a blue that does.
+        let water = nearest_block_color([64, 64, 255]);
+        assert_eq!(water.block, Some("minecraft:lapis_block"));
+        assert_eq!(nearest_block_color([255, 255, 255]).block, Some("minecraft:white_concrete"));
+    }
+
+    #[test]
+    fn map_files_hold_a_locked_map_of_every_pixel() {
+        let map = MapData { center: (-40, 200), scale: 1, colors: vec![4 * 13 + 2; MAP_SIZE * MAP_SIZE] };
+        let mut nbt = Vec::new();
+        GzDecoder::new(map.to_bytes().unwrap().as_slice()).read_to_end(&mut nbt).unwrap();
+        let Value::Compound(file) = fastnbt::from_bytes::<Value>(&nbt).unwrap() else { panic!("not a compound") };
+        assert_eq!(file["DataVersion"], Value::Int(DATA_VERSION));
+        let Value::Compound(data) = &file["data"] else { panic!("no data") };
+        assert_eq!(data["xCenter"], Value::Int(-40));
+        assert_eq!(data["zCenter"], Value::Int(200));
+        assert_eq!(data["scale"], Value::Byte(1));
+        assert_eq!(data["locked"], Value::Byte(1));
+        assert_eq!(data["dimension"], Value::String("minecraft:overworld".to_string()));
+        let Value::ByteArray(colors) = &data["colors"] else { panic!("no colours") };
+        assert_eq!(colors.len(), MAP_SIZE * MAP_SIZE);
+        assert!(colors.iter().all(|&p| p == 54));
+
+        assert!(MapData { colors: vec![0; 10], ..map }.to_bytes().is_err());
+    }
+}
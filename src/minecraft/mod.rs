@@ -5,13 +5,19 @@ mod block_entity;
 mod form;
 mod color;
 mod block_id;
+mod region;
+mod schematic;
+mod map;
 
 pub mod util;
 pub use block::Block;
 pub use biomes::{Biome, BiomeWoodtype, BiomeStonetype};
-pub use chunk::{Chunks, Chunk};
+pub use chunk::{Chunks, Chunk, Block as ChunkBlock, ChunkSection, BlockStates, Biomes, HeightMaps, SECTION_SIZE, SECTION_VOLUME, SECTION_BIOME_VOLUME};
 pub use block_entity::BlockEntity;
 pub use form::BlockForm;
 pub use color::{Color, recolor_block, color_block};
 pub use block::string_to_block;
-pub use block_id::BlockID;
\ No newline at end of file
+pub use block_id::BlockID;
+pub use region::{RegionFile, region_file_name, REGION_SIZE};
+pub use map::{MapColor, MapData, MAP_COLORS, MAP_SIZE, TRANSPARENT, nearest_block_color, nearest_pixel, pixel_rgb};
+pub use schematic::{Schematic, SchematicBlocks, SchematicBlockEntity, block_state_string, DATA_VERSION};
\ No newline at end of file
@@ -0,0 +1,195 @@
+use std::{collections::HashMap, io::{Read, Write}, path::Path};
+
+use anyhow::{anyhow, bail};
+use flate2::{read::{GzDecoder, ZlibDecoder}, write::ZlibEncoder, Compression};
+use log::warn;
+
+/// Chunks along one edge of a region file.
+pub const REGION_SIZE: i32 = 32;
+const SECTOR_BYTES: usize = 4096;
+const HEADER_SECTORS: usize = 2;
+const CHUNKS_PER_REGION: usize = (REGION_SIZE * REGION_SIZE) as usize;
+
+const COMPRESSION_GZIP: u8 = 1;
+const COMPRESSION_ZLIB: u8 = 2;
+const COMPRESSION_NONE: u8 = 3;
+/// Set on the compression byte when the payload lives in a separate `.mcc` file.
+const EXTERNAL_FLAG: u8 = 128;
+
+/// One Anvil region file (`r.<x>.<z>.mca`): 32×32 chunks, each stored as
+/// compressed NBT behind a two-sector location/timestamp header.
+///
+/// Chunks are held decompressed. Reading accepts gzip, zlib and uncompressed
+/// payloads; writing always uses zlib, as the game does by default.
+///
+/// The game moves a chunk too big for the region into `c.<x>.<z>.mcc` beside
+/// it. [`read`](Self::read) loads those; [`from_bytes`](Self::from_bytes),
+/// with no folder to look in, leaves them out with a warning. Either way an
+/// untouched external chunk is written back still pointing at its `.mcc`.
+#[derive(Debug, Clone)]
+pub struct RegionFile {
+    chunks: Vec<Option<Vec<u8>>>,
+    timestamps: Vec<u32>,
+    /// Compression of each slot whose payload stays in its `.mcc` file.
+    external: HashMap<usize, u8>,
+}
+
+impl Default for RegionFile {
+    fn default() -> Self {
+        Self { chunks: vec![None; CHUNKS_PER_REGION], timestamps: vec![0; CHUNKS_PER_REGION], external: HashMap::new() }
+    }
+}
+
+/// Where a chunk's payload is: inline as decompressed NBT, or in a `.mcc`
+/// file with the given compression.
+enum Payload {
+    Inline(Vec<u8>),
+    External(u8),
+}
+
+/// The region file holding chunk `(chunk_x, chunk_z)`.
+pub fn region_file_name(chunk_x: i32, chunk_z: i32) -> String {
+    format!("r.{}.{}.mca", chunk_x.div_euclid(REGION_SIZE), chunk_z.div_euclid(REGION_SIZE))
+}
+
+fn slot(chunk_x: i32, chunk_z: i32) -> usize {
+    (chunk_x.rem_euclid(REGION_SIZE) + chunk_z.rem_euclid(REGION_SIZE) * REGION_SIZE) as usize
+}
+
+impl RegionFile {
+    /// The region file at `path`, with any external chunks loaded from the
+    /// `.mcc` files beside it.
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        let coords: Option<Vec<i32>> = name
+            .strip_prefix("r.")
+            .and_then(|rest| rest.strip_suffix(".mca"))
+            .map(|rest| rest.split('.').filter_map(|n| n.parse().ok()).collect());
+        let (Some(dir), Some(&[region_x, region_z])) = (path.parent(), coords.as_deref()) else {
+            return Self::from_bytes(&bytes);
+        };
+        Self::parse(&bytes, |index, compression| {
+            let chunk_x = region_x * REGION_SIZE + index as i32 % REGION_SIZE;
+            let chunk_z = region_z * REGION_SIZE + index as i32 / REGION_SIZE;
+            let mcc = dir.join(format!("c.{chunk_x}.{chunk_z}.mcc"));
+            std::fs::read(&mcc)
+                .map_err(anyhow::Error::from)
+                .and_then(|data| decompress(&data, compression))
+                .map_err(|e| anyhow!("{}: {e}", mcc.display()))
+        })
+    }
+
+    /// A region from its bytes alone. External chunks can't be found from
+    /// here, so they're left out.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Self::parse(bytes, |_, _| Err(anyhow!("no region folder to find its .mcc file in")))
+    }
+
+    /// Reads `bytes`, asking `external` for each chunk stored outside them by
+    /// slot and compression. A chunk `external` can't supply is skipped.
+    fn parse(bytes: &[u8], external: impl Fn(usize, u8) -> anyhow::Result<Vec<u8>>) -> anyhow::Result<Self> {
+        if bytes.len() < HEADER_SECTORS * SECTOR_BYTES {
+            // The game leaves zero-length region files behind; treat them as empty.
+            return Ok(Self::default());
+        }
+
+        let mut region = Self::default();
+        for index in 0..CHUNKS_PER_REGION {
+            let location = &bytes[index * 4..index * 4 + 4];
+            let offset = u32::from_be_bytes([0, location[0], location[1], location[2]]) as usize * SECTOR_BYTES;
+            let timestamp_at = SECTOR_BYTES + index * 4;
+            region.timestamps[index] = u32::from_be_bytes(bytes[timestamp_at..timestamp_at + 4].try_into()?);
+            if offset == 0 || location[3] == 0 {
+                continue;
+            }
+            match decode_payload(bytes, offset).map_err(|e| anyhow!("chunk slot {index}: {e}"))? {
+                Payload::Inline(nbt) => region.chunks[index] = Some(nbt),
+                Payload::External(compression) => {
+                    region.external.insert(index, compression);
+                    match external(index, compression) {
+                        Ok(nbt) => region.chunks[index] = Some(nbt),
+                        Err(e) => warn!("Skipping external chunk in slot {index}: {e}"),
+                    }
+                }
+            }
+        }
+        Ok(region)
+    }
+
+    /// The uncompressed NBT of chunk `(chunk_x, chunk_z)` (absolute chunk
+    /// coordinates), or `None` if it was never generated.
+    pub fn chunk(&self, chunk_x: i32, chunk_z: i32) -> Option<&[u8]> {
+        self.chunks[slot(chunk_x, chunk_z)].as_deref()
+    }
+
+    pub fn set_chunk(&mut self, chunk_x: i32, chunk_z: i32, nbt: Vec<u8>, timestamp: u32) {
+        let index = slot(chunk_x, chunk_z);
+        self.external.remove(&index);
+        self.chunks[index] = Some(nbt);
+        self.timestamps[index] = timestamp;
+    }
+
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        let mut header = vec![0u8; HEADER_SECTORS * SECTOR_BYTES];
+        let mut body = Vec::new();
+        for (index, chunk) in self.chunks.iter().enumerate() {
+            let mut payload = Vec::new();
+            if let Some(&compression) = self.external.get(&index) {
+                // Just the header, still pointing at the `.mcc` file.
+                payload.extend_from_slice(&1u32.to_be_bytes());
+                payload.push(compression | EXTERNAL_FLAG);
+            } else {
+                let Some(nbt) = chunk else { continue };
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(nbt)?;
+                let compressed = encoder.finish()?;
+                payload.extend_from_slice(&(compressed.len() as u32 + 1).to_be_bytes());
+                payload.push(COMPRESSION_ZLIB);
+                payload.extend_from_slice(&compressed);
+            }
+            let sectors = payload.len().div_ceil(SECTOR_BYTES);
+            if sectors > u8::MAX as usize {
+                bail!("chunk slot {index} needs {sectors} sectors; oversized chunks are not supported");
+            }
+            payload.resize(sectors * SECTOR_BYTES, 0);
+
+            let sector = HEADER_SECTORS + body.len() / SECTOR_BYTES;
+            header[index * 4..index * 4 + 3].copy_from_slice(&(sector as u32).to_be_bytes()[1..]);
+            header[index * 4 + 3] = sectors as u8;
+            header[SECTOR_BYTES + index * 4..SECTOR_BYTES + index * 4 + 4].copy_from_slice(&self.timestamps[index].to_be_bytes());
+            body.extend_from_slice(&payload);
+        }
+        header.extend_from_slice(&body);
+        Ok(header)
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+}
+
+fn decode_payload(bytes: &[u8], offset: usize) -> anyhow::Result<Payload> {
+    let header = bytes.get(offset..offset + 5).ok_or_else(|| anyhow!("offset past end of file"))?;
+    let length = u32::from_be_bytes(header[..4].try_into()?) as usize;
+    let compression = header[4];
+    if compression & EXTERNAL_FLAG != 0 {
+        return Ok(Payload::External(compression & !EXTERNAL_FLAG));
+    }
+    let data = bytes
+        .get(offset + 5..offset + 4 + length)
+        .ok_or_else(|| anyhow!("payload runs past end of file"))?;
+    Ok(Payload::Inline(decompress(data, compression)?))
+}
+
+fn decompress(data: &[u8], compression: u8) -> anyhow::Result<Vec<u8>> {
+    let mut nbt = Vec::new();
+    match compression {
+        COMPRESSION_GZIP => { GzDecoder::new(data).read_to_end(&mut nbt)?; }
+        COMPRESSION_ZLIB => { ZlibDecoder::new(data).read_to_end(&mut nbt)?; }
+        COMPRESSION_NONE => nbt.extend_from_slice(data),
+        other => bail!("unsupported compression type {other}"),
+    }
+    Ok(nbt)
+}
@@ -0,0 +1,207 @@
+use std::{collections::HashMap, io::{Read, Write}, path::Path};
+
+use anyhow::{anyhow, bail};
+use fastnbt::{ByteArray, IntArray, Value};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::geometry::Point3D;
+
+use super::Block;
+
+/// The data version written into exported schematics and structures: the
+/// 1.21.11 release the rest of the generator targets.
+pub const DATA_VERSION: i32 = 4671;
+
+/// A Sponge Schematic v3 (`.schem`), the format WorldEdit, FAWE and Axiom read.
+///
+/// Blocks are a palette of block-state strings plus one varint palette index
+/// per cell, in `x`, then `z`, then `y` order. Block entities carry their
+/// `Block::data` compound, positioned relative to the schematic's corner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Schematic {
+    #[serde(rename = "Version")]
+    pub version: i32,
+    #[serde(rename = "DataVersion")]
+    pub data_version: i32,
+    #[serde(rename = "Metadata", default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, Value>>,
+    #[serde(rename = "Width")]
+    pub width: i16,
+    #[serde(rename = "Height")]
+    pub height: i16,
+    #[serde(rename = "Length")]
+    pub length: i16,
+    /// Where the schematic's corner sat in the world it was cut from.
+    #[serde(rename = "Offset", default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<IntArray>,
+    #[serde(rename = "Blocks")]
+    pub blocks: SchematicBlocks,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchematicBlocks {
+    #[serde(rename = "Palette")]
+    pub palette: HashMap<String, i32>,
+    #[serde(rename = "Data")]
+    pub data: ByteArray,
+    #[serde(rename = "BlockEntities", default)]
+    pub block_entities: Vec<SchematicBlockEntity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SchematicBlockEntity {
+    #[serde(rename = "Pos")]
+    pub pos: IntArray,
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Data", default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<HashMap<String, Value>>,
+}
+
+/// The file's root compound: everything sits under a `Schematic` tag.
+#[derive(Deserialize)]
+struct SchematicFile {
+    #[serde(rename = "Schematic")]
+    schematic: Schematic,
+}
+
+#[derive(Serialize)]
+struct SchematicFileRef<'a> {
+    #[serde(rename = "Schematic")]
+    schematic: &'a Schematic,
+}
+
+/// `minecraft:`-qualified id, as schematic readers expect.
+fn namespaced(id: &str) -> String {
+    if id.contains(':') { id.to_string() } else { format!("minecraft:{}", id) }
+}
+
+/// The block-state string for `block`, e.g. `minecraft:oak_stairs[facing=east,half=top]`.
+/// Properties are sorted so equal states always share a palette entry.
+pub fn block_state_string(block: &Block) -> String {
+    let id = namespaced(block.id.as_str());
+    match &block.state {
+        Some(state) if !state.is_empty() => {
+            let mut properties: Vec<_> = state.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            properties.sort();
+            format!("{}[{}]", id, properties.join(","))
+        }
+        _ => id,
+    }
+}
+
+fn write_varint(out: &mut Vec<i8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte as i8);
+            return;
+        }
+        out.push((byte | 0x80) as i8);
+    }
+}
+
+fn read_varints(data: &[i8]) -> Vec<u32> {
+    let mut values = Vec::new();
+    let (mut value, mut shift) = (0u32, 0);
+    for &byte in data {
+        let byte = byte as u8;
+        value |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            values.push(value);
+            (value, shift) = (0, 0);
+        } else {
+            shift += 7;
+        }
+    }
+    values
+}
+
+impl Schematic {
+    /// Builds a `size` schematic by asking `block_at` for every cell (corner-
+    /// relative). `offset` records where that corner sits in the world.
+    pub fn from_fn(size: Point3D, offset: Point3D, mut block_at: impl FnMut(Point3D) -> Block) -> anyhow::Result<Self> {
+        if [size.x, size.y, size.z].iter().any(|&n| n <= 0 || n > u16::MAX as i32) {
+            bail!("schematic size {:?} is out of range", size);
+        }
+
+        let mut palette: HashMap<String, i32> = HashMap::new();
+        let mut data = Vec::with_capacity((size.x * size.y * size.z) as usize);
+        let mut block_entities = Vec::new();
+        for y in 0..size.y {
+            for z in 0..size.z {
+                for x in 0..size.x {
+                    let block = block_at(Point3D::new(x, y, z));
+                    let next = palette.len() as i32;
+                    let index = *palette.entry(block_state_string(&block)).or_insert(next);
+                    write_varint(&mut data, index as u32);
+
+                    let Some(snbt) = &block.data else { continue };
+                    match fastsnbt::from_str::<Value>(snbt) {
+                        Ok(Value::Compound(mut compound)) => {
+                            let id = match compound.remove("id") {
+                                Some(Value::String(id)) => id,
+                                _ => namespaced(block.id.as_str()),
+                            };
+                            for key in ["x", "y", "z"] {
+                                compound.remove(key);
+                            }
+                            block_entities.push(SchematicBlockEntity {
+                                pos: IntArray::new(vec![x, y, z]),
+                                id,
+                                data: Some(compound),
+                            });
+                        }
+                        _ => log::warn!("Dropping unparseable block entity data at {:?}: {}", Point3D::new(x, y, z), snbt),
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            version: 3,
+            data_version: DATA_VERSION,
+            metadata: None,
+            // Stored as unsigned shorts; values above i16::MAX wrap on purpose.
+            width: size.x as u16 as i16,
+            height: size.y as u16 as i16,
+            length: size.z as u16 as i16,
+            offset: Some(IntArray::new(vec![offset.x, offset.y, offset.z])),
+            blocks: SchematicBlocks { palette, data: ByteArray::new(data), block_entities },
+        })
+    }
+
+    pub fn size(&self) -> Point3D {
+        Point3D::new(self.width as u16 as i32, self.height as u16 as i32, self.length as u16 as i32)
+    }
+
+    /// Every cell's block-state string, in storage order (`x`, then `z`, then `y`).
+    pub fn block_states(&self) -> anyhow::Result<Vec<String>> {
+        let names: HashMap<i32, &String> = self.blocks.palette.iter().map(|(name, &index)| (index, name)).collect();
+        read_varints(&self.blocks.data)
+            .into_iter()
+            .map(|index| names.get(&(index as i32)).map(|name| name.to_string()).ok_or_else(|| anyhow!("palette index {index} is undefined")))
+            .collect()
+    }
+
+    /// Gzip-compressed NBT, as `.schem` files are stored.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        let nbt = fastnbt::to_bytes(&SchematicFileRef { schematic: self })?;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&nbt)?;
+        Ok(encoder.finish()?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut nbt = Vec::new();
+        GzDecoder::new(bytes).read_to_end(&mut nbt)?;
+        Ok(fastnbt::from_bytes::<SchematicFile>(&nbt)?.schematic)
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+}
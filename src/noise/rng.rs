@@ -1,8 +1,9 @@
+use serde_derive::{Deserialize, Serialize};
 use std::{collections::HashMap, hash::{DefaultHasher, Hash, Hasher}};
 
 use crate::geometry::{Point2D, Point3D};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Seed(pub i64);
 
 impl From<i64> for Seed {
@@ -11,6 +12,7 @@ impl From<i64> for Seed {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct RNG {
     seed: Seed,
     state: i64,
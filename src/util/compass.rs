@@ -1,4 +1,4 @@
-use crate::{editor::Editor, geometry::{EAST, NORTH, SOUTH, UP, WEST}};
+use crate::{editor::Editor, geometry::{EAST, NORTH, SOUTH, UP, WEST, LocalPoint3D}};
 
 pub async fn build_compass(editor: &Editor) {
     let midpoint = editor.world().world_rect_2d().size / 2;
@@ -6,9 +6,9 @@ pub async fn build_compass(editor: &Editor) {
     let offset = UP * 30;
     let point = point + offset;
 
-    editor.place_block(&"red_wool".into(), point + NORTH).await;
-    editor.place_block(&"stone".into(), point).await;
-    editor.place_block(&"blue_wool".into(), point + SOUTH).await;
-    editor.place_block(&"green_wool".into(), point + EAST).await;
-    editor.place_block(&"orange_wool".into(), point + WEST).await;
+    editor.place_block(&"red_wool".into(), LocalPoint3D(point + NORTH)).await;
+    editor.place_block(&"stone".into(), LocalPoint3D(point)).await;
+    editor.place_block(&"blue_wool".into(), LocalPoint3D(point + SOUTH)).await;
+    editor.place_block(&"green_wool".into(), LocalPoint3D(point + EAST)).await;
+    editor.place_block(&"orange_wool".into(), LocalPoint3D(point + WEST)).await;
 }
\ No newline at end of file
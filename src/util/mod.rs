@@ -2,6 +2,7 @@ mod logging;
 mod compass;
 mod mean;
 mod json_escape;
+pub mod pairs;
 
 pub use logging::init_logger;
 pub use compass::build_compass;
@@ -0,0 +1,20 @@
+//! Serde adapter for maps keyed by something JSON can't use as an object key
+//! (a `Point2D`, say): the map is written as a list of `[key, value]` pairs.
+//! Use as `#[serde(with = "crate::util::pairs")]` on a `HashMap` field.
+
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<K: Serialize, V: Serialize, S: Serializer>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(map)
+}
+
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Ok(Vec::<(K, V)>::deserialize(deserializer)?.into_iter().collect())
+}
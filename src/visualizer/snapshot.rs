@@ -1,5 +1,6 @@
 use crate::editor::World;
 use crate::generator::build_claim::BuildClaim;
+use crate::geometry::{LocalPoint3D, Point2D};
 
 use super::types::*;
 
@@ -149,12 +150,11 @@ pub fn extract_buildings(world: &World) -> BuildingsData {
 pub fn extract_claims(world: &World) -> ClaimMapData {
     let width = world.size().x as usize;
     let depth = world.size().z as usize;
-    let claim_map = world.get_build_claim_map();
-
     let mut claims = Vec::with_capacity(width * depth);
     for x in 0..width {
         for z in 0..depth {
-            let claim_str = match &claim_map[x][z] {
+            let claim = world.get_claim(Point2D::new(x as i32, z as i32)).unwrap_or(BuildClaim::None);
+            let claim_str = match claim {
                 BuildClaim::None => "none",
                 BuildClaim::Nature => "nature",
                 BuildClaim::Wall => "wall",
@@ -163,6 +163,9 @@ pub fn extract_claims(world: &World) -> ClaimMapData {
                 BuildClaim::PathPlanned(_) => "path",
                 BuildClaim::Building(_) => "building",
                 BuildClaim::Structure(_) => "structure",
+                BuildClaim::ProductionArea(_) => "production",
+                BuildClaim::Ship => "ship",
+                BuildClaim::MapBoard => "structure",
             };
             claims.push(claim_str.to_string());
         }
@@ -195,7 +198,7 @@ pub fn extract_blocks(world: &World) -> BlockMapData {
                 blocks.push("water".to_string());
             } else {
                 // Read the block at ground level; if air/plant, try one below
-                let block = world.get_block(crate::geometry::Point3D::new(x as i32, surface_y, z as i32));
+                let block = world.get_block(LocalPoint3D(crate::geometry::Point3D::new(x as i32, surface_y, z as i32)));
                 let block_id = block.as_ref().map(|b| b.id.as_str()).unwrap_or("air");
 
                 // Strip minecraft: prefix for consistent lookup
@@ -208,7 +211,7 @@ pub fn extract_blocks(world: &World) -> BlockMapData {
                     || id.contains("cornflower") || id == "dead_bush" || id == "sugar_cane"
                 {
                     // Non-solid surface block — read one below for the actual ground
-                    let below = world.get_block(crate::geometry::Point3D::new(x as i32, surface_y - 1, z as i32));
+                    let below = world.get_block(LocalPoint3D(crate::geometry::Point3D::new(x as i32, surface_y - 1, z as i32)));
                     blocks.push(below.map(|b| b.id.as_str().to_string()).unwrap_or_else(|| id.to_string()));
                 } else {
                     blocks.push(block_id.to_string());